@@ -1,18 +1,47 @@
 use dioxus_core::Template;
-use torin::prelude::CursorPoint;
+use freya_engine::prelude::DirectContext;
+use freya_native_core::NodeId;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+};
 use uuid::Uuid;
 use winit::window::{
     CursorIcon,
     Window,
 };
 
+use crate::CursorAffinity;
+
 pub struct TextGroupMeasurement {
     pub text_id: Uuid,
     pub cursor_id: usize,
     pub cursor_position: Option<CursorPoint>,
     pub cursor_selection: Option<(CursorPoint, CursorPoint)>,
+    pub cursor_line_movement: Option<CursorLineMovement>,
+}
+
+/// Move a cursor across the visual lines of a paragraph, wrapped lines included.
+pub struct CursorLineMovement {
+    /// Current position of the cursor, in UTF-16 code units.
+    pub position: usize,
+    /// Which side of a line wrap the cursor is currently shown at.
+    pub affinity: CursorAffinity,
+    /// How many lines to move, negative values move upwards.
+    pub lines: isize,
+    /// Horizontal offset to aim for, defaults to the current offset of the cursor.
+    pub x: Option<f32>,
 }
 
+/// Called with the captured PNG image of a screenshot, or `None` if it couldn't be captured.
+pub type ScreenshotCallback = Box<dyn FnOnce(Option<Vec<u8>>) + Send + Sync>;
+
+/// Called with the topmost element at a point of the Window, or `None` if there is none.
+pub type NodeAtPointCallback = Box<dyn FnOnce(Option<NodeId>) + Send + Sync>;
+
+/// Called with the GPU context of the Window, in the thread it's rendered in.
+pub type GpuContextCallback = Box<dyn FnOnce(&mut DirectContext) + Send + Sync>;
+
 /// Custom EventLoop messages
 pub enum EventMessage {
     /// Update the given template
@@ -25,6 +54,8 @@ pub enum EventMessage {
     RemeasureTextGroup(TextGroupMeasurement),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
+    /// Emit a mouse movement at the current cursor position, so the elements that moved under it react to it
+    ReplayCursorMovement,
     /// Accessibility Window Event
     Accessibility(accesskit_winit::WindowEvent),
     /// Focus the given accessibility NodeID
@@ -39,6 +70,12 @@ pub enum EventMessage {
     ExitApp,
     /// Callback to access the Window.
     WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
+    /// Capture the next rendered frame as a PNG image, only the given area of it if any.
+    CaptureScreenshot(Option<Area>, ScreenshotCallback),
+    /// Find the topmost element at the given point of the Window, in logical pixels.
+    NodeAtPoint(CursorPoint, NodeAtPointCallback),
+    /// Callback to access the GPU context of the Window, it's not called if the app is not rendered with the GPU.
+    WithGpuContext(GpuContextCallback),
 }
 
 impl From<accesskit_winit::Event> for EventMessage {
@@ -1,6 +1,12 @@
-use std::ops::Div;
+use std::{
+    fmt,
+    ops::Div,
+};
 
-use freya_engine::prelude::Paragraph;
+use freya_engine::prelude::{
+    Affinity,
+    Paragraph,
+};
 use torin::geometry::{
     Area,
     Size2D,
@@ -9,26 +15,211 @@ use torin::geometry::{
 /// Layout info of a certain Node, used by `use_node`.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct NodeReferenceLayout {
+    /// Area of the Node in window coordinates, after the offsets of all its ancestors, including their scroll offsets.
+    /// Useful to anchor elements rendered elsewhere in the tree, e.g in a different layer, to this Node.
     pub area: Area,
+    /// Size occupied by the children of the Node.
     pub inner: Size2D,
+    /// Children marked with `snap_align`.
+    pub snap_points: Vec<SnapPoint>,
+    /// Areas of the children laid out in the flow of the Node, so not `absolute` or `global`, relative to the area of the Node.
+    /// Only reported when referenced with `ReferenceChildren::All`.
+    pub children: Vec<Area>,
+    /// Area of the window where the Node can be seen, in window coordinates.
+    /// It's the window area clipped by all the ancestors of the Node with `overflow: clip`, e.g a `ScrollView`.
+    pub viewport: Area,
+    /// Visual lines of the text, wrapped lines included, if the Node is a `label` or a `paragraph`.
+    pub text_lines: Vec<TextLineMetrics>,
+    /// Whether the text has more lines than its `max_lines`, so some of them are cut off,
+    /// if the Node is a `label` or a `paragraph`.
+    pub exceeded_max_lines: bool,
 }
 
 impl NodeReferenceLayout {
     pub fn div(&mut self, rhs: f32) {
         self.area = self.area.div(rhs);
         self.inner = self.inner.div(rhs);
+        self.viewport = self.viewport.div(rhs);
+        for snap_point in &mut self.snap_points {
+            snap_point.area = snap_point.area.div(rhs);
+        }
+        for child in &mut self.children {
+            *child = child.div(rhs);
+        }
+        for line in &mut self.text_lines {
+            line.div(rhs);
+        }
+    }
+
+    /// Check if any part of the Node is inside of its viewport.
+    /// `buffer` extends the area of the Node in every direction, so it's visible before it's actually scrolled into view.
+    pub fn is_visible(&self, buffer: f32) -> bool {
+        self.viewport.intersects(&self.area.inflate(buffer, buffer))
+    }
+}
+
+/// Metrics of a visual line of a measured `label` or `paragraph`.
+///
+/// The offsets are relative to the top-left corner of the Node without its margin.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLineMetrics {
+    /// Number of the line, starting from `0`.
+    pub line_number: usize,
+    /// Index in the text where the line starts.
+    pub start_index: usize,
+    /// Index in the text where the line ends, excluding the line break.
+    pub end_index: usize,
+    /// Index in the text where the line ends, excluding the trailing whitespaces.
+    pub end_excluding_whitespaces: usize,
+    /// Whether the line ends with a line break instead of being wrapped.
+    pub hard_break: bool,
+    /// Vertical offset of the baseline.
+    pub baseline: f32,
+    /// Distance from the baseline to the top of the tallest glyph.
+    pub ascent: f32,
+    /// Distance from the baseline to the bottom of the lowest glyph.
+    pub descent: f32,
+    /// Horizontal offset where the line starts, which depends on the text alignment.
+    pub left: f32,
+    /// Width of the line, without the trailing whitespaces.
+    pub width: f32,
+    /// Total height of the line.
+    pub height: f32,
+}
+
+impl TextLineMetrics {
+    /// Vertical offset of the top of the line.
+    pub fn top(&self) -> f32 {
+        self.baseline - self.ascent
+    }
+
+    /// Vertical offset of the bottom of the line.
+    pub fn bottom(&self) -> f32 {
+        self.top() + self.height
+    }
+
+    pub fn div(&mut self, rhs: f32) {
+        self.baseline /= rhs;
+        self.ascent /= rhs;
+        self.descent /= rhs;
+        self.left /= rhs;
+        self.width /= rhs;
+        self.height /= rhs;
+    }
+}
+
+/// How a child is aligned to the viewport of its scrollable parent when snapping to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapAlign {
+    Start,
+    Center,
+    End,
+}
+
+/// A child marked with `snap_align`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapPoint {
+    /// Area of the child relative to the content of its parent, without the scroll offsets.
+    pub area: Area,
+    pub align: SnapAlign,
+}
+
+/// Which side of a line wrap a cursor is placed at, as the same position in the text
+/// is both the end of a wrapped line and the start of the next one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorAffinity {
+    /// At the end of the line before the position.
+    Upstream,
+    /// At the start of the line of the position.
+    #[default]
+    Downstream,
+}
+
+impl From<Affinity> for CursorAffinity {
+    fn from(affinity: Affinity) -> Self {
+        match affinity {
+            Affinity::Upstream => Self::Upstream,
+            Affinity::Downstream => Self::Downstream,
+        }
+    }
+}
+
+impl fmt::Display for CursorAffinity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Upstream => "upstream",
+            Self::Downstream => "downstream",
+        })
     }
 }
 
 /// Messages emitted from the layout library to the Nodes. Used in `use_editable`.
 #[derive(Debug)]
 pub enum CursorLayoutResponse {
-    CursorPosition { position: usize, id: usize },
-    TextSelection { from: usize, to: usize, id: usize },
+    CursorPosition {
+        position: usize,
+        affinity: CursorAffinity,
+        id: usize,
+    },
+    TextSelection {
+        from: usize,
+        to: usize,
+        id: usize,
+    },
+    CursorLineMoved {
+        position: usize,
+        affinity: CursorAffinity,
+        x: f32,
+        id: usize,
+    },
 }
 
 pub struct CachedParagraph(pub Paragraph);
 
+/// Same layout as the [CachedParagraph] of a measured paragraph but only painting the outline of its glyphs,
+/// and the shadows of the text filled with a gradient, so the fill doesn't tint them.
+/// Only present when any of its text has a `text_stroke_width`, or shadows and a gradient fill.
+pub struct CachedStrokeParagraph(pub Paragraph);
+
+/// Present in the measured data of a `label` whose text was trimmed from its middle to fit, with `text_overflow: ellipsis-middle`.
+pub struct EllipsizedMiddle;
+
+/// Indices of the text of a measured paragraph where zero-width spaces were inserted, so its lines
+/// can be broken between any two graphemes with `word_break: "break-all"`, before the small kana of Japanese text,
+/// or at the hyphenation points of its words with `hyphens: "auto"`. The ones where a line is hyphenated are laid out as hyphens.
+///
+/// They are UTF-16 indices of the original text, sorted. The indices of the [CachedParagraph] include
+/// the inserted zero-width spaces, so they must be mapped from and to the ones of the text with this.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InsertedBreaks(pub Vec<usize>);
+
+impl InsertedBreaks {
+    /// Map an index of the original text to the laid out text.
+    /// Indices where a break was inserted are placed after it, so they stay next to the following glyph.
+    pub fn to_layout_index(&self, index: usize) -> usize {
+        index + self.0.partition_point(|inserted| *inserted <= index)
+    }
+
+    /// Map an index of the laid out text back to the original text.
+    /// Indices at either side of an inserted break map to the same index.
+    pub fn to_text_index(&self, index: usize) -> usize {
+        let inserted_before = self
+            .0
+            .iter()
+            .enumerate()
+            .take_while(|(i, inserted)| *inserted + i < index)
+            .count();
+        index - inserted_before
+    }
+}
+
+/// How many glyphs of a measured paragraph couldn't be shaped with any of the available fonts.
+pub struct UnresolvedGlyphs(pub usize);
+
+/// Font families requested with `font_family` in a measured paragraph that were not found,
+/// and so fell back to the default fonts. Only collected in debug builds.
+pub struct MissingFontFamilies(pub Vec<String>);
+
 /// # Safety
 /// Skia `Paragraph` are neither Sync or Send, but in order to store them in the Associated
 /// data of the Nodes in Torin (which will be used across threads when making the attributes diffing),
@@ -36,3 +227,5 @@ pub struct CachedParagraph(pub Paragraph);
 /// In the main thread when measuring the layout and painting.
 unsafe impl Send for CachedParagraph {}
 unsafe impl Sync for CachedParagraph {}
+unsafe impl Send for CachedStrokeParagraph {}
+unsafe impl Sync for CachedStrokeParagraph {}
@@ -0,0 +1,28 @@
+use std::sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+};
+
+use freya_native_core::NodeId;
+use rustc_hash::FxHashSet;
+
+/// Nodes with a `layout_transition`, whose children move when their position in the layout changes.
+#[derive(Default, Clone)]
+pub struct LayoutTransitionNodes {
+    pub nodes: Arc<Mutex<FxHashSet<NodeId>>>,
+}
+
+impl LayoutTransitionNodes {
+    pub fn insert_node(&self, node_id: NodeId) {
+        self.nodes.lock().unwrap().insert(node_id);
+    }
+
+    pub fn remove_node(&self, node_id: NodeId) {
+        self.nodes.lock().unwrap().remove(&node_id);
+    }
+
+    pub fn nodes(&self) -> MutexGuard<FxHashSet<NodeId>> {
+        self.nodes.lock().unwrap()
+    }
+}
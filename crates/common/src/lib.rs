@@ -1,9 +1,11 @@
 mod event_messages;
 mod layers;
 mod layout;
+mod layout_transitions;
 mod paragraphs;
 
 pub use event_messages::*;
 pub use layers::*;
 pub use layout::*;
+pub use layout_transitions::*;
 pub use paragraphs::*;
@@ -94,6 +94,7 @@ pub fn Accordion(props: AccordionProps) -> Element {
             overflow: "clip",
             color: "{color}",
             padding: "10",
+            box_sizing: "border-box",
             margin: "2 4",
             corner_radius: "6",
             width: "100%",
@@ -179,6 +179,7 @@ pub fn Button(
             width: "{width}",
             height: "{height}",
             padding: "{padding}",
+            box_sizing: "border-box",
             margin: "{margin}",
             focusable: "true",
             overflow: "clip",
@@ -84,6 +84,7 @@ pub fn Checkbox(
             width: "18",
             height: "18",
             padding: "4",
+            box_sizing: "border-box",
             main_align: "center",
             cross_align: "center",
             corner_radius: "4",
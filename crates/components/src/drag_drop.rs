@@ -3,8 +3,17 @@ use freya_elements::{
     elements as dioxus_elements,
     events::MouseEvent,
 };
-use freya_hooks::use_node_signal;
-use torin::prelude::CursorPoint;
+use freya_hooks::{
+    use_node_signal,
+    use_node_signal_with_children,
+};
+use freya_node_state::ReferenceChildren;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+    DirectionMode,
+    Point2D,
+};
 
 /// Properties for the [`DragProvider`] component.
 #[derive(Props, Clone, PartialEq)]
@@ -29,15 +38,21 @@ pub struct DragZoneProps<T: Clone + 'static + PartialEq> {
     children: Element,
     /// Data that will be handled to the destination [`DropZone`].
     data: T,
+    /// Handler for the `ondragstart` event, called with the data when the element is picked up.
+    ondragstart: Option<EventHandler<T>>,
 }
 
 /// Make the inner children draggable to other [`DropZone`].
+///
+/// The drag starts when pressing the mouse over the children and ends when releasing it anywhere,
+/// while the `drag_element` follows the cursor.
 #[allow(non_snake_case)]
 pub fn DragZone<T: 'static + Clone + PartialEq>(
     DragZoneProps {
         data,
         children,
         drag_element,
+        ondragstart,
     }: DragZoneProps<T>,
 ) -> Element {
     let mut drags = use_context::<Signal<Option<T>>>();
@@ -71,6 +86,9 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
         );
         dragging.set(true);
         *drags.write() = Some(data.clone());
+        if let Some(ondragstart) = &ondragstart {
+            ondragstart.call(data.clone());
+        }
     };
 
     let onglobalclick = move |_: MouseEvent| {
@@ -108,30 +126,243 @@ pub struct DropZoneProps<T: 'static + PartialEq + Clone> {
     children: Element,
     /// Handler for the `ondrop` event.
     ondrop: EventHandler<T>,
+    /// Handler for the `ondragover` event, called with the dragged data while it's moved over the DropZone.
+    ondragover: Option<EventHandler<T>>,
+    /// Decide whether the dragged data can be dropped here. All data is accepted by default.
+    accepts: Option<fn(&T) -> bool>,
 }
 
 /// Elements from [`DragZone`]s can be dropped here.
+///
+/// The data is dropped on the innermost DropZone under the cursor when the mouse is released.
+/// DropZones that don't accept the data behave as if they weren't there, so it can still be dropped on an outer one,
+/// and if no DropZone accepts it the drag simply ends.
 #[allow(non_snake_case)]
 pub fn DropZone<T: 'static + Clone + PartialEq>(props: DropZoneProps<T>) -> Element {
     let mut drags = use_context::<Signal<Option<T>>>();
 
+    let is_accepted = move |data: &T| props.accepts.map_or(true, |accepts| accepts(data));
+
+    let onmouseover = move |_: MouseEvent| {
+        if let Some(ondragover) = &props.ondragover {
+            if let Some(current_drags) = &*drags.read() {
+                if is_accepted(current_drags) {
+                    ondragover.call(current_drags.clone());
+                }
+            }
+        }
+    };
+
     let onclick = move |_: MouseEvent| {
-        if let Some(current_drags) = &*drags.read() {
-            props.ondrop.call(current_drags.clone());
+        let Some(current_drags) = drags.read().clone() else {
+            return;
+        };
+        if is_accepted(&current_drags) {
+            props.ondrop.call(current_drags);
+            *drags.write() = None;
+        }
+    };
+
+    rsx!(
+        rect {
+            onmouseover,
+            onclick,
+            {props.children}
+        }
+    )
+}
+
+/// Properties for the [`ListDropZone`] component.
+#[derive(Props, PartialEq, Clone)]
+pub struct ListDropZoneProps<T: 'static + PartialEq + Clone> {
+    /// Items of the list.
+    children: Element,
+    /// Handler for the `ondrop` event, called with the dragged data and the index among the items where it's dropped.
+    ondrop: EventHandler<(T, usize)>,
+    /// Handler for the `ondragover` event, called with the dragged data and the index where it would be dropped while it's moved over the list.
+    ondragover: Option<EventHandler<(T, usize)>>,
+    /// Decide whether the dragged data can be dropped here. All data is accepted by default.
+    accepts: Option<fn(&T) -> bool>,
+    /// Direction the items are laid out in. Default `vertical`.
+    #[props(default)]
+    direction: DirectionMode,
+    /// Color of the line shown where the data would be dropped. Default `rgb(30, 90, 200)`.
+    #[props(default = "rgb(30, 90, 200)".to_string(), into)]
+    indicator_color: String,
+    /// Thickness of the line shown where the data would be dropped. Default `2`.
+    #[props(default = 2.0)]
+    indicator_thickness: f32,
+}
+
+/// A [`DropZone`] for lists, useful to reorder them.
+///
+/// While accepted data is dragged over the list, a line between the two items closest to the cursor shows where it would be dropped.
+/// The handlers get the index it would be inserted at, from `0` (before the first item) to the number of items (after the last one).
+/// Children that take no space are not counted as items, like the element of a [`DragZone`] that follows the cursor.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut items = use_signal(|| vec!["A", "B", "C"]);
+///
+///     rsx!(
+///         DragProvider::<usize> {
+///             ListDropZone {
+///                 ondrop: move |(from, to): (usize, usize)| {
+///                     let mut items = items.write();
+///                     let item = items.remove(from);
+///                     items.insert(if to > from { to - 1 } else { to }, item);
+///                 },
+///                 for (i, item) in items.read().iter().enumerate() {
+///                     DragZone {
+///                         key: "{item}",
+///                         data: i,
+///                         drag_element: rsx!(label { "{item}" }),
+///                         label { "{item}" }
+///                     }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ListDropZone<T: 'static + Clone + PartialEq>(props: ListDropZoneProps<T>) -> Element {
+    let mut drags = use_context::<Signal<Option<T>>>();
+    let (node_reference, layout) = use_node_signal_with_children(ReferenceChildren::All);
+    let mut drop_index = use_signal(|| None::<usize>);
+
+    let is_accepted = move |data: &T| props.accepts.map_or(true, |accepts| accepts(data));
+    let find_drop_index = move |e: &MouseEvent, direction: &DirectionMode| {
+        let layout = layout.read();
+        let cursor = e.get_screen_coordinates().to_f32() - layout.area.origin.to_vector();
+        list_drop_index(&list_items(&layout.children), cursor, direction)
+    };
+
+    let direction = props.direction.clone();
+    let onmouseover = move |e: MouseEvent| {
+        let Some(current_drags) = &*drags.read() else {
+            return;
+        };
+        if is_accepted(current_drags) {
+            let index = find_drop_index(&e, &direction);
+            if *drop_index.peek() != Some(index) {
+                drop_index.set(Some(index));
+            }
+            if let Some(ondragover) = &props.ondragover {
+                ondragover.call((current_drags.clone(), index));
+            }
         }
-        if drags.read().is_some() {
+    };
+
+    let onmouseleave = move |_: MouseEvent| {
+        drop_index.set(None);
+    };
+
+    let direction = props.direction.clone();
+    let onclick = move |e: MouseEvent| {
+        let Some(current_drags) = drags.read().clone() else {
+            return;
+        };
+        if is_accepted(&current_drags) {
+            drop_index.set(None);
+            props
+                .ondrop
+                .call((current_drags, find_drop_index(&e, &direction)));
             *drags.write() = None;
         }
     };
 
+    // Hide the indicator once the drag ends elsewhere
+    let indicator = drop_index
+        .read()
+        .filter(|_| drags.read().is_some())
+        .map(|index| {
+            let thickness = props.indicator_thickness;
+            let offset = list_indicator_offset(
+                &list_items(&layout.read().children),
+                index,
+                &props.direction,
+            ) - thickness / 2.0;
+            match props.direction {
+                DirectionMode::Vertical => (
+                    format!("{offset}"),
+                    "0".to_string(),
+                    "100%".to_string(),
+                    format!("{thickness}"),
+                ),
+                DirectionMode::Horizontal => (
+                    "0".to_string(),
+                    format!("{offset}"),
+                    format!("{thickness}"),
+                    "100%".to_string(),
+                ),
+            }
+        });
+    let show_indicator = indicator.is_some();
+    let (top, left, width, height) = indicator.unwrap_or_default();
+
     rsx!(
         rect {
+            reference: node_reference,
+            direction: props.direction.pretty(),
+            onmouseover,
+            onmouseleave,
             onclick,
             {props.children}
+            if show_indicator {
+                rect {
+                    position: "absolute",
+                    position_top: "{top}",
+                    position_left: "{left}",
+                    width: "{width}",
+                    height: "{height}",
+                    background: "{props.indicator_color}",
+                }
+            }
         }
     )
 }
 
+/// Areas of the items of a list, without the children that take no space like the `drag_element` of a [`DragZone`].
+fn list_items(children: &[Area]) -> Vec<Area> {
+    children
+        .iter()
+        .filter(|child| !child.is_empty())
+        .copied()
+        .collect()
+}
+
+/// Index where data dropped at the given `cursor` is inserted among the `children` of a list, before the first one whose center is after the cursor.
+fn list_drop_index(children: &[Area], cursor: Point2D, direction: &DirectionMode) -> usize {
+    children
+        .iter()
+        .position(|child| match direction {
+            DirectionMode::Vertical => cursor.y < child.center().y,
+            DirectionMode::Horizontal => cursor.x < child.center().x,
+        })
+        .unwrap_or(children.len())
+}
+
+/// Offset across the list of the space between the children where data is inserted at the given `index`.
+fn list_indicator_offset(children: &[Area], index: usize, direction: &DirectionMode) -> f32 {
+    let (start, end) = match direction {
+        DirectionMode::Vertical => (Area::min_y, Area::max_y),
+        DirectionMode::Horizontal => (Area::min_x, Area::max_x),
+    };
+    match (
+        index.checked_sub(1).and_then(|i| children.get(i)),
+        children.get(index),
+    ) {
+        (Some(previous), Some(next)) => (end(previous) + start(next)) / 2.0,
+        (Some(previous), None) => end(previous),
+        (None, Some(next)) => start(next),
+        (None, None) => 0.0,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use freya::prelude::*;
@@ -219,4 +450,189 @@ mod test {
             Some("Enabled: true")
         );
     }
+
+    #[tokio::test]
+    pub async fn drag_drop_rejected() {
+        fn drop_app() -> Element {
+            let mut state = use_signal(|| "none");
+
+            rsx!(
+                DragProvider::<i32> {
+                    rect {
+                        height: "50%",
+                        width: "100%",
+                        DragZone {
+                            data: 1,
+                            ondragstart: move |_| state.set("started"),
+                            drag_element: rsx!(
+                                label {
+                                    width: "200",
+                                    "Moving"
+                                }
+                            ),
+                            label {
+                                "Move"
+                            }
+                        }
+                    },
+                    DropZone {
+                        ondrop: move |_: i32| state.set("outer"),
+                        DropZone {
+                            accepts: |data: &i32| *data > 1,
+                            ondragover: move |_| state.set("over"),
+                            ondrop: move |_: i32| state.set("inner"),
+                            rect {
+                                height: "50%",
+                                width: "100%",
+                                label {
+                                    "{state}"
+                                }
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drop_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        let label = root.get(1).get(0).get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("started"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (5.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // The inner DropZone doesn't accept the data so it isn't notified
+        assert_eq!(label.get(0).text(), Some("started"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // So it's dropped on the outer one instead
+        assert_eq!(label.get(0).text(), Some("outer"));
+    }
+
+    #[tokio::test]
+    pub async fn list_drop_zone() {
+        fn list_drop_app() -> Element {
+            let mut over = use_signal(|| None);
+            let mut dropped = use_signal(|| None);
+
+            rsx!(
+                DragProvider::<i32> {
+                    rect {
+                        height: "100",
+                        width: "100%",
+                        DragZone {
+                            data: 7,
+                            drag_element: rsx!(
+                                label {
+                                    width: "200",
+                                    "Moving"
+                                }
+                            ),
+                            label {
+                                "Move"
+                            }
+                        }
+                    }
+                    ListDropZone {
+                        ondragover: move |(_, index): (i32, usize)| over.set(Some(index)),
+                        ondrop: move |drop: (i32, usize)| dropped.set(Some(drop)),
+                        for _ in 0..3 {
+                            rect {
+                                height: "50",
+                                width: "100",
+                            }
+                        }
+                    }
+                    label {
+                        "{over.read():?} {dropped.read():?}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(list_drop_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Over the first half of the second item
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (5.0, 160.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(2).get(0).text(), Some("Some(1) None"));
+
+        // The indicator is shown between the first and second items
+        let indicator = root.get(1).get(3).area().unwrap();
+        assert_eq!(indicator.min_y(), 149.0);
+        assert_eq!(indicator.height(), 2.0);
+
+        // Dropped over the second half of the last item
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 240.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(2).get(0).text(), Some("Some(1) Some((7, 3))"));
+        assert!(root.get(1).get(3).is_placeholder());
+    }
+
+    #[test]
+    pub fn list_drop_index() {
+        use torin::prelude::{
+            Area,
+            DirectionMode,
+            Point2D,
+            Size2D,
+        };
+
+        let children =
+            [0.0, 50.0, 100.0].map(|x| Area::new(Point2D::new(x, 0.0), Size2D::new(50.0, 20.0)));
+
+        let horizontal = |x: f32| {
+            super::list_drop_index(&children, Point2D::new(x, 10.0), &DirectionMode::Horizontal)
+        };
+        assert_eq!(horizontal(10.0), 0);
+        assert_eq!(horizontal(30.0), 1);
+        assert_eq!(horizontal(140.0), 3);
+
+        // Only the axis of the direction is taken into account
+        let vertical = super::list_drop_index(
+            &children,
+            Point2D::new(130.0, 5.0),
+            &DirectionMode::Vertical,
+        );
+        assert_eq!(vertical, 0);
+    }
 }
@@ -164,6 +164,7 @@ pub fn Graph(props: GraphProps) -> Element {
             width: "{width}",
             height: "{height}",
             padding: "15 5",
+            box_sizing: "border-box",
             background: "white",
             rect {
                 canvas_reference: canvas.attribute(),
@@ -3,6 +3,7 @@ use freya_elements::{
     elements as dioxus_elements,
     events::{
         keyboard::Key,
+        ImePreeditEvent,
         KeyboardData,
         MouseEvent,
     },
@@ -131,6 +132,12 @@ pub fn Input(
         }
     };
 
+    let onimepreedit = move |e: ImePreeditEvent| {
+        if focus.is_focused() {
+            editable.process_event(&EditableEvent::ImePreedit(e.data));
+        }
+    };
+
     let onmousedown = move |e: MouseEvent| {
         if !display_placeholder {
             editable.process_event(&EditableEvent::MouseDown(e.data, 0));
@@ -169,7 +176,7 @@ pub fn Input(
     let (background, cursor_char) = if focus.is_focused() {
         (
             theme.hover_background,
-            editable.editor().read().visible_cursor_pos().to_string(),
+            editable.visible_cursor_pos_with_preedit().to_string(),
         )
     } else {
         (theme.background, "none".to_string())
@@ -197,6 +204,18 @@ pub fn Input(
         (InputMode::Shown, _) => value,
     };
 
+    // Show the text being composed with the IME where the cursor is
+    let preedit = if focus.is_focused() {
+        editable.ime_preedit().read().get_preedit().to_string()
+    } else {
+        String::new()
+    };
+    let preedit_at = text
+        .char_indices()
+        .nth(editable.editor().read().visible_cursor_pos())
+        .map_or(text.len(), |(i, _)| i);
+    let (text_before_preedit, text_after_preedit) = text.split_at(preedit_at);
+
     rsx!(
         rect {
             width: "{width}",
@@ -216,6 +235,7 @@ pub fn Input(
                 margin: "8 12",
                 onkeydown,
                 onkeyup,
+                onimepreedit,
                 onglobalclick,
                 onmouseenter,
                 onmouseleave,
@@ -228,8 +248,22 @@ pub fn Input(
                 cursor_color: "{color}",
                 max_lines: "1",
                 highlights,
-                text {
-                    "{text}"
+                if preedit.is_empty() {
+                    text {
+                        "{text}"
+                    }
+                } else {
+                    text {
+                        "{text_before_preedit}"
+                    }
+                    text {
+                        decoration: "underline",
+                        decoration_style: "dotted",
+                        "{preedit}"
+                    }
+                    text {
+                        "{text_after_preedit}"
+                    }
                 }
             }
         }
@@ -238,10 +272,23 @@ pub fn Input(
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
+    use tokio::time::advance;
+
+    /// Longer than any caret blink period, so a blinking caret would have been hidden at some point.
+    const BLINK_PERIODS: Duration = Duration::from_secs(3);
+
+    /// Check the caret is shown at the given position with the color of the text.
+    fn assert_solid_caret(paragraph: &TestNode, position: i32) {
+        let state = paragraph.state();
+        assert_eq!(state.cursor.position, Some(position));
+        assert_eq!(state.cursor.color, state.font_style.color);
+    }
 
-    #[tokio::test]
+    #[tokio::test(start_paused = true)]
     pub async fn input() {
         fn input_app() -> Element {
             let mut value = use_signal(|| "Hello, Worl".to_string());
@@ -287,5 +334,73 @@ mod test {
 
         // Check that "d" has been written into the input.
         assert_eq!(text.get(0).text(), Some("Hello, World"));
+
+        // The caret stays solid while typing
+        for _ in 0..3 {
+            advance(BLINK_PERIODS / 3).await;
+            utils.wait_for_update().await;
+            assert_solid_caret(&text, 12);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn input_ime() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "Hello, ".to_string());
+
+            rsx!(Input {
+                value: value.read().clone(),
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The text being composed is shown at the cursor
+        utils.push_event(PlatformEvent::Ime {
+            name: EventName::ImePreedit,
+            preedit: "にほん".to_string(),
+            cursor: Some((3, 3)),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("Hello, "));
+        assert_eq!(text.get(1).text(), Some("にほん"));
+
+        // The caret stays solid at the cursor of the composition
+        for _ in 0..3 {
+            advance(BLINK_PERIODS / 3).await;
+            utils.wait_for_update().await;
+            assert_solid_caret(&text, 10);
+        }
+
+        // And it's written once committed
+        utils.push_event(PlatformEvent::Ime {
+            name: EventName::ImePreedit,
+            preedit: String::new(),
+            cursor: None,
+        });
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("日本".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("Hello, 日本"));
     }
 }
@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::use_node_visibility;
+
+/// Defer rendering its children until they become visible for the first time, e.g when they get scrolled
+/// into view in a `ScrollView`, and keep them mounted from then on.
+///
+/// This is useful for expensive content that is not shown at first, like the content of tabs or accordions.
+/// Unlike virtualization, the children are never unmounted once they have been rendered.
+///
+/// Until its children are mounted, it takes the space given by `width` and `height`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ScrollView {
+///             rect {
+///                 height: "1000",
+///             }
+///             LazyMount {
+///                 height: "200",
+///                 label {
+///                     "Rendered once it's about to be scrolled into view"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn LazyMount(
+    /// Inner children of the LazyMount.
+    children: Element,
+    /// Width of the LazyMount. Default `auto`.
+    #[props(default = "auto".to_string(), into)]
+    width: String,
+    /// Height of the LazyMount. Default `auto`.
+    #[props(default = "auto".to_string(), into)]
+    height: String,
+    /// How many pixels away from its viewport the LazyMount is considered visible already, so the children
+    /// are mounted right before they are scrolled into view. Default `100`.
+    #[props(default = 100.0)]
+    buffer: f32,
+) -> Element {
+    let (reference, is_visible) = use_node_visibility(buffer);
+    let mut mounted = use_signal(|| false);
+
+    use_effect(move || {
+        if *is_visible.read() {
+            mounted.set(true);
+        }
+    });
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            if *mounted.read() {
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn lazy_mount() {
+        fn lazy_mount_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "800",
+                        width: "200",
+                    }
+                    LazyMount {
+                        label {
+                            "Loaded"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(lazy_mount_app);
+        let root = utils.root();
+        let lazy_mount = root.get(0).get(0).get(0).get(1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Not rendered while it's out of view
+        assert!(lazy_mount.get(0).is_placeholder());
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Rendered once it gets within the buffer of the viewport
+        assert_eq!(lazy_mount.get(0).get(0).text(), Some("Loaded"));
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // And kept mounted after it's scrolled out of view again
+        assert_eq!(lazy_mount.get(0).get(0).text(), Some("Loaded"));
+    }
+}
@@ -16,6 +16,7 @@ mod graph;
 mod hooks;
 mod icons;
 mod input;
+mod lazy_mount;
 mod link;
 mod loader;
 mod menu;
@@ -23,8 +24,10 @@ mod native_container;
 mod native_router;
 mod network_image;
 mod popup;
+mod portal;
 mod progress_bar;
 mod radio;
+mod ripple;
 mod scroll_views;
 mod sidebar;
 mod slider;
@@ -54,6 +57,7 @@ pub use graph::*;
 pub use hooks::*;
 pub use icons::*;
 pub use input::*;
+pub use lazy_mount::*;
 pub use link::*;
 pub use loader::*;
 pub use menu::*;
@@ -61,8 +65,10 @@ pub use native_container::*;
 pub use native_router::*;
 pub use network_image::*;
 pub use popup::*;
+pub use portal::*;
 pub use progress_bar::*;
 pub use radio::*;
+pub use ripple::*;
 pub use scroll_views::*;
 pub use sidebar::*;
 pub use slider::*;
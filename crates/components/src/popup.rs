@@ -24,7 +24,7 @@ pub fn PopupBackground(children: Element) -> Element {
         height: "100v",
         width: "100v",
         background: "rgb(0, 0, 0, 150)",
-        position: "absolute",
+        position: "global",
         position_top: "0",
         position_left: "0",
         layer: "-99",
@@ -36,6 +36,8 @@ pub fn PopupBackground(children: Element) -> Element {
 
 /// Floating window intended for quick interactions. Also called `Dialog` in other frameworks.
 ///
+/// The keyboard focus is kept within the popup while it's open, and goes back to where it was once it's closed.
+///
 /// # Styling
 /// Inherits the [`PopupTheme`](freya_hooks::PopupTheme) theme.
 /// ```rust, no_run
@@ -112,12 +114,14 @@ pub fn Popup(
         PopupBackground {
             rect {
                 padding: "14",
+                box_sizing: "border-box",
                 corner_radius: "8",
                 background: "{background}",
                 color: "{color}",
                 shadow: "0 4 5 0 rgb(0, 0, 0, 30)",
                 width: "{width}",
                 height: "{height}",
+                focus_trap: "true",
                 onkeydown,
                 if show_close_button {
                     rect {
@@ -126,6 +130,7 @@ pub fn Popup(
                         cross_align: "end",
                         Button {
                             theme: theme_with!(ButtonTheme {
+                                box_sizing: "border-box",
                                 padding: "6".into(),
                                 margin: "0".into(),
                                 width: "30".into(),
@@ -0,0 +1,106 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+
+/// Render its children above the rest of the app, positioned relative to the window instead of its parent.
+///
+/// The children are not clipped by any ancestor with `overflow: clip` (like a `ScrollView`), and
+/// they don't take any space in their parent. Because they are still part of the same components tree,
+/// they keep sharing state with their parent components and their events bubble up to them as usual.
+///
+/// This is useful for popovers, menus or modals that are declared inside scrolled or clipped containers.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ScrollView {
+///             Portal {
+///                 top: "50",
+///                 left: "50",
+///                 label {
+///                     "Hello, World!"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Portal(
+    /// Inner children of the Portal.
+    children: Element,
+    /// Distance from the top edge of the window. Default `0`.
+    #[props(default = "0".to_string(), into)]
+    top: String,
+    /// Distance from the left edge of the window. Default `0`.
+    #[props(default = "0".to_string(), into)]
+    left: String,
+    /// Layer of the Portal. Default `-999`.
+    #[props(default = "-999".to_string(), into)]
+    layer: String,
+) -> Element {
+    rsx!(
+        rect {
+            position: "global",
+            position_top: "{top}",
+            position_left: "{left}",
+            layer: "{layer}",
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn portal() {
+        fn portal_app() -> Element {
+            let mut clicks = use_signal(|| 0);
+
+            rsx!(
+                rect {
+                    margin: "100",
+                    width: "50",
+                    height: "50",
+                    overflow: "clip",
+                    onclick: move |_| clicks += 1,
+                    Portal {
+                        top: "200",
+                        left: "200",
+                        rect {
+                            width: "100",
+                            height: "100",
+                        }
+                    }
+                }
+                label {
+                    "{clicks}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(portal_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        // The Portal is placed relative to the window and doesn't take space in its parent
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.area().unwrap().origin, (200.0, 200.0).into());
+        assert_eq!(root.get(1).area().unwrap().min_y(), 250.0);
+
+        // Clicking the Portal outside of the clipped parent still bubbles up to its parent
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (250.0, 250.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(root.get(1).get(0).text(), Some("1"));
+    }
+}
@@ -52,6 +52,7 @@ pub fn ProgressBar(
             width: "{width}",
             height: "{height}",
             padding: "2",
+            box_sizing: "border-box",
             rect {
                 corner_radius: "999",
                 width: "100%",
@@ -69,6 +69,7 @@ pub fn Radio(
             height: "18",
             border: "2 solid {fill}",
             padding: "4",
+            box_sizing: "border-box",
             main_align: "center",
             cross_align: "center",
             corner_radius: "99",
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    elements as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_animation,
+    use_node_signal,
+    AnimNum,
+    Ease,
+    Function,
+};
+
+/// A ripple started by a press in a [`Ripple`].
+#[derive(Clone, Copy, PartialEq)]
+struct RippleWave {
+    id: usize,
+    x: f32,
+    y: f32,
+    radius: f32,
+}
+
+/// Show an expanding and fading circle from where its children are pressed, as a feedback for the press.
+///
+/// Every press starts a new ripple, which animates independently of the others.
+/// The ripples are clipped to the area of the children, so set the same `corner_radius` as them when they are rounded.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         Ripple {
+///             corner_radius: "8",
+///             rect {
+///                 width: "150",
+///                 height: "50",
+///                 corner_radius: "8",
+///                 background: "rgb(30, 90, 200)",
+///                 main_align: "center",
+///                 cross_align: "center",
+///                 label {
+///                     "Press me"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Ripple(
+    /// Inner children of the Ripple.
+    children: Element,
+    /// Color of the ripples. Default `rgb(255, 255, 255, 0.3)`.
+    #[props(default = "rgb(255, 255, 255, 0.3)".to_string(), into)]
+    color: String,
+    /// Corner radius the ripples are clipped with. Default `0`.
+    #[props(default = "0".to_string(), into)]
+    corner_radius: String,
+    /// How long every ripple lasts. Default `500ms`.
+    #[props(default = Duration::from_millis(500))]
+    duration: Duration,
+) -> Element {
+    let (reference, layout) = use_node_signal();
+    let mut waves = use_signal(Vec::<RippleWave>::new);
+    let mut next_id = use_signal(|| 0);
+
+    let onmousedown = move |e: MouseEvent| {
+        let area = layout.read().area;
+        let cursor = e.get_screen_coordinates().to_f32();
+        let (x, y) = (cursor.x - area.min_x(), cursor.y - area.min_y());
+
+        // Big enough to cover the farthest corner
+        let radius = [
+            (0.0, 0.0),
+            (area.width(), 0.0),
+            (0.0, area.height()),
+            (area.width(), area.height()),
+        ]
+        .into_iter()
+        .map(|(corner_x, corner_y): (f32, f32)| (corner_x - x).hypot(corner_y - y))
+        .fold(0.0, f32::max);
+
+        let id = *next_id.peek();
+        next_id += 1;
+        waves.write().push(RippleWave { id, x, y, radius });
+    };
+
+    rsx!(
+        rect {
+            reference,
+            overflow: "clip",
+            corner_radius: "{corner_radius}",
+            onmousedown,
+            {children}
+            for wave in waves.read().iter().copied() {
+                RippleCircle {
+                    key: "{wave.id}",
+                    wave,
+                    color: color.clone(),
+                    duration,
+                    onfinish: move |id| waves.write().retain(|wave: &RippleWave| wave.id != id),
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn RippleCircle(
+    wave: RippleWave,
+    color: String,
+    duration: Duration,
+    onfinish: EventHandler<usize>,
+) -> Element {
+    let animation = use_animation(move |ctx| {
+        ctx.auto_start(true);
+        (
+            ctx.with(
+                AnimNum::new(0., 1.)
+                    .duration(duration)
+                    .ease(Ease::Out)
+                    .function(Function::Quad),
+            ),
+            ctx.with(
+                AnimNum::new(1., 0.)
+                    .duration(duration)
+                    .ease(Ease::In)
+                    .function(Function::Quad),
+            ),
+        )
+    });
+
+    use_effect(move || {
+        if animation.has_run_yet() && !animation.is_running() {
+            onfinish.call(wave.id);
+        }
+    });
+
+    let (scale, opacity) = animation.get();
+    let radius = wave.radius * scale.read().as_f32();
+    let opacity = opacity.read().as_f32();
+
+    rsx!(rect {
+        position: "absolute",
+        position_top: "{wave.y - radius}",
+        position_left: "{wave.x - radius}",
+        width: "{radius * 2.0}",
+        height: "{radius * 2.0}",
+        corner_radius: "{radius}",
+        background: "{color}",
+        opacity: "{opacity}",
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::advance;
+
+    #[tokio::test(start_paused = true)]
+    pub async fn ripple() {
+        fn ripple_app() -> Element {
+            rsx!(Ripple {
+                duration: Duration::from_millis(50),
+                rect {
+                    width: "100",
+                    height: "100",
+                }
+            })
+        }
+
+        let mut utils = launch_test(ripple_app);
+        let ripple = utils.root().get(0);
+        utils.wait_for_update().await;
+
+        for _ in 0..2 {
+            utils.push_event(PlatformEvent::Mouse {
+                name: EventName::MouseDown,
+                cursor: (25.0, 25.0).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+        }
+
+        // Every press starts its own ripple from the pressed point
+        assert_eq!(ripple.children_ids().len(), 3);
+        for i in 1..3 {
+            let wave = ripple.get(i).area().unwrap();
+            assert_eq!(wave.center().round(), (25.0, 25.0).into());
+        }
+
+        advance(Duration::from_millis(100)).await;
+        for _ in 0..3 {
+            utils.wait_for_update().await;
+        }
+
+        // And they are removed once they finish
+        assert!(ripple.get(1).is_placeholder());
+    }
+}
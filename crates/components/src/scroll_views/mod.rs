@@ -4,13 +4,31 @@ mod scroll_view;
 mod use_scroll_controller;
 mod virtual_scroll_view;
 
+use std::time::Duration;
+
+use dioxus::prelude::{
+    spawn,
+    Readable,
+    Signal,
+    Task,
+    Writable,
+};
+use freya_common::{
+    SnapAlign,
+    SnapPoint,
+};
 use freya_elements::events::{
     keyboard::Key,
     KeyboardEvent,
 };
+use freya_hooks::UsePlatform;
 pub use scroll_bar::*;
 pub use scroll_thumb::*;
 pub use scroll_view::*;
+use tokio::time::{
+    sleep,
+    Instant,
+};
 pub use use_scroll_controller::*;
 pub use virtual_scroll_view::*;
 
@@ -18,6 +36,54 @@ pub use virtual_scroll_view::*;
 #[doc(hidden)]
 pub const SCROLL_SPEED_MULTIPLIER: f32 = 5.0;
 
+// Distance to the edges of the viewport from where dragging the content auto scrolls it.
+#[doc(hidden)]
+pub const AUTO_SCROLL_EDGE: f32 = 30.0;
+
+// Distance auto scrolled every frame when the cursor is at the edge of the viewport or past it.
+#[doc(hidden)]
+pub const AUTO_SCROLL_MAX_SPEED: f32 = 15.0;
+
+// How long since the last wheel event the scroll waits before snapping.
+#[doc(hidden)]
+pub const SCROLL_SNAP_DELAY: Duration = Duration::from_millis(150);
+
+// Fraction of the viewport size from where `proximity` snapping settles in a snap point.
+#[doc(hidden)]
+pub const SCROLL_SNAP_PROXIMITY: f32 = 0.3;
+
+// Fraction of the viewport size the content can be stretched past its bounds when overscrolling.
+#[doc(hidden)]
+pub const OVERSCROLL_MAX: f32 = 0.2;
+
+// Angular frequency of the spring that brings the overscrolled content back, the higher the faster.
+#[doc(hidden)]
+pub const OVERSCROLL_SPRING_FREQUENCY: f32 = 12.0;
+
+/// How a [`ScrollView`] settles its scroll in the children marked with `snap_align`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScrollSnap {
+    /// Don't snap.
+    #[default]
+    None,
+    /// Always snap to the closest child.
+    Mandatory,
+    /// Only snap when close enough to a child.
+    Proximity,
+}
+
+/// What a scroll view does when the user keeps scrolling once its start or end is reached.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Overscroll {
+    /// Stop scrolling.
+    #[default]
+    None,
+    /// Stretch the content past its bounds and spring it back once the user stops scrolling.
+    Bounce,
+    /// Show a glow in that edge without moving the content.
+    Glow,
+}
+
 #[doc(hidden)]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Axis {
@@ -88,28 +154,268 @@ pub fn get_scroll_position_from_cursor(
     new_position as i32
 }
 
+/// Get the scroll position after a wheel movement, and the fraction of a pixel that was scrolled beyond it.
+///
+/// The fraction must be given back as `remainder` with the next movement,
+/// so the tiny deltas of precise trackpads add up instead of being lost when rounding the position.
 #[doc(hidden)]
 pub fn get_scroll_position_from_wheel(
     wheel_movement: f32,
     inner_size: f32,
     viewport_size: f32,
     scroll_position: f32,
-) -> i32 {
+    remainder: f32,
+) -> (i32, f32) {
     if viewport_size >= inner_size {
-        return 0;
+        return (0, 0.0);
     }
 
-    let new_position = scroll_position + wheel_movement;
+    let new_position = scroll_position + remainder + wheel_movement;
 
     if new_position >= 0.0 && wheel_movement > 0.0 {
-        return 0;
+        return (0, 0.0);
     }
 
     if new_position <= -(inner_size - viewport_size) && wheel_movement < 0.0 {
-        return -(inner_size - viewport_size) as i32;
+        return (-(inner_size - viewport_size) as i32, 0.0);
     }
 
-    new_position as i32
+    let rounded_position = new_position.round();
+    (rounded_position as i32, new_position - rounded_position)
+}
+
+/// Fraction of a pixel scrolled with the wheel beyond the scroll position it was left at.
+///
+/// It's only given back while the scroll position stays there, so it's dropped once anything else
+/// scrolls, e.g dragging the scrollbar, the keyboard or the scroll controller.
+#[doc(hidden)]
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct WheelRemainder {
+    position: i32,
+    fraction: f32,
+}
+
+impl WheelRemainder {
+    fn at(&self, position: i32) -> f32 {
+        if self.position == position {
+            self.fraction
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Scroll an axis of a scroll controller with a wheel movement, starting from its current position
+/// and adding up the fractions of a pixel left by the previous movements in `wheel_remainder`.
+///
+/// Returns the distance that was scrolled, so the scrollable ancestors can take what is left of the movement.
+#[doc(hidden)]
+pub fn scroll_with_wheel(
+    wheel_movement: f32,
+    inner_size: f32,
+    viewport_size: f32,
+    mut scrolled: Signal<i32>,
+    mut wheel_remainder: Signal<WheelRemainder>,
+) -> f32 {
+    // Other wheel events might have scrolled it since the last render
+    let scroll_position =
+        get_corrected_scroll_position(inner_size, viewport_size, *scrolled.peek() as f32);
+    let remainder = wheel_remainder.peek().at(*scrolled.peek());
+
+    let (new_position, new_fraction) = get_scroll_position_from_wheel(
+        wheel_movement,
+        inner_size,
+        viewport_size,
+        scroll_position,
+        remainder,
+    );
+
+    // Only scroll when there is still area to scroll
+    if *scrolled.peek() != new_position {
+        scrolled.set(new_position);
+    }
+
+    let new_remainder = WheelRemainder {
+        position: new_position,
+        fraction: new_fraction,
+    };
+    if *wheel_remainder.peek() != new_remainder {
+        wheel_remainder.set(new_remainder);
+    }
+
+    new_position as f32 + new_fraction - (scroll_position + remainder)
+}
+
+/// Get how much to scroll in the next frame while dragging the content with the cursor at the given position,
+/// faster the closer it gets to the edges of the viewport.
+#[doc(hidden)]
+pub fn get_auto_scroll_delta(
+    cursor_position: f32,
+    inner_size: f32,
+    viewport_start: f32,
+    viewport_size: f32,
+) -> f32 {
+    if viewport_size >= inner_size {
+        return 0.0;
+    }
+
+    let speed = |distance: f32| {
+        (AUTO_SCROLL_MAX_SPEED * ((AUTO_SCROLL_EDGE - distance) / AUTO_SCROLL_EDGE).min(1.0))
+            .max(1.0)
+    };
+
+    let distance_to_start = cursor_position - viewport_start;
+    let distance_to_end = viewport_start + viewport_size - cursor_position;
+
+    if distance_to_start < AUTO_SCROLL_EDGE {
+        speed(distance_to_start)
+    } else if distance_to_end < AUTO_SCROLL_EDGE {
+        -speed(distance_to_end)
+    } else {
+        0.0
+    }
+}
+
+/// Get the scroll position of the snap point closest to the given scroll position, if any should be snapped to
+/// with the given [`ScrollSnap`] mode.
+#[doc(hidden)]
+pub fn get_snap_scroll_position(
+    scroll_snap: &ScrollSnap,
+    snap_points: &[SnapPoint],
+    axis: &Axis,
+    scroll_position: i32,
+    inner_size: f32,
+    viewport_size: f32,
+) -> Option<i32> {
+    let proximity = match scroll_snap {
+        ScrollSnap::Mandatory => f32::INFINITY,
+        ScrollSnap::Proximity => viewport_size * SCROLL_SNAP_PROXIMITY,
+        ScrollSnap::None => return None,
+    };
+    let current_position = -scroll_position as f32;
+    let max_position = (inner_size - viewport_size).max(0.0);
+
+    snap_points
+        .iter()
+        .map(|snap_point| {
+            let (start, size) = match axis {
+                Axis::X => (snap_point.area.min_x(), snap_point.area.width()),
+                Axis::Y => (snap_point.area.min_y(), snap_point.area.height()),
+            };
+            let position = match snap_point.align {
+                SnapAlign::Start => start,
+                SnapAlign::Center => start + (size - viewport_size) / 2.0,
+                SnapAlign::End => start + size - viewport_size,
+            };
+            position.clamp(0.0, max_position)
+        })
+        .filter(|position| (position - current_position).abs() <= proximity)
+        .min_by(|a, b| {
+            (a - current_position)
+                .abs()
+                .total_cmp(&(b - current_position).abs())
+        })
+        .map(|position| -position.round() as i32)
+}
+
+/// Get how far the content is stretched past its bounds after a wheel movement that couldn't be scrolled.
+///
+/// The further it's already stretched the more it resists, up to [`OVERSCROLL_MAX`] of the viewport.
+#[doc(hidden)]
+pub fn get_overscroll_position(overscroll: f32, wheel_movement: f32, viewport_size: f32) -> f32 {
+    let max_overscroll = viewport_size * OVERSCROLL_MAX;
+    if max_overscroll <= 0.0 {
+        return 0.0;
+    }
+
+    let resistance = 1.0 - (overscroll.abs() / max_overscroll).min(1.0);
+    (overscroll + wheel_movement * resistance).clamp(-max_overscroll, max_overscroll)
+}
+
+/// Stretch the content of a scroll view past its bounds in the given axis with a wheel movement that couldn't be scrolled,
+/// and spring it back once the user stops scrolling.
+#[doc(hidden)]
+pub fn overscroll_with_wheel(
+    wheel_movement: f32,
+    axis: Axis,
+    viewport_size: f32,
+    mut overscroll: Signal<(f32, f32)>,
+    mut overscroll_task: Signal<Option<Task>>,
+    platform: UsePlatform,
+) {
+    let (mut overscroll_x, mut overscroll_y) = *overscroll.peek();
+    match axis {
+        Axis::X => {
+            overscroll_x = get_overscroll_position(overscroll_x, wheel_movement, viewport_size)
+        }
+        Axis::Y => {
+            overscroll_y = get_overscroll_position(overscroll_y, wheel_movement, viewport_size)
+        }
+    }
+    overscroll.set((overscroll_x, overscroll_y));
+
+    // Spring back once the user stops scrolling
+    if let Some(task) = overscroll_task.write().take() {
+        task.cancel();
+    }
+    let mut ticker = platform.new_ticker();
+    let task = spawn(async move {
+        sleep(SCROLL_SNAP_DELAY).await;
+        let (start_x, start_y) = *overscroll.peek();
+        let started_at = Instant::now();
+        loop {
+            platform.request_animation_frame();
+            ticker.tick().await;
+
+            let elapsed = started_at.elapsed();
+            let position = (
+                get_overscroll_spring_position(start_x, elapsed),
+                get_overscroll_spring_position(start_y, elapsed),
+            );
+            overscroll.set(position);
+            if position == (0., 0.) {
+                break;
+            }
+        }
+        overscroll_task.set(None);
+    });
+    overscroll_task.set(Some(task));
+}
+
+/// Get the edges of a scroll view that glow while it's overscrolled, along with how strong their glow is.
+#[doc(hidden)]
+pub fn get_overscroll_glows(
+    (overscroll_x, overscroll_y): (f32, f32),
+    viewport_width: f32,
+    viewport_height: f32,
+) -> Vec<(&'static str, f32)> {
+    let strength = |overscroll: f32, viewport_size: f32| {
+        (overscroll.abs() / (viewport_size * OVERSCROLL_MAX)).min(1.0)
+    };
+    [
+        (overscroll_y > 0.0).then(|| ("top", strength(overscroll_y, viewport_height))),
+        (overscroll_y < 0.0).then(|| ("bottom", strength(overscroll_y, viewport_height))),
+        (overscroll_x > 0.0).then(|| ("left", strength(overscroll_x, viewport_width))),
+        (overscroll_x < 0.0).then(|| ("right", strength(overscroll_x, viewport_width))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Get how far the content is still stretched past its bounds once `elapsed` has passed since it started
+/// springing back from `overscroll`, following a critically damped spring so it doesn't bounce past the bounds.
+#[doc(hidden)]
+pub fn get_overscroll_spring_position(overscroll: f32, elapsed: Duration) -> f32 {
+    let time = elapsed.as_secs_f32() * OVERSCROLL_SPRING_FREQUENCY;
+    let position = overscroll * (1.0 + time) * (-time).exp();
+
+    // Settle once it's not noticeable anymore
+    if position.abs() < 0.5 {
+        0.0
+    } else {
+        position
+    }
 }
 
 /// Limit the scroll position to the scroll view bounds to avoid overflows
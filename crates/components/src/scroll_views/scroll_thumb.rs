@@ -56,6 +56,7 @@ pub fn ScrollThumb(
             width: "{width}",
             height: "{height}",
             padding: "2",
+            box_sizing: "border-box",
             rect {
                 width: "100%",
                 height: "100%",
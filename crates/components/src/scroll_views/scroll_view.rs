@@ -11,27 +11,40 @@ use freya_elements::{
 use freya_hooks::{
     use_applied_theme,
     use_focus,
-    use_node,
+    use_node_signal_with_children,
+    use_platform,
     ScrollBarThemeWith,
     ScrollViewThemeWith,
+    SelectionDragMark,
 };
+use freya_node_state::ReferenceChildren;
+use tokio::time::sleep;
+use torin::prelude::CursorPoint;
 
 use super::use_scroll_controller::ScrollController;
 use crate::{
+    get_auto_scroll_delta,
     get_container_size,
     get_corrected_scroll_position,
+    get_overscroll_glows,
     get_scroll_position_from_cursor,
-    get_scroll_position_from_wheel,
     get_scrollbar_pos_and_size,
+    get_snap_scroll_position,
     is_scrollbar_visible,
     manage_key_event,
+    overscroll_with_wheel,
     scroll_views::use_scroll_controller::{
         use_scroll_controller,
         ScrollConfig,
     },
+    scroll_with_wheel,
     Axis,
+    Overscroll,
     ScrollBar,
+    ScrollSnap,
     ScrollThumb,
+    WheelRemainder,
+    SCROLL_SNAP_DELAY,
     SCROLL_SPEED_MULTIPLIER,
 };
 
@@ -53,6 +66,26 @@ pub struct ScrollViewProps {
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Continue scrolling the closest scrollable ancestor with what's left of the wheel movement
+    /// once this one reaches its start or end. Enabled by default.
+    #[props(default = true, into)]
+    pub scroll_chaining: bool,
+    /// Multiplier of how much every wheel movement scrolls in this container, on top of the sensitivity of the window.
+    /// It must be greater than `0`. Default is `1.0`.
+    #[props(default = 1.0, into)]
+    pub scroll_sensitivity: f32,
+    /// Keep the content rendered in an offscreen surface so scrolling doesn't need to render it again,
+    /// disabled by default. Only recommended for large content that rarely changes.
+    #[props(default = false, into)]
+    pub cache: bool,
+    /// Settle the scroll in the closest child marked with `snap_align` once the user stops scrolling.
+    /// [`ScrollSnap::None`] by default.
+    #[props(default)]
+    pub scroll_snap: ScrollSnap,
+    /// What happens when the user keeps scrolling once the start or end is reached, [`Overscroll::None`] by default.
+    /// Overscrolling takes the movement that `scroll_chaining` would pass to the ancestors.
+    #[props(default)]
+    pub overscroll: Overscroll,
 
     pub scroll_controller: Option<ScrollController>,
 }
@@ -120,11 +153,23 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
     let mut clicking_shift = use_signal(|| false);
     let mut clicking_alt = use_signal(|| false);
+    let wheel_remainder_x = use_signal(WheelRemainder::default);
+    let wheel_remainder_y = use_signal(WheelRemainder::default);
+    // Where the content was pressed, until it's released
+    let mut dragging_content = use_signal::<Option<CursorPoint>>(|| None);
+    let mut auto_scroll = use_signal(|| (0f32, 0f32));
+    let mut auto_scroll_task = use_signal::<Option<Task>>(|| None);
+    let selection_drag = use_hook(try_consume_context::<Signal<SelectionDragMark>>);
+    let mut snap_task = use_signal::<Option<Task>>(|| None);
+    let overscroll = use_signal(|| (0f32, 0f32));
+    let overscroll_task = use_signal::<Option<Task>>(|| None);
+    let platform = use_platform();
     let mut scroll_controller = props
         .scroll_controller
         .unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
-    let (node_ref, size) = use_node();
+    let (node_ref, node_size) = use_node_signal_with_children(ReferenceChildren::SnapPoints);
+    let size = node_size.read().clone();
 
     let mut focus = use_focus();
     let theme = use_applied_theme!(&props.theme, scroll_view);
@@ -136,8 +181,11 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     let user_direction = &props.direction;
     let show_scrollbar = props.show_scrollbar;
     let scroll_with_arrows = props.scroll_with_arrows;
+    let scroll_chaining = props.scroll_chaining;
+    let scroll_sensitivity = props.scroll_sensitivity;
+    let overscroll_mode = props.overscroll;
 
-    scroll_controller.use_apply(size.inner.width, size.inner.height);
+    scroll_controller.use_apply(size.inner.width, size.inner.height, size.area);
 
     let direction_is_vertical = user_direction == "vertical";
 
@@ -166,49 +214,117 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     let (scrollbar_x, scrollbar_width) =
         get_scrollbar_pos_and_size(size.inner.width, size.area.width(), corrected_scrolled_x);
 
+    // Settle the scroll of the given axis in the closest snap point
+    let is_snapping = props.scroll_snap != ScrollSnap::None;
+    let scroll_snap = props.scroll_snap;
+    let snap = move |axis: Axis| {
+        let size = node_size.peek();
+        let (mut scrolled, inner_size, viewport_size) = match axis {
+            Axis::X => (scrolled_x, size.inner.width, size.area.width()),
+            Axis::Y => (scrolled_y, size.inner.height, size.area.height()),
+        };
+        let scroll_position =
+            get_corrected_scroll_position(inner_size, viewport_size, *scrolled.peek() as f32)
+                as i32;
+        if let Some(snap_position) = get_snap_scroll_position(
+            &scroll_snap,
+            &size.snap_points,
+            &axis,
+            scroll_position,
+            inner_size,
+            viewport_size,
+        ) {
+            if *scrolled.peek() != snap_position {
+                scrolled.set(snap_position);
+            }
+        }
+    };
+
     // Moves the Y axis when the user scrolls in the container
+    let wheel_snap = snap.clone();
     let onwheel = move |e: WheelEvent| {
-        let speed_multiplier = if *clicking_alt.peek() {
+        if e.is_default_prevented() {
+            return;
+        }
+
+        let alt_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
             1.0
         };
+        let speed_multiplier = alt_multiplier * scroll_sensitivity;
 
-        let wheel_movement = e.get_delta_y() as f32 * speed_multiplier;
+        let wheel_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
 
         let scroll_vertically_or_not = (direction_is_vertical && !*clicking_shift.peek())
             || !direction_is_vertical && *clicking_shift.peek();
 
-        if scroll_vertically_or_not {
-            let scroll_position_y = get_scroll_position_from_wheel(
+        let scrolled_distance = if scroll_vertically_or_not {
+            scroll_with_wheel(
                 wheel_movement,
                 size.inner.height,
                 size.area.height(),
-                corrected_scrolled_y,
-            );
-
-            // Only scroll when there is still area to scroll
-            if *scrolled_y.peek() != scroll_position_y {
-                e.stop_propagation();
-                *scrolled_y.write() = scroll_position_y;
-            } else {
-                return;
-            }
+                scrolled_y,
+                wheel_remainder_y,
+            )
         } else {
-            let scroll_position_x = get_scroll_position_from_wheel(
+            scroll_with_wheel(
                 wheel_movement,
                 size.inner.width,
                 size.area.width(),
-                corrected_scrolled_x,
+                scrolled_x,
+                wheel_remainder_x,
+            )
+        };
+
+        e.consume_delta_y((scrolled_distance / speed_multiplier) as f64);
+
+        // Stretch the content past its bounds with what couldn't be scrolled
+        if overscroll_mode != Overscroll::None && e.get_remaining_delta_y().abs() >= 1.0 {
+            let overscroll_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
+            e.consume_delta_y(e.get_remaining_delta_y());
+
+            let (axis, viewport_size) = if scroll_vertically_or_not {
+                (Axis::Y, size.area.height())
+            } else {
+                (Axis::X, size.area.width())
+            };
+            overscroll_with_wheel(
+                overscroll_movement,
+                axis,
+                viewport_size,
+                overscroll,
+                overscroll_task,
+                platform,
             );
+        }
 
-            // Only scroll when there is still area to scroll
-            if *scrolled_x.peek() != scroll_position_x {
-                e.stop_propagation();
-                *scrolled_x.write() = scroll_position_x;
+        // Let the scrollable ancestors scroll what couldn't be scrolled here
+        if !scroll_chaining || e.get_remaining_delta_y().abs() < 1.0 {
+            e.stop_propagation();
+        }
+
+        if scrolled_distance == 0.0 {
+            return;
+        }
+
+        // Snap once the user stops scrolling
+        if is_snapping {
+            let axis = if scroll_vertically_or_not {
+                Axis::Y
             } else {
-                return;
+                Axis::X
+            };
+            let snap = wheel_snap.clone();
+            if let Some(task) = snap_task.write().take() {
+                task.cancel();
             }
+            let task = spawn(async move {
+                sleep(SCROLL_SNAP_DELAY).await;
+                snap(axis);
+                snap_task.set(None);
+            });
+            snap_task.set(Some(task));
         }
 
         focus.focus();
@@ -244,6 +360,69 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
 
         if clicking_scrollbar.is_some() {
             focus.focus();
+            return;
+        }
+
+        // Auto scroll while selecting text close to the edges of the viewport, but not when dragging anything else
+        let selecting = dragging_content.peek().is_some_and(|pressed_at| {
+            selection_drag.is_some_and(|selection_drag| {
+                selection_drag.peek().started_at() == Some(pressed_at)
+            })
+        });
+        if selecting {
+            let cursor = e.get_screen_coordinates();
+            let delta = (
+                get_auto_scroll_delta(
+                    cursor.x as f32,
+                    size.inner.width,
+                    size.area.min_x(),
+                    size.area.width(),
+                ),
+                get_auto_scroll_delta(
+                    cursor.y as f32,
+                    size.inner.height,
+                    size.area.min_y(),
+                    size.area.height(),
+                ),
+            );
+            if *auto_scroll.peek() != delta {
+                auto_scroll.set(delta);
+            }
+
+            if delta != (0., 0.) && auto_scroll_task.peek().is_none() {
+                let mut ticker = platform.new_ticker();
+                let task = spawn(async move {
+                    loop {
+                        platform.request_animation_frame();
+                        ticker.tick().await;
+
+                        let (delta_x, delta_y) = *auto_scroll.peek();
+                        if (delta_x, delta_y) == (0., 0.) {
+                            break;
+                        }
+
+                        let x = get_corrected_scroll_position(
+                            size.inner.width,
+                            size.area.width(),
+                            *scrolled_x.peek() as f32 + delta_x,
+                        ) as i32;
+                        let y = get_corrected_scroll_position(
+                            size.inner.height,
+                            size.area.height(),
+                            *scrolled_y.peek() as f32 + delta_y,
+                        ) as i32;
+
+                        if (x, y) != (*scrolled_x.peek(), *scrolled_y.peek()) {
+                            scrolled_x.set(x);
+                            scrolled_y.set(y);
+                            // Let the content that moved under the cursor know, so it can extend its text selection
+                            platform.replay_cursor_movement();
+                        }
+                    }
+                    auto_scroll_task.set(None);
+                });
+                auto_scroll_task.set(Some(task));
+            }
         }
     };
 
@@ -310,10 +489,25 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
         *clicking_scrollbar.write() = Some((Axis::X, coordinates.x));
     };
 
-    // Unmark any scrollbar
+    // Start dragging the content
+    let onmousedown = move |e: MouseEvent| {
+        dragging_content.set(Some(e.get_screen_coordinates()));
+    };
+
+    // Unmark any scrollbar and stop dragging the content
     let onclick = move |_: MouseEvent| {
         if clicking_scrollbar.peek().is_some() {
-            *clicking_scrollbar.write() = None;
+            let released_scrollbar = clicking_scrollbar.write().take();
+            if let Some((axis, _)) = released_scrollbar {
+                snap(axis);
+            }
+        }
+        if dragging_content.peek().is_some() {
+            dragging_content.set(None);
+            auto_scroll.set((0., 0.));
+            if let Some(task) = auto_scroll_task.write().take() {
+                task.cancel();
+            }
         }
     };
 
@@ -341,6 +535,27 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
 
     let focus_id = focus.attribute();
 
+    let (overscroll_x, overscroll_y) = *overscroll.read();
+    let (content_offset_x, content_offset_y) = if overscroll_mode == Overscroll::Bounce {
+        (
+            corrected_scrolled_x + overscroll_x,
+            corrected_scrolled_y + overscroll_y,
+        )
+    } else {
+        (corrected_scrolled_x, corrected_scrolled_y)
+    };
+
+    // Edges that glow while overscrolling
+    let glows = if overscroll_mode == Overscroll::Glow {
+        get_overscroll_glows(
+            (overscroll_x, overscroll_y),
+            size.area.width(),
+            size.area.height(),
+        )
+    } else {
+        Vec::new()
+    };
+
     rsx!(
         rect {
             role: "scrollView",
@@ -360,15 +575,25 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 rect {
                     overflow: "clip",
                     padding: "{padding}",
+                    box_sizing: "border-box",
                     height: "100%",
                     width: "100%",
                     direction: "{user_direction}",
-                    offset_y: "{corrected_scrolled_y}",
-                    offset_x: "{corrected_scrolled_x}",
+                    offset_y: "{content_offset_y}",
+                    offset_x: "{content_offset_x}",
+                    cache: "{props.cache}",
                     reference: node_ref,
                     onwheel: onwheel,
+                    onmousedown,
                     {props.children}
                 }
+                for (edge, strength) in glows {
+                    OverscrollGlow {
+                        key: "{edge}",
+                        edge,
+                        strength,
+                    }
+                }
                 ScrollBar {
                     width: "100%",
                     height: "{horizontal_scrollbar_size}",
@@ -402,10 +627,63 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     )
 }
 
+/// Glow shown in an edge of a scroll view with [`Overscroll::Glow`] while it's overscrolled.
+#[allow(non_snake_case)]
+#[component]
+pub(crate) fn OverscrollGlow(edge: &'static str, strength: f32) -> Element {
+    let (width, height, angle) = match edge {
+        "top" => ("100%", "24", 180),
+        "bottom" => ("100%", "24", 0),
+        "left" => ("24", "100%", 90),
+        _ => ("24", "100%", 270),
+    };
+    let background =
+        format!("linear-gradient({angle}deg, rgb(0, 0, 0, 0.25) 0%, transparent 100%)");
+
+    match edge {
+        "bottom" => rsx!(rect {
+            position: "absolute",
+            position_bottom: "0",
+            position_left: "0",
+            width,
+            height,
+            opacity: "{strength}",
+            background,
+        }),
+        "right" => rsx!(rect {
+            position: "absolute",
+            position_top: "0",
+            position_right: "0",
+            width,
+            height,
+            opacity: "{strength}",
+            background,
+        }),
+        _ => rsx!(rect {
+            position: "absolute",
+            position_top: "0",
+            position_left: "0",
+            width,
+            height,
+            opacity: "{strength}",
+            background,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
+    use tokio::time::advance;
+
+    use crate::{
+        get_overscroll_position,
+        get_overscroll_spring_position,
+        SCROLL_SNAP_DELAY,
+    };
 
     #[tokio::test]
     pub async fn scroll_view_wheel() {
@@ -448,6 +726,7 @@ mod test {
             name: EventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
         });
 
         utils.wait_for_update().await;
@@ -460,6 +739,495 @@ mod test {
         assert!(content.get(3).is_visible()); // 4. 600 -> 800, 800 > 300
     }
 
+    #[tokio::test]
+    pub async fn scroll_view_wheel_fractional_deltas() {
+        fn scroll_view_wheel_fractional_deltas_app() -> Element {
+            rsx!(
+                ScrollView {
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_fractional_deltas_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Tiny deltas of a precise trackpad
+        for _ in 0..100 {
+            utils.push_event(PlatformEvent::Wheel {
+                name: EventName::Wheel,
+                scroll: (0., -0.3).into(),
+                cursor: (5., 5.).into(),
+                modifiers: Modifiers::empty(),
+            });
+            utils.wait_for_update().await;
+        }
+
+        // Add up instead of being lost
+        assert_eq!(content.get(0).area().unwrap().min_y(), -30.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_fractional_deltas_after_jump() {
+        fn scroll_view_wheel_fractional_deltas_after_jump_app() -> Element {
+            let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+
+            rsx!(
+                ScrollView {
+                    scroll_controller,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                            onclick: move |_| scroll_controller.scroll_to_y(0),
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_fractional_deltas_after_jump_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        let wheel = PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -0.6).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        };
+
+        // Scrolled a bit more than it was moved
+        utils.push_event(wheel.clone());
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).area().unwrap().min_y(), -1.);
+
+        // Jump back to the start
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).area().unwrap().min_y(), 0.);
+
+        // The extra scroll of the first movement isn't taken from this one
+        utils.push_event(wheel);
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).area().unwrap().min_y(), -1.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_sensitivity() {
+        fn scroll_view_wheel_sensitivity_app() -> Element {
+            rsx!(
+                ScrollView {
+                    scroll_sensitivity: 2.0,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_sensitivity_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -50.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // Scrolls twice the distance of the wheel movement
+        assert_eq!(content.get(0).area().unwrap().min_y(), -100.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_prevent_default() {
+        fn scroll_view_wheel_prevent_default_app() -> Element {
+            let mut wheel = use_signal(String::new);
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                        onwheel: move |e: WheelEvent| {
+                            let ctrl = e.get_modifiers().contains(Modifiers::CONTROL);
+                            wheel.set(format!("{} {} {ctrl}", e.get_delta_x(), e.get_delta_y()));
+                            e.prevent_default();
+                        }
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    label {
+                        height: "200",
+                        "{wheel}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_prevent_default_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (10., -300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::CONTROL,
+        });
+
+        utils.wait_for_update().await;
+
+        // The listener received the raw deltas and the ScrollView didn't scroll
+        assert_eq!(content.get(3).get(0).text(), Some("10 -300 true"));
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn scroll_view_snap() {
+        fn scroll_view_snap_app() -> Element {
+            rsx!(
+                ScrollView {
+                    scroll_snap: ScrollSnap::Mandatory,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            snap_align: "start",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_snap_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -150.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // The short scroll moved the content freely
+        assert_eq!(content.get(1).area().unwrap().min_y(), 50.);
+
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+
+        // And then it settled in the start of the closest child
+        assert_eq!(content.get(1).area().unwrap().min_y(), 0.);
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn scroll_view_overscroll_bounce() {
+        fn scroll_view_overscroll_app() -> Element {
+            rsx!(
+                ScrollView {
+                    overscroll: Overscroll::Bounce,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_overscroll_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 50.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // Scrolling up from the start stretches the content past it
+        assert_eq!(content.get(0).area().unwrap().min_y(), 50.);
+
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+        advance(Duration::from_millis(600)).await;
+        for _ in 0..3 {
+            utils.wait_for_update().await;
+        }
+
+        // And then it springs back to the start
+        assert_eq!(content.get(0).area().unwrap().min_y(), 0.);
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn scroll_view_overscroll_glow() {
+        fn scroll_view_overscroll_glow_app() -> Element {
+            rsx!(
+                ScrollView {
+                    overscroll: Overscroll::Glow,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_overscroll_glow_app);
+        let root = utils.root();
+        let container = root.get(0).get(0);
+        let content = container.get(0);
+        utils.wait_for_update().await;
+        assert_eq!(container.children_ids().len(), 2);
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 50.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // The content stays in place but a glow is shown in the top edge
+        assert_eq!(content.get(0).area().unwrap().min_y(), 0.);
+        assert_eq!(container.children_ids().len(), 3);
+        assert_eq!(container.get(1).area().unwrap().min_y(), 0.);
+
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+        advance(Duration::from_millis(600)).await;
+        for _ in 0..3 {
+            utils.wait_for_update().await;
+        }
+
+        // And then it fades out
+        assert_eq!(container.children_ids().len(), 2);
+    }
+
+    #[test]
+    pub fn overscroll_spring() {
+        // The movement resists more the further the content is stretched, up to a fraction of the viewport
+        assert_eq!(get_overscroll_position(0., 50., 500.), 50.);
+        assert_eq!(get_overscroll_position(50., 50., 500.), 75.);
+        assert_eq!(get_overscroll_position(-90., -500., 500.), -100.);
+
+        // The spring brings it back to the bound without going past it
+        let mut previous = 100.;
+        for frame in 0..60 {
+            let position = get_overscroll_spring_position(100., Duration::from_millis(frame * 16));
+            assert!(position <= previous && position >= 0.);
+            previous = position;
+        }
+        assert_eq!(
+            get_overscroll_spring_position(100., Duration::from_secs(1)),
+            0.
+        );
+        assert_eq!(
+            get_overscroll_spring_position(-100., Duration::from_secs(1)),
+            0.
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn scroll_view_snap_proximity() {
+        fn scroll_view_snap_proximity_app() -> Element {
+            rsx!(
+                ScrollView {
+                    scroll_snap: ScrollSnap::Proximity,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            snap_align: "start",
+                            height: "400",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_snap_proximity_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Too far from any child to snap
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -200.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).area().unwrap().min_y(), -200.);
+
+        // Close enough to the second child
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -100.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(1).area().unwrap().min_y(), 0.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_chaining() {
+        fn scroll_view_chaining_app() -> Element {
+            let mut scroll_chaining = use_signal(|| true);
+
+            rsx!(
+                ScrollView {
+                    ScrollView {
+                        scroll_chaining: *scroll_chaining.read(),
+                        theme: theme_with!(ScrollViewTheme {
+                            height: "200".into(),
+                        }),
+                        rect {
+                            height: "400",
+                            width: "200",
+                        }
+                    }
+                    rect {
+                        height: "600",
+                        width: "200",
+                        onclick: move |_| scroll_chaining.set(false),
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_chaining_app);
+        let root = utils.root();
+        let outer_content = root.get(0).get(0).get(0);
+        let inner_content = outer_content.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // The inner ScrollView scrolled to its end and the outer one scrolled the rest
+        assert_eq!(inner_content.get(0).area().unwrap().min_y(), -300.);
+        assert_eq!(outer_content.get(0).area().unwrap().min_y(), -100.);
+
+        // Disable the chaining
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 400.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // The inner ScrollView can't scroll anymore, but neither does the outer one
+        assert_eq!(outer_content.get(0).area().unwrap().min_y(), -100.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_chaining_consecutive_wheels() {
+        fn scroll_view_chaining_consecutive_wheels_app() -> Element {
+            rsx!(
+                ScrollView {
+                    ScrollView {
+                        theme: theme_with!(ScrollViewTheme {
+                            height: "200".into(),
+                        }),
+                        rect {
+                            height: "400",
+                            width: "200",
+                        }
+                    }
+                    rect {
+                        height: "600",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_chaining_consecutive_wheels_app);
+        let root = utils.root();
+        let outer_content = root.get(0).get(0).get(0);
+        let inner_content = outer_content.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Both wheel events are handled before the ScrollViews render again
+        for _ in 0..2 {
+            utils.push_event(PlatformEvent::Wheel {
+                name: EventName::Wheel,
+                scroll: (0., -150.).into(),
+                cursor: (5., 5.).into(),
+                modifiers: Modifiers::empty(),
+            });
+        }
+        utils.wait_for_update().await;
+
+        // The second one only had 50 left to scroll in the inner ScrollView, so the outer one scrolled the rest
+        assert_eq!(inner_content.get(0).area().unwrap().min_y(), -300.);
+        assert_eq!(outer_content.get(0).area().unwrap().min_y(), -100.);
+    }
+
     #[tokio::test]
     pub async fn scroll_view_scrollbar() {
         fn scroll_view_scrollbar_app() -> Element {
@@ -558,4 +1326,149 @@ mod test {
         assert!(content.get(2).is_visible());
         assert!(content.get(3).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn scroll_view_auto_scroll() {
+        fn scroll_view_auto_scroll_app() -> Element {
+            let mut editable = use_editable(
+                || EditableConfig::new("Hello, World!".repeat(200)),
+                EditableMode::MultipleLinesSingleEditor,
+            );
+            let mut movements = use_signal(|| 0);
+
+            let onmousedown = move |e: MouseEvent| {
+                editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+            };
+
+            let onmouseover = move |e: MouseEvent| {
+                movements += 1;
+                editable.process_event(&EditableEvent::MouseOver(e.data, 0));
+            };
+
+            let onclick = move |_: MouseEvent| {
+                editable.process_event(&EditableEvent::Click);
+            };
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "2000",
+                        width: "200",
+                        cursor_reference: editable.cursor_attr(),
+                        onmousedown,
+                        onmouseover,
+                        onclick,
+                        label {
+                            "{movements}"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_auto_scroll_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        let viewport = root.get(0).area().unwrap();
+        assert_eq!(content.get(0).area().unwrap().min_y(), viewport.min_y());
+
+        // Drag the content to the bottom edge of the viewport
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (100., 100.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 495.).into(),
+            button: Some(MouseButton::Left),
+        });
+        for _ in 0..10 {
+            utils.wait_for_update().await;
+        }
+
+        // The content kept scrolling and moving under the cursor without it moving
+        let scrolled = content.get(0).area().unwrap().min_y();
+        assert!(scrolled < viewport.min_y());
+        let movements = content
+            .get(0)
+            .get(0)
+            .get(0)
+            .text()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        assert!(movements > 1);
+
+        // Moving the cursor back inside stops it
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 250.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let stopped = content.get(0).area().unwrap().min_y();
+        for _ in 0..5 {
+            utils.wait_for_update().await;
+        }
+        assert_eq!(content.get(0).area().unwrap().min_y(), stopped);
+
+        // Ending the drag at the edge doesn't scroll
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (100., 250.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 495.).into(),
+            button: None,
+        });
+        for _ in 0..5 {
+            utils.wait_for_update().await;
+        }
+        assert_eq!(content.get(0).area().unwrap().min_y(), stopped);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_no_auto_scroll_without_selection() {
+        fn scroll_view_no_auto_scroll_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "2000",
+                        width: "200",
+                        onmousedown: |_| {},
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_no_auto_scroll_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        let viewport = root.get(0).area().unwrap();
+
+        // Dragging something that isn't a text selection to the edge doesn't scroll
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (100., 100.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 495.).into(),
+            button: Some(MouseButton::Left),
+        });
+        for _ in 0..10 {
+            utils.wait_for_update().await;
+        }
+
+        assert_eq!(content.get(0).area().unwrap().min_y(), viewport.min_y());
+    }
 }
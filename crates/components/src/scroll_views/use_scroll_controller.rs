@@ -5,12 +5,22 @@ use dioxus::prelude::{
     schedule_update_any,
     use_drop,
     use_hook,
+    use_memo,
+    Memo,
+    ReadOnlySignal,
     Readable,
     ScopeId,
     Signal,
     Writable,
     WritableVecExt,
 };
+use freya_common::NodeReferenceLayout;
+use torin::prelude::{
+    Area,
+    Size2D,
+};
+
+use crate::get_corrected_scroll_position;
 
 #[derive(Default, PartialEq, Eq)]
 pub enum ScrollPosition {
@@ -20,6 +30,55 @@ pub enum ScrollPosition {
     // Specific
 }
 
+/// Where to place an area when bringing it into view with [`ScrollController::scroll_into_view`].
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ScrollAlignment {
+    /// Align it with the start of the viewport.
+    Start,
+    /// Center it in the viewport.
+    Center,
+    /// Align it with the end of the viewport.
+    End,
+    /// Scroll as little as possible, not moving at all if it's already fully visible.
+    #[default]
+    Nearest,
+}
+
+/// Get the scroll position that places the range `start..end` of a viewport, given in absolute coordinates,
+/// in the requested alignment. The result is clamped to the content bounds.
+fn get_scroll_position_for_area(
+    (start, end): (f32, f32),
+    (viewport_start, viewport_size): (f32, f32),
+    inner_size: f32,
+    scroll_position: f32,
+    alignment: ScrollAlignment,
+) -> i32 {
+    // Position of the area relative to the start of the content
+    let content_start = start - viewport_start - scroll_position;
+    let size = end - start;
+
+    let align_start = -content_start;
+    let align_end = viewport_size - content_start - size;
+
+    let new_position = match alignment {
+        ScrollAlignment::Start => align_start,
+        ScrollAlignment::Center => (viewport_size - size) / 2.0 - content_start,
+        ScrollAlignment::End => align_end,
+        ScrollAlignment::Nearest => {
+            let viewport_end = viewport_start + viewport_size;
+            if start >= viewport_start && end <= viewport_end {
+                scroll_position
+            } else if start < viewport_start || size > viewport_size {
+                align_start
+            } else {
+                align_end
+            }
+        }
+    };
+
+    get_corrected_scroll_position(inner_size, viewport_size, new_position) as i32
+}
+
 #[derive(Default, PartialEq, Eq)]
 pub enum ScrollDirection {
     #[default]
@@ -33,8 +92,17 @@ pub struct ScrollConfig {
     pub default_horizontal_position: ScrollPosition,
 }
 
+pub(crate) enum ScrollTarget {
+    Position(ScrollPosition),
+    /// Bring the Node with the given layout into the viewport.
+    Node {
+        layout: ReadOnlySignal<NodeReferenceLayout>,
+        alignment: ScrollAlignment,
+    },
+}
+
 pub struct ScrollRequest {
-    pub(crate) position: ScrollPosition,
+    pub(crate) target: ScrollTarget,
     pub(crate) direction: ScrollDirection,
     pub(crate) init: bool,
     pub(crate) applied_by: HashSet<ScopeId>,
@@ -43,7 +111,7 @@ pub struct ScrollRequest {
 impl ScrollRequest {
     pub fn new(position: ScrollPosition, direction: ScrollDirection) -> ScrollRequest {
         ScrollRequest {
-            position,
+            target: ScrollTarget::Position(position),
             direction,
             init: false,
             applied_by: HashSet::default(),
@@ -57,6 +125,8 @@ pub struct ScrollController {
     requests: Signal<Vec<ScrollRequest>>,
     x: Signal<i32>,
     y: Signal<i32>,
+    /// Size of the content and the viewport of the scroll view it was last applied in.
+    sizes: Signal<(Size2D, Size2D)>,
 }
 
 impl From<ScrollController> for (Signal<i32>, Signal<i32>) {
@@ -72,10 +142,11 @@ impl ScrollController {
             y: Signal::new(y),
             requests_subscribers: Signal::new(HashSet::new()),
             requests: Signal::new(initial_requests),
+            sizes: Signal::new((Size2D::default(), Size2D::default())),
         }
     }
 
-    pub fn use_apply(&mut self, width: f32, height: f32) {
+    pub fn use_apply(&mut self, width: f32, height: f32, viewport: Area) {
         let scope_id = current_scope_id().unwrap();
 
         if !self.requests_subscribers.peek().contains(&scope_id) {
@@ -87,6 +158,11 @@ impl ScrollController {
             requests_subscribers.write().remove(&scope_id);
         });
 
+        let sizes = (Size2D::new(width, height), viewport.size);
+        if *self.sizes.peek() != sizes {
+            self.sizes.set(sizes);
+        }
+
         self.requests.write().retain_mut(|request| {
             if request.applied_by.contains(&scope_id) {
                 return true;
@@ -94,21 +170,21 @@ impl ScrollController {
 
             match request {
                 ScrollRequest {
-                    position: ScrollPosition::Start,
+                    target: ScrollTarget::Position(ScrollPosition::Start),
                     direction: ScrollDirection::Vertical,
                     ..
                 } => {
                     *self.y.write() = 0;
                 }
                 ScrollRequest {
-                    position: ScrollPosition::Start,
+                    target: ScrollTarget::Position(ScrollPosition::Start),
                     direction: ScrollDirection::Horizontal,
                     ..
                 } => {
                     *self.x.write() = 0;
                 }
                 ScrollRequest {
-                    position: ScrollPosition::End,
+                    target: ScrollTarget::Position(ScrollPosition::End),
                     direction: ScrollDirection::Vertical,
                     init,
                     ..
@@ -119,7 +195,7 @@ impl ScrollController {
                     *self.y.write() = -height as i32;
                 }
                 ScrollRequest {
-                    position: ScrollPosition::End,
+                    target: ScrollTarget::Position(ScrollPosition::End),
                     direction: ScrollDirection::Horizontal,
                     init,
                     ..
@@ -129,6 +205,36 @@ impl ScrollController {
                     }
                     *self.x.write() = -width as i32;
                 }
+                ScrollRequest {
+                    target: ScrollTarget::Node { layout, alignment },
+                    direction: ScrollDirection::Vertical,
+                    ..
+                } => {
+                    let area = layout.peek().area;
+                    let y = get_scroll_position_for_area(
+                        (area.min_y(), area.max_y()),
+                        (viewport.min_y(), viewport.height()),
+                        height,
+                        *self.y.peek() as f32,
+                        *alignment,
+                    );
+                    *self.y.write() = y;
+                }
+                ScrollRequest {
+                    target: ScrollTarget::Node { layout, alignment },
+                    direction: ScrollDirection::Horizontal,
+                    ..
+                } => {
+                    let area = layout.peek().area;
+                    let x = get_scroll_position_for_area(
+                        (area.min_x(), area.max_x()),
+                        (viewport.min_x(), viewport.width()),
+                        width,
+                        *self.x.peek() as f32,
+                        *alignment,
+                    );
+                    *self.x.write() = x;
+                }
             }
 
             request.applied_by.insert(scope_id);
@@ -152,6 +258,28 @@ impl ScrollController {
     ) {
         self.requests
             .push(ScrollRequest::new(scroll_position, scroll_direction));
+        self.notify_subscribers();
+    }
+
+    /// Scroll so the Node whose layout is given, obtained with `use_node_signal`, becomes visible in both directions.
+    /// Its latest layout is used once the scroll view applies the request.
+    pub fn scroll_into_view(
+        &mut self,
+        layout: ReadOnlySignal<NodeReferenceLayout>,
+        alignment: ScrollAlignment,
+    ) {
+        for direction in [ScrollDirection::Vertical, ScrollDirection::Horizontal] {
+            self.requests.push(ScrollRequest {
+                target: ScrollTarget::Node { layout, alignment },
+                direction,
+                init: false,
+                applied_by: HashSet::default(),
+            });
+        }
+        self.notify_subscribers();
+    }
+
+    fn notify_subscribers(&self) {
         let schedule = schedule_update_any();
         for scope_id in self.requests_subscribers.read().iter() {
             schedule(*scope_id);
@@ -159,6 +287,82 @@ impl ScrollController {
     }
 }
 
+/// Scroll position of a scroll view, see [`use_scroll`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollInfo {
+    /// How far the content is scrolled to the right, from `0` to `max_x`.
+    pub scroll_x: f32,
+    /// How far the content is scrolled down, from `0` to `max_y`.
+    pub scroll_y: f32,
+    /// How far the content can be scrolled to the right.
+    pub max_x: f32,
+    /// How far the content can be scrolled down.
+    pub max_y: f32,
+}
+
+impl ScrollInfo {
+    /// Check if the content is scrolled to the top, which is always the case when it can't be scrolled vertically.
+    pub fn is_at_top(&self) -> bool {
+        self.scroll_y <= 0.0
+    }
+
+    /// Check if the content is scrolled to the bottom, which is always the case when it can't be scrolled vertically.
+    pub fn is_at_bottom(&self) -> bool {
+        self.scroll_y >= self.max_y
+    }
+}
+
+/// Subscribe to the scroll position of the scroll view that uses the given [`ScrollController`],
+/// updated as the user scrolls and as its content or viewport change size.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+///     let scroll = use_scroll(scroll_controller);
+///
+///     rsx!(
+///         ScrollView {
+///             scroll_controller,
+///             theme: theme_with!(ScrollViewTheme {
+///                 height: "calc(100% - 50)".into(),
+///             }),
+///             rect {
+///                 height: "2000",
+///             }
+///         }
+///         label {
+///             "Read {scroll.read().scroll_y} of {scroll.read().max_y}"
+///         }
+///         if !scroll.read().is_at_top() {
+///             Button {
+///                 onpress: move |_| scroll_controller.scroll_to(ScrollPosition::Start, ScrollDirection::Vertical),
+///                 label { "Back to top" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scroll(scroll_controller: ScrollController) -> Memo<ScrollInfo> {
+    use_memo(move || {
+        let (inner, viewport) = *scroll_controller.sizes.read();
+        let max_x = (inner.width - viewport.width).max(0.0);
+        let max_y = (inner.height - viewport.height).max(0.0);
+        let x = *scroll_controller.x.read() as f32;
+        let y = *scroll_controller.y.read() as f32;
+
+        // The offsets of the content go from `0` to negative numbers as it's scrolled
+        ScrollInfo {
+            scroll_x: get_corrected_scroll_position(inner.width, viewport.width, x).abs(),
+            scroll_y: get_corrected_scroll_position(inner.height, viewport.height, y).abs(),
+            max_x,
+            max_y,
+        }
+    })
+}
+
 pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollController {
     use_hook(|| {
         let config = init();
@@ -167,13 +371,13 @@ pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollContr
             0,
             vec![
                 ScrollRequest {
-                    position: config.default_vertical_position,
+                    target: ScrollTarget::Position(config.default_vertical_position),
                     direction: ScrollDirection::Vertical,
                     init: true,
                     applied_by: HashSet::default(),
                 },
                 ScrollRequest {
-                    position: config.default_horizontal_position,
+                    target: ScrollTarget::Position(config.default_horizontal_position),
                     direction: ScrollDirection::Horizontal,
                     init: true,
                     applied_by: HashSet::default(),
@@ -275,4 +479,144 @@ mod test {
         assert!(content.get(3).is_visible());
         assert!(content.get(4).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn use_scroll() {
+        fn scroll_view_app() -> Element {
+            let scroll_controller = use_scroll_controller(ScrollConfig::default);
+            let scroll = use_scroll(scroll_controller);
+            let ScrollInfo {
+                scroll_y, max_y, ..
+            } = *scroll.read();
+
+            rsx!(
+                label {
+                    "{scroll_y} {max_y} {scroll.read().is_at_top()} {scroll.read().is_at_bottom()}"
+                }
+                ScrollView {
+                    scroll_controller,
+                    theme: theme_with!(ScrollViewTheme {
+                        height: "400".into(),
+                    }),
+                    rect {
+                        height: "1000",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let root = utils.root();
+        let label = root.get(0).get(0);
+        assert_eq!(label.text(), Some("0 600 true false"));
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(label.text(), Some("300 600 false false"));
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -500.).into(),
+            cursor: (5., 100.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(label.text(), Some("600 600 false true"));
+    }
+
+    #[tokio::test]
+    pub async fn scroll_into_view() {
+        fn scroll_view_app() -> Element {
+            let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+            let (first_reference, first_layout) = use_node_signal();
+            let (third_reference, third_layout) = use_node_signal();
+
+            rsx!(
+                Button {
+                    onclick: move |_| {
+                        scroll_controller.scroll_into_view(third_layout, ScrollAlignment::Start);
+                    },
+                    label {
+                        "Third"
+                    }
+                }
+                Button {
+                    onclick: move |_| {
+                        scroll_controller.scroll_into_view(first_layout, ScrollAlignment::Nearest);
+                    },
+                    label {
+                        "First"
+                    }
+                }
+                ScrollView {
+                    scroll_controller,
+                    rect {
+                        reference: first_reference,
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        reference: third_reference,
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let root = utils.root();
+        let third_button = root.get(0).area().unwrap();
+        let first_button = root.get(1).area().unwrap();
+        let viewport = root.get(2).area().unwrap();
+        let content = root.get(2).get(0).get(0);
+
+        // Bring the third item to the start of the viewport
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: third_button.center().cast(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(2).area().unwrap().min_y(), viewport.min_y());
+        assert!(!content.get(0).is_visible());
+
+        // Bring the first item back into view
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: first_button.center().cast(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).area().unwrap().min_y(), viewport.min_y());
+        assert!(content.get(0).is_visible());
+    }
 }
@@ -16,6 +16,7 @@ use freya_hooks::{
     use_applied_theme,
     use_focus,
     use_node,
+    use_platform,
     ScrollBarThemeWith,
     ScrollViewThemeWith,
 };
@@ -23,17 +24,24 @@ use freya_hooks::{
 use crate::{
     get_container_size,
     get_corrected_scroll_position,
+    get_overscroll_glows,
     get_scroll_position_from_cursor,
-    get_scroll_position_from_wheel,
     get_scrollbar_pos_and_size,
     is_scrollbar_visible,
     manage_key_event,
-    scroll_views::use_scroll_controller,
+    overscroll_with_wheel,
+    scroll_views::{
+        scroll_view::OverscrollGlow,
+        use_scroll_controller,
+    },
+    scroll_with_wheel,
     Axis,
+    Overscroll,
     ScrollBar,
     ScrollConfig,
     ScrollController,
     ScrollThumb,
+    WheelRemainder,
     SCROLL_SPEED_MULTIPLIER,
 };
 
@@ -65,10 +73,26 @@ pub struct VirtualScrollViewProps<
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Continue scrolling the closest scrollable ancestor with what's left of the wheel movement
+    /// once this one reaches its start or end. Enabled by default.
+    #[props(default = true, into)]
+    pub scroll_chaining: bool,
+    /// Multiplier of how much every wheel movement scrolls in this container, on top of the sensitivity of the window.
+    /// It must be greater than `0`. Default is `1.0`.
+    #[props(default = 1.0, into)]
+    pub scroll_sensitivity: f32,
     /// Cache elements or not, changing `builder_args` will invalidate the cache if enabled.
     /// Default is `true`.
     #[props(default = true, into)]
     pub cache_elements: bool,
+    /// Extra items to render before and after the visible ones, useful to avoid
+    /// flickering when scrolling fast. Default is `0`.
+    #[props(default = 0, into)]
+    pub overscan: usize,
+    /// What happens when the user keeps scrolling once the start or end is reached, [`Overscroll::None`] by default.
+    /// Overscrolling takes the movement that `scroll_chaining` would pass to the ancestors.
+    #[props(default)]
+    pub overscroll: Overscroll,
 
     pub scroll_controller: Option<ScrollController>,
 }
@@ -85,7 +109,11 @@ impl<
             && self.direction == other.direction
             && self.show_scrollbar == other.show_scrollbar
             && self.scroll_with_arrows == other.scroll_with_arrows
+            && self.scroll_chaining == other.scroll_chaining
+            && self.scroll_sensitivity == other.scroll_sensitivity
             && self.builder_args == other.builder_args
+            && self.overscan == other.overscan
+            && self.overscroll == other.overscroll
             && self.scroll_controller == other.scroll_controller
     }
 }
@@ -95,6 +123,7 @@ fn get_render_range(
     scroll_position: f32,
     item_size: f32,
     item_length: f32,
+    overscan: usize,
 ) -> Range<usize> {
     let render_index_start = (-scroll_position) / item_size;
     let potentially_visible_length = (viewport_size / item_size) + 1.0;
@@ -106,7 +135,10 @@ fn get_render_range(
         render_index_start + potentially_visible_length
     };
 
-    render_index_start as usize..(render_index_end as usize)
+    let render_index_start = (render_index_start as usize).saturating_sub(overscan);
+    let render_index_end = (render_index_end as usize + overscan).min(item_length as usize);
+
+    render_index_start..render_index_end
 }
 
 /// One-direction scrollable area that dynamically builds and renders items based in their size and current available size,
@@ -175,6 +207,11 @@ pub fn VirtualScrollView<
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
     let mut clicking_shift = use_signal(|| false);
     let mut clicking_alt = use_signal(|| false);
+    let wheel_remainder_x = use_signal(WheelRemainder::default);
+    let wheel_remainder_y = use_signal(WheelRemainder::default);
+    let overscroll = use_signal(|| (0f32, 0f32));
+    let overscroll_task = use_signal::<Option<Task>>(|| None);
+    let platform = use_platform();
     let mut scroll_controller = props
         .scroll_controller
         .unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
@@ -192,12 +229,15 @@ pub fn VirtualScrollView<
     let items_length = props.length;
     let items_size = props.item_size;
     let scroll_with_arrows = props.scroll_with_arrows;
+    let scroll_chaining = props.scroll_chaining;
+    let scroll_sensitivity = props.scroll_sensitivity;
+    let overscroll_mode = props.overscroll;
 
     let direction_is_vertical = user_direction == "vertical";
 
     let inner_size = items_size + (items_size * items_length as f32);
 
-    scroll_controller.use_apply(inner_size, inner_size);
+    scroll_controller.use_apply(inner_size, inner_size, size.area);
 
     let vertical_scrollbar_is_visible = user_direction != "horizontal"
         && is_scrollbar_visible(show_scrollbar, inner_size, size.area.height());
@@ -220,47 +260,69 @@ pub fn VirtualScrollView<
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
-        let speed_multiplier = if *clicking_alt.peek() {
+        if e.is_default_prevented() {
+            return;
+        }
+
+        let alt_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
             1.0
         };
+        let speed_multiplier = alt_multiplier * scroll_sensitivity;
 
-        let wheel_movement = e.get_delta_y() as f32 * speed_multiplier;
+        let wheel_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
 
         let scroll_vertically_or_not = (direction_is_vertical && !*clicking_shift.peek())
             || !direction_is_vertical && *clicking_shift.peek();
 
-        if scroll_vertically_or_not {
-            let scroll_position_y = get_scroll_position_from_wheel(
+        let scrolled_distance = if scroll_vertically_or_not {
+            scroll_with_wheel(
                 wheel_movement,
                 inner_size,
                 size.area.height(),
-                corrected_scrolled_y,
-            );
-
-            // Only scroll when there is still area to scroll
-            if *scrolled_y.peek() != scroll_position_y {
-                e.stop_propagation();
-                *scrolled_y.write() = scroll_position_y;
-            } else {
-                return;
-            }
+                scrolled_y,
+                wheel_remainder_y,
+            )
         } else {
-            let scroll_position_x = get_scroll_position_from_wheel(
+            scroll_with_wheel(
                 wheel_movement,
                 inner_size,
                 size.area.width(),
-                corrected_scrolled_x,
-            );
+                scrolled_x,
+                wheel_remainder_x,
+            )
+        };
 
-            // Only scroll when there is still area to scroll
-            if *scrolled_x.peek() != scroll_position_x {
-                e.stop_propagation();
-                *scrolled_x.write() = scroll_position_x;
+        e.consume_delta_y((scrolled_distance / speed_multiplier) as f64);
+
+        // Stretch the content past its bounds with what couldn't be scrolled
+        if overscroll_mode != Overscroll::None && e.get_remaining_delta_y().abs() >= 1.0 {
+            let overscroll_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
+            e.consume_delta_y(e.get_remaining_delta_y());
+
+            let (axis, viewport_size) = if scroll_vertically_or_not {
+                (Axis::Y, size.area.height())
             } else {
-                return;
-            }
+                (Axis::X, size.area.width())
+            };
+            overscroll_with_wheel(
+                overscroll_movement,
+                axis,
+                viewport_size,
+                overscroll,
+                overscroll_task,
+                platform,
+            );
+        }
+
+        // Let the scrollable ancestors scroll what couldn't be scrolled here
+        if !scroll_chaining || e.get_remaining_delta_y().abs() < 1.0 {
+            e.stop_propagation();
+        }
+
+        if scrolled_distance == 0.0 {
+            return;
         }
 
         focus.focus();
@@ -388,7 +450,9 @@ pub fn VirtualScrollView<
         scroll_position,
         items_size,
         items_length as f32,
+        props.overscan,
     );
+    let render_index_start = render_range.start;
 
     let children = if props.cache_elements {
         let children = use_memo(use_reactive(
@@ -417,9 +481,31 @@ pub fn VirtualScrollView<
         .map(|f| f.0 == Axis::Y)
         .unwrap_or_default();
 
-    let offset_y_min = (-corrected_scrolled_y / items_size).floor() * items_size;
+    let offset_y_min = if user_direction == "vertical" {
+        render_index_start as f32 * items_size
+    } else {
+        0.0
+    };
     let offset_y = -corrected_scrolled_y - offset_y_min;
 
+    let (overscroll_x, overscroll_y) = *overscroll.read();
+    let (content_offset_x, content_offset_y) = if overscroll_mode == Overscroll::Bounce {
+        (overscroll_x, -offset_y + overscroll_y)
+    } else {
+        (0.0, -offset_y)
+    };
+
+    // Edges that glow while overscrolling
+    let glows = if overscroll_mode == Overscroll::Glow {
+        get_overscroll_glows(
+            (overscroll_x, overscroll_y),
+            size.area.width(),
+            size.area.height(),
+        )
+    } else {
+        Vec::new()
+    };
+
     let focus_id = focus.attribute();
 
     rsx!(
@@ -441,14 +527,23 @@ pub fn VirtualScrollView<
                 rect {
                     overflow: "clip",
                     padding: "{padding}",
+                    box_sizing: "border-box",
                     height: "100%",
                     width: "100%",
                     direction: "{user_direction}",
-                    offset_y: "{-offset_y}",
+                    offset_y: "{content_offset_y}",
+                    offset_x: "{content_offset_x}",
                     reference: node_ref,
                     onwheel: onwheel,
                     {children}
                 }
+                for (edge, strength) in glows {
+                    OverscrollGlow {
+                        key: "{edge}",
+                        edge,
+                        strength,
+                    }
+                }
                 ScrollBar {
                     width: "100%",
                     height: "{horizontal_scrollbar_size}",
@@ -484,8 +579,13 @@ pub fn VirtualScrollView<
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
+    use tokio::time::advance;
+
+    use crate::SCROLL_SNAP_DELAY;
 
     #[tokio::test]
     pub async fn virtual_scroll_view_wheel() {
@@ -531,6 +631,7 @@ mod test {
             name: EventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
         });
 
         utils.wait_for_update().await;
@@ -671,4 +772,110 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_overscan() {
+        fn virtual_scroll_view_overscan_app() -> Element {
+            let values = use_signal(|| ["Hello, World!"].repeat(30));
+
+            rsx!(VirtualScrollView {
+                length: values.read().len(),
+                item_size: 50.0,
+                direction: "vertical",
+                overscan: 2,
+                builder: move |index, _: &Option<()>| {
+                    let value = values.read()[index];
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "50",
+                            "{index} {value}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_overscan_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // 11 visible items plus 2 extra after them
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 13);
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // 11 visible items plus 2 extra in each side, from 4 to 19
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 15);
+        for (n, i) in (4..19).enumerate() {
+            let child = content.get(n);
+            assert_eq!(
+                child.get(0).text(),
+                Some(format!("{i} Hello, World!").as_str())
+            );
+        }
+
+        // The first visible item is still at the top
+        assert_eq!(content.get(2).area().unwrap().min_y(), 0.0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn virtual_scroll_view_overscroll_bounce() {
+        fn virtual_scroll_view_overscroll_bounce_app() -> Element {
+            rsx!(VirtualScrollView {
+                length: 30,
+                item_size: 50.0,
+                direction: "vertical",
+                overscroll: Overscroll::Bounce,
+                builder: move |index, _: &Option<()>| {
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "50",
+                            "{index}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_overscroll_bounce_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 50.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // Scrolling up from the start stretches the content past it
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.get(0).area().unwrap().min_y(), 50.);
+
+        advance(SCROLL_SNAP_DELAY * 2).await;
+        utils.wait_for_update().await;
+        advance(Duration::from_millis(600)).await;
+        for _ in 0..3 {
+            utils.wait_for_update().await;
+        }
+
+        // And then it springs back to the start
+        assert_eq!(content.get(0).area().unwrap().min_y(), 0.);
+    }
 }
@@ -122,6 +122,7 @@ pub fn SidebarItem(
             color: "{font_theme.color}",
             corner_radius: "99",
             padding: "8 10",
+            box_sizing: "border-box",
             background: "{background}",
             {children}
         }
@@ -207,6 +207,7 @@ pub fn Slider(
                         height: "18",
                         corner_radius: "50",
                         padding: "4",
+                        box_sizing: "border-box",
                         rect {
                             height: "100%",
                             width: "100%",
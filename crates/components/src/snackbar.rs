@@ -98,6 +98,7 @@ pub fn SnackBarBox(children: Element, theme: Option<SnackBarThemeWith>) -> Eleme
             background: "{background}",
             overflow: "clip",
             padding: "10",
+            box_sizing: "border-box",
             color: "{color}",
             direction: "horizontal",
             {children}
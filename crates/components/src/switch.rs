@@ -152,6 +152,7 @@ pub fn Switch(props: SwitchProps) -> Element {
             width: "50",
             height: "25",
             padding: "1",
+            box_sizing: "border-box",
             corner_radius: "50",
             background: "{background}",
             border: "{border}",
@@ -166,6 +167,7 @@ pub fn Switch(props: SwitchProps) -> Element {
                 height: "100%",
                 offset_x: "{offset_x}",
                 padding: "2.5",
+                box_sizing: "border-box",
                 corner_radius: "50",
                 rect {
                     background: "{circle}",
@@ -158,6 +158,7 @@ pub fn TableCell(props: TableCellProps) -> Element {
         rect {
             overflow: "clip",
             padding: "{padding}",
+            box_sizing: "border-box",
             width: "{width}%",
             main_align: "end",
             cross_align: "center",
@@ -249,6 +249,7 @@ pub fn BottomTab(children: Element, theme: Option<BottomTabThemeWith>) -> Elemen
             background: "{background}",
             text_align: "center",
             padding: "{padding}",
+            box_sizing: "border-box",
             main_align: "center",
             cross_align: "center",
             corner_radius: "99",
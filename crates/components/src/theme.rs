@@ -1,22 +1,119 @@
 use dioxus::prelude::*;
 use freya_hooks::{
-    use_init_theme,
     Theme,
+    ThemeWith,
 };
 
 /// Properties for the [`ThemeProvider`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct ThemeProviderProps {
-    /// Theme to provide.
+    /// Theme to provide. Uses the Theme of the closest [`ThemeProvider`] above by default, or the default [`Theme`] if there is none.
     pub theme: Option<Theme>,
+    /// Parts of the Theme to override, on top of the `theme`.
+    pub overrides: Option<ThemeWith>,
     /// Inner children to provide a Theme to.
     pub children: Element,
 }
 
 /// Provides a `Theme` for all its children.
+///
+/// The children are updated when the `theme` changes, and so are the ones of nested [`ThemeProvider`]s
+/// that don't provide their own `theme`, which can still override parts of it with `overrides`.
 #[allow(non_snake_case)]
 pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
-    use_init_theme(|| props.theme.unwrap_or_default());
+    let parent_theme = use_hook(try_consume_context::<Signal<Theme>>);
+
+    // Follow the passed theme, or the theme of the parent provider when there is none
+    let mut new_theme = props
+        .theme
+        .or_else(|| parent_theme.map(|parent_theme| parent_theme.read().clone()))
+        .unwrap_or_default();
+    if let Some(overrides) = &props.overrides {
+        new_theme.apply_optional(overrides);
+    }
+
+    let mut theme = use_context_provider(|| Signal::new(new_theme.clone()));
+    let mut provided_theme = use_signal(|| new_theme.clone());
+
+    // Updated while rendering so the children never see the previous theme,
+    // but only when it actually changes so the writes through `use_theme` are kept
+    if *provided_theme.peek() != new_theme {
+        provided_theme.set(new_theme.clone());
+        theme.set(new_theme);
+    }
 
     rsx!({ props.children })
 }
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn theme_provider() {
+        fn theme_provider_app() -> Element {
+            let mut dark = use_signal(|| false);
+            let theme = if dark() { DARK_THEME } else { LIGHT_THEME };
+
+            rsx!(
+                ThemeProvider {
+                    theme,
+                    rect {
+                        onclick: move |_| dark.toggle(),
+                        ThemeName {}
+                        ThemeProvider {
+                            ThemeName {}
+                        }
+                        ThemeProvider {
+                            overrides: ThemeWith {
+                                button: Some(theme_with!(ButtonTheme {
+                                    padding: "20".into(),
+                                })),
+                                ..Default::default()
+                            },
+                            ThemeName {}
+                            ButtonPadding {}
+                        }
+                    }
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        fn ThemeName() -> Element {
+            let theme = use_get_theme();
+            rsx!(label { "{theme.name}" })
+        }
+
+        #[allow(non_snake_case)]
+        fn ButtonPadding() -> Element {
+            let theme = use_get_theme();
+            rsx!(label { "{theme.button.padding}" })
+        }
+
+        let mut utils = launch_test(theme_provider_app);
+        let root = utils.root().get(0);
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(0).get(0).text(), Some(LIGHT_THEME.name));
+        assert_eq!(root.get(1).get(0).text(), Some(LIGHT_THEME.name));
+        // Nested providers can override parts of the theme of their parent
+        assert_eq!(root.get(2).get(0).text(), Some(LIGHT_THEME.name));
+        assert_eq!(root.get(3).get(0).text(), Some("20"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The theme changes are passed down to the nested providers
+        assert_eq!(root.get(0).get(0).text(), Some(DARK_THEME.name));
+        assert_eq!(root.get(1).get(0).text(), Some(DARK_THEME.name));
+        assert_eq!(root.get(2).get(0).text(), Some(DARK_THEME.name));
+        assert_eq!(root.get(3).get(0).text(), Some("20"));
+    }
+}
@@ -1,10 +1,29 @@
+use std::time::Duration;
+
 use dioxus::prelude::*;
-use freya_elements::elements as dioxus_elements;
+use freya_core::prelude::NativePlatformReceiver;
+use freya_elements::{
+    elements as dioxus_elements,
+    events::{
+        MouseEvent,
+        WheelEvent,
+    },
+};
 use freya_hooks::{
     use_applied_theme,
+    use_debounce,
+    use_node_signal,
+    use_platform_information,
     TooltipTheme,
     TooltipThemeWith,
 };
+use torin::prelude::{
+    Area,
+    Point2D,
+    Size2D,
+};
+
+use crate::Portal;
 
 /// Properties for the [`Tooltip`] component.
 #[derive(Props, Clone, PartialEq)]
@@ -15,7 +34,7 @@ pub struct TooltipProps {
     pub url: String,
 }
 
-/// `Tooltip` component
+/// `Tooltip` component. Use it inside a [`TooltipContainer`] to show it when hovering an element.
 ///
 /// # Styling
 /// Inherits the [`TooltipTheme`](freya_hooks::TooltipTheme)
@@ -40,3 +59,292 @@ pub fn Tooltip(TooltipProps { url, theme }: TooltipProps) -> Element {
         }
     )
 }
+
+/// Side of the anchor where the tooltip of a [`TooltipContainer`] is placed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TooltipPosition {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// To the left of the anchor.
+    Left,
+    /// To the right of the anchor.
+    Right,
+}
+
+impl TooltipPosition {
+    fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Calculate where to place a tooltip of the given size next to its anchor,
+/// flipping it to the opposite side if it doesn't fit in the viewport and keeping it inside of it.
+fn get_tooltip_origin(
+    position: TooltipPosition,
+    anchor: &Area,
+    tooltip: &Size2D,
+    viewport: &Size2D,
+    gap: f32,
+) -> Point2D {
+    let origin_at = |position: TooltipPosition| match position {
+        TooltipPosition::Top => Point2D::new(
+            anchor.center().x - tooltip.width / 2.0,
+            anchor.min_y() - gap - tooltip.height,
+        ),
+        TooltipPosition::Bottom => Point2D::new(
+            anchor.center().x - tooltip.width / 2.0,
+            anchor.max_y() + gap,
+        ),
+        TooltipPosition::Left => Point2D::new(
+            anchor.min_x() - gap - tooltip.width,
+            anchor.center().y - tooltip.height / 2.0,
+        ),
+        TooltipPosition::Right => Point2D::new(
+            anchor.max_x() + gap,
+            anchor.center().y - tooltip.height / 2.0,
+        ),
+    };
+    let fits = |origin: &Point2D| {
+        origin.x >= 0.0
+            && origin.y >= 0.0
+            && origin.x + tooltip.width <= viewport.width
+            && origin.y + tooltip.height <= viewport.height
+    };
+    let fits_side = |origin: &Point2D| match position {
+        TooltipPosition::Top | TooltipPosition::Bottom => {
+            origin.y >= 0.0 && origin.y + tooltip.height <= viewport.height
+        }
+        TooltipPosition::Left | TooltipPosition::Right => {
+            origin.x >= 0.0 && origin.x + tooltip.width <= viewport.width
+        }
+    };
+
+    let mut origin = origin_at(position);
+    if !fits(&origin) && !fits_side(&origin) {
+        let flipped = origin_at(position.opposite());
+        if fits_side(&flipped) {
+            origin = flipped;
+        }
+    }
+
+    // Keep it inside the viewport, favouring the top left corner when it's too big
+    origin.x = origin.x.min(viewport.width - tooltip.width).max(0.0);
+    origin.y = origin.y.min(viewport.height - tooltip.height).max(0.0);
+    origin
+}
+
+/// Show a tooltip next to its children after hovering them for a while.
+///
+/// The tooltip is rendered in a [`Portal`], so it's drawn above the rest of the app and it's not clipped by
+/// its ancestors. It's placed in the given `position` relative to the children, flipping to the opposite side
+/// when it doesn't fit in the window.
+/// It hides once the cursor leaves the children, a mouse button is pressed anywhere or the children are scrolled.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         TooltipContainer {
+///             position: TooltipPosition::Top,
+///             tooltip: rsx!(
+///                 Tooltip {
+///                     url: "Hello, World!"
+///                 }
+///             ),
+///             Button {
+///                 label {
+///                     "Hover me"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn TooltipContainer(
+    /// Content of the tooltip, like a [`Tooltip`].
+    tooltip: Element,
+    /// Element the tooltip is anchored to.
+    children: Element,
+    /// Side of the children where the tooltip is placed. Default [`TooltipPosition::Bottom`].
+    #[props(default)]
+    position: TooltipPosition,
+    /// How long the children need to be hovered until the tooltip is shown. Default `500ms`.
+    /// This is only read when the component is created.
+    #[props(default = Duration::from_millis(500))]
+    delay: Duration,
+    /// Distance between the children and the tooltip. Default `4`.
+    #[props(default = 4.0)]
+    gap: f32,
+) -> Element {
+    let mut is_visible = use_signal(|| false);
+    let (anchor_reference, anchor_layout) = use_node_signal();
+    let (tooltip_reference, tooltip_layout) = use_node_signal();
+    let platform_information = use_platform_information();
+    let show = use_debounce(delay, move |_| is_visible.set(true));
+
+    let mut hide = move || {
+        show.cancel();
+        if *is_visible.peek() {
+            is_visible.set(false);
+        }
+    };
+
+    let onmouseenter = move |_: MouseEvent| show.action(());
+
+    let onmouseleave = move |_: MouseEvent| hide();
+
+    let onglobalmousedown = move |_: MouseEvent| hide();
+
+    let onwheel = move |_: WheelEvent| hide();
+
+    let tooltip = if *is_visible.read() {
+        let scale_factor = try_consume_context::<NativePlatformReceiver>()
+            .map(|platform_receiver| platform_receiver.borrow().scale_factor)
+            .unwrap_or(1.0) as f32;
+        let viewport = platform_information.read().viewport_size / scale_factor;
+        let anchor = anchor_layout.read().area;
+        let tooltip_size = tooltip_layout.read().area.size;
+        let origin = get_tooltip_origin(position, &anchor, &tooltip_size, &viewport, gap);
+        // Hide it until its size is known so it doesn't jump around
+        let opacity = if tooltip_size.is_empty() { 0 } else { 1 };
+
+        rsx!(
+            Portal {
+                top: "{origin.y}",
+                left: "{origin.x}",
+                rect {
+                    reference: tooltip_reference,
+                    opacity: "{opacity}",
+                    {tooltip}
+                }
+            }
+        )
+    } else {
+        None
+    };
+
+    rsx!(
+        rect {
+            reference: anchor_reference,
+            onmouseenter,
+            onmouseleave,
+            onglobalmousedown,
+            onwheel,
+            {children}
+            {tooltip}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::advance;
+
+    use super::get_tooltip_origin;
+
+    #[test]
+    pub fn tooltip_origin() {
+        let viewport = Size2D::new(500.0, 500.0);
+        let tooltip = Size2D::new(100.0, 50.0);
+
+        // Centered below the anchor
+        let anchor = Area::new((200.0, 100.0).into(), (100.0, 50.0).into());
+        assert_eq!(
+            get_tooltip_origin(TooltipPosition::Bottom, &anchor, &tooltip, &viewport, 5.0),
+            (200.0, 155.0).into()
+        );
+
+        // Flipped above the anchor because there is no space below it
+        let anchor = Area::new((200.0, 420.0).into(), (100.0, 50.0).into());
+        assert_eq!(
+            get_tooltip_origin(TooltipPosition::Bottom, &anchor, &tooltip, &viewport, 5.0),
+            (200.0, 365.0).into()
+        );
+
+        // Moved inside of the viewport
+        let anchor = Area::new((0.0, 100.0).into(), (50.0, 50.0).into());
+        assert_eq!(
+            get_tooltip_origin(TooltipPosition::Top, &anchor, &tooltip, &viewport, 5.0),
+            (0.0, 45.0).into()
+        );
+
+        // Flipped to the right of the anchor
+        assert_eq!(
+            get_tooltip_origin(TooltipPosition::Left, &anchor, &tooltip, &viewport, 5.0),
+            (55.0, 100.0).into()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    pub async fn tooltip_container() {
+        fn tooltip_container_app() -> Element {
+            rsx!(
+                rect {
+                    height: "50",
+                    width: "100",
+                    TooltipContainer {
+                        delay: Duration::from_millis(50),
+                        position: TooltipPosition::Right,
+                        tooltip: rsx!(
+                            label {
+                                "Tooltip"
+                            }
+                        ),
+                        rect {
+                            width: "100",
+                            height: "50",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(tooltip_container_app);
+        let anchor = utils.root().get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (50.0, 25.0).into(),
+            button: None,
+        });
+        utils.wait_for_update().await;
+
+        // Not shown until the delay passes
+        assert!(anchor.get(1).is_placeholder());
+
+        advance(Duration::from_millis(100)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Shown to the right of the anchor
+        let tooltip = anchor.get(1).get(0);
+        assert_eq!(tooltip.get(0).get(0).text(), Some("Tooltip"));
+        assert_eq!(tooltip.area().unwrap().min_x(), 104.0);
+
+        // Hidden after pressing a mouse button
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (50.0, 25.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert!(anchor.get(1).is_placeholder());
+    }
+}
@@ -0,0 +1,74 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::runtime::Runtime;
+
+fn list(cache: bool) -> Element {
+    rsx!(
+        ScrollView {
+            cache,
+            for i in 0..1000 {
+                rect {
+                    key: "{i}",
+                    height: "40",
+                    width: "100%",
+                    direction: "horizontal",
+                    label {
+                        "Row {i}"
+                    }
+                    label {
+                        "Some static content"
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn cached_list() -> Element {
+    list(true)
+}
+
+fn uncached_list() -> Element {
+    list(false)
+}
+
+fn scroll_static_list(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let _guard = runtime.enter();
+
+    let mut group = c.benchmark_group("Scroll a long static list");
+
+    for (name, app) in [
+        ("uncached", uncached_list as fn() -> Element),
+        ("cached", cached_list),
+    ] {
+        let mut utils = launch_test(app);
+        runtime.block_on(utils.wait_for_update());
+
+        let mut direction = -1.0;
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                // Scroll back and forth so it never reaches the end
+                direction *= -1.0;
+                utils.push_event(PlatformEvent::Wheel {
+                    name: EventName::Wheel,
+                    scroll: (0., -50. * direction).into(),
+                    cursor: (5., 5.).into(),
+                    modifiers: Modifiers::empty(),
+                });
+                runtime.block_on(utils.wait_for_update());
+                utils.create_snapshot()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, scroll_static_list);
+criterion_main!(benches);
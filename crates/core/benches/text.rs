@@ -0,0 +1,85 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::runtime::Runtime;
+
+fn labels(changing: bool) -> Element {
+    let mut frame = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            direction: "horizontal",
+            onclick: move |_| frame += 1,
+            for column in 0..10 {
+                rect {
+                    key: "{column}",
+                    width: "10%",
+                    for row in 0..50 {
+                        if changing {
+                            label {
+                                key: "{row}",
+                                "Label {row} {frame}"
+                            }
+                        } else {
+                            label {
+                                key: "{row}",
+                                "Label {row}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn static_labels() -> Element {
+    labels(false)
+}
+
+fn changing_labels() -> Element {
+    labels(true)
+}
+
+fn measure_labels(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let _guard = runtime.enter();
+
+    let mut group = c.benchmark_group("Measure a screen full of labels");
+
+    for (name, app) in [
+        ("changing", changing_labels as fn() -> Element),
+        ("static", static_labels),
+    ] {
+        let mut utils = launch_test(app);
+        runtime.block_on(utils.wait_for_update());
+
+        let measured = utils.paragraph_cache().measured_paragraphs();
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                // Every frame is measured again, but only the changing labels are laid out again
+                utils.push_event(PlatformEvent::Mouse {
+                    name: EventName::Click,
+                    cursor: (5., 5.).into(),
+                    button: Some(MouseButton::Left),
+                });
+                runtime.block_on(utils.wait_for_update());
+            })
+        });
+        println!(
+            "{name}: {} paragraphs measured",
+            utils.paragraph_cache().measured_paragraphs() - measured
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, measure_labels);
+criterion_main!(benches);
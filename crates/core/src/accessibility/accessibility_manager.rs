@@ -13,7 +13,14 @@ use accesskit::{
     Tree,
     TreeUpdate,
 };
-use freya_node_state::AccessibilityNodeState;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_node_state::{
+    AccessibilityNodeState,
+    StyleState,
+};
 use torin::prelude::LayoutNode;
 
 use crate::{
@@ -25,12 +32,25 @@ pub type SharedAccessibilityManager = Arc<Mutex<AccessibilityManager>>;
 
 pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
+/// Subtree the keyboard focus is kept within, see the `focus_trap` attribute.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusTrap {
+    /// Node with the `focus_trap` attribute.
+    pub node_id: NodeId,
+    /// Accessibility Nodes inside of it.
+    pub nodes: Vec<AccessibilityId>,
+    /// Node focused when the trap started, which gets focused again once it's released.
+    pub previous_focused_id: AccessibilityId,
+}
+
 /// Manages the Accessibility integration.
 pub struct AccessibilityManager {
     /// Accessibility Nodes
     pub nodes: Vec<(AccessibilityId, Node)>,
     /// Current focused Accessibility Node.
     pub focused_id: AccessibilityId,
+    /// Active focus traps, in the order they started. Only the last one is used.
+    pub focus_traps: Vec<FocusTrap>,
 }
 
 impl AccessibilityManager {
@@ -38,6 +58,7 @@ impl AccessibilityManager {
         Self {
             focused_id,
             nodes: Vec::default(),
+            focus_traps: Vec::default(),
         }
     }
 
@@ -98,8 +119,14 @@ impl AccessibilityManager {
             y1: area.max_y(),
         });
 
+        // Disabled nodes can't be focused
+        let disabled = dioxus_node.get::<StyleState>().unwrap().disabled;
+        if disabled {
+            builder.set_disabled();
+        }
+
         // Set focusable action
-        if node_accessibility.focusable {
+        if node_accessibility.focusable && !disabled {
             builder.add_action(Action::Focus);
         } else {
             builder.add_action(Action::Default);
@@ -111,6 +138,45 @@ impl AccessibilityManager {
         self.push_node(accessibility_id, node);
     }
 
+    /// Update the focus traps with the Nodes that have the `focus_trap` attribute, and the Accessibility Nodes inside of them.
+    ///
+    /// Returns the Node to focus again if any trap was released.
+    pub fn set_focus_traps(
+        &mut self,
+        traps: Vec<(NodeId, Vec<AccessibilityId>)>,
+    ) -> Option<AccessibilityId> {
+        let mut restore_focus = None;
+
+        // Focus what was focused before the oldest of the released traps
+        self.focus_traps.retain(|focus_trap| {
+            let is_active = traps
+                .iter()
+                .any(|(node_id, _)| *node_id == focus_trap.node_id);
+            if !is_active && restore_focus.is_none() {
+                restore_focus = Some(focus_trap.previous_focused_id);
+            }
+            is_active
+        });
+
+        for (node_id, nodes) in traps {
+            if let Some(focus_trap) = self
+                .focus_traps
+                .iter_mut()
+                .find(|focus_trap| focus_trap.node_id == node_id)
+            {
+                focus_trap.nodes = nodes;
+            } else {
+                self.focus_traps.push(FocusTrap {
+                    node_id,
+                    nodes,
+                    previous_focused_id: restore_focus.unwrap_or(self.focused_id),
+                });
+            }
+        }
+
+        restore_focus
+    }
+
     /// Update the focused Node ID and generate a TreeUpdate if necessary.
     pub fn set_focus_with_update(&mut self, new_focus_id: AccessibilityId) -> Option<TreeUpdate> {
         self.focused_id = new_focus_id;
@@ -171,40 +237,50 @@ impl AccessibilityManager {
 
     /// Focus the next/previous Node starting from the currently focused Node.
     pub fn set_focus_on_next_node(&mut self, direction: AccessibilityFocusDirection) -> TreeUpdate {
-        let node_index = self
+        let focus_trap = self.focus_traps.last();
+
+        // Skip disabled nodes, unless it's the one currently focused so we know where to move from
+        let candidates = self
             .nodes
             .iter()
-            .enumerate()
-            .find(|(_, node)| node.0 == self.focused_id)
-            .map(|(i, _)| i);
+            .filter(|(id, node)| !node.is_disabled() || *id == self.focused_id)
+            .filter(|(id, _)| focus_trap.map_or(true, |focus_trap| focus_trap.nodes.contains(id)))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        let node_index = candidates.iter().position(|id| *id == self.focused_id);
 
         let target_node = if direction == AccessibilityFocusDirection::Forward {
             // Find the next Node
             if let Some(node_index) = node_index {
-                if node_index == self.nodes.len() - 1 {
-                    self.nodes.first()
+                if node_index == candidates.len() - 1 {
+                    candidates.first()
                 } else {
-                    self.nodes.get(node_index + 1)
+                    candidates.get(node_index + 1)
                 }
             } else {
-                self.nodes.first()
+                candidates.first()
             }
         } else {
             // Find the previous Node
             if let Some(node_index) = node_index {
                 if node_index == 0 {
-                    self.nodes.last()
+                    candidates.last()
                 } else {
-                    self.nodes.get(node_index - 1)
+                    candidates.get(node_index - 1)
                 }
             } else {
-                self.nodes.last()
+                candidates.last()
             }
         };
 
-        self.focused_id = target_node
-            .map(|(id, _)| *id)
-            .unwrap_or(ACCESSIBILITY_ROOT_ID);
+        // The focus doesn't leave a trap, even if there is nothing to focus inside of it
+        let fallback_id = if focus_trap.is_some() {
+            self.focused_id
+        } else {
+            ACCESSIBILITY_ROOT_ID
+        };
+        self.focused_id = target_node.copied().unwrap_or(fallback_id);
 
         TreeUpdate {
             nodes: Vec::new(),
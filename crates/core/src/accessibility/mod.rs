@@ -62,11 +62,16 @@ impl NodeAccessibility for DioxusNode<'_> {
     }
 }
 
+/// Add the Accessibility Nodes of the DOM to the manager and update its focus traps.
+///
+/// Returns the Node to focus again if any focus trap was released.
 pub fn process_accessibility(
     layout: &Torin<NodeId>,
     rdom: &DioxusDOM,
     accessibility_manager: &mut AccessibilityManager,
-) {
+) -> Option<AccessibilityId> {
+    let mut focus_traps = Vec::<(NodeId, Vec<AccessibilityId>)>::new();
+
     rdom.traverse_depth_first_advanced(|node| {
         if !node.node_type().is_element() {
             return false;
@@ -74,6 +79,11 @@ pub fn process_accessibility(
 
         let layout_node = layout.get(node.id()).unwrap();
         let node_accessibility = &*node.get::<AccessibilityNodeState>().unwrap();
+
+        if node_accessibility.focus_trap {
+            focus_traps.push((node.id(), Vec::new()));
+        }
+
         if let Some(accessibility_id) = node_accessibility.accessibility_id {
             accessibility_manager.add_node(
                 &node,
@@ -81,6 +91,20 @@ pub fn process_accessibility(
                 accessibility_id,
                 node_accessibility,
             );
+
+            // Add it to every focus trap it is inside of, including itself
+            if !focus_traps.is_empty() {
+                let mut ancestor_id = Some(node.id());
+                while let Some(node_id) = ancestor_id {
+                    if let Some((_, nodes)) = focus_traps
+                        .iter_mut()
+                        .find(|(trap_node_id, _)| *trap_node_id == node_id)
+                    {
+                        nodes.push(accessibility_id);
+                    }
+                    ancestor_id = rdom.get(node_id).and_then(|node| node.parent_id());
+                }
+            }
         }
 
         if let Some(tag) = node.node_type().tag() {
@@ -91,4 +115,6 @@ pub fn process_accessibility(
 
         true
     });
+
+    accessibility_manager.set_focus_traps(focus_traps)
 }
@@ -54,6 +54,7 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             direction: layout.direction,
             padding: layout.padding,
             margin: layout.margin,
+            auto_margin: layout.auto_margin,
             main_alignment: layout.main_alignment,
             cross_alignment: layout.cross_alignment,
             offset_x: layout.offset_x,
@@ -61,6 +62,8 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
             content: layout.content,
+            box_sizing: layout.box_sizing,
+            paragraph_spacing: layout.paragraph_spacing,
             contains_text,
         };
 
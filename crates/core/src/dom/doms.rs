@@ -7,6 +7,7 @@ use std::sync::{
 use dioxus_core::VirtualDom;
 use freya_common::{
     Layers,
+    LayoutTransitionNodes,
     ParagraphElements,
     TextGroupMeasurement,
 };
@@ -14,6 +15,7 @@ use freya_native_core::{
     prelude::{
         DioxusState,
         State,
+        TypeErasedState,
     },
     real_dom::{
         NodeRef,
@@ -34,11 +36,22 @@ use freya_node_state::{
     TransformState,
     ViewportState,
 };
+use rustc_hash::FxHashSet;
 use torin::prelude::*;
 use tracing::info;
 
 use super::mutations_writer::MutationsWriter;
-use crate::prelude::measure_paragraph;
+use crate::{
+    layout_transitions::LayoutTransitions,
+    node::{
+        get_node_state,
+        NodeState,
+    },
+    prelude::{
+        get_glyph_index_at,
+        measure_paragraph,
+    },
+};
 
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
 pub type DioxusNode<'a> = NodeRef<'a, CustomAttributeValues>;
@@ -117,13 +130,24 @@ pub struct FreyaDOM {
     rdom: DioxusDOM,
     dioxus_integration_state: DioxusState,
     torin: Arc<Mutex<Torin<NodeId>>>,
+    layout_transitions: Arc<Mutex<LayoutTransitions>>,
+    layout_transition_nodes: LayoutTransitionNodes,
     paragraphs: ParagraphElements,
     layers: Layers,
+    invalidated_nodes: FxHashSet<NodeId>,
 }
 
 impl Default for FreyaDOM {
     fn default() -> Self {
-        let mut rdom = RealDom::<CustomAttributeValues>::new([
+        Self::new(Vec::new())
+    }
+}
+
+impl FreyaDOM {
+    /// Create a DOM whose nodes also compute the given custom states, along with the built-in ones.
+    /// Custom states can depend on the built-in states and read any attribute, including [AttributeName::Custom](freya_native_core::prelude::AttributeName::Custom) ones.
+    pub fn new(custom_states: Vec<TypeErasedState<CustomAttributeValues>>) -> Self {
+        let states = [
             CursorState::to_type_erased(),
             FontStyleState::to_type_erased(),
             ReferencesState::to_type_erased(),
@@ -133,23 +157,36 @@ impl Default for FreyaDOM {
             AccessibilityNodeState::to_type_erased(),
             ViewportState::to_type_erased(),
             LayerState::to_type_erased(),
-        ]);
+        ]
+        .into_iter()
+        .chain(custom_states)
+        .collect::<Vec<_>>();
+        let mut rdom = RealDom::<CustomAttributeValues>::new(states);
         let dioxus_integration_state = DioxusState::create(&mut rdom);
         Self {
             rdom,
             dioxus_integration_state,
             torin: Arc::new(Mutex::new(Torin::new())),
+            layout_transitions: Arc::new(Mutex::new(LayoutTransitions::default())),
+            layout_transition_nodes: LayoutTransitionNodes::default(),
             paragraphs: ParagraphElements::default(),
             layers: Layers::default(),
+            invalidated_nodes: FxHashSet::default(),
         }
     }
-}
 
-impl FreyaDOM {
     pub fn layout(&self) -> MutexGuard<Torin<NodeId>> {
         self.torin.lock().unwrap()
     }
 
+    pub fn layout_transitions(&self) -> MutexGuard<LayoutTransitions> {
+        self.layout_transitions.lock().unwrap()
+    }
+
+    pub fn layout_transition_nodes(&self) -> &LayoutTransitionNodes {
+        &self.layout_transition_nodes
+    }
+
     pub fn layers(&self) -> &Layers {
         &self.layers
     }
@@ -168,6 +205,7 @@ impl FreyaDOM {
             layout: &mut self.torin.lock().unwrap(),
             layers: &self.layers,
             paragraphs: &self.paragraphs,
+            layout_transition_nodes: &self.layout_transition_nodes,
             scale_factor,
         });
 
@@ -175,6 +213,7 @@ impl FreyaDOM {
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.layout_transition_nodes.clone());
 
         self.rdom.update_state(ctx);
     }
@@ -189,14 +228,22 @@ impl FreyaDOM {
             layout: &mut self.torin.lock().unwrap(),
             layers: &self.layers,
             paragraphs: &self.paragraphs,
+            layout_transition_nodes: &self.layout_transition_nodes,
             scale_factor,
         });
 
+        self.update_states()
+    }
+
+    /// Update the states of the Nodes whose attributes changed, also outside of the [`VirtualDOM`](dioxus_core::VirtualDom)
+    /// like their interaction states. Returns whether it must be repainted and whether its layout must be measured again.
+    pub fn update_states(&mut self) -> (bool, bool) {
         // Update the Nodes states
         let mut ctx = SendAnyMap::new();
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.layout_transition_nodes.clone());
 
         // Update the Node's states
         let (_, diff) = self.rdom.update_state(ctx);
@@ -204,6 +251,10 @@ impl FreyaDOM {
         let must_repaint = !diff.is_empty();
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
 
+        self.invalidated_nodes.extend(diff.keys());
+        self.invalidated_nodes
+            .extend(self.torin.lock().unwrap().get_dirty_nodes());
+
         if !diff.is_empty() {
             info!(
                 "Updated DOM, now with {} nodes",
@@ -214,6 +265,11 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Take the Nodes whose state or layout changed since the last call, useful to invalidate anything cached for them.
+    pub fn take_invalidated_nodes(&mut self) -> FxHashSet<NodeId> {
+        std::mem::take(&mut self.invalidated_nodes)
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
@@ -228,6 +284,13 @@ impl FreyaDOM {
         &mut self.dioxus_integration_state
     }
 
+    /// Get a snapshot of the resolved state of the given [`NodeId`], as of the last time the DOM was updated.
+    /// Unlike its attributes, this includes the values inherited from its ancestors, like the font styles.
+    /// Returns `None` if there is no such Node.
+    pub fn node_state(&self, node_id: NodeId) -> Option<NodeState> {
+        self.rdom().get(node_id).map(|node| get_node_state(&node))
+    }
+
     /// Measure all the paragraphs registered under the given TextId
     pub fn measure_paragraphs(&self, text_measurement: TextGroupMeasurement, scale_factor: f64) {
         let paragraphs = self.paragraphs.paragraphs();
@@ -244,4 +307,19 @@ impl FreyaDOM {
             }
         }
     }
+
+    /// Get the index of the glyph closest to the given `point` in the text node of the given [`NodeId`].
+    /// The `point` is relative to the top-left corner of the node.
+    pub fn glyph_index_at(
+        &self,
+        node_id: NodeId,
+        point: CursorPoint,
+        scale_factor: f64,
+    ) -> Option<usize> {
+        let node = self.rdom().get(node_id)?;
+        let layout = self.layout();
+        let layout_node = layout.get(node_id)?;
+
+        get_glyph_index_at(&node, layout_node, point, scale_factor)
+    }
 }
@@ -4,6 +4,7 @@ use dioxus_core::{
 };
 use freya_common::{
     Layers,
+    LayoutTransitionNodes,
     ParagraphElements,
 };
 use freya_native_core::{
@@ -28,6 +29,7 @@ pub struct MutationsWriter<'a> {
     pub layout: &'a mut Torin<NodeId>,
     pub layers: &'a Layers,
     pub paragraphs: &'a ParagraphElements,
+    pub layout_transition_nodes: &'a LayoutTransitionNodes,
     pub scale_factor: f32,
 }
 
@@ -39,7 +41,7 @@ impl<'a> MutationsWriter<'a> {
         // Remove from layout
         self.layout.remove(node_id, &mut dom_adapter, true);
 
-        // Remove from layers and paragraph elements
+        // Remove from layers, paragraph elements and layout transitions
         let mut stack = vec![node_id];
         let tree = self.native_writer.rdom.tree_ref();
         while let Some(node_id) = stack.pop() {
@@ -78,6 +80,34 @@ impl<'a> MutationsWriter<'a> {
                     self.paragraphs
                         .remove_paragraph(node_id, &cursor_ref.text_id);
                 }
+
+                // Remove from layout transitions
+                self.layout_transition_nodes.remove_node(node_id);
+            }
+        }
+    }
+
+    /// Current parents of the `m` Nodes about to be inserted, if they are being moved from somewhere else.
+    fn stack_parents(&self, m: usize) -> Vec<NodeId> {
+        let tree = self.native_writer.rdom.tree_ref();
+        self.native_writer
+            .state
+            .stack_nodes(m)
+            .iter()
+            .filter_map(|node_id| tree.parent_id(*node_id))
+            .collect()
+    }
+
+    /// Invalidate the layout of the given Node's parent and siblings, along with the previous parents
+    /// and siblings of the inserted Nodes, as they might have been moved from another position, e.g when reordering a keyed list.
+    fn invalidate_siblings(&mut self, id: ElementId, old_parents: Vec<NodeId>) {
+        let node_id = self.native_writer.state.element_to_node_id(id);
+        let tree = self.native_writer.rdom.tree_ref();
+        let parent_id = tree.parent_id(node_id);
+        for parent_id in parent_id.into_iter().chain(old_parents) {
+            self.layout.invalidate(parent_id);
+            for child_id in tree.children_ids(parent_id) {
+                self.layout.invalidate(child_id);
             }
         }
     }
@@ -125,11 +155,15 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
     }
 
     fn insert_nodes_after(&mut self, id: dioxus_core::ElementId, m: usize) {
+        let old_parents = self.stack_parents(m);
         self.native_writer.insert_nodes_after(id, m);
+        self.invalidate_siblings(id, old_parents);
     }
 
     fn insert_nodes_before(&mut self, id: dioxus_core::ElementId, m: usize) {
+        let old_parents = self.stack_parents(m);
         self.native_writer.insert_nodes_before(id, m);
+        self.invalidate_siblings(id, old_parents);
     }
 
     fn set_attribute(
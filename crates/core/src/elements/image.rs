@@ -1,12 +1,21 @@
+use std::time::Instant;
+
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
+    ImagePlayback,
     ReferencesState,
     StyleState,
 };
 
 use super::utils::ElementUtils;
-use crate::dom::DioxusNode;
+use crate::{
+    dom::DioxusNode,
+    skia::{
+        ImagesCache,
+        RenderQuality,
+    },
+};
 
 pub struct ImageElement;
 
@@ -18,21 +27,51 @@ impl ElementUtils for ImageElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
+        images_cache: &mut ImagesCache,
         _default_fonts: &[String],
         _scale_factor: f32,
+        render_quality: &RenderQuality,
     ) {
         let area = layout_node.visible_area();
         let node_style = node_ref.get::<StyleState>().unwrap();
         let node_references = node_ref.get::<ReferencesState>().unwrap();
 
-        let draw_img = |bytes: &[u8]| {
-            let pic = Image::from_encoded(unsafe { Data::new_bytes(bytes) });
+        let mut draw_img = |bytes: &[u8]| {
+            let pic = if is_animated(bytes) {
+                images_cache.get_animated_image(
+                    node_ref.id(),
+                    bytes,
+                    node_style.image_playback == ImagePlayback::Play,
+                    Instant::now(),
+                )
+            } else {
+                Image::from_encoded(unsafe { Data::new_bytes(bytes) })
+            };
             if let Some(pic) = pic {
                 let mut paint = Paint::default();
-                paint.set_anti_alias(true);
+                paint.set_anti_alias(render_quality.anti_alias);
+                if let Some(tint) = node_style.image_tint {
+                    paint.set_color_filter(color_filters::blend(
+                        tint,
+                        node_style.image_tint_mode.blend_mode(),
+                    ));
+                }
+                let center = node_style
+                    .image_slice
+                    .map(|slice| {
+                        let left = slice.left() as i32;
+                        let top = slice.top() as i32;
+                        IRect::new(
+                            left,
+                            top,
+                            (pic.width() - slice.right() as i32).max(left),
+                            (pic.height() - slice.bottom() as i32).max(top),
+                        )
+                    })
+                    .unwrap_or_default();
                 canvas.draw_image_nine(
                     pic,
-                    IRect::new(0, 0, 0, 0),
+                    center,
                     Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
                     FilterMode::Last,
                     Some(&paint),
@@ -50,3 +89,35 @@ impl ElementUtils for ImageElement {
         }
     }
 }
+
+/// Check if the given encoded image might have multiple frames, so only GIFs and APNGs
+/// leave the fast path of decoding plain images.
+fn is_animated(bytes: &[u8]) -> bool {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    if bytes.starts_with(b"GIF8") {
+        return true;
+    }
+
+    let Some(mut chunks) = bytes.strip_prefix(PNG_SIGNATURE) else {
+        return false;
+    };
+
+    // APNGs declare their animation in an `acTL` chunk before the image data
+    while chunks.len() >= 8 {
+        let chunk_length =
+            u32::from_be_bytes([chunks[0], chunks[1], chunks[2], chunks[3]]) as usize;
+        match &chunks[4..8] {
+            b"acTL" => return true,
+            b"IDAT" => return false,
+            _ => {}
+        }
+        // Length, type, data and CRC
+        let Some(next_chunk) = chunks.get(12 + chunk_length..) else {
+            return false;
+        };
+        chunks = next_chunk;
+    }
+
+    false
+}
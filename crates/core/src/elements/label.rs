@@ -1,10 +1,19 @@
-use freya_common::CachedParagraph;
+use freya_common::{
+    CachedParagraph,
+    CachedStrokeParagraph,
+};
 use freya_engine::prelude::*;
+use freya_native_core::real_dom::NodeImmutable;
+use freya_node_state::FontStyleState;
 
 use super::utils::ElementUtils;
 use crate::prelude::{
+    align_cross_align_paragraph,
     align_main_align_paragraph,
+    paint_paragraph,
     DioxusNode,
+    ImagesCache,
+    RenderQuality,
 };
 
 pub struct LabelElement;
@@ -17,21 +26,20 @@ impl ElementUtils for LabelElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
+        _images_cache: &mut ImagesCache,
         _default_fonts: &[String],
         _scale_factor: f32,
+        _render_quality: &RenderQuality,
     ) {
-        let paragraph = &layout_node
-            .data
-            .as_ref()
-            .unwrap()
-            .get::<CachedParagraph>()
-            .unwrap()
-            .0;
+        let data = layout_node.data.as_ref().unwrap();
+        let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+        let stroke = data.get::<CachedStrokeParagraph>().map(|stroke| &stroke.0);
         let area = layout_node.visible_area();
 
-        let x = area.min_x();
+        let x = area.min_x() + align_cross_align_paragraph(node_ref, &area, paragraph);
         let y = area.min_y() + align_main_align_paragraph(node_ref, &area, paragraph);
 
-        paragraph.paint(canvas, (x, y));
+        let font_style = node_ref.get::<FontStyleState>().unwrap();
+        paint_paragraph(canvas, paragraph, stroke, (x, y), font_style.fill.as_ref());
     }
 }
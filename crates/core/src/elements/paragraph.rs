@@ -1,17 +1,37 @@
-use freya_common::CachedParagraph;
+use freya_common::{
+    CachedParagraph,
+    CachedStrokeParagraph,
+    CursorAffinity,
+    InsertedBreaks,
+};
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
-use freya_node_state::CursorState;
-use torin::geometry::Area;
+use freya_node_state::{
+    CursorState,
+    FontStyleState,
+};
+use torin::{
+    geometry::Area,
+    prelude::LayoutNode,
+};
 
 use super::utils::ElementUtils;
 use crate::{
     dom::DioxusNode,
     prelude::{
+        align_cross_align_paragraph,
         align_highlights_and_cursor_paragraph,
         align_main_align_paragraph,
     },
-    skia::create_paragraph,
+    skia::{
+        caret_text_box,
+        create_paragraph,
+        has_stroke_paragraph,
+        inserted_breaks_of,
+        paint_paragraph,
+        ImagesCache,
+        RenderQuality,
+    },
 };
 
 pub struct ParagraphElement;
@@ -24,44 +44,61 @@ impl ElementUtils for ParagraphElement {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
+        _images_cache: &mut ImagesCache,
         default_fonts: &[String],
         scale_factor: f32,
+        render_quality: &RenderQuality,
     ) {
         let area = layout_node.visible_area();
         let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+        let font_style = &*node_ref.get::<FontStyleState>().unwrap();
+        let breaks = inserted_breaks_of(layout_node);
 
-        let paint = |paragraph: &Paragraph| {
-            let x = area.min_x();
+        let paint = |paragraph: &Paragraph, stroke: Option<&Paragraph>| {
+            let x = area.min_x() + align_cross_align_paragraph(node_ref, &area, paragraph);
             let y = area.min_y() + align_main_align_paragraph(node_ref, &area, paragraph);
 
             // Draw the highlights if specified
-            draw_cursor_highlights(&area, paragraph, canvas, node_ref);
+            draw_cursor_highlights(&area, paragraph, &breaks, canvas, node_ref, render_quality);
 
             // Draw a cursor if specified
-            draw_cursor(&area, paragraph, canvas, node_ref);
+            draw_cursor(&area, paragraph, &breaks, canvas, node_ref, render_quality);
 
-            paragraph.paint(canvas, (x, y));
-        };
+            paint_paragraph(canvas, paragraph, stroke, (x, y), font_style.fill.as_ref());
 
-        if node_cursor_state.position.is_some() {
-            let paragraph = create_paragraph(
+            // Recolor the highlighted glyphs if specified
+            draw_selected_text(
+                &area,
+                paragraph,
+                &breaks,
+                canvas,
                 node_ref,
-                &area.size,
-                font_collection,
-                true,
-                default_fonts,
-                scale_factor,
+                (x, y),
+                render_quality,
             );
-            paint(&paragraph);
+        };
+
+        if node_cursor_state.position.is_some() {
+            let create = |stroke: bool| {
+                create_paragraph(
+                    node_ref,
+                    &area.size,
+                    font_collection,
+                    true,
+                    default_fonts,
+                    scale_factor,
+                    render_quality,
+                    stroke,
+                )
+            };
+            let paragraph = create(false);
+            let stroke = has_stroke_paragraph(node_ref).then(|| create(true));
+            paint(&paragraph, stroke.as_ref());
         } else {
-            let paragraph = &layout_node
-                .data
-                .as_ref()
-                .unwrap()
-                .get::<CachedParagraph>()
-                .unwrap()
-                .0;
-            paint(paragraph);
+            let data = layout_node.data.as_ref().unwrap();
+            let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+            let stroke = data.get::<CachedStrokeParagraph>().map(|stroke| &stroke.0);
+            paint(paragraph, stroke);
         };
     }
 }
@@ -69,8 +106,10 @@ impl ElementUtils for ParagraphElement {
 fn draw_cursor_highlights(
     area: &Area,
     paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
     canvas: &Canvas,
     node_ref: &DioxusNode,
+    render_quality: &RenderQuality,
 ) -> Option<()> {
     let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
 
@@ -86,7 +125,7 @@ fn draw_cursor_highlights(
             }
         };
         let cursor_rects = paragraph.get_rects_for_range(
-            *from..*to,
+            breaks.to_layout_index(*from)..breaks.to_layout_index(*to),
             RectHeightStyle::Tight,
             RectWidthStyle::Tight,
         );
@@ -100,7 +139,7 @@ fn draw_cursor_highlights(
             );
 
             let mut paint = Paint::default();
-            paint.set_anti_alias(true);
+            paint.set_anti_alias(render_quality.anti_alias);
             paint.set_style(PaintStyle::Fill);
             paint.set_color(highlight_color);
 
@@ -111,34 +150,109 @@ fn draw_cursor_highlights(
     Some(())
 }
 
-fn draw_cursor(
+fn draw_selected_text(
     area: &Area,
     paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
     canvas: &Canvas,
     node_ref: &DioxusNode,
+    (x, y): (f32, f32),
+    render_quality: &RenderQuality,
 ) -> Option<()> {
     let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
 
-    let cursor = node_cursor_state.position?;
-    let cursor_color = node_cursor_state.color;
-    let cursor_position = cursor as usize;
+    let highlights = node_cursor_state.highlights.as_ref()?;
+    let selection_text_color = node_cursor_state.selection_text_color?;
 
-    let cursor_rects = paragraph.get_rects_for_range(
-        cursor_position..cursor_position + 1,
-        RectHeightStyle::Tight,
-        RectWidthStyle::Tight,
-    );
-    let cursor_rect = cursor_rects.first()?;
+    let mut paint = Paint::default();
+    paint.set_anti_alias(render_quality.anti_alias);
+    paint.set_blend_mode(BlendMode::SrcIn);
+    paint.set_color(selection_text_color);
 
-    let (start, end) =
-        align_highlights_and_cursor_paragraph(node_ref, area, paragraph, cursor_rect, Some(1.0));
+    for (from, to) in highlights.iter() {
+        let (from, to) = (from.min(to), from.max(to));
+        let cursor_rects = paragraph.get_rects_for_range(
+            breaks.to_layout_index(*from)..breaks.to_layout_index(*to),
+            RectHeightStyle::Tight,
+            RectWidthStyle::Tight,
+        );
+        for cursor_rect in cursor_rects {
+            let (start, end) = align_highlights_and_cursor_paragraph(
+                node_ref,
+                area,
+                paragraph,
+                &cursor_rect,
+                None,
+            );
+            let rect = Rect::new(start.x, start.y, end.x, end.y);
+
+            // Paint the glyphs inside the highlight again and replace their color
+            canvas.save();
+            canvas.clip_rect(rect, ClipOp::Intersect, render_quality.anti_alias);
+            canvas.save_layer_alpha_f(rect, 1.0);
+            paragraph.paint(canvas, (x, y));
+            canvas.draw_rect(rect, &paint);
+            canvas.restore();
+            canvas.restore();
+        }
+    }
+
+    Some(())
+}
+
+fn draw_cursor(
+    area: &Area,
+    paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+    render_quality: &RenderQuality,
+) -> Option<()> {
+    let cursor_color = node_ref.get::<CursorState>().unwrap().color;
+    let caret = caret_area(area, paragraph, breaks, node_ref)?;
 
     let mut paint = Paint::default();
-    paint.set_anti_alias(true);
+    paint.set_anti_alias(render_quality.anti_alias);
     paint.set_style(PaintStyle::Fill);
     paint.set_color(cursor_color);
 
-    canvas.draw_rect(Rect::new(start.x, start.y, end.x, end.y), &paint);
+    canvas.draw_rect(
+        Rect::new(caret.min_x(), caret.min_y(), caret.max_x(), caret.max_y()),
+        &paint,
+    );
 
     Some(())
 }
+
+/// Area of the cursor drawn in the given paragraph, if it has one.
+fn caret_area(
+    area: &Area,
+    paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
+    node_ref: &DioxusNode,
+) -> Option<Area> {
+    let cursor_state = node_ref.get::<CursorState>().unwrap();
+    let cursor_position = breaks.to_layout_index(cursor_state.position? as usize);
+
+    // The measured paragraph has no glyph after the end of the text, so it's placed after the last one
+    let cursor_rect = caret_text_box(paragraph, cursor_position, cursor_state.affinity)
+        .or_else(|| caret_text_box(paragraph, cursor_position, CursorAffinity::Upstream))?;
+
+    let (start, end) =
+        align_highlights_and_cursor_paragraph(node_ref, area, paragraph, &cursor_rect, Some(1.0));
+
+    Some(Area::new(start, (end - start).to_size()))
+}
+
+/// Measure the area of the cursor of a `paragraph` that shows one, so the input method can place its candidates next to it.
+pub fn measure_caret_area(layout_node: &LayoutNode, node_ref: &DioxusNode) -> Option<Area> {
+    node_ref.get::<CursorState>().unwrap().position?;
+
+    let CachedParagraph(paragraph) = layout_node.data.as_ref()?.get::<CachedParagraph>()?;
+    caret_area(
+        &layout_node.visible_area(),
+        paragraph,
+        &inserted_breaks_of(layout_node),
+        node_ref,
+    )
+}
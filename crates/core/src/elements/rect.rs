@@ -1,8 +1,10 @@
+use std::time::Instant;
+
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
+    Border,
     BorderAlignment,
-    BorderStyle,
     Fill,
     ReferencesState,
     ShadowPosition,
@@ -10,6 +12,7 @@ use freya_node_state::{
 };
 use torin::{
     prelude::{
+        Area,
         CursorPoint,
         LayoutNode,
     },
@@ -17,7 +20,13 @@ use torin::{
 };
 
 use super::utils::ElementUtils;
-use crate::dom::DioxusNode;
+use crate::{
+    dom::DioxusNode,
+    skia::{
+        ImagesCache,
+        RenderQuality,
+    },
+};
 
 pub struct RectElement;
 
@@ -43,6 +52,102 @@ impl RectElement {
             ],
         )
     }
+
+    /// Stroke a border along the outline of the element, only inside of `clip` if there is any.
+    #[allow(clippy::too_many_arguments)]
+    fn render_border(
+        &self,
+        canvas: &Canvas,
+        paint: &Paint,
+        border: &Border,
+        area: Area,
+        rounded_rect: RRect,
+        node_style: &StyleState,
+        scale_factor: f32,
+        render_quality: &RenderQuality,
+        clip: Option<&Path>,
+    ) {
+        let mut radius = node_style.corner_radius;
+        radius.scale(scale_factor);
+
+        let mut border_with = border.width;
+        border_with *= scale_factor;
+
+        // Create a new paint and path
+        let mut border_paint = paint.clone();
+        let mut border_path = Path::new();
+
+        // Setup paint params
+        border_paint.set_anti_alias(render_quality.anti_alias);
+        border_paint.set_style(PaintStyle::Stroke);
+        match &border.fill {
+            Fill::Color(color) => {
+                border_paint.set_color(*color);
+            }
+            Fill::LinearGradient(gradient) => {
+                border_paint.set_shader(gradient.into_shader(area));
+            }
+            Fill::RadialGradient(gradient) => {
+                border_paint.set_shader(gradient.into_shader(area));
+            }
+            Fill::ConicGradient(gradient) => {
+                border_paint.set_shader(gradient.into_shader(area));
+            }
+        }
+        border_paint.set_stroke_width(border_with);
+
+        // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
+        // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
+        let outset = Point::new(border_with / 2.0, border_with / 2.0)
+            * match border.alignment {
+                BorderAlignment::Center => 0.0,
+                BorderAlignment::Inner => -1.0,
+                BorderAlignment::Outer => 1.0,
+            };
+
+        // Add either the RRect or smoothed path based on whether smoothing is used.
+        if radius.smoothing > 0.0 {
+            border_path.add_path(
+                &node_style
+                    .corner_radius
+                    .smoothed_path(rounded_rect.with_outset(outset)),
+                Point::new(area.min_x(), area.min_y()) - outset,
+                None,
+            );
+        } else {
+            border_path.add_rrect(rounded_rect.with_outset(outset), None);
+        }
+
+        if let Some(clip) = clip {
+            canvas.save();
+            canvas.clip_path(clip, ClipOp::Intersect, render_quality.anti_alias);
+            canvas.draw_path(&border_path, &border_paint);
+            canvas.restore();
+        } else {
+            canvas.draw_path(&border_path, &border_paint);
+        }
+    }
+
+    /// Get the outline of the element, the same one it's painted with, clipped to, and hit-tested against.
+    fn get_path(&self, layout_node: &LayoutNode, node_ref: &DioxusNode, scale_factor: f32) -> Path {
+        let rounded_rect = self.get_rounded_rect(layout_node, node_ref, scale_factor);
+        let area = layout_node.visible_area().to_f32();
+        let node_style = &*node_ref.get::<StyleState>().unwrap();
+        let mut radius = node_style.corner_radius;
+        radius.scale(scale_factor);
+
+        let mut path = Path::new();
+        if radius.smoothing > 0.0 {
+            path.add_path(
+                &radius.smoothed_path(rounded_rect),
+                (area.min_x(), area.min_y()),
+                None,
+            );
+        } else {
+            path.add_rrect(rounded_rect, None);
+        }
+        path
+    }
 }
 
 impl ElementUtils for RectElement {
@@ -53,9 +158,9 @@ impl ElementUtils for RectElement {
         layout_node: &LayoutNode,
         scale_factor: f32,
     ) -> bool {
-        let rounded_rect = self.get_rounded_rect(layout_node, node_ref, scale_factor);
+        let path = self.get_path(layout_node, node_ref, scale_factor);
         let point = point.to_f32();
-        rounded_rect.contains(Rect::new(point.x, point.y, point.x + 1., point.y + 1.))
+        path.contains((point.x, point.y))
     }
 
     fn clip(
@@ -65,9 +170,9 @@ impl ElementUtils for RectElement {
         canvas: &Canvas,
         scale_factor: f32,
     ) {
-        let rounded_rect = self.get_rounded_rect(layout_node, node_ref, scale_factor);
+        let path = self.get_path(layout_node, node_ref, scale_factor);
 
-        canvas.clip_rrect(rounded_rect, ClipOp::Intersect, true);
+        canvas.clip_path(&path, ClipOp::Intersect, true);
     }
 
     fn render(
@@ -77,19 +182,27 @@ impl ElementUtils for RectElement {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         _font_manager: &FontMgr,
+        _images_cache: &mut ImagesCache,
         _default_fonts: &[String],
         scale_factor: f32,
+        render_quality: &RenderQuality,
     ) {
+        let area = layout_node.visible_area().to_f32();
+
+        // Empty boxes still take their place in the layout, but there is nothing to paint
+        if area.is_empty() {
+            return;
+        }
+
         let node_style = &*node_ref.get::<StyleState>().unwrap();
 
         let mut paint = Paint::default();
-        let mut path = Path::new();
-        let area = layout_node.visible_area().to_f32();
+        let path = self.get_path(layout_node, node_ref, scale_factor);
 
-        paint.set_anti_alias(true);
+        paint.set_anti_alias(render_quality.anti_alias);
         paint.set_style(PaintStyle::Fill);
 
-        match &node_style.background {
+        match &node_style.background_at(Instant::now()) {
             Fill::Color(color) => {
                 paint.set_color(*color);
             }
@@ -107,25 +220,7 @@ impl ElementUtils for RectElement {
         let mut radius = node_style.corner_radius;
         radius.scale(scale_factor);
 
-        let rounded_rect = RRect::new_rect_radii(
-            Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
-            &[
-                (radius.top_left, radius.top_left).into(),
-                (radius.top_right, radius.top_right).into(),
-                (radius.bottom_right, radius.bottom_right).into(),
-                (radius.bottom_left, radius.bottom_left).into(),
-            ],
-        );
-
-        if radius.smoothing > 0.0 {
-            path.add_path(
-                &radius.smoothed_path(rounded_rect),
-                (area.min_x(), area.min_y()),
-                None,
-            );
-        } else {
-            path.add_rrect(rounded_rect, None);
-        }
+        let rounded_rect = self.get_rounded_rect(layout_node, node_ref, scale_factor);
 
         canvas.draw_path(&path, &paint);
 
@@ -199,7 +294,7 @@ impl ElementUtils for RectElement {
                         ShadowPosition::Normal => ClipOp::Difference,
                         ShadowPosition::Inset => ClipOp::Intersect,
                     },
-                    true,
+                    render_quality.anti_alias,
                 );
                 canvas.draw_path(&shadow_path, &shadow_paint);
                 canvas.restore();
@@ -207,56 +302,47 @@ impl ElementUtils for RectElement {
         }
 
         // Borders
-        if node_style.border.width > 0.0 && node_style.border.style != BorderStyle::None {
-            let mut border_with = node_style.border.width;
-            border_with *= scale_factor;
-
-            // Create a new paint and path
-            let mut border_paint = paint.clone();
-            let mut border_path = Path::new();
-
-            // Setup paint params
-            border_paint.set_anti_alias(true);
-            border_paint.set_style(PaintStyle::Stroke);
-            match &node_style.border.fill {
-                Fill::Color(color) => {
-                    border_paint.set_color(*color);
-                }
-                Fill::LinearGradient(gradient) => {
-                    border_paint.set_shader(gradient.into_shader(area));
-                }
-                Fill::RadialGradient(gradient) => {
-                    border_paint.set_shader(gradient.into_shader(area));
-                }
-                Fill::ConicGradient(gradient) => {
-                    border_paint.set_shader(gradient.into_shader(area));
-                }
-            }
-            border_paint.set_stroke_width(border_with);
-
-            // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
-            // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
-            let outset = Point::new(border_with / 2.0, border_with / 2.0)
-                * match node_style.border.alignment {
-                    BorderAlignment::Center => 0.0,
-                    BorderAlignment::Inner => -1.0,
-                    BorderAlignment::Outer => 1.0,
-                };
-
-            // Add either the RRect or smoothed path based on whether smoothing is used.
-            if radius.smoothing > 0.0 {
-                border_path.add_path(
-                    &node_style
-                        .corner_radius
-                        .smoothed_path(rounded_rect.with_outset(outset)),
-                    Point::new(area.min_x(), area.min_y()) - outset,
+        let border = &node_style.border;
+        if node_style.border_sides.is_empty() {
+            if border.is_visible() {
+                self.render_border(
+                    canvas,
+                    &paint,
+                    border,
+                    area,
+                    rounded_rect,
+                    node_style,
+                    scale_factor,
+                    render_quality,
                     None,
                 );
-            } else {
-                border_path.add_rrect(rounded_rect.with_outset(outset), None);
             }
-
-            canvas.draw_path(&border_path, &border_paint);
+        } else {
+            // Every side is stroked separately, clipped to where it meets the sides next to it
+            let sides = node_style.border_sides.resolve(border);
+            let widths = sides.clone().map(|side| {
+                if side.is_visible() {
+                    side.width * scale_factor
+                } else {
+                    0.0
+                }
+            });
+            for (i, side) in sides.iter().enumerate() {
+                if side.is_visible() {
+                    let clip = border_side_clip(area, widths, border.alignment, i);
+                    self.render_border(
+                        canvas,
+                        &paint,
+                        side,
+                        area,
+                        rounded_rect,
+                        node_style,
+                        scale_factor,
+                        render_quality,
+                        Some(&clip),
+                    );
+                }
+            }
         }
 
         let references = node_ref.get::<ReferencesState>().unwrap();
@@ -266,3 +352,59 @@ impl ElementUtils for RectElement {
         }
     }
 }
+
+/// Area where the border of the given side (clockwise starting from the top) is painted,
+/// split from the sides next to it by the lines that join the outer and inner corners of the borders.
+fn border_side_clip(area: Area, widths: [f32; 4], alignment: BorderAlignment, side: usize) -> Path {
+    let outset = match alignment {
+        BorderAlignment::Inner => 0.0,
+        BorderAlignment::Center => 0.5,
+        BorderAlignment::Outer => 1.0,
+    };
+    let [top, right, bottom, left] = widths;
+
+    let outer_top = area.min_y() - top * outset;
+    let outer_right = area.max_x() + right * outset;
+    let outer_bottom = area.max_y() + bottom * outset;
+    let outer_left = area.min_x() - left * outset;
+
+    let inner_top = outer_top + top;
+    let inner_right = outer_right - right;
+    let inner_bottom = outer_bottom - bottom;
+    let inner_left = outer_left + left;
+
+    let points = match side {
+        0 => [
+            (outer_left, outer_top),
+            (outer_right, outer_top),
+            (inner_right, inner_top),
+            (inner_left, inner_top),
+        ],
+        1 => [
+            (outer_right, outer_top),
+            (outer_right, outer_bottom),
+            (inner_right, inner_bottom),
+            (inner_right, inner_top),
+        ],
+        2 => [
+            (outer_right, outer_bottom),
+            (outer_left, outer_bottom),
+            (inner_left, inner_bottom),
+            (inner_right, inner_bottom),
+        ],
+        _ => [
+            (outer_left, outer_bottom),
+            (outer_left, outer_top),
+            (inner_left, inner_top),
+            (inner_left, inner_bottom),
+        ],
+    };
+
+    let mut path = Path::new();
+    path.move_to(points[0]);
+    for point in &points[1..] {
+        path.line_to(*point);
+    }
+    path.close();
+    path
+}
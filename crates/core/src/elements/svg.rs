@@ -4,7 +4,13 @@ use freya_node_state::StyleState;
 use torin::prelude::LayoutNode;
 
 use super::utils::ElementUtils;
-use crate::dom::DioxusNode;
+use crate::{
+    dom::DioxusNode,
+    skia::{
+        ImagesCache,
+        RenderQuality,
+    },
+};
 
 pub struct SvgElement;
 
@@ -16,22 +22,25 @@ impl ElementUtils for SvgElement {
         canvas: &Canvas,
         _font_collection: &mut FontCollection,
         font_manager: &FontMgr,
+        images_cache: &mut ImagesCache,
         _default_fonts: &[String],
-        _scale_factor: f32,
+        scale_factor: f32,
+        _render_quality: &RenderQuality,
     ) {
         let area = layout_node.visible_area();
         let node_style = &*node_ref.get::<StyleState>().unwrap();
 
-        let x = area.min_x();
-        let y = area.min_y();
         if let Some(svg_data) = &node_style.svg_data {
-            let svg_dom = svg::Dom::from_bytes(svg_data.as_slice(), font_manager);
-            if let Ok(mut svg_dom) = svg_dom {
-                canvas.save();
-                canvas.translate((x, y));
-                svg_dom.set_container_size((area.width() as i32, area.height() as i32));
-                svg_dom.render(canvas);
-                canvas.restore();
+            let size = (area.width().ceil() as i32, area.height().ceil() as i32);
+            let image = images_cache.get_svg(
+                svg_data,
+                size,
+                scale_factor,
+                node_style.preserve_aspect_ratio,
+                font_manager,
+            );
+            if let Some(image) = image {
+                canvas.draw_image(image, (area.min_x(), area.min_y()), None);
             }
         }
     }
@@ -10,7 +10,13 @@ use torin::prelude::{
 };
 
 use super::*;
-use crate::dom::DioxusNode;
+use crate::{
+    dom::DioxusNode,
+    skia::{
+        ImagesCache,
+        RenderQuality,
+    },
+};
 
 pub trait ElementUtils {
     fn is_point_inside_area(
@@ -40,8 +46,10 @@ pub trait ElementUtils {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         font_manager: &FontMgr,
+        images_cache: &mut ImagesCache,
         default_fonts: &[String],
         scale_factor: f32,
+        render_quality: &RenderQuality,
     );
 }
 
@@ -112,8 +120,10 @@ impl ElementUtils for ElementWithUtils {
         canvas: &Canvas,
         font_collection: &mut FontCollection,
         font_manager: &FontMgr,
+        images_cache: &mut ImagesCache,
         default_fonts: &[String],
         scale_factor: f32,
+        render_quality: &RenderQuality,
     ) {
         match self {
             Self::Rect(el) => el.render(
@@ -122,8 +132,10 @@ impl ElementUtils for ElementWithUtils {
                 canvas,
                 font_collection,
                 font_manager,
+                images_cache,
                 default_fonts,
                 scale_factor,
+                render_quality,
             ),
             Self::Svg(el) => el.render(
                 layout_node,
@@ -131,8 +143,10 @@ impl ElementUtils for ElementWithUtils {
                 canvas,
                 font_collection,
                 font_manager,
+                images_cache,
                 default_fonts,
                 scale_factor,
+                render_quality,
             ),
             Self::Paragraph(el) => el.render(
                 layout_node,
@@ -140,8 +154,10 @@ impl ElementUtils for ElementWithUtils {
                 canvas,
                 font_collection,
                 font_manager,
+                images_cache,
                 default_fonts,
                 scale_factor,
+                render_quality,
             ),
             Self::Image(el) => el.render(
                 layout_node,
@@ -149,8 +165,10 @@ impl ElementUtils for ElementWithUtils {
                 canvas,
                 font_collection,
                 font_manager,
+                images_cache,
                 default_fonts,
                 scale_factor,
+                render_quality,
             ),
             Self::Label(el) => el.render(
                 layout_node,
@@ -158,8 +176,10 @@ impl ElementUtils for ElementWithUtils {
                 canvas,
                 font_collection,
                 font_manager,
+                images_cache,
                 default_fonts,
                 scale_factor,
+                render_quality,
             ),
         }
     }
@@ -8,9 +8,12 @@ use freya_elements::{
     events::{
         pointer::PointerType,
         FileData,
+        GestureData,
+        ImePreeditData,
         KeyboardData,
         MouseData,
         PointerData,
+        ResizeData,
         TouchData,
         WheelData,
     },
@@ -94,10 +97,12 @@ impl DomEvent {
                     layer,
                 }
             }
-            PlatformEvent::Wheel { scroll, .. } => Self {
+            PlatformEvent::Wheel {
+                scroll, modifiers, ..
+            } => Self {
                 node_id,
                 name,
-                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y)),
+                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y, modifiers)),
                 bubbles,
                 layer,
             },
@@ -113,6 +118,15 @@ impl DomEvent {
                 bubbles,
                 layer,
             },
+            PlatformEvent::Ime {
+                preedit, cursor, ..
+            } => Self {
+                node_id,
+                name,
+                data: DomEventData::ImePreedit(ImePreeditData::new(preedit, cursor)),
+                bubbles,
+                layer,
+            },
             PlatformEvent::Touch {
                 location,
                 finger_id,
@@ -151,6 +165,33 @@ impl DomEvent {
                     layer,
                 }
             }
+            PlatformEvent::Gesture {
+                cursor,
+                scale,
+                translation,
+                phase,
+                ..
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    data: DomEventData::Gesture(GestureData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        scale,
+                        translation / scale_factor,
+                        phase,
+                    )),
+                    bubbles,
+                    layer,
+                }
+            }
             PlatformEvent::File {
                 name, file_path, ..
             } => {
@@ -173,10 +214,13 @@ impl DomEvent {
 pub enum DomEventData {
     Mouse(MouseData),
     Keyboard(KeyboardData),
+    ImePreedit(ImePreeditData),
     Wheel(WheelData),
     Touch(TouchData),
     Pointer(PointerData),
+    Gesture(GestureData),
     File(FileData),
+    Resize(ResizeData),
 }
 
 impl DomEventData {
@@ -184,10 +228,13 @@ impl DomEventData {
         match self {
             DomEventData::Mouse(m) => Rc::new(PlatformEventData::new(Box::new(m))),
             DomEventData::Keyboard(k) => Rc::new(PlatformEventData::new(Box::new(k))),
+            DomEventData::ImePreedit(i) => Rc::new(PlatformEventData::new(Box::new(i))),
             DomEventData::Wheel(w) => Rc::new(PlatformEventData::new(Box::new(w))),
             DomEventData::Touch(t) => Rc::new(PlatformEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(PlatformEventData::new(Box::new(p))),
+            DomEventData::Gesture(g) => Rc::new(PlatformEventData::new(Box::new(g))),
             DomEventData::File(fd) => Rc::new(PlatformEventData::new(Box::new(fd))),
+            DomEventData::Resize(r) => Rc::new(PlatformEventData::new(Box::new(r))),
         }
     }
 }
@@ -10,6 +10,20 @@ use freya_node_state::{
     ViewportState,
 };
 use itertools::sorted;
+use rustc_hash::FxHashSet;
+use torin::{
+    prelude::{
+        Area,
+        CursorPoint,
+        Gaps,
+        LayoutNode,
+        Point2D,
+        Size2D,
+        Torin,
+    },
+    scaled::Scaled,
+};
+use winit::event::MouseButton;
 
 pub use crate::events::{
     DomEvent,
@@ -35,24 +49,33 @@ pub fn process_events(
     // 2. Get potential events that could be emitted based on the elements layout and viewports
     let potential_events = measure_potential_event_listeners(events, dom, scale_factor);
 
-    // 3. Get what events can be actually emitted based on what elements are listening
+    // 3. Track what elements with interaction styles are under the cursor
+    for (name, interactive_nodes) in measure_interactive_nodes(events, &potential_events, dom) {
+        nodes_state.process_interactions(name, interactive_nodes);
+    }
+
+    // 4. Track whether an element that moves the window is being dragged
+    let window_drag_start = measure_window_drag_start(events, &potential_events, dom);
+    nodes_state.process_window_drag(events, window_drag_start, scale_factor);
+
+    // 5. Get what events can be actually emitted based on what elements are listening
     let dom_events = measure_dom_events(potential_events, dom, scale_factor);
 
-    // 4. Filter the dom events and get potential collateral events, e.g. mouseover -> mouseenter
+    // 6. Filter the dom events and get potential collateral events, e.g. mouseover -> mouseenter
     let (potential_collateral_events, mut to_emit_dom_events) =
         nodes_state.process_events(&dom_events, events);
 
-    // 5. Get what collateral events can actually be emitted
+    // 7. Get what collateral events can actually be emitted
     let to_emit_dom_collateral_events =
         measure_dom_events(potential_collateral_events, dom, scale_factor);
 
     let colateral_global_events = measure_colateral_global_events(&to_emit_dom_collateral_events);
 
-    // 6. Join both the dom and colateral dom events and sort them
+    // 8. Join both the dom and colateral dom events and sort them
     to_emit_dom_events.extend(to_emit_dom_collateral_events);
     to_emit_dom_events.sort_unstable();
 
-    // 7. Emit the global events
+    // 9. Emit the global events
     measure_global_events_listeners(
         global_events,
         colateral_global_events,
@@ -61,13 +84,27 @@ pub fn process_events(
         scale_factor,
     );
 
-    // 8. Emit all the vents
+    // 10. Emit all the vents
     event_emitter.send(to_emit_dom_events).unwrap();
 
-    // 9. Clear the events queue
+    // 11. Clear the events queue
     events.clear();
 }
 
+/// Emit `resize` events to the VirtualDOM for the elements that changed their size in the latest layout
+pub fn process_resize_events(
+    dom: &FreyaDOM,
+    event_emitter: &EventEmitter,
+    nodes_state: &mut NodesState,
+    scale_factor: f64,
+) {
+    let resize_events = nodes_state.process_resizes(dom.rdom(), &dom.layout(), scale_factor);
+
+    if !resize_events.is_empty() {
+        event_emitter.send(resize_events).unwrap();
+    }
+}
+
 /// Measure colateral global events
 pub fn measure_colateral_global_events(events: &[DomEvent]) -> Vec<DomEvent> {
     let mut global_events = Vec::default();
@@ -115,7 +152,9 @@ pub fn measure_potential_event_listeners(
             let layout_node = layout.get(*node_id);
             if let Some(layout_node) = layout_node {
                 'events: for event in events.iter() {
-                    if let PlatformEvent::Keyboard { name, .. } = event {
+                    if let PlatformEvent::Keyboard { name, .. } | PlatformEvent::Ime { name, .. } =
+                        event
+                    {
                         let event_data = PotentialEvent {
                             node_id: *node_id,
                             layer: Some(*layer),
@@ -127,59 +166,32 @@ pub fn measure_potential_event_listeners(
                             PlatformEvent::Mouse { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Wheel { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Touch { name, location, .. } => Some((name, location)),
+                            PlatformEvent::Gesture { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::File { name, cursor, .. } => Some((name, cursor)),
                             _ => None,
                         };
                         if let Some((name, cursor)) = data {
-                            let node = rdom.get(*node_id).unwrap();
-                            let node_type = node.node_type();
-                            let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils())
-                            else {
-                                continue;
-                            };
-                            let cursor_is_inside = element_utils.is_point_inside_area(
+                            if !is_point_inside_node(
                                 cursor,
-                                &node,
+                                *node_id,
                                 layout_node,
-                                scale_factor as f32,
-                            );
-
-                            // Make sure the cursor is inside the node area
-                            if cursor_is_inside {
-                                let node = rdom.get(*node_id).unwrap();
-                                let node_viewports = node.get::<ViewportState>().unwrap();
-
-                                // Make sure the cursor is inside all the applicable viewports from the element
-                                for node_id in &node_viewports.viewports {
-                                    let node_ref = rdom.get(*node_id).unwrap();
-                                    let node_type = node_ref.node_type();
-                                    let Some(element_utils) =
-                                        node_type.tag().and_then(|tag| tag.utils())
-                                    else {
-                                        continue;
-                                    };
-                                    let layout_node = layout.get(*node_id).unwrap();
-                                    if !element_utils.is_point_inside_area(
-                                        cursor,
-                                        &node_ref,
-                                        layout_node,
-                                        scale_factor as f32,
-                                    ) {
-                                        continue 'events;
-                                    }
-                                }
-
-                                let event_data = PotentialEvent {
-                                    node_id: *node_id,
-                                    layer: Some(*layer),
-                                    event: event.clone(),
-                                };
-
-                                potential_events
-                                    .entry(*name)
-                                    .or_insert_with(Vec::new)
-                                    .push(event_data);
+                                rdom,
+                                &layout,
+                                scale_factor,
+                            ) {
+                                continue 'events;
                             }
+
+                            let event_data = PotentialEvent {
+                                node_id: *node_id,
+                                layer: Some(*layer),
+                                event: event.clone(),
+                            };
+
+                            potential_events
+                                .entry(*name)
+                                .or_insert_with(Vec::new)
+                                .push(event_data);
                         }
                     }
                 }
@@ -190,6 +202,222 @@ pub fn measure_potential_event_listeners(
     potential_events
 }
 
+/// Get the topmost element at the given point of the window, hit tested just like the events.
+/// Returns `None` if there is no element at that point.
+pub fn measure_node_at_point(
+    fdom: &FreyaDOM,
+    point: CursorPoint,
+    scale_factor: f64,
+) -> Option<NodeId> {
+    let layout = fdom.layout();
+    let rdom = fdom.rdom();
+    let layers = fdom.layers();
+
+    // Look from the top to the bottom
+    for (_, layer_nodes) in sorted(layers.layers().iter()).rev() {
+        for node_id in layer_nodes.iter().rev() {
+            let Some(layout_node) = layout.get(*node_id) else {
+                continue;
+            };
+            if is_point_inside_node(&point, *node_id, layout_node, rdom, &layout, scale_factor) {
+                return Some(*node_id);
+            }
+        }
+    }
+
+    None
+}
+
+/// Check if the point is inside the area of the element, or its `hit_padding`, and inside all the viewports that clip it.
+fn is_point_inside_node(
+    point: &CursorPoint,
+    node_id: NodeId,
+    layout_node: &LayoutNode,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    scale_factor: f64,
+) -> bool {
+    let node = rdom.get(node_id).unwrap();
+    let node_type = node.node_type();
+    let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+        return false;
+    };
+    let point_is_inside =
+        element_utils.is_point_inside_area(point, &node, layout_node, scale_factor as f32)
+            || is_point_inside_hit_padding(point, &node, layout_node, scale_factor as f32);
+
+    if !point_is_inside {
+        return false;
+    }
+
+    // Make sure the point is inside all the applicable viewports from the element
+    let node_viewports = node.get::<ViewportState>().unwrap();
+    for viewport_id in &node_viewports.viewports {
+        let viewport_ref = rdom.get(*viewport_id).unwrap();
+        let viewport_type = viewport_ref.node_type();
+        let Some(element_utils) = viewport_type.tag().and_then(|tag| tag.utils()) else {
+            continue;
+        };
+        let viewport_layout_node = layout.get(*viewport_id).unwrap();
+        if !element_utils.is_point_inside_area(
+            point,
+            &viewport_ref,
+            viewport_layout_node,
+            scale_factor as f32,
+        ) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Measure what elements with interaction styles are under the cursor of every mouse event, in the order of the events.
+fn measure_interactive_nodes(
+    events: &EventsQueue,
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+) -> Vec<(EventName, FxHashSet<NodeId>)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            PlatformEvent::Mouse { name, cursor, .. } => Some((
+                *name,
+                measure_interactive_nodes_at(*name, *cursor, potential_events, fdom),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Measure what elements with interaction styles are under the cursor of the given mouse event.
+fn measure_interactive_nodes_at(
+    name: EventName,
+    cursor: CursorPoint,
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+) -> FxHashSet<NodeId> {
+    let rdom = fdom.rdom();
+    let mut interactive_nodes = FxHashSet::default();
+    let mut child_node: Option<NodeId> = None;
+
+    for PotentialEvent { node_id, event, .. } in
+        potential_events.get(&name).into_iter().flatten().rev()
+    {
+        let PlatformEvent::Mouse {
+            cursor: event_cursor,
+            ..
+        } = event
+        else {
+            continue;
+        };
+        if *event_cursor != cursor {
+            continue;
+        }
+
+        // Like events, elements behind a non-transparent element are only reached if they are its ancestors
+        if let Some(child_node) = child_node {
+            if !is_node_parent_of(rdom, child_node, *node_id) {
+                continue;
+            }
+        }
+
+        let Some(node) = rdom.get(*node_id) else {
+            continue;
+        };
+        let style = node.get::<StyleState>().unwrap();
+
+        if style.has_interaction_styles() && !style.disabled {
+            interactive_nodes.insert(*node_id);
+        }
+
+        if style.background != Fill::Color(Color::TRANSPARENT) {
+            child_node = Some(*node_id);
+        }
+    }
+
+    interactive_nodes
+}
+
+/// Get where the left mouse button was pressed, if it was pressed on an element with `window_drag`.
+fn measure_window_drag_start(
+    events: &EventsQueue,
+    potential_events: &PotentialEvents,
+    fdom: &FreyaDOM,
+) -> Option<CursorPoint> {
+    let cursor = events.iter().rev().find_map(|event| match event {
+        PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor,
+            button: Some(MouseButton::Left),
+        } => Some(*cursor),
+        _ => None,
+    })?;
+
+    let rdom = fdom.rdom();
+
+    for PotentialEvent { node_id, event, .. } in potential_events
+        .get(&EventName::MouseDown)
+        .into_iter()
+        .flatten()
+        .rev()
+    {
+        let PlatformEvent::Mouse {
+            cursor: event_cursor,
+            ..
+        } = event
+        else {
+            continue;
+        };
+        if *event_cursor != cursor {
+            continue;
+        }
+
+        let Some(node) = rdom.get(*node_id) else {
+            continue;
+        };
+        let style = node.get::<StyleState>().unwrap();
+
+        if style.window_drag && !style.disabled {
+            return Some(cursor);
+        }
+
+        // Elements with a background on top of the drag region, like buttons, keep receiving the events
+        if style.background != Fill::Color(Color::TRANSPARENT) {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Check if the point is inside the area of the node expanded by its `hit_padding`.
+fn is_point_inside_hit_padding(
+    point: &CursorPoint,
+    node_ref: &DioxusNode,
+    layout_node: &LayoutNode,
+    scale_factor: f32,
+) -> bool {
+    let mut hit_padding = node_ref.get::<StyleState>().unwrap().hit_padding;
+    if hit_padding == Gaps::default() {
+        return false;
+    }
+    hit_padding.scale(scale_factor);
+
+    let area = layout_node.visible_area();
+    let hit_area = Area::new(
+        Point2D::new(
+            area.min_x() - hit_padding.left(),
+            area.min_y() - hit_padding.top(),
+        ),
+        Size2D::new(
+            area.width() + hit_padding.horizontal(),
+            area.height() + hit_padding.vertical(),
+        ),
+    );
+    hit_area.contains(point.to_f32())
+}
+
 fn is_node_parent_of(rdom: &DioxusDOM, node: NodeId, parent_node: NodeId) -> bool {
     let mut stack = vec![parent_node];
     while let Some(id) = stack.pop() {
@@ -238,8 +466,14 @@ fn measure_dom_events(
                 let Some(node) = rdom.get(*node_id) else {
                     continue;
                 };
-
-                if rdom.is_node_listening(node_id, &collateral_event) {
+                let StyleState {
+                    background,
+                    disabled,
+                    ..
+                } = &*node.get::<StyleState>().unwrap();
+
+                // Disabled nodes (and their descendants) don't receive any event
+                if !disabled && rdom.is_node_listening(node_id, &collateral_event) {
                     let valid_node = if let Some(child_node) = child_node {
                         is_node_parent_of(rdom, child_node, *node_id)
                     } else {
@@ -262,8 +496,6 @@ fn measure_dom_events(
                     }
                 }
 
-                let StyleState { background, .. } = &*node.get::<StyleState>().unwrap();
-
                 if background != &Fill::Color(Color::TRANSPARENT)
                     && !event.get_name().does_go_through_solid()
                 {
@@ -305,6 +537,9 @@ fn measure_global_events_listeners(
         let listeners = fdom.rdom().get_listeners(&event_name);
 
         for listener in listeners {
+            if listener.get::<StyleState>().unwrap().disabled {
+                continue;
+            }
             let event = DomEvent::new(
                 PotentialEvent {
                     node_id: listener.id(),
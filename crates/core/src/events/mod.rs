@@ -4,6 +4,8 @@ pub mod events_measurer;
 pub mod nodes_state;
 pub mod platform_event;
 pub mod potential_event;
+pub mod scroll_sensitivity;
+pub mod touch_gestures;
 
 pub use dom_event::*;
 pub use event_name::*;
@@ -11,3 +13,5 @@ pub use events_measurer::*;
 pub use nodes_state::*;
 pub use platform_event::*;
 pub use potential_event::*;
+pub use scroll_sensitivity::*;
+pub use touch_gestures::*;
@@ -1,11 +1,36 @@
 #![allow(clippy::type_complexity)]
 
-use freya_native_core::NodeId;
-use rustc_hash::FxHashMap;
+use freya_elements::events::ResizeData;
+use freya_native_core::{
+    attributes::AttributeName,
+    node::OwnedAttributeValue,
+    real_dom::{
+        NodeImmutable,
+        NodeTypeMut,
+    },
+    NodeId,
+};
+use freya_node_state::{
+    AccessibilityNodeState,
+    CustomAttributeValues,
+    Interaction,
+    StyleState,
+};
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
+use torin::prelude::{
+    CursorPoint,
+    Size2D,
+    Torin,
+};
 
 use crate::{
+    dom::DioxusDOM,
     events::{
         DomEvent,
+        DomEventData,
         PlatformEvent,
     },
     prelude::{
@@ -13,8 +38,13 @@ use crate::{
         PotentialEvent,
         PotentialEvents,
     },
+    types::AccessibilityId,
 };
 
+/// How far the cursor must move while pressing an element with `window_drag` before the window starts being dragged,
+/// so pressing and releasing it without moving is still a click.
+const WINDOW_DRAG_THRESHOLD: f64 = 4.0;
+
 #[derive(Clone)]
 struct NodeMetadata {
     layer: Option<i16>,
@@ -24,9 +54,180 @@ struct NodeMetadata {
 #[derive(Default)]
 pub struct NodesState {
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    /// Nodes with interaction styles under the cursor.
+    hovered_styled_nodes: FxHashSet<NodeId>,
+    /// Nodes with interaction styles that were pressed and not released yet.
+    pressed_styled_nodes: FxHashSet<NodeId>,
+    /// Focused Node, if it has interaction styles.
+    focused_styled_node: Option<(AccessibilityId, Option<NodeId>)>,
+    /// Interaction states as they were last applied to the DOM.
+    applied_interactions: FxHashMap<NodeId, Interaction>,
+    /// Where an element with `window_drag` was pressed, until it's released or the window starts being dragged.
+    window_drag_start: Option<CursorPoint>,
+    /// Whether the window must start being dragged.
+    window_drag_requested: bool,
+    /// Sizes of the Nodes listening for `resize` as they were last emitted.
+    resized_nodes: FxHashMap<NodeId, Size2D>,
 }
 
 impl NodesState {
+    /// Track the Nodes with interaction styles that are under the cursor of a mouse event.
+    pub fn process_interactions(&mut self, name: EventName, nodes: FxHashSet<NodeId>) {
+        match name {
+            EventName::MouseDown => {
+                self.pressed_styled_nodes = nodes.clone();
+            }
+            EventName::Click
+            | EventName::MiddleClick
+            | EventName::RightClick
+            | EventName::PointerUp => {
+                self.pressed_styled_nodes.clear();
+            }
+            _ => {}
+        }
+
+        self.hovered_styled_nodes = nodes;
+    }
+
+    /// Track the mouse events to tell whether an element with `window_drag` is being dragged or just clicked.
+    /// `drag_start` is where it was pressed, if it was pressed in these events.
+    pub fn process_window_drag(
+        &mut self,
+        events: &[PlatformEvent],
+        drag_start: Option<CursorPoint>,
+        scale_factor: f64,
+    ) {
+        for event in events {
+            let PlatformEvent::Mouse { name, cursor, .. } = event else {
+                continue;
+            };
+            match name {
+                EventName::MouseDown => {
+                    self.window_drag_start = drag_start;
+                }
+                EventName::MouseOver => {
+                    if let Some(start) = self.window_drag_start {
+                        if (*cursor - start).length() >= WINDOW_DRAG_THRESHOLD * scale_factor {
+                            self.window_drag_start = None;
+                            self.window_drag_requested = true;
+                            // Dragging the window swallows the release of the mouse button
+                            self.pressed_styled_nodes.clear();
+                        }
+                    }
+                }
+                EventName::Click
+                | EventName::MiddleClick
+                | EventName::RightClick
+                | EventName::PointerUp => {
+                    self.window_drag_start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Compare the sizes of the Nodes listening for `resize` with the ones of the previous layout,
+    /// returning a `resize` event for every Node whose size changed or that was just measured.
+    pub fn process_resizes(
+        &mut self,
+        rdom: &DioxusDOM,
+        layout: &Torin<NodeId>,
+        scale_factor: f64,
+    ) -> Vec<DomEvent> {
+        let mut events = Vec::new();
+        let mut resized_nodes = FxHashMap::default();
+
+        for node in rdom.get_listeners(&EventName::Resize) {
+            let node_id = node.id();
+            let Some(layout_node) = layout.get(node_id) else {
+                continue;
+            };
+            let size = layout_node.visible_area().size / scale_factor as f32;
+
+            if self.resized_nodes.get(&node_id) != Some(&size) {
+                events.push(DomEvent {
+                    name: EventName::Resize,
+                    node_id,
+                    data: DomEventData::Resize(ResizeData::new(size)),
+                    bubbles: false,
+                    layer: None,
+                });
+            }
+
+            resized_nodes.insert(node_id, size);
+        }
+
+        // Nodes no longer listening are forgotten, so they are emitted again if they start listening later
+        self.resized_nodes = resized_nodes;
+
+        events
+    }
+
+    /// Check if the window must start being dragged, as an element with `window_drag` was dragged.
+    pub fn take_window_drag(&mut self) -> bool {
+        std::mem::take(&mut self.window_drag_requested)
+    }
+
+    /// Apply the interaction states that changed since the last call to the Nodes of the DOM,
+    /// so their interaction styles are resolved in the next update of their states.
+    pub fn apply_interactions(&mut self, rdom: &mut DioxusDOM, focused_id: AccessibilityId) {
+        if self.focused_styled_node.map(|(id, _)| id) != Some(focused_id) {
+            let mut focused_node = None;
+            rdom.traverse_depth_first(|node| {
+                if focused_node.is_some() {
+                    return;
+                }
+                let Some(accessibility) = node.get::<AccessibilityNodeState>() else {
+                    return;
+                };
+                let Some(style) = node.get::<StyleState>() else {
+                    return;
+                };
+                if accessibility.accessibility_id == Some(focused_id)
+                    && style.has_interaction_styles()
+                {
+                    focused_node = Some(node.id());
+                }
+            });
+            self.focused_styled_node = Some((focused_id, focused_node));
+        }
+
+        let mut interactions = FxHashMap::<NodeId, Interaction>::default();
+        for node_id in &self.hovered_styled_nodes {
+            interactions.entry(*node_id).or_default().hovered = true;
+        }
+        for node_id in &self.pressed_styled_nodes {
+            interactions.entry(*node_id).or_default().pressed = true;
+        }
+        if let Some((_, Some(node_id))) = self.focused_styled_node {
+            interactions.entry(node_id).or_default().focused = true;
+        }
+
+        let changed_nodes = self
+            .applied_interactions
+            .keys()
+            .chain(interactions.keys())
+            .filter(|node_id| self.applied_interactions.get(*node_id) != interactions.get(*node_id))
+            .copied()
+            .collect::<FxHashSet<_>>();
+
+        for node_id in changed_nodes {
+            let Some(mut node) = rdom.get_mut(node_id) else {
+                interactions.remove(&node_id);
+                continue;
+            };
+            let interaction = interactions.get(&node_id).copied().unwrap_or_default();
+            if let NodeTypeMut::Element(mut element) = node.node_type_mut() {
+                element.set_attribute(
+                    AttributeName::Interaction,
+                    OwnedAttributeValue::Custom(CustomAttributeValues::Interaction(interaction)),
+                );
+            }
+        }
+
+        self.applied_interactions = interactions;
+    }
+
     /// Update the node states given the new events
     pub fn process_events(
         &mut self,
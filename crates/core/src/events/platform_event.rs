@@ -28,6 +28,7 @@ pub enum PlatformEvent {
         name: EventName,
         scroll: CursorPoint,
         cursor: CursorPoint,
+        modifiers: Modifiers,
     },
     /// A Keyboard event.
     Keyboard {
@@ -36,6 +37,12 @@ pub enum PlatformEvent {
         code: Code,
         modifiers: Modifiers,
     },
+    /// An IME composition event.
+    Ime {
+        name: EventName,
+        preedit: String,
+        cursor: Option<(usize, usize)>,
+    },
     /// A Touch event.
     Touch {
         name: EventName,
@@ -44,6 +51,14 @@ pub enum PlatformEvent {
         phase: TouchPhase,
         force: Option<Force>,
     },
+    /// A Pinch or Pan gesture event.
+    Gesture {
+        name: EventName,
+        cursor: CursorPoint,
+        scale: f64,
+        translation: CursorPoint,
+        phase: TouchPhase,
+    },
     /// A File event.
     File {
         name: EventName,
@@ -53,12 +68,24 @@ pub enum PlatformEvent {
 }
 
 impl PlatformEvent {
+    /// Create a [PlatformEvent::Wheel] that scrolls by `scroll` with the cursor at `cursor`, without any modifier pressed.
+    pub fn wheel(scroll: impl Into<CursorPoint>, cursor: impl Into<CursorPoint>) -> Self {
+        Self::Wheel {
+            name: EventName::Wheel,
+            scroll: scroll.into(),
+            cursor: cursor.into(),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
     pub fn get_name(&self) -> EventName {
         match self {
             Self::Mouse { name, .. } => *name,
             Self::Wheel { name, .. } => *name,
             Self::Keyboard { name, .. } => *name,
+            Self::Ime { name, .. } => *name,
             Self::Touch { name, .. } => *name,
+            Self::Gesture { name, .. } => *name,
             Self::File { name, .. } => *name,
         }
     }
@@ -68,7 +95,9 @@ impl PlatformEvent {
             Self::Mouse { name, .. } => *name = new_name,
             Self::Wheel { name, .. } => *name = new_name,
             Self::Keyboard { name, .. } => *name = new_name,
+            Self::Ime { name, .. } => *name = new_name,
             Self::Touch { name, .. } => *name = new_name,
+            Self::Gesture { name, .. } => *name = new_name,
             Self::File { name, .. } => *name = new_name,
         }
     }
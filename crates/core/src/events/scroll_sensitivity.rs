@@ -0,0 +1,42 @@
+use winit::event::MouseScrollDelta;
+
+/// Distance scrolled for every line of the wheel deltas that are measured in lines.
+pub const DEFAULT_SCROLL_LINE_HEIGHT: f32 = 53.0;
+
+/// How the wheel deltas of the platform are turned into scroll offsets.
+///
+/// Mouse wheels usually report how many lines they moved, while touchpads report how many pixels,
+/// so both are normalized into logical pixels in order to feel the same everywhere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollSensitivity {
+    /// Multiplier of every scroll offset, `1.0` by default.
+    pub multiplier: f32,
+    /// Logical pixels scrolled for every line of the deltas measured in lines. [`DEFAULT_SCROLL_LINE_HEIGHT`] by default.
+    pub line_height: f32,
+}
+
+impl Default for ScrollSensitivity {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            line_height: DEFAULT_SCROLL_LINE_HEIGHT,
+        }
+    }
+}
+
+impl ScrollSensitivity {
+    /// Turn a wheel delta into a scroll offset in logical pixels.
+    pub fn normalize(&self, delta: MouseScrollDelta, scale_factor: f64) -> (f64, f64) {
+        let (x, y) = match delta {
+            MouseScrollDelta::LineDelta(x, y) => {
+                ((x * self.line_height) as f64, (y * self.line_height) as f64)
+            }
+            MouseScrollDelta::PixelDelta(position) => {
+                (position.x / scale_factor, position.y / scale_factor)
+            }
+        };
+
+        let multiplier = self.multiplier as f64;
+        (x * multiplier, y * multiplier)
+    }
+}
@@ -0,0 +1,120 @@
+use torin::prelude::CursorPoint;
+use winit::event::TouchPhase;
+
+use crate::{
+    events::PlatformEvent,
+    prelude::EventName,
+};
+
+/// Recognizes `pinch` and `pan` gestures from the touch events of the window.
+///
+/// Touches are tracked by their finger id from the frame they start until they end or get cancelled,
+/// in the order they started. The first touch is the primary one, and gestures are emitted to the element under it.
+/// Only the first two touches take part in a gesture, which lasts while both of them are down.
+#[derive(Default)]
+pub struct TouchGestures {
+    touches: Vec<(u64, CursorPoint)>,
+}
+
+impl TouchGestures {
+    /// Track a touch event, returning the gesture events it caused.
+    pub fn process_touch(
+        &mut self,
+        finger_id: u64,
+        location: CursorPoint,
+        phase: TouchPhase,
+    ) -> Vec<PlatformEvent> {
+        let previous = self.gesture_points();
+
+        match phase {
+            TouchPhase::Started => {
+                self.touches.retain(|(id, _)| *id != finger_id);
+                self.touches.push((finger_id, location));
+            }
+            TouchPhase::Moved => {
+                if let Some((_, point)) = self.touches.iter_mut().find(|(id, _)| *id == finger_id) {
+                    *point = location;
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.touches.retain(|(id, _)| *id != finger_id);
+            }
+        }
+
+        let current = self.gesture_points();
+
+        match (previous, current) {
+            // A second finger started touching
+            (None, Some((_, primary, _))) => {
+                gesture_events(primary, 1.0, CursorPoint::zero(), TouchPhase::Started)
+            }
+            // One of the two fingers moved
+            (Some((previous_ids, previous_a, previous_b)), Some((ids, a, b)))
+                if previous_ids == ids =>
+            {
+                if (previous_a, previous_b) == (a, b) {
+                    return Vec::new();
+                }
+                let previous_distance = (previous_b - previous_a).length();
+                let scale = if previous_distance > 0.0 {
+                    (b - a).length() / previous_distance
+                } else {
+                    1.0
+                };
+                let translation = a.lerp(b, 0.5) - previous_a.lerp(previous_b, 0.5);
+
+                gesture_events(a, scale, translation.to_point(), TouchPhase::Moved)
+            }
+            // One of the two fingers stopped touching, but there was a third one that takes its place
+            (Some((_, previous_primary, _)), Some((_, primary, _))) => {
+                let mut events = gesture_events(previous_primary, 1.0, CursorPoint::zero(), phase);
+                events.extend(gesture_events(
+                    primary,
+                    1.0,
+                    CursorPoint::zero(),
+                    TouchPhase::Started,
+                ));
+                events
+            }
+            // One of the two fingers stopped touching
+            (Some((_, primary, _)), None) => {
+                gesture_events(primary, 1.0, CursorPoint::zero(), phase)
+            }
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// Finger ids and locations of the two touches that take part in the gesture, the primary touch first.
+    fn gesture_points(&self) -> Option<([u64; 2], CursorPoint, CursorPoint)> {
+        match self.touches.as_slice() {
+            [(primary_id, primary), (secondary_id, secondary), ..] => {
+                Some(([*primary_id, *secondary_id], *primary, *secondary))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn gesture_events(
+    cursor: CursorPoint,
+    scale: f64,
+    translation: CursorPoint,
+    phase: TouchPhase,
+) -> Vec<PlatformEvent> {
+    vec![
+        PlatformEvent::Gesture {
+            name: EventName::Pinch,
+            cursor,
+            scale,
+            translation: CursorPoint::zero(),
+            phase,
+        },
+        PlatformEvent::Gesture {
+            name: EventName::Pan,
+            cursor,
+            scale: 1.0,
+            translation,
+            phase,
+        },
+    ]
+}
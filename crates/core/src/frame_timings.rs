@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// How long the stages of the last rendered frame took.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    /// Applying the changes of the VirtualDOM to the RealDOM.
+    pub dom_updates: Duration,
+    /// Measuring the layout.
+    pub layout: Duration,
+    /// Painting the DOM into the canvas.
+    pub render: Duration,
+    /// The whole frame, from the DOM updates to the end of the render.
+    pub frame: Duration,
+    /// Frames rendered in the last second.
+    pub fps: usize,
+}
+
+pub type FrameTimingsSender = watch::Sender<FrameTimings>;
+pub type FrameTimingsReceiver = watch::Receiver<FrameTimings>;
@@ -1,32 +1,113 @@
+use std::{
+    ops::Div,
+    time::Instant,
+};
+
+use freya_common::NodeReferenceLayout;
 use freya_engine::prelude::*;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_node_state::{
+    LayoutState,
+    ViewportState,
+};
+use rustc_hash::FxHashMap;
 use torin::geometry::Area;
 
 use crate::{
     dom::*,
-    skia::SkiaMeasurer,
+    skia::{
+        ParagraphCache,
+        RenderQuality,
+        SkiaMeasurer,
+    },
 };
 
 /// Process the layout of the DOM
+#[allow(clippy::too_many_arguments)]
 pub fn process_layout(
     fdom: &FreyaDOM,
     area: Area,
     font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
     scale_factor: f64,
     default_fonts: &[String],
+    render_quality: RenderQuality,
+    paragraph_cache: &mut ParagraphCache,
 ) {
-    {
+    let layout_references = {
         let rdom = fdom.rdom();
         let mut dom_adapter = DioxusDOMAdapter::new(rdom, scale_factor as f32);
-        let skia_measurer =
-            SkiaMeasurer::new(rdom, font_collection, default_fonts, scale_factor as f32);
+        let mut skia_measurer = Some(SkiaMeasurer::new(
+            rdom,
+            font_collection,
+            font_manager,
+            default_fonts,
+            scale_factor as f32,
+            render_quality,
+            paragraph_cache,
+        ));
 
         // Finds the best Node from where to start measuring
         fdom.layout().find_best_root(&mut dom_adapter);
 
         let root_id = fdom.rdom().root_id();
 
+        let now = Instant::now();
+        let mut layout_transitions = fdom.layout_transitions();
+        layout_transitions.record(rdom, fdom.layout_transition_nodes(), &fdom.layout(), now);
+
         // Measure the layout
         fdom.layout()
-            .measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
+            .measure(root_id, area, &mut skia_measurer, &mut dom_adapter);
+
+        layout_transitions.start(rdom, &fdom.layout(), now);
+
+        skia_measurer
+            .map(|skia_measurer| skia_measurer.layout_references.into_inner())
+            .unwrap_or_default()
+    };
+
+    notify_viewports(fdom, layout_references, area, scale_factor as f32);
+}
+
+/// Send the new layouts to the Nodes with layout references, along with where they can be seen
+/// now that the whole layout is measured.
+fn notify_viewports(
+    fdom: &FreyaDOM,
+    layout_references: FxHashMap<NodeId, NodeReferenceLayout>,
+    window_area: Area,
+    scale_factor: f32,
+) {
+    let layout = fdom.layout();
+    let rdom = fdom.rdom();
+
+    for (node_id, mut node_layout) in layout_references {
+        let Some(node) = rdom.get(node_id) else {
+            continue;
+        };
+        let Some(layout_state) = node.get::<LayoutState>() else {
+            continue;
+        };
+        let Some(reference) = &layout_state.node_ref else {
+            continue;
+        };
+        let Some(node_viewports) = node.get::<ViewportState>() else {
+            continue;
+        };
+
+        // Clip the window by every ancestor that clips this Node
+        node_layout.viewport = node_viewports
+            .viewports
+            .iter()
+            .try_fold(window_area, |viewport, viewport_id| {
+                viewport.intersection(&layout.get(*viewport_id)?.visible_area())
+            })
+            .unwrap_or_default()
+            .div(scale_factor);
+
+        reference.0.send(node_layout).ok();
     }
 }
@@ -0,0 +1,110 @@
+use std::time::Instant;
+
+use freya_common::LayoutTransitionNodes;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_node_state::{
+    ActiveTransition,
+    StyleState,
+    TransitionTiming,
+};
+use rustc_hash::FxHashMap;
+use torin::prelude::Torin;
+
+use crate::dom::DioxusDOM;
+
+/// Children of the nodes with a `layout_transition`, painted moving from where they were in the previous layout
+/// towards their current position.
+///
+/// Positions are relative to the parent, so children only move when their place inside of it changes.
+#[derive(Default)]
+pub struct LayoutTransitions {
+    previous_positions: FxHashMap<NodeId, ((f32, f32), TransitionTiming)>,
+    transitions: FxHashMap<NodeId, ActiveTransition<(f32, f32)>>,
+}
+
+impl LayoutTransitions {
+    /// Remember where the children of the nodes with a `layout_transition` are painted, before measuring the layout again.
+    pub fn record(
+        &mut self,
+        rdom: &DioxusDOM,
+        layout_transition_nodes: &LayoutTransitionNodes,
+        layout: &Torin<NodeId>,
+        now: Instant,
+    ) {
+        self.previous_positions.clear();
+        self.transitions.retain(|node_id, transition| {
+            !transition.is_finished(now) && layout.get(*node_id).is_some()
+        });
+
+        for node_id in layout_transition_nodes.nodes().iter() {
+            let Some(node) = rdom.get(*node_id) else {
+                continue;
+            };
+            let Some(timing) = node
+                .get::<StyleState>()
+                .and_then(|style| style.layout_transition)
+            else {
+                continue;
+            };
+            let Some(parent) = layout.get(*node_id) else {
+                continue;
+            };
+
+            for child_id in node.child_ids() {
+                let Some(child) = layout.get(child_id) else {
+                    continue;
+                };
+                let (offset_x, offset_y) = self.offset_at(child_id, now).unwrap_or_default();
+                let position = (
+                    child.area.min_x() - parent.area.min_x() + offset_x,
+                    child.area.min_y() - parent.area.min_y() + offset_y,
+                );
+                self.previous_positions.insert(child_id, (position, timing));
+            }
+        }
+    }
+
+    /// Start moving the recorded children whose position changed in the new layout.
+    /// Children that were not recorded, like the ones that were just added, are not moved.
+    pub fn start(&mut self, rdom: &DioxusDOM, layout: &Torin<NodeId>, now: Instant) {
+        for (child_id, ((previous_x, previous_y), timing)) in self.previous_positions.drain() {
+            let Some(parent_id) = rdom.get(child_id).and_then(|child| child.parent_id()) else {
+                self.transitions.remove(&child_id);
+                continue;
+            };
+            let Some((child, parent)) = layout.get(child_id).zip(layout.get(parent_id)) else {
+                self.transitions.remove(&child_id);
+                continue;
+            };
+
+            let offset = (
+                previous_x - (child.area.min_x() - parent.area.min_x()),
+                previous_y - (child.area.min_y() - parent.area.min_y()),
+            );
+
+            if offset != (0.0, 0.0) {
+                self.transitions
+                    .insert(child_id, ActiveTransition::new(offset, now, timing));
+            }
+        }
+    }
+
+    /// Get the transition of the given node, if it's moving or has moved.
+    pub fn get(&self, node_id: NodeId) -> Option<&ActiveTransition<(f32, f32)>> {
+        self.transitions.get(&node_id)
+    }
+
+    /// Offset from its layout position where the given node is painted at the given instant.
+    pub fn offset_at(&self, node_id: NodeId, now: Instant) -> Option<(f32, f32)> {
+        let transition = self
+            .transitions
+            .get(&node_id)
+            .filter(|transition| !transition.is_finished(now))?;
+        let (from_x, from_y) = transition.from;
+        let remaining = 1.0 - transition.progress(now);
+        Some((from_x * remaining, from_y * remaining))
+    }
+}
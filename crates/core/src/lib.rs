@@ -2,7 +2,9 @@ pub mod accessibility;
 pub mod dom;
 pub mod elements;
 pub mod events;
+pub mod frame_timings;
 pub mod layout;
+pub mod layout_transitions;
 pub mod node;
 pub mod platform_state;
 pub mod plugins;
@@ -17,7 +19,9 @@ pub mod prelude {
         dom::*,
         elements::*,
         events::*,
+        frame_timings::*,
         layout::*,
+        layout_transitions::*,
         node::*,
         platform_state::*,
         plugins::*,
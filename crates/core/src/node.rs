@@ -127,6 +127,10 @@ impl NodeState {
                 "line_height",
                 AttributeType::Measure(self.font_style.line_height),
             ),
+            (
+                "line_height_behavior",
+                AttributeType::Text(self.font_style.line_height_behavior.pretty()),
+            ),
             (
                 "text_align",
                 AttributeType::TextAlignment(&self.font_style.text_align),
@@ -135,9 +139,29 @@ impl NodeState {
                 "text_overflow",
                 AttributeType::TextOverflow(&self.font_style.text_overflow),
             ),
+            (
+                "word_break",
+                AttributeType::Text(self.font_style.word_break.pretty()),
+            ),
+            (
+                "hyphens",
+                AttributeType::Text(self.font_style.hyphens.pretty()),
+            ),
+            (
+                "line_break",
+                AttributeType::Text(self.font_style.line_break.pretty()),
+            ),
             ("offset_x", AttributeType::Measure(self.size.offset_x.get())),
             ("offset_y", AttributeType::Measure(self.size.offset_y.get())),
             ("content", AttributeType::Content(&self.size.content)),
+            (
+                "box_sizing",
+                AttributeType::Text(self.size.box_sizing.pretty()),
+            ),
+            (
+                "paragraph_spacing",
+                AttributeType::Measure(self.size.paragraph_spacing.get()),
+            ),
         ];
 
         let shadows = &self.style.shadows;
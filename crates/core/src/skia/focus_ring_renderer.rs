@@ -0,0 +1,76 @@
+use freya_engine::prelude::*;
+use freya_node_state::{
+    BorderAlignment,
+    BorderStyle,
+    Fill,
+    StyleState,
+};
+use torin::{
+    geometry::Area,
+    scaled::Scaled,
+};
+
+use super::RenderQuality;
+
+/// Render the focus ring of the given node, outside of its own border
+pub fn render_focus_ring(
+    canvas: &Canvas,
+    area: &Area,
+    node_style: &StyleState,
+    scale_factor: f32,
+    render_quality: &RenderQuality,
+) {
+    let focus_ring = &node_style.focus_ring;
+    let ring_width = focus_ring.width * scale_factor;
+
+    let border = &node_style.border;
+    let border_width = if border.style != BorderStyle::None {
+        border.width * scale_factor
+    } else {
+        0.0
+    };
+    let border_outset = match border.alignment {
+        BorderAlignment::Inner => 0.0,
+        BorderAlignment::Center => border_width / 2.0,
+        BorderAlignment::Outer => border_width,
+    };
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(render_quality.anti_alias);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(ring_width);
+    match &focus_ring.fill {
+        Fill::Color(color) => {
+            paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(*area));
+        }
+        Fill::RadialGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(*area));
+        }
+        Fill::ConicGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(*area));
+        }
+    }
+
+    let mut radius = node_style.corner_radius;
+    radius.scale(scale_factor);
+
+    let rounded_rect = RRect::new_rect_radii(
+        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+        &[
+            (radius.top_left, radius.top_left).into(),
+            (radius.top_right, radius.top_right).into(),
+            (radius.bottom_right, radius.bottom_right).into(),
+            (radius.bottom_left, radius.bottom_left).into(),
+        ],
+    );
+
+    // Strokes are centered on the path, so grow it by half the ring width to keep the whole ring outside
+    let outset = border_outset + ring_width / 2.0;
+
+    let mut path = Path::new();
+    path.add_rrect(rounded_rect.with_outset((outset, outset)), None);
+    canvas.draw_path(&path, &paint);
+}
@@ -0,0 +1,282 @@
+use std::{
+    collections::hash_map::Entry,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use freya_engine::prelude::*;
+use freya_native_core::NodeId;
+use freya_node_state::{
+    HashedBytes,
+    SvgAspectRatio,
+};
+use rustc_hash::{
+    FxHashMap,
+    FxHasher,
+};
+
+/// Identifies a rasterized SVG by its content and how it was rendered.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct SvgKey {
+    data_hash: u64,
+    width: i32,
+    height: i32,
+    scale_factor: u32,
+    aspect_ratio: Option<SvgAspectRatio>,
+}
+
+struct CachedImage {
+    image: Image,
+    used: bool,
+}
+
+/// Frames with a shorter delay than this are shown for [`DEFAULT_FRAME_DURATION`] instead, like browsers do.
+const MIN_FRAME_DURATION: Duration = Duration::from_millis(10);
+const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+/// All the decoded frames of an animated image, with how long each one is shown.
+struct AnimatedImage {
+    frames: Vec<(Image, Duration)>,
+    duration: Duration,
+    used: bool,
+}
+
+impl AnimatedImage {
+    fn decode(image_data: &[u8]) -> Option<Self> {
+        let mut codec = codec::Codec::from_data(Data::new_copy(image_data))?;
+        let info = codec.info();
+        let frames = (0..codec.get_frame_count())
+            .filter_map(|frame_index| {
+                let frame_duration = codec
+                    .get_frame_info(frame_index)
+                    .map(|frame_info| Duration::from_millis(frame_info.duration.max(0) as u64))
+                    .filter(|frame_duration| *frame_duration >= MIN_FRAME_DURATION)
+                    .unwrap_or(DEFAULT_FRAME_DURATION);
+                let options = codec::Options {
+                    zero_initialized: codec::ZeroInitialized::No,
+                    subset: None,
+                    frame_index,
+                    prior_frame: None,
+                };
+                let image = codec.get_image(info.clone(), &options).ok()?;
+                Some((image, frame_duration))
+            })
+            .collect::<Vec<_>>();
+
+        if frames.is_empty() {
+            return None;
+        }
+
+        let duration = frames
+            .iter()
+            .map(|(_, frame_duration)| *frame_duration)
+            .sum();
+
+        Some(Self {
+            frames,
+            duration,
+            used: true,
+        })
+    }
+
+    /// Get the frame shown at the given position of the animation, looping once it ends.
+    fn frame_at(&self, position: Duration) -> &Image {
+        let mut offset =
+            Duration::from_nanos((position.as_nanos() % self.duration.as_nanos()) as u64);
+        for (frame, frame_duration) in &self.frames {
+            if offset < *frame_duration {
+                return frame;
+            }
+            offset -= *frame_duration;
+        }
+        &self.frames[0].0
+    }
+}
+
+/// Playback of an animated image in a certain Node.
+struct PlaybackState {
+    position: Duration,
+    last_frame_at: Instant,
+    used: bool,
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Keeps rasterized images across frames so they don't need to be parsed and drawn again every frame.
+#[derive(Default)]
+pub struct ImagesCache {
+    svgs: FxHashMap<SvgKey, CachedImage>,
+    rasterized_svgs: usize,
+    animated_images: FxHashMap<u64, AnimatedImage>,
+    masks: FxHashMap<u64, CachedImage>,
+    playbacks: FxHashMap<NodeId, PlaybackState>,
+    has_running_animations: bool,
+    /// Whether anything was added since the last eviction.
+    changed: bool,
+}
+
+impl ImagesCache {
+    /// Get the given SVG rasterized at the given size, only parsing and rasterizing it when it wasn't already.
+    /// SVGs with a `viewBox` are scaled to the given size following the given aspect ratio or their own
+    /// `preserveAspectRatio` (centered and keeping their aspect ratio by default), and SVGs without one are drawn at their own size scaled by the scale factor.
+    pub fn get_svg(
+        &mut self,
+        svg_data: &HashedBytes,
+        (width, height): (i32, i32),
+        scale_factor: f32,
+        aspect_ratio: Option<SvgAspectRatio>,
+        font_manager: &FontMgr,
+    ) -> Option<Image> {
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let key = SvgKey {
+            data_hash: svg_data.hash,
+            width,
+            height,
+            scale_factor: scale_factor.to_bits(),
+            aspect_ratio,
+        };
+
+        if let Some(cached) = self.svgs.get_mut(&key) {
+            cached.used = true;
+            return Some(cached.image.clone());
+        }
+
+        let mut svg_dom = svg::Dom::from_bytes(svg_data.as_slice(), font_manager).ok()?;
+
+        // Make the viewBox fill the size instead of drawing the SVG at its own width and height
+        let mut root = svg_dom.root();
+        if root.view_box().is_some() {
+            root.set_width(svg::Length::new(100.0, svg::LengthUnit::Percentage));
+            root.set_height(svg::Length::new(100.0, svg::LengthUnit::Percentage));
+            // Otherwise the SVG's own preserveAspectRatio is used
+            if let Some(aspect_ratio) = aspect_ratio {
+                root.set_preserve_aspect_ratio(svg::PreserveAspectRatio {
+                    align: svg::Align::XMidYMid,
+                    scale: match aspect_ratio {
+                        SvgAspectRatio::Meet => svg::Scale::Meet,
+                        SvgAspectRatio::Slice => svg::Scale::Slice,
+                    },
+                });
+            }
+        }
+
+        // The size is in physical pixels, so the SVG is laid out in logical units and scaled up
+        let mut surface = raster_n32_premul((width, height))?;
+        svg_dom.set_container_size((width as f32 / scale_factor, height as f32 / scale_factor));
+        surface.canvas().scale((scale_factor, scale_factor));
+        svg_dom.render(surface.canvas());
+        let image = surface.image_snapshot();
+
+        self.rasterized_svgs += 1;
+        self.changed = true;
+        self.svgs.insert(
+            key,
+            CachedImage {
+                image: image.clone(),
+                used: true,
+            },
+        );
+
+        Some(image)
+    }
+
+    /// Get the frame of an animated image (e.g GIF) to show in the given Node at the given instant.
+    /// The frames are only decoded once, and the animation only advances while `playing`.
+    pub fn get_animated_image(
+        &mut self,
+        node_id: NodeId,
+        image_data: &[u8],
+        playing: bool,
+        now: Instant,
+    ) -> Option<Image> {
+        let animated_image = match self.animated_images.entry(hash_bytes(image_data)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                self.changed = true;
+                entry.insert(AnimatedImage::decode(image_data)?)
+            }
+        };
+        animated_image.used = true;
+
+        if animated_image.frames.len() == 1 {
+            return Some(animated_image.frames[0].0.clone());
+        }
+
+        let playback = self.playbacks.entry(node_id).or_insert_with(|| {
+            self.changed = true;
+            PlaybackState {
+                position: Duration::ZERO,
+                last_frame_at: now,
+                used: true,
+            }
+        });
+        if playing {
+            playback.position += now.saturating_duration_since(playback.last_frame_at);
+            self.has_running_animations = true;
+        }
+        playback.last_frame_at = now;
+        playback.used = true;
+
+        Some(animated_image.frame_at(playback.position).clone())
+    }
+
+    /// Get the decoded image of a mask, only decoding it when it wasn't already.
+    pub fn get_mask(&mut self, mask_data: &HashedBytes) -> Option<Image> {
+        let cached = match self.masks.entry(mask_data.hash) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                self.changed = true;
+                entry.insert(CachedImage {
+                    image: Image::from_encoded(Data::new_copy(mask_data.as_slice()))?,
+                    used: true,
+                })
+            }
+        };
+        cached.used = true;
+
+        Some(cached.image.clone())
+    }
+
+    /// Check if any animated image rendered since the last [`ImagesCache::evict_unused`] is still playing.
+    pub fn has_running_animations(&self) -> bool {
+        self.has_running_animations
+    }
+
+    /// Drop the images that were not used since the last eviction.
+    /// Meant to be called once every frame is rendered, but the cache is only walked when images were added
+    /// since the last eviction, so frames that reuse the same images don't pay for it.
+    pub fn evict_unused(&mut self) {
+        self.has_running_animations = false;
+
+        if !std::mem::take(&mut self.changed) {
+            return;
+        }
+
+        self.svgs
+            .retain(|_, cached| std::mem::take(&mut cached.used));
+        self.animated_images
+            .retain(|_, cached| std::mem::take(&mut cached.used));
+        self.masks
+            .retain(|_, cached| std::mem::take(&mut cached.used));
+        self.playbacks
+            .retain(|_, playback| std::mem::take(&mut playback.used));
+    }
+
+    /// How many times an SVG has been parsed and rasterized.
+    pub fn rasterized_svgs(&self) -> usize {
+        self.rasterized_svgs
+    }
+}
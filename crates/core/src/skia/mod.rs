@@ -1,8 +1,23 @@
+mod focus_ring_renderer;
+pub mod images_cache;
+pub mod paint_order_renderer;
 pub mod paragraph;
+pub mod paragraph_cache;
+pub mod pictures_cache;
+pub mod render_quality;
+pub mod screenshot;
 pub mod skia_measurer;
 pub mod skia_renderer;
+pub mod text_measurer;
 mod wireframe_renderer;
 
+pub use images_cache::*;
+pub use paint_order_renderer::*;
 pub use paragraph::*;
+pub use paragraph_cache::*;
+pub use pictures_cache::*;
+pub use render_quality::*;
+pub use screenshot::*;
 pub use skia_measurer::*;
 pub use skia_renderer::*;
+pub use text_measurer::*;
@@ -0,0 +1,87 @@
+use freya_engine::prelude::*;
+use torin::geometry::Area;
+
+/// A node in the order it was painted, see [`render_paint_order`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaintedNode {
+    pub area: Area,
+    /// Layer the node was resolved to, nodes in lower layers are painted first.
+    pub layer: i16,
+    /// Value of the `layer` attribute of the node, relative to its parent.
+    pub relative_layer: i16,
+}
+
+/// Render an outline over every node with a color that depends on its paint order,
+/// labeled with its position in the order and its resolved and relative layers.
+///
+/// Nodes are expected in the order they were painted, so the ones that come later were painted on top.
+pub fn render_paint_order(
+    canvas: &Canvas,
+    font_collection: &FontCollection,
+    default_fonts: &[String],
+    scale_factor: f32,
+    nodes: &[PaintedNode],
+) {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(scale_factor);
+
+    let mut label_paint = Paint::default();
+    label_paint.set_anti_alias(true);
+    label_paint.set_style(PaintStyle::Fill);
+
+    let mut text_style = TextStyle::new();
+    text_style.set_color(Color::WHITE);
+    text_style.set_font_size(11.0 * scale_factor);
+    text_style.set_font_families(default_fonts);
+
+    let mut paragraph_style = ParagraphStyle::default();
+    paragraph_style.set_text_style(&text_style);
+    paragraph_style.set_max_lines(1);
+
+    for (index, node) in nodes.iter().enumerate() {
+        let color = paint_order_color(index);
+        let area = node.area;
+
+        paint.set_color(color);
+        canvas.draw_rect(
+            Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            &paint,
+        );
+
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+        paragraph_builder.add_text(format!(
+            "#{index} layer {} relative {}",
+            node.layer, node.relative_layer
+        ));
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(f32::MAX);
+
+        // Label in the top left corner, with the same color as the outline
+        let padding = 2.0 * scale_factor;
+        label_paint.set_color(color);
+        canvas.draw_rect(
+            Rect::new(
+                area.min_x(),
+                area.min_y(),
+                area.min_x() + paragraph.longest_line() + padding * 2.0,
+                area.min_y() + paragraph.height(),
+            ),
+            &label_paint,
+        );
+        paragraph.paint(canvas, (area.min_x() + padding, area.min_y()));
+    }
+}
+
+/// Color a node is outlined with, cycled through following the paint order.
+fn paint_order_color(index: usize) -> Color {
+    match index % 6 {
+        0 => Color::from_rgb(230, 25, 75),
+        1 => Color::from_rgb(60, 180, 75),
+        2 => Color::from_rgb(0, 130, 200),
+        3 => Color::from_rgb(245, 130, 48),
+        4 => Color::from_rgb(145, 30, 180),
+        _ => Color::from_rgb(0, 160, 160),
+    }
+}
@@ -1,22 +1,93 @@
-use std::ops::Mul;
+use std::{
+    borrow::Cow,
+    ops::Mul,
+};
 
 use freya_common::{
     CachedParagraph,
+    CursorAffinity,
     CursorLayoutResponse,
+    CursorLineMovement,
+    InsertedBreaks,
     TextGroupMeasurement,
 };
+use freya_engine::prelude::{
+    BlendMode,
+    Canvas,
+    Paint,
+    Paragraph,
+    Rect,
+    RectHeightStyle,
+    RectWidthStyle,
+    TextBox,
+};
 use freya_native_core::prelude::NodeImmutable;
-use freya_node_state::CursorState;
+use freya_node_state::{
+    CursorState,
+    Fill,
+};
 use torin::prelude::{
+    Area,
     CursorPoint,
     LayoutNode,
 };
 
 use crate::prelude::{
+    align_cross_align_paragraph,
     align_main_align_paragraph,
     DioxusNode,
 };
 
+/// Get the index of the glyph closest to the given `point`, relative to the top-left corner of the text node.
+///
+/// Returns `None` if the node is not a measured `label` or `paragraph`.
+pub fn get_glyph_index_at(
+    node: &DioxusNode,
+    layout_node: &LayoutNode,
+    point: CursorPoint,
+    scale_factor: f64,
+) -> Option<usize> {
+    let paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+    let breaks = inserted_breaks_of(layout_node);
+
+    let offset = (
+        align_cross_align_paragraph(node, &layout_node.area, paragraph),
+        align_main_align_paragraph(node, &layout_node.area, paragraph),
+    );
+
+    Some(glyph_index_at(paragraph, &breaks, point, offset, scale_factor).0)
+}
+
+/// Indices where zero-width spaces were inserted in the text of the given measured `label` or `paragraph`,
+/// to map the indices of its [CachedParagraph] from and to the ones of the text.
+pub fn inserted_breaks_of(layout_node: &LayoutNode) -> Cow<InsertedBreaks> {
+    layout_node
+        .data
+        .as_ref()
+        .and_then(|data| data.get::<InsertedBreaks>())
+        .map(Cow::Borrowed)
+        .unwrap_or_default()
+}
+
+/// Index in the text of the glyph closest to the given `point` and which side of a line wrap it's at.
+fn glyph_index_at(
+    paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
+    point: CursorPoint,
+    (x, y): (f32, f32),
+    scale_factor: f64,
+) -> (usize, CursorAffinity) {
+    let position = CursorPoint::new(point.x - x as f64, point.y - y as f64);
+
+    let glyph =
+        paragraph.get_glyph_position_at_coordinate(position.mul(scale_factor).to_i32().to_tuple());
+
+    (
+        breaks.to_text_index(glyph.position as usize),
+        glyph.affinity.into(),
+    )
+}
+
 /// Merasure the cursor positio and text selection and notify the subscribed component of the element.
 pub fn measure_paragraph(
     node: &DioxusNode,
@@ -38,47 +109,222 @@ pub fn measure_paragraph(
         return;
     }
 
-    let y = align_main_align_paragraph(node, &layout_node.area, paragraph);
+    let breaks = inserted_breaks_of(layout_node);
+
+    let offset = (
+        align_cross_align_paragraph(node, &layout_node.area, paragraph),
+        align_main_align_paragraph(node, &layout_node.area, paragraph),
+    );
 
     if let Some(cursor_reference) = &cursor_state.cursor_ref {
         if let Some(cursor_position) = text_measurement.cursor_position {
-            let position = CursorPoint::new(cursor_position.x, cursor_position.y - y as f64);
-
             // Calculate the new cursor position
-            let char_position = paragraph
-                .get_glyph_position_at_coordinate(position.mul(scale_factor).to_i32().to_tuple());
+            let (char_position, affinity) =
+                glyph_index_at(paragraph, &breaks, cursor_position, offset, scale_factor);
 
             // Notify the cursor reference listener
             cursor_reference
                 .cursor_sender
                 .send(CursorLayoutResponse::CursorPosition {
-                    position: char_position.position as usize,
+                    position: char_position,
+                    affinity,
                     id: text_measurement.cursor_id,
                 })
                 .ok();
         }
 
-        if let Some((origin, dist)) = text_measurement.cursor_selection {
-            let origin_position = CursorPoint::new(origin.x, origin.y - y as f64);
-            let dist_position = CursorPoint::new(dist.x, dist.y - y as f64);
-
+        if let Some((origin, dist)) = text_measurement
+            .cursor_selection
+            .filter(|_| cursor_state.selectable)
+        {
             // Calculate the start of the highlighting
-            let origin_char = paragraph.get_glyph_position_at_coordinate(
-                origin_position.mul(scale_factor).to_i32().to_tuple(),
-            );
+            let (origin_char, _) = glyph_index_at(paragraph, &breaks, origin, offset, scale_factor);
             // Calculate the end of the highlighting
-            let dist_char = paragraph.get_glyph_position_at_coordinate(
-                dist_position.mul(scale_factor).to_i32().to_tuple(),
-            );
+            let (dist_char, _) = glyph_index_at(paragraph, &breaks, dist, offset, scale_factor);
 
             cursor_reference
                 .cursor_sender
                 .send(CursorLayoutResponse::TextSelection {
-                    from: origin_char.position as usize,
-                    to: dist_char.position as usize,
+                    from: origin_char,
+                    to: dist_char,
                     id: text_measurement.cursor_id,
                 })
                 .ok();
         }
+
+        if let Some(movement) = &text_measurement.cursor_line_movement {
+            if let Some((position, affinity, x)) =
+                move_cursor_across_lines(paragraph, &breaks, movement)
+            {
+                cursor_reference
+                    .cursor_sender
+                    .send(CursorLayoutResponse::CursorLineMoved {
+                        position,
+                        affinity,
+                        x,
+                        id: text_measurement.cursor_id,
+                    })
+                    .ok();
+            }
+        }
+    }
+}
+
+/// Find the index in the text of the cursor after moving it across the visual lines of the paragraph,
+/// along with the side of the line wrap it ends at and the horizontal offset it aimed for.
+fn move_cursor_across_lines(
+    paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
+    movement: &CursorLineMovement,
+) -> Option<(usize, CursorAffinity, f32)> {
+    let (caret_x, caret_y) = caret_coordinates(
+        paragraph,
+        breaks.to_layout_index(movement.position),
+        movement.affinity,
+    )?;
+    let x = movement.x.unwrap_or(caret_x);
+
+    let lines = paragraph.get_line_metrics();
+    let current_line = lines
+        .iter()
+        .position(|line| {
+            let top = line.baseline - line.ascent;
+            (top..top + line.height).contains(&(caret_y as f64))
+        })
+        .unwrap_or(lines.len().saturating_sub(1));
+
+    let target_line = current_line as isize + movement.lines;
+
+    let glyph = if target_line < 0 {
+        // Moved above the first line
+        return Some((0, CursorAffinity::Downstream, x));
+    } else if let Some(line) = lines.get(target_line as usize) {
+        let y = line.baseline - line.ascent + line.height / 2.0;
+        paragraph.get_glyph_position_at_coordinate((x, y as f32))
+    } else {
+        // Moved below the last line
+        paragraph
+            .get_glyph_position_at_coordinate((paragraph.longest_line() + 1.0, paragraph.height()))
+    };
+
+    Some((
+        breaks.to_text_index(glyph.position as usize),
+        glyph.affinity.into(),
+        x,
+    ))
+}
+
+/// Horizontal offset and vertical center of the cursor placed in the given glyph index.
+fn caret_coordinates(
+    paragraph: &Paragraph,
+    position: usize,
+    affinity: CursorAffinity,
+) -> Option<(f32, f32)> {
+    let text_box = caret_text_box(paragraph, position, affinity)
+        // The cursor is at the end of the text
+        .or_else(|| caret_text_box(paragraph, position, CursorAffinity::Upstream))?;
+
+    Some((
+        text_box.rect.left,
+        (text_box.rect.top + text_box.rect.bottom) / 2.0,
+    ))
+}
+
+/// Box of the line where the cursor placed in the given glyph index is, starting where the cursor is.
+///
+/// With an upstream affinity the cursor is after the previous glyph, so at a line wrap it stays at the end of the wrapped line.
+pub fn caret_text_box(
+    paragraph: &Paragraph,
+    position: usize,
+    affinity: CursorAffinity,
+) -> Option<TextBox> {
+    if affinity == CursorAffinity::Upstream && position > 0 {
+        let mut text_box = paragraph
+            .get_rects_for_range(
+                position - 1..position,
+                RectHeightStyle::Tight,
+                RectWidthStyle::Tight,
+            )
+            .into_iter()
+            .next()?;
+        text_box.rect.left = text_box.rect.right;
+        Some(text_box)
+    } else {
+        paragraph
+            .get_rects_for_range(
+                position..position + 1,
+                RectHeightStyle::Tight,
+                RectWidthStyle::Tight,
+            )
+            .into_iter()
+            .next()
+    }
+}
+
+/// Paint the given paragraph at the given position, filling its glyphs with `fill` instead of their color if specified.
+/// The fill spans the bounding box of the text lines.
+/// The outline of the glyphs in `stroke` is painted behind them, if any, along with the shadows of the filled text.
+pub fn paint_paragraph(
+    canvas: &Canvas,
+    paragraph: &Paragraph,
+    stroke: Option<&Paragraph>,
+    (x, y): (f32, f32),
+    fill: Option<&Fill>,
+) {
+    if let Some(stroke) = stroke {
+        stroke.paint(canvas, (x, y));
+    }
+
+    let Some(fill) = fill else {
+        paragraph.paint(canvas, (x, y));
+        return;
+    };
+
+    let (left, right) =
+        paragraph
+            .get_line_metrics()
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(left, right), line| {
+                (
+                    left.min(line.left as f32),
+                    right.max((line.left + line.width) as f32),
+                )
+            });
+    if left > right {
+        return;
+    }
+    let bounds = Area::new(
+        (x + left, y).into(),
+        (right - left, paragraph.height()).into(),
+    );
+    let rect = Rect::new(
+        bounds.min_x(),
+        bounds.min_y(),
+        bounds.max_x(),
+        bounds.max_y(),
+    );
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_blend_mode(BlendMode::SrcIn);
+    match fill {
+        Fill::Color(color) => {
+            paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(bounds));
+        }
+        Fill::RadialGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(bounds));
+        }
+        Fill::ConicGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(bounds));
+        }
     }
+
+    // Paint the text in its own layer and replace its color with the fill
+    canvas.save_layer_alpha_f(rect, 1.0);
+    paragraph.paint(canvas, (x, y));
+    canvas.draw_rect(rect, &paint);
+    canvas.restore();
 }
@@ -0,0 +1,239 @@
+use std::{
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::Arc,
+};
+
+use freya_common::{
+    CachedParagraph,
+    UnresolvedGlyphs,
+};
+use freya_engine::prelude::*;
+use freya_native_core::{
+    prelude::{
+        ElementNode,
+        NodeType,
+        SendAnyMap,
+    },
+    real_dom::NodeImmutable,
+    tags::TagName,
+};
+use freya_node_state::FontStyleState;
+use rustc_hash::{
+    FxHashMap,
+    FxHasher,
+};
+use torin::prelude::Size2D;
+
+use crate::dom::DioxusNode;
+
+/// How many measured paragraphs are kept by default before dropping the least recently used ones.
+pub const DEFAULT_PARAGRAPH_CACHE_CAPACITY: usize = 1024;
+
+/// Everything the layout of a `label` or `paragraph` depends on: its text, its font styles and the width available to it.
+#[derive(Clone, PartialEq)]
+pub struct ParagraphKey {
+    is_label: bool,
+    spans: Vec<(FontStyleState, String)>,
+    width: f32,
+    scale_factor: f32,
+}
+
+impl ParagraphKey {
+    /// Create the key of the given `label` or `paragraph`, or `None` if it's any other element.
+    pub fn new(node: &DioxusNode, area_size: &Size2D, scale_factor: f32) -> Option<Self> {
+        let node_type = node.node_type();
+        let NodeType::Element(ElementNode { tag, .. }) = &*node_type else {
+            return None;
+        };
+        let font_style = (*node.get::<FontStyleState>().unwrap()).clone();
+
+        let (is_label, spans) = match tag {
+            TagName::Label => {
+                let mut text = String::new();
+                for child in node.children() {
+                    if let NodeType::Text(child_text) = &*child.node_type() {
+                        text.push_str(child_text);
+                    }
+                }
+                (true, vec![(font_style, text)])
+            }
+            TagName::Paragraph => {
+                let mut spans = vec![(font_style, String::new())];
+                for text_span in node.children() {
+                    if let NodeType::Element(ElementNode { tag, .. }) = &*text_span.node_type() {
+                        if tag != &TagName::Text {
+                            continue;
+                        }
+                        let mut text = String::new();
+                        for child in text_span.children() {
+                            if let NodeType::Text(child_text) = &*child.node_type() {
+                                text.push_str(child_text);
+                            }
+                        }
+                        let font_style = (*text_span.get::<FontStyleState>().unwrap()).clone();
+                        spans.push((font_style, text));
+                    }
+                }
+                (false, spans)
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            is_label,
+            spans,
+            width: area_size.width,
+            scale_factor,
+        })
+    }
+
+    /// Create the key of the given text in a single style, as if it was the text of a `label`.
+    pub fn from_text(
+        font_style: FontStyleState,
+        text: String,
+        width: f32,
+        scale_factor: f32,
+    ) -> Self {
+        Self {
+            is_label: true,
+            spans: vec![(font_style, text)],
+            width,
+            scale_factor,
+        }
+    }
+
+    /// Font styles can't be hashed, so only the texts and sizes are, and the styles are compared on lookup.
+    fn text_hash(&self) -> u64 {
+        let mut hasher = FxHasher::default();
+        self.is_label.hash(&mut hasher);
+        for (_, text) in &self.spans {
+            text.hash(&mut hasher);
+        }
+        self.width.to_bits().hash(&mut hasher);
+        self.scale_factor.to_bits().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+struct CachedMeasurement {
+    key: ParagraphKey,
+    size: Size2D,
+    data: Arc<SendAnyMap>,
+    last_used: u64,
+}
+
+/// Keeps the laid out paragraphs of `label` and `paragraph` elements across layout measurements,
+/// so unchanged text isn't shaped and laid out again.
+/// Once it holds more than its capacity, the least recently used paragraphs are dropped.
+pub struct ParagraphCache {
+    entries: FxHashMap<u64, Vec<CachedMeasurement>>,
+    len: usize,
+    capacity: usize,
+    tick: u64,
+    measured_paragraphs: usize,
+}
+
+impl Default for ParagraphCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_PARAGRAPH_CACHE_CAPACITY)
+    }
+}
+
+impl ParagraphCache {
+    /// Create a cache that keeps up to `capacity` paragraphs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            len: 0,
+            capacity,
+            tick: 0,
+            measured_paragraphs: 0,
+        }
+    }
+
+    /// Get the size and laid out paragraph for the given key, only calling `measure` when it isn't cached.
+    /// `measure` can also store other data about the paragraph in the given map.
+    pub fn get_or_measure(
+        &mut self,
+        key: ParagraphKey,
+        measure: impl FnOnce(&mut SendAnyMap) -> Paragraph,
+    ) -> (Size2D, Arc<SendAnyMap>) {
+        self.tick += 1;
+        let hash = key.text_hash();
+
+        if let Some(cached) = self
+            .entries
+            .get_mut(&hash)
+            .and_then(|bucket| bucket.iter_mut().find(|cached| cached.key == key))
+        {
+            cached.last_used = self.tick;
+            return (cached.size, cached.data.clone());
+        }
+
+        let mut map = SendAnyMap::new();
+        let mut paragraph = measure(&mut map);
+        let size = Size2D::new(paragraph.longest_line(), paragraph.height());
+        let unresolved_glyphs = paragraph.unresolved_glyphs().unwrap_or_default();
+        map.insert(CachedParagraph(paragraph));
+        map.insert(UnresolvedGlyphs(unresolved_glyphs));
+        let data = Arc::new(map);
+
+        self.measured_paragraphs += 1;
+        self.entries
+            .entry(hash)
+            .or_default()
+            .push(CachedMeasurement {
+                key,
+                size,
+                data: data.clone(),
+                last_used: self.tick,
+            });
+        self.len += 1;
+
+        while self.len > self.capacity {
+            self.evict_least_recently_used();
+        }
+
+        (size, data)
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .flat_map(|(hash, bucket)| {
+                bucket
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, cached)| (cached.last_used, *hash, index))
+            })
+            .min();
+
+        if let Some((_, hash, index)) = oldest {
+            let bucket = self.entries.get_mut(&hash).unwrap();
+            bucket.swap_remove(index);
+            if bucket.is_empty() {
+                self.entries.remove(&hash);
+            }
+            self.len -= 1;
+        }
+    }
+
+    /// How many paragraphs are currently cached.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if there are no cached paragraphs.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// How many times a paragraph has been laid out because it wasn't cached.
+    pub fn measured_paragraphs(&self) -> usize {
+        self.measured_paragraphs
+    }
+}
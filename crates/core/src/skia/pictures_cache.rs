@@ -0,0 +1,192 @@
+use freya_engine::prelude::*;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use rustc_hash::FxHashMap;
+use torin::prelude::Area;
+
+use crate::{
+    prelude::DioxusDOM,
+    types::AccessibilityId,
+};
+
+/// The painted subtree of a Node, along with what it was painted with.
+struct CachedPicture {
+    picture: Picture,
+    area: Area,
+    scale_factor: f32,
+    focus_visible_id: Option<AccessibilityId>,
+    used: bool,
+}
+
+/// Extra area rendered around the visible area of a scrolled Node, so it can be scrolled by up to this distance before rendering it again.
+pub const SCROLL_SURFACE_MARGIN: f32 = 256.0;
+
+/// Layout of the content of a scrolled Node, used to know if only its scroll offsets changed since it was last rendered.
+#[derive(Clone, PartialEq)]
+pub struct ScrollContent {
+    /// Visible area of the scrolled Node.
+    pub area: Area,
+    /// Scroll offsets, already scaled.
+    pub offset: (f32, f32),
+    /// Areas of the children, without the scroll offsets.
+    pub children: Vec<(NodeId, Area)>,
+}
+
+/// The descendants of a scrolled Node rendered into an image, with [`SCROLL_SURFACE_MARGIN`] around its visible area.
+struct ScrollSurface {
+    image: Image,
+    origin: (f32, f32),
+    content: ScrollContent,
+    scale_factor: f32,
+    focus_visible_id: Option<AccessibilityId>,
+    used: bool,
+}
+
+/// Keeps the subtrees of the Nodes marked with `cache: true` recorded into pictures,
+/// so they can be replayed across frames instead of being painted again.
+#[derive(Default)]
+pub struct PicturesCache {
+    pictures: FxHashMap<NodeId, CachedPicture>,
+    recorded_pictures: usize,
+    scroll_surfaces: FxHashMap<NodeId, ScrollSurface>,
+    rendered_scroll_surfaces: usize,
+}
+
+impl PicturesCache {
+    /// Get the recorded picture of the given Node, as long as it's still valid for the given area, scale factor and focused Node.
+    pub fn get(
+        &mut self,
+        node_id: NodeId,
+        area: Area,
+        scale_factor: f32,
+        focus_visible_id: Option<AccessibilityId>,
+    ) -> Option<Picture> {
+        let cached = self.pictures.get_mut(&node_id)?;
+        if cached.area != area
+            || cached.scale_factor != scale_factor
+            || cached.focus_visible_id != focus_visible_id
+        {
+            return None;
+        }
+        cached.used = true;
+        Some(cached.picture.clone())
+    }
+
+    /// Store the recorded picture of the given Node.
+    pub fn insert(
+        &mut self,
+        node_id: NodeId,
+        picture: Picture,
+        area: Area,
+        scale_factor: f32,
+        focus_visible_id: Option<AccessibilityId>,
+    ) {
+        self.recorded_pictures += 1;
+        self.pictures.insert(
+            node_id,
+            CachedPicture {
+                picture,
+                area,
+                scale_factor,
+                focus_visible_id,
+                used: true,
+            },
+        );
+    }
+
+    /// Get the rendered descendants of the given scrolled Node and where to draw them, as long as only
+    /// its scroll offsets changed and its visible area is still covered by the rendered margin.
+    pub fn get_scroll_surface(
+        &mut self,
+        node_id: NodeId,
+        content: &ScrollContent,
+        scale_factor: f32,
+        focus_visible_id: Option<AccessibilityId>,
+    ) -> Option<(Image, (f32, f32))> {
+        let surface = self.scroll_surfaces.get_mut(&node_id)?;
+        let delta_x = content.offset.0 - surface.content.offset.0;
+        let delta_y = content.offset.1 - surface.content.offset.1;
+        let margin = SCROLL_SURFACE_MARGIN * scale_factor;
+        if surface.content.area != content.area
+            || surface.content.children != content.children
+            || surface.scale_factor != scale_factor
+            || surface.focus_visible_id != focus_visible_id
+            || delta_x.abs() > margin
+            || delta_y.abs() > margin
+        {
+            return None;
+        }
+        surface.used = true;
+        Some((
+            surface.image.clone(),
+            (surface.origin.0 + delta_x, surface.origin.1 + delta_y),
+        ))
+    }
+
+    /// Store the rendered descendants of the given scrolled Node, `origin` being where the image was rendered at.
+    pub fn insert_scroll_surface(
+        &mut self,
+        node_id: NodeId,
+        image: Image,
+        origin: (f32, f32),
+        content: ScrollContent,
+        scale_factor: f32,
+        focus_visible_id: Option<AccessibilityId>,
+    ) {
+        self.rendered_scroll_surfaces += 1;
+        self.scroll_surfaces.insert(
+            node_id,
+            ScrollSurface {
+                image,
+                origin,
+                content,
+                scale_factor,
+                focus_visible_id,
+                used: true,
+            },
+        );
+    }
+
+    /// Drop the pictures containing any of the given Nodes, which is the picture of
+    /// the Node itself and the ones of its ancestors.
+    /// Scroll surfaces only contain the descendants, so they are kept when only the scrolled Node changed.
+    pub fn invalidate(&mut self, rdom: &DioxusDOM, nodes: impl IntoIterator<Item = NodeId>) {
+        if self.pictures.is_empty() && self.scroll_surfaces.is_empty() {
+            return;
+        }
+
+        for node_id in nodes {
+            let mut node = rdom.get(node_id);
+            while let Some(node_ref) = node {
+                self.pictures.remove(&node_ref.id());
+                if node_ref.id() != node_id {
+                    self.scroll_surfaces.remove(&node_ref.id());
+                }
+                node = node_ref
+                    .parent_id()
+                    .and_then(|parent_id| rdom.get(parent_id));
+            }
+        }
+    }
+
+    /// Drop the pictures that were not used since the last call.
+    /// Meant to be called once every frame is rendered.
+    pub fn evict_unused(&mut self) {
+        self.pictures
+            .retain(|_, cached| std::mem::take(&mut cached.used));
+        self.scroll_surfaces
+            .retain(|_, surface| std::mem::take(&mut surface.used));
+    }
+
+    /// How many times a subtree has been recorded into a picture.
+    pub fn recorded_pictures(&self) -> usize {
+        self.recorded_pictures
+    }
+
+    /// How many times the descendants of a scrolled Node have been rendered into a surface.
+    pub fn rendered_scroll_surfaces(&self) -> usize {
+        self.rendered_scroll_surfaces
+    }
+}
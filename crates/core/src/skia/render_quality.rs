@@ -0,0 +1,59 @@
+use freya_engine::prelude::{
+    Edging,
+    FontHinting,
+    FontRastrSettings,
+    PixelGeometry,
+    SurfaceProps,
+    SurfacePropsFlags,
+};
+
+/// Trade the visual quality of the rendering for performance, or the other way around.
+///
+/// The defaults favor quality, disabling antialiasing can help on low-end hardware.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderQuality {
+    /// Smooth the edges of the backgrounds, borders, shadows, focus rings, images and text cursors and selections
+    /// of the elements. Default `true`.
+    /// Without it they are painted faster, but they look jagged unless they are aligned to the pixels.
+    pub anti_alias: bool,
+    /// Render text with subpixel (LCD) antialiasing, which looks sharper on most displays
+    /// but shows color fringes on rotated, scaled or transparent text.
+    /// Default `true` except on macOS, which doesn't use it since its displays have a high density.
+    pub subpixel_text: bool,
+}
+
+impl Default for RenderQuality {
+    fn default() -> Self {
+        Self {
+            anti_alias: true,
+            subpixel_text: !cfg!(target_os = "macos"),
+        }
+    }
+}
+
+impl RenderQuality {
+    /// Properties for the surfaces the elements are rendered into.
+    pub fn surface_props(&self) -> SurfaceProps {
+        let pixel_geometry = if self.subpixel_text {
+            PixelGeometry::RGBH
+        } else {
+            PixelGeometry::Unknown
+        };
+        SurfaceProps::new(SurfacePropsFlags::empty(), pixel_geometry)
+    }
+
+    /// How the glyphs of the text are rasterized, the surfaces also need to use [RenderQuality::surface_props]
+    /// to render them with subpixel antialiasing.
+    pub fn font_rastr_settings(&self) -> FontRastrSettings {
+        let edging = if self.subpixel_text {
+            Edging::SubpixelAntiAlias
+        } else {
+            Edging::AntiAlias
+        };
+        FontRastrSettings {
+            edging,
+            subpixel: true,
+            hinting: FontHinting::Slight,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use freya_engine::prelude::{
+    EncodedImageFormat,
+    IRect,
+    Surface,
+};
+use torin::prelude::Area;
+
+/// Encode what is rendered in the given surface as a PNG image, only the given area of it if any.
+///
+/// Rendered surfaces on the GPU are read back through their context,
+/// so it must be called after rendering the frame and before presenting it.
+pub fn encode_surface_png(surface: &mut Surface, area: Option<Area>) -> Option<Vec<u8>> {
+    let image = if let Some(area) = area {
+        let area = area.round_out();
+        surface.image_snapshot_with_bounds(IRect::new(
+            area.min_x() as i32,
+            area.min_y() as i32,
+            area.max_x() as i32,
+            area.max_y() as i32,
+        ))?
+    } else {
+        surface.image_snapshot()
+    };
+    let mut context = surface.direct_context();
+    let data = image.encode(context.as_mut(), EncodedImageFormat::PNG, None)?;
+    Some(data.as_bytes().to_vec())
+}
@@ -1,8 +1,21 @@
-use std::sync::Arc;
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    sync::{
+        Arc,
+        OnceLock,
+    },
+};
 
 use freya_common::{
     CachedParagraph,
+    CachedStrokeParagraph,
+    EllipsizedMiddle,
+    InsertedBreaks,
+    MissingFontFamilies,
     NodeReferenceLayout,
+    SnapPoint,
+    TextLineMetrics,
 };
 use freya_engine::prelude::*;
 use freya_native_core::{
@@ -19,9 +32,21 @@ use freya_node_state::{
     CursorState,
     FontStyleState,
     HighlightMode,
+    Hyphens,
     LayoutState,
+    LineBreak,
+    ReferenceChildren,
     TextOverflow,
+    WordBreak,
+};
+use hyphenation::{
+    Hyphenator,
+    Language,
+    Load,
+    Standard,
 };
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
 use torin::prelude::{
     Alignment,
     Area,
@@ -30,30 +55,53 @@ use torin::prelude::{
     Node,
     Point2D,
     Size2D,
+    Torin,
 };
+use tracing::warn;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::dom::*;
+use crate::{
+    dom::*,
+    skia::{
+        inserted_breaks_of,
+        ParagraphCache,
+        ParagraphKey,
+        RenderQuality,
+    },
+};
 
 /// Provides Text measurements using Skia APIs like SkParagraph
 pub struct SkiaMeasurer<'a> {
     pub font_collection: &'a FontCollection,
+    pub font_manager: &'a FontMgr,
     pub rdom: &'a DioxusDOM,
     pub default_fonts: &'a [String],
     pub scale_factor: f32,
+    pub render_quality: RenderQuality,
+    pub paragraph_cache: &'a mut ParagraphCache,
+    /// Layouts of the Nodes with references measured so far, sent once their viewports are known, see `notify_viewports`.
+    pub layout_references: RefCell<FxHashMap<NodeId, NodeReferenceLayout>>,
 }
 
 impl<'a> SkiaMeasurer<'a> {
     pub fn new(
         rdom: &'a DioxusDOM,
         font_collection: &'a FontCollection,
+        font_manager: &'a FontMgr,
         default_fonts: &'a [String],
         scale_factor: f32,
+        render_quality: RenderQuality,
+        paragraph_cache: &'a mut ParagraphCache,
     ) -> Self {
         Self {
             font_collection,
+            font_manager,
             rdom,
             default_fonts,
             scale_factor,
+            render_quality,
+            paragraph_cache,
+            layout_references: RefCell::default(),
         }
     }
 }
@@ -68,33 +116,80 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
         let node = self.rdom.get(node_id).unwrap();
         let node_type = node.node_type();
 
+        let key = ParagraphKey::new(&node, area_size, self.scale_factor)?;
+
         match &*node_type {
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Label => {
-                let label = create_label(
-                    &node,
-                    area_size,
-                    self.font_collection,
-                    self.default_fonts,
-                    self.scale_factor,
-                );
-                let res = Size2D::new(label.longest_line(), label.height());
-                let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(label));
-                Some((res, Arc::new(map)))
+                Some(self.paragraph_cache.get_or_measure(key, |data| {
+                    if cfg!(debug_assertions) {
+                        report_missing_font_families(
+                            &node,
+                            self.font_manager,
+                            self.font_collection,
+                            data,
+                        );
+                    }
+                    data.insert(inserted_breaks(&node));
+                    if has_stroke_paragraph(&node) {
+                        data.insert(CachedStrokeParagraph(create_label(
+                            &node,
+                            area_size,
+                            self.font_collection,
+                            self.default_fonts,
+                            self.scale_factor,
+                            &self.render_quality,
+                            true,
+                        )));
+                    }
+                    let (paragraph, ellipsized_middle) = build_label(
+                        &node,
+                        area_size,
+                        self.font_collection,
+                        self.default_fonts,
+                        self.scale_factor,
+                        &self.render_quality,
+                        false,
+                    );
+                    if ellipsized_middle {
+                        data.insert(EllipsizedMiddle);
+                    }
+                    paragraph
+                }))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Paragraph => {
-                let paragraph = create_paragraph(
-                    &node,
-                    area_size,
-                    self.font_collection,
-                    false,
-                    self.default_fonts,
-                    self.scale_factor,
-                );
-                let res = Size2D::new(paragraph.longest_line(), paragraph.height());
-                let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(paragraph));
-                Some((res, Arc::new(map)))
+                Some(self.paragraph_cache.get_or_measure(key, |data| {
+                    if cfg!(debug_assertions) {
+                        report_missing_font_families(
+                            &node,
+                            self.font_manager,
+                            self.font_collection,
+                            data,
+                        );
+                    }
+                    data.insert(inserted_breaks(&node));
+                    if has_stroke_paragraph(&node) {
+                        data.insert(CachedStrokeParagraph(create_paragraph(
+                            &node,
+                            area_size,
+                            self.font_collection,
+                            false,
+                            self.default_fonts,
+                            self.scale_factor,
+                            &self.render_quality,
+                            true,
+                        )));
+                    }
+                    create_paragraph(
+                        &node,
+                        area_size,
+                        self.font_collection,
+                        false,
+                        self.default_fonts,
+                        self.scale_factor,
+                        &self.render_quality,
+                        false,
+                    )
+                }))
             }
             _ => None,
         }
@@ -110,52 +205,348 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
             .unwrap_or_default()
     }
 
-    fn notify_layout_references(&self, node_id: NodeId, layout_node: &LayoutNode) {
+    fn notify_layout_references_with_layout(
+        &self,
+        node_id: NodeId,
+        layout_node: &LayoutNode,
+        layout: &Torin<NodeId>,
+    ) {
         let node = self.rdom.get(node_id).unwrap();
         let size_state = &*node.get::<LayoutState>().unwrap();
 
-        if let Some(reference) = &size_state.node_ref {
+        if size_state.node_ref.is_some() {
+            let offset_x = size_state.offset_x.get() * self.scale_factor;
+            let offset_y = size_state.offset_y.get() * self.scale_factor;
+            let children_kind = size_state.node_ref_children;
+            let snap_points = if children_kind != ReferenceChildren::None {
+                node.children()
+                    .into_iter()
+                    .filter_map(|child| {
+                        let align = child.get::<LayoutState>()?.snap_align?;
+                        let mut area = layout.get(child.id())?.area;
+                        area.origin.x -= layout_node.inner_area.min_x() + offset_x;
+                        area.origin.y -= layout_node.inner_area.min_y() + offset_y;
+                        Some(SnapPoint { area, align })
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let children = if children_kind == ReferenceChildren::All {
+                node.children()
+                    .into_iter()
+                    .filter(|child| {
+                        child
+                            .get::<LayoutState>()
+                            .is_some_and(|layout| !layout.position.is_out_of_flow())
+                    })
+                    .filter_map(|child| {
+                        let mut area = layout.get(child.id())?.area;
+                        area.origin -= layout_node.area.origin.to_vector();
+                        Some(area)
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            let paragraph = layout_node
+                .data
+                .as_ref()
+                .and_then(|data| data.get::<CachedParagraph>());
+            let text_lines = paragraph
+                .map(|CachedParagraph(paragraph)| {
+                    measure_text_lines(
+                        &node,
+                        &layout_node.visible_area(),
+                        paragraph,
+                        &inserted_breaks_of(layout_node),
+                    )
+                })
+                .unwrap_or_default();
+            // Labels trimmed with a middle ellipsis fit in their lines, but they still exceeded them
+            let exceeded_max_lines = paragraph
+                .is_some_and(|CachedParagraph(paragraph)| paragraph.did_exceed_max_lines())
+                || layout_node
+                    .data
+                    .as_ref()
+                    .is_some_and(|data| data.get::<EllipsizedMiddle>().is_some());
+
             let mut node_layout = NodeReferenceLayout {
                 area: layout_node.area,
                 inner: layout_node.inner_sizes,
+                snap_points,
+                children,
+                viewport: Area::default(),
+                text_lines,
+                exceeded_max_lines,
             };
             node_layout.div(self.scale_factor);
-            reference.0.send(node_layout).ok();
+            self.layout_references
+                .borrow_mut()
+                .insert(node_id, node_layout);
         }
     }
 }
 
+/// Metrics of the visual lines of the given paragraph, relative to the area of its Node.
+fn measure_text_lines(
+    node: &DioxusNode,
+    area: &Area,
+    paragraph: &Paragraph,
+    breaks: &InsertedBreaks,
+) -> Vec<TextLineMetrics> {
+    let x = align_cross_align_paragraph(node, area, paragraph);
+    let y = align_main_align_paragraph(node, area, paragraph);
+
+    paragraph
+        .get_line_metrics()
+        .into_iter()
+        .map(|line| TextLineMetrics {
+            line_number: line.line_number,
+            start_index: breaks.to_text_index(line.start_index),
+            end_index: breaks.to_text_index(line.end_index),
+            end_excluding_whitespaces: breaks.to_text_index(line.end_excluding_whitespaces),
+            hard_break: line.hard_break,
+            baseline: line.baseline as f32 + y,
+            ascent: line.ascent as f32,
+            descent: line.descent as f32,
+            left: line.left as f32 + x,
+            width: line.width as f32,
+            height: line.height as f32,
+        })
+        .collect()
+}
+
+/// Warn about the font families of the given `label` or `paragraph` that were not found
+/// in the app fonts nor in the system fonts of the font collection, and store them in its layout data as [MissingFontFamilies].
+fn report_missing_font_families(
+    node: &DioxusNode,
+    font_manager: &FontMgr,
+    font_collection: &FontCollection,
+    data: &mut SendAnyMap,
+) {
+    let system_font_manager = font_collection.fallback_manager();
+    let mut missing_font_families = Vec::<String>::new();
+
+    let mut find_font_families = |font_style: &FontStyleState| {
+        for font_family in &font_style.font_family {
+            if !missing_font_families.contains(font_family)
+                && font_manager.match_family(font_family).count() == 0
+                && !system_font_manager
+                    .as_ref()
+                    .is_some_and(|system_font_manager| {
+                        system_font_manager.match_family(font_family).count() > 0
+                    })
+            {
+                missing_font_families.push(font_family.clone());
+            }
+        }
+    };
+
+    find_font_families(&node.get::<FontStyleState>().unwrap());
+    for child in node.children() {
+        if let NodeType::Element(ElementNode { tag, .. }) = &*child.node_type() {
+            if tag == &TagName::Text {
+                find_font_families(&child.get::<FontStyleState>().unwrap());
+            }
+        }
+    }
+
+    if !missing_font_families.is_empty() {
+        warn!(
+            "The font families {missing_font_families:?} were not found, falling back to the default fonts"
+        );
+    }
+
+    data.insert(MissingFontFamilies(missing_font_families));
+}
+
+/// Check if the given `label` or `paragraph`, or any of its `text` spans, has an outline
+/// or shadows of a fill, so it needs a [CachedStrokeParagraph] to paint them.
+pub fn has_stroke_paragraph(node: &DioxusNode) -> bool {
+    let has_stroke =
+        |font_style: &FontStyleState| font_style.has_text_stroke() || font_style.has_fill_shadows();
+
+    if has_stroke(&node.get::<FontStyleState>().unwrap()) {
+        return true;
+    }
+
+    node.children()
+        .iter()
+        .any(|child| match &*child.node_type() {
+            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Text => {
+                has_stroke(&child.get::<FontStyleState>().unwrap())
+            }
+            _ => false,
+        })
+}
+
+/// Compose a new SkParagraph for a `label`, painting only the outline of its glyphs if `stroke` is `true`.
 pub fn create_label(
     node: &DioxusNode,
     area_size: &Size2D,
     font_collection: &FontCollection,
     default_font_family: &[String],
     scale_factor: f32,
+    render_quality: &RenderQuality,
+    stroke: bool,
 ) -> Paragraph {
+    build_label(
+        node,
+        area_size,
+        font_collection,
+        default_font_family,
+        scale_factor,
+        render_quality,
+        stroke,
+    )
+    .0
+}
+
+/// Same as [create_label], but also telling whether its text was trimmed with a middle ellipsis.
+fn build_label(
+    node: &DioxusNode,
+    area_size: &Size2D,
+    font_collection: &FontCollection,
+    default_font_family: &[String],
+    scale_factor: f32,
+    render_quality: &RenderQuality,
+    stroke: bool,
+) -> (Paragraph, bool) {
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
+    let mut text = String::new();
+    for child in node.children() {
+        if let NodeType::Text(child_text) = &*child.node_type() {
+            text.push_str(child_text);
+        }
+    }
+
+    build_text_paragraph(
+        &text,
+        font_style,
+        area_size.width,
+        font_collection,
+        default_font_family,
+        scale_factor,
+        render_quality,
+        stroke,
+    )
+}
+
+/// Compose a new SkParagraph for the given text in a single style, laid out in the given width like a `label`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_text_paragraph(
+    text: &str,
+    font_style: &FontStyleState,
+    width: f32,
+    font_collection: &FontCollection,
+    default_font_family: &[String],
+    scale_factor: f32,
+    render_quality: &RenderQuality,
+    stroke: bool,
+) -> Paragraph {
+    build_text_paragraph(
+        text,
+        font_style,
+        width,
+        font_collection,
+        default_font_family,
+        scale_factor,
+        render_quality,
+        stroke,
+    )
+    .0
+}
+
+/// Same as [create_text_paragraph], but also telling whether the text was trimmed with a middle ellipsis.
+#[allow(clippy::too_many_arguments)]
+fn build_text_paragraph(
+    text: &str,
+    font_style: &FontStyleState,
+    width: f32,
+    font_collection: &FontCollection,
+    default_font_family: &[String],
+    scale_factor: f32,
+    render_quality: &RenderQuality,
+    stroke: bool,
+) -> (Paragraph, bool) {
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
-    let text_style = font_style.text_style(default_font_family, scale_factor);
+    paragraph_style.set_font_rastr_settings(render_quality.font_rastr_settings());
+    paragraph_style
+        .set_text_height_behavior(font_style.line_height_behavior.text_height_behavior());
+    let text_style = text_style(font_style, default_font_family, scale_factor, stroke);
     paragraph_style.set_text_style(&text_style);
 
     if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
         paragraph_style.set_ellipsis(ellipsis);
     }
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+    let middle_ellipsis = font_style.text_overflow == TextOverflow::EllipsisMiddle;
+    if middle_ellipsis {
+        paragraph_style.set_max_lines(1);
+    }
 
-    for child in node.children() {
-        if let NodeType::Text(text) = &*child.node_type() {
-            paragraph_builder.add_text(text);
-        }
+    let build = |text: &str| {
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+        paragraph_builder.add_text(text);
+
+        let mut paragraph = paragraph_builder.build();
+        layout_paragraph(&mut paragraph, width + 1.0, font_style.line_break);
+        paragraph
+    };
+
+    // The text is laid out in a single line, so it doesn't need more line breaks
+    if middle_ellipsis {
+        ellipsize_middle(text, build)
+    } else {
+        let broken_text = break_text(text, font_style);
+        let points = hyphenation_points(text, font_style, 0);
+        let paragraph = hyphenate(&points, |hyphens| {
+            build(&show_hyphens(&broken_text, 0, hyphens))
+        });
+        (paragraph, false)
     }
+}
 
-    let mut paragraph = paragraph_builder.build();
-    paragraph.layout(area_size.width + 1.0);
-    paragraph
+/// Lay out the text in a single line, replacing as few characters as possible from its middle
+/// with an ellipsis so it fits, and tell whether it had to.
+/// Skia can only place the ellipsis at the end, so the text is trimmed from both sides of the middle until it fits.
+fn ellipsize_middle(text: &str, build: impl Fn(&str) -> Paragraph) -> (Paragraph, bool) {
+    const ELLIPSIS: &str = "...";
+
+    let paragraph = build(text);
+    if !paragraph.did_exceed_max_lines() {
+        return (paragraph, false);
+    }
+
+    let chars = text.chars().collect::<Vec<char>>();
+    let build_trimmed = |kept: usize| {
+        let (head, tail) = ((kept + 1) / 2, kept / 2);
+        let mut trimmed = chars[..head].iter().collect::<String>();
+        trimmed.push_str(ELLIPSIS);
+        trimmed.extend(&chars[chars.len() - tail..]);
+        build(&trimmed)
+    };
+
+    // Look for the most characters that can be kept
+    let mut paragraph = build_trimmed(0);
+    let (mut min, mut max) = (0, chars.len() - 1);
+    while min < max {
+        let kept = (min + max + 1) / 2;
+        let trimmed = build_trimmed(kept);
+        if trimmed.did_exceed_max_lines() {
+            max = kept - 1;
+        } else {
+            min = kept;
+            paragraph = trimmed;
+        }
+    }
+    (paragraph, true)
 }
 
 /// Align the Y axis of the highlights and cursor of a paragraph
@@ -168,7 +559,8 @@ pub fn align_highlights_and_cursor_paragraph(
 ) -> (Point2D, Point2D) {
     let cursor_state = node.get::<CursorState>().unwrap();
 
-    let x = area.min_x() + cursor_rect.rect.left;
+    let x =
+        area.min_x() + align_cross_align_paragraph(node, area, paragraph) + cursor_rect.rect.left;
     let x2 = x + width.unwrap_or(cursor_rect.rect.right - cursor_rect.rect.left);
 
     match cursor_state.highlight_mode {
@@ -203,7 +595,26 @@ pub fn align_main_align_paragraph(node: &DioxusNode, area: &Area, paragraph: &Pa
     }
 }
 
-/// Compose a new SkParagraph
+/// Align the cross alignment of a paragraph with balanced lines.
+///
+/// They are laid out narrower than their area, so their text alignment is moved to be relative to the whole area.
+pub fn align_cross_align_paragraph(node: &DioxusNode, area: &Area, paragraph: &Paragraph) -> f32 {
+    let font_style = node.get::<FontStyleState>().unwrap();
+
+    if font_style.line_break != LineBreak::Balanced {
+        return 0.;
+    }
+
+    let free_width = (area.width() - paragraph.max_width()).max(0.);
+    match font_style.text_align {
+        TextAlign::Center => free_width / 2.0,
+        TextAlign::Right | TextAlign::End => free_width,
+        _ => 0.,
+    }
+}
+
+/// Compose a new SkParagraph, painting only the outline of its glyphs if `stroke` is `true`.
+#[allow(clippy::too_many_arguments)]
 pub fn create_paragraph(
     node: &DioxusNode,
     area_size: &Size2D,
@@ -211,6 +622,8 @@ pub fn create_paragraph(
     is_rendering: bool,
     default_font_family: &[String],
     scale_factor: f32,
+    render_quality: &RenderQuality,
+    stroke: bool,
 ) -> Paragraph {
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
@@ -218,16 +631,18 @@ pub fn create_paragraph(
     paragraph_style.set_text_align(font_style.text_align);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
+    paragraph_style.set_font_rastr_settings(render_quality.font_rastr_settings());
+    paragraph_style
+        .set_text_height_behavior(font_style.line_height_behavior.text_height_behavior());
 
     if font_style.text_overflow == TextOverflow::Ellipsis {
         paragraph_style.set_ellipsis("…");
     }
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
-
-    let text_style = font_style.text_style(default_font_family, scale_factor);
-    paragraph_builder.push_style(&text_style);
-
+    let mut spans = Vec::new();
+    // Hyphenation points of the spans, counted in UTF-16 code units of the laid out text
+    let mut points = Vec::new();
+    let mut layout_offset = 0;
     for text_span in node.children() {
         match &*text_span.node_type() {
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Text => {
@@ -235,23 +650,428 @@ pub fn create_paragraph(
                 let text_node = *text_nodes.first().unwrap();
                 let text_node_type = &*text_node.node_type();
                 let font_style = text_span.get::<FontStyleState>().unwrap();
-                let text_style = font_style.text_style(default_font_family, scale_factor);
-                paragraph_builder.push_style(&text_style);
 
                 if let NodeType::Text(text) = text_node_type {
-                    paragraph_builder.add_text(text);
+                    points.extend(hyphenation_points(text, &font_style, layout_offset));
+                    let text = break_text(text, &font_style).into_owned();
+                    layout_offset += text.encode_utf16().count();
+                    spans.push(((*font_style).clone(), text));
                 }
             }
             _ => {}
         }
     }
 
-    if is_rendering {
-        // This is very tricky, but it works! It allows freya to render the cursor at the end of a line.
-        paragraph_builder.add_text(" ");
+    let build = |first_line_end: Option<usize>, hyphens: &[usize]| {
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+
+        let root_text_style = text_style(font_style, default_font_family, scale_factor, stroke);
+        paragraph_builder.push_style(&root_text_style);
+
+        // The first line is measured in UTF-16 code units, like the glyph positions
+        let mut first_line_remaining = first_line_end;
+        let mut span_offset = 0;
+        for (span_font_style, text) in &spans {
+            let span_length = text.encode_utf16().count();
+            let text = show_hyphens(text, span_offset, hyphens);
+            span_offset += span_length;
+            let split = first_line_remaining.map(|remaining| {
+                let split = utf16_to_byte_index(&text, remaining);
+                first_line_remaining = Some(remaining - text[..split].encode_utf16().count());
+                split
+            });
+            let (first_line_text, text) = text.split_at(split.unwrap_or_default());
+
+            if !first_line_text.is_empty() {
+                let mut first_line_font_style = span_font_style.clone();
+                font_style.first_line.apply(&mut first_line_font_style);
+                let first_line_text_style = text_style(
+                    &first_line_font_style,
+                    default_font_family,
+                    scale_factor,
+                    stroke,
+                );
+                paragraph_builder.push_style(&first_line_text_style);
+                paragraph_builder.add_text(first_line_text);
+            }
+
+            let span_text_style =
+                text_style(span_font_style, default_font_family, scale_factor, stroke);
+            paragraph_builder.push_style(&span_text_style);
+            paragraph_builder.add_text(text);
+        }
+
+        if is_rendering {
+            // This is very tricky, but it works! It allows freya to render the cursor at the end of a line.
+            paragraph_builder.add_text(" ");
+        }
+
+        let mut paragraph = paragraph_builder.build();
+        layout_paragraph(&mut paragraph, area_size.width + 1.0, font_style.line_break);
+        paragraph
+    };
+
+    let build = |first_line_end: Option<usize>| {
+        hyphenate(&points, |hyphens| build(first_line_end, hyphens))
+    };
+
+    let mut paragraph = build(None);
+
+    if !font_style.first_line.is_empty() {
+        // The first line might get shorter once styled, so it's styled again until it's stable
+        let mut first_line_end = get_first_line_end(&paragraph);
+        for _ in 0..3 {
+            paragraph = build(Some(first_line_end));
+            let styled_first_line_end = get_first_line_end(&paragraph);
+            if styled_first_line_end >= first_line_end {
+                break;
+            }
+            first_line_end = styled_first_line_end;
+        }
+    }
+
+    paragraph
+}
+
+/// Lay out the paragraph in the given width, breaking its lines with the given strategy.
+///
+/// Balanced lines are found by narrowing down the width the paragraph is laid out in
+/// for as long as it keeps the same number of lines, so it takes several layouts.
+fn layout_paragraph(paragraph: &mut Paragraph, width: f32, line_break: LineBreak) {
+    paragraph.layout(width);
+
+    let lines = paragraph.line_number();
+    if line_break == LineBreak::Greedy || lines < 2 || paragraph.did_exceed_max_lines() {
+        return;
+    }
+
+    // The lines already fit in the longest one, so it's the widest the balanced lines can be
+    let mut fitting_width = paragraph.longest_line().ceil().min(width);
+    let mut overflowing_width = 0.0;
+    let mut laid_out_width = width;
+    while fitting_width - overflowing_width > 1.0 {
+        let mid = (overflowing_width + fitting_width) / 2.0;
+        paragraph.layout(mid);
+        laid_out_width = mid;
+        if paragraph.line_number() <= lines && !paragraph.did_exceed_max_lines() {
+            fitting_width = mid;
+        } else {
+            overflowing_width = mid;
+        }
+    }
+
+    if laid_out_width != fitting_width {
+        paragraph.layout(fitting_width);
+    }
+}
+
+/// Glyph position where the second line of the paragraph starts, or the end of the text if there is only one line.
+fn get_first_line_end(paragraph: &Paragraph) -> usize {
+    let line_metrics = paragraph.get_line_metrics();
+    match line_metrics.get(1) {
+        Some(second_line) => {
+            let y = second_line.baseline - second_line.ascent + second_line.height / 2.0;
+            paragraph
+                .get_glyph_position_at_coordinate((0.0, y as f32))
+                .position as usize
+        }
+        None => usize::MAX,
+    }
+}
+
+/// Byte index of the given UTF-16 code units offset in the text, or its length if it's beyond it.
+fn utf16_to_byte_index(text: &str, utf16_index: usize) -> usize {
+    let mut utf16_count = 0;
+    for (byte_index, c) in text.char_indices() {
+        if utf16_count >= utf16_index {
+            return byte_index;
+        }
+        utf16_count += c.len_utf16();
+    }
+    text.len()
+}
+
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Allow breaking the lines of text with `word_break: "break-all"` between any two graphemes,
+/// Japanese text before its small kana, and text with `hyphens: "auto"` at the hyphenation points of its words,
+/// by placing zero-width spaces between them. Their positions are given by [inserted_breaks].
+/// Words that don't fit in a line are always broken by Skia, so `break-word` doesn't need to change the text.
+fn break_text<'a>(text: &'a str, font_style: &FontStyleState) -> Cow<'a, str> {
+    let indices = break_indices(text, font_style);
+    if indices.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut broken_text = String::with_capacity(text.len() + indices.len() * 3);
+    let mut last_index = 0;
+    for index in indices {
+        broken_text.push_str(&text[last_index..index]);
+        broken_text.push(ZERO_WIDTH_SPACE);
+        last_index = index;
+    }
+    broken_text.push_str(&text[last_index..]);
+    Cow::Owned(broken_text)
+}
+
+/// Byte indices of the given text where [break_text] inserts a zero-width space.
+fn break_indices(text: &str, font_style: &FontStyleState) -> Vec<usize> {
+    if font_style.word_break == WordBreak::BreakAll {
+        break_all_indices(text).collect()
+    } else if font_style.resolved_lang().is_some_and(is_japanese) {
+        japanese_break_indices(text).collect()
+    } else if let Some(dictionary) = hyphenation_dictionary(font_style) {
+        hyphenation_indices(text, dictionary).collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Byte indices between two graphemes that aren't whitespaces, so the text can already be broken around them.
+fn break_all_indices(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let is_whitespace = |grapheme: &str| grapheme.chars().all(char::is_whitespace);
+
+    text.grapheme_indices(true)
+        .tuple_windows()
+        .filter(move |((_, previous), (_, next))| !is_whitespace(previous) && !is_whitespace(next))
+        .map(|(_, (index, _))| index)
+}
+
+fn is_japanese(lang: &str) -> bool {
+    lang.split(['-', '_'])
+        .next()
+        .is_some_and(|language| language.eq_ignore_ascii_case("ja"))
+}
+
+/// Byte indices before the small kana and prolonged sound marks of the given text.
+/// Skia never breaks before them, like the strict line breaking of CSS, but Japanese text
+/// is usually broken there too, like the normal line breaking of CSS.
+fn japanese_break_indices(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.char_indices()
+        .tuple_windows()
+        .filter(|((_, previous), (_, next))| {
+            !previous.is_whitespace() && is_conditional_japanese_starter(*next)
+        })
+        .map(|(_, (index, _))| index)
+}
+
+/// Characters of the `CJ` line breaking class of Unicode.
+fn is_conditional_japanese_starter(c: char) -> bool {
+    matches!(
+        c,
+        'ぁ' | 'ぃ'
+            | 'ぅ'
+            | 'ぇ'
+            | 'ぉ'
+            | 'っ'
+            | 'ゃ'
+            | 'ゅ'
+            | 'ょ'
+            | 'ゎ'
+            | 'ゕ'
+            | 'ゖ'
+            | 'ァ'
+            | 'ィ'
+            | 'ゥ'
+            | 'ェ'
+            | 'ォ'
+            | 'ッ'
+            | 'ャ'
+            | 'ュ'
+            | 'ョ'
+            | 'ヮ'
+            | 'ヵ'
+            | 'ヶ'
+            | 'ー'
+            | '\u{31F0}'..='\u{31FF}'
+            | '\u{FF67}'..='\u{FF70}'
+    )
+}
+
+/// Hyphenation patterns to hyphenate the text of the given style, if it has `hyphens: "auto"` and they are available for its language.
+/// Only the English ones are embedded, and they are loaded the first time they are needed.
+fn hyphenation_dictionary(font_style: &FontStyleState) -> Option<&'static Standard> {
+    static ENGLISH: OnceLock<Option<Standard>> = OnceLock::new();
+
+    if font_style.hyphens != Hyphens::Auto || font_style.word_break == WordBreak::BreakAll {
+        return None;
+    }
+
+    let language = font_style.resolved_lang()?.split(['-', '_']).next()?;
+    if !language.eq_ignore_ascii_case("en") {
+        return None;
+    }
+
+    ENGLISH
+        .get_or_init(|| Standard::from_embedded(Language::EnglishUS).ok())
+        .as_ref()
+}
+
+/// Byte indices of the hyphenation points of the words of the given text.
+fn hyphenation_indices<'a>(
+    text: &'a str,
+    dictionary: &'a Standard,
+) -> impl Iterator<Item = usize> + 'a {
+    text.split_word_bound_indices()
+        .filter(|(_, word)| word.chars().all(char::is_alphabetic))
+        .flat_map(|(index, word)| {
+            // The patterns are lowercase, and lowercasing ASCII letters keeps the byte indices
+            let breaks = dictionary.hyphenate(&word.to_ascii_lowercase()).breaks;
+            breaks
+                .into_iter()
+                .map(move |hyphen_index| index + hyphen_index)
+        })
+}
+
+/// UTF-16 indices of the laid out text where [break_text] inserts a zero-width space at a hyphenation point of the given text,
+/// which starts at `layout_offset` of the laid out text.
+fn hyphenation_points(text: &str, font_style: &FontStyleState, layout_offset: usize) -> Vec<usize> {
+    if hyphenation_dictionary(font_style).is_none() {
+        return Vec::new();
+    }
+
+    let mut last_index = 0;
+    let mut utf16_index = layout_offset;
+    break_indices(text, font_style)
+        .into_iter()
+        .enumerate()
+        .map(|(inserted_before, index)| {
+            utf16_index += text[last_index..index].encode_utf16().count();
+            last_index = index;
+            utf16_index + inserted_before
+        })
+        .collect()
+}
+
+/// Replace the zero-width spaces at the given UTF-16 indices of the laid out text with hyphens,
+/// for the given text that starts at `layout_offset` of it.
+/// Both are a single UTF-16 code unit long, so the indices of the laid out text don't change.
+fn show_hyphens<'a>(text: &'a str, layout_offset: usize, hyphens: &[usize]) -> Cow<'a, str> {
+    if hyphens.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut utf16_index = layout_offset;
+    let text = text
+        .chars()
+        .map(|c| {
+            let index = utf16_index;
+            utf16_index += c.len_utf16();
+            if c == ZERO_WIDTH_SPACE && hyphens.binary_search(&index).is_ok() {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect();
+    Cow::Owned(text)
+}
+
+/// Lay out a text with the given hyphenation points, showing a hyphen at the ones where its lines are wrapped.
+///
+/// The hyphens are wider than the zero-width spaces they replace, so the lines might wrap somewhere else
+/// once they are shown, and it's laid out again until they stay at the end of the lines.
+/// If they don't, the text is shown without hyphens.
+fn hyphenate(points: &[usize], build: impl Fn(&[usize]) -> Paragraph) -> Paragraph {
+    const MAX_LAYOUTS: usize = 4;
+
+    let mut paragraph = build(&[]);
+    if points.is_empty() {
+        return paragraph;
+    }
+
+    let mut hyphens = Vec::new();
+    for _ in 0..MAX_LAYOUTS {
+        let wrapped = wrapped_points(&paragraph, points);
+        if wrapped == hyphens {
+            return paragraph;
+        }
+        hyphens = wrapped;
+        paragraph = build(&hyphens);
     }
 
-    let mut paragraph = paragraph_builder.build();
-    paragraph.layout(area_size.width + 1.0);
+    build(&[])
+}
+
+/// Hyphenation points of the laid out text where one of its lines is wrapped.
+fn wrapped_points(paragraph: &Paragraph, points: &[usize]) -> Vec<usize> {
     paragraph
+        .get_line_metrics()
+        .iter()
+        .filter(|line| !line.hard_break)
+        .filter_map(|line| {
+            // The zero-width space or hyphen stays at the end of the line
+            points
+                .iter()
+                .find(|point| line.end_index == **point + 1 || line.end_index == **point)
+                .copied()
+        })
+        .collect()
+}
+
+/// Indices of the text of the given `label` or `paragraph` where zero-width spaces are inserted
+/// to break its lines, to map the indices of its laid out paragraph.
+pub fn inserted_breaks(node: &DioxusNode) -> InsertedBreaks {
+    let mut breaks = Vec::new();
+    let mut utf16_offset = 0;
+    let mut add_text = |text: &str, font_style: &FontStyleState| {
+        let mut last_index = 0;
+        let mut utf16_index = utf16_offset;
+        for index in break_indices(text, font_style) {
+            utf16_index += text[last_index..index].encode_utf16().count();
+            breaks.push(utf16_index);
+            last_index = index;
+        }
+        utf16_offset += text.encode_utf16().count();
+    };
+
+    match &*node.node_type() {
+        NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Label => {
+            let font_style = node.get::<FontStyleState>().unwrap();
+            // The text is laid out in a single line
+            if font_style.text_overflow == TextOverflow::EllipsisMiddle {
+                return InsertedBreaks::default();
+            }
+
+            let mut text = String::new();
+            for child in node.children() {
+                if let NodeType::Text(child_text) = &*child.node_type() {
+                    text.push_str(child_text);
+                }
+            }
+            add_text(&text, &font_style);
+        }
+        NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Paragraph => {
+            for text_span in node.children() {
+                if let NodeType::Element(ElementNode { tag, .. }) = &*text_span.node_type() {
+                    if tag != &TagName::Text {
+                        continue;
+                    }
+                    let text_nodes = text_span.children();
+                    let Some(text_node) = text_nodes.first() else {
+                        continue;
+                    };
+                    if let NodeType::Text(text) = &*text_node.node_type() {
+                        add_text(text, &text_span.get::<FontStyleState>().unwrap());
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    InsertedBreaks(breaks)
+}
+
+fn text_style(
+    font_style: &FontStyleState,
+    default_font_family: &[String],
+    scale_factor: f32,
+    stroke: bool,
+) -> TextStyle {
+    if stroke {
+        font_style.stroke_text_style(default_font_family, scale_factor)
+    } else {
+        font_style.text_style(default_font_family, scale_factor)
+    }
 }
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use freya_engine::prelude::*;
 use freya_native_core::{
     node::NodeType,
@@ -7,6 +9,11 @@ use freya_native_core::{
     NodeId,
 };
 use freya_node_state::{
+    AccessibilityNodeState,
+    BorderStyle,
+    LayerState,
+    LayoutState,
+    OverflowMode,
     StyleState,
     TransformState,
     ViewportState,
@@ -17,25 +24,62 @@ use torin::prelude::{
     Torin,
 };
 
-use super::wireframe_renderer;
+use super::{
+    focus_ring_renderer,
+    wireframe_renderer,
+};
 use crate::{
     dom::DioxusNode,
     elements::{
+        measure_caret_area,
         ElementUtils,
         ElementUtilsResolver,
     },
+    layout_transitions::LayoutTransitions,
     prelude::DioxusDOM,
+    skia::{
+        ImagesCache,
+        PicturesCache,
+        RenderQuality,
+        ScrollContent,
+        SCROLL_SURFACE_MARGIN,
+    },
+    types::AccessibilityId,
 };
 
+/// Where a node is being rendered into.
+#[derive(Clone, Copy)]
+enum RenderTarget<'a> {
+    /// The canvas of the window.
+    Canvas,
+    /// The picture of a cached ancestor, or of itself.
+    Picture,
+    /// The surface of a scrolled ancestor, whose own clipping is applied when drawing the surface.
+    ScrollSurface { ignored_viewports: &'a [NodeId] },
+}
+
 pub struct SkiaRenderer<'a> {
     pub canvas_area: Area,
     pub canvas: &'a Canvas,
     pub font_collection: &'a mut FontCollection,
     pub font_manager: &'a FontMgr,
+    pub images_cache: &'a mut ImagesCache,
+    pub pictures_cache: &'a mut PicturesCache,
     pub matrices: Vec<(Matrix, Vec<NodeId>)>,
     pub opacities: Vec<(f32, Vec<NodeId>)>,
+    /// Node whose layer is being rendered, only the nodes painted in it are rendered, see [`StyleState::isolates_subtree`].
+    pub layer_root: Option<NodeId>,
     pub default_fonts: &'a [String],
     pub scale_factor: f32,
+    pub render_quality: RenderQuality,
+    /// Node focused with the keyboard, which gets its focus ring rendered.
+    pub focus_visible_id: Option<AccessibilityId>,
+    /// Children moving after a layout change, see the `layout_transition` attribute.
+    pub layout_transitions: &'a LayoutTransitions,
+    /// Set when any rendered node still has a running transition.
+    pub has_running_transitions: bool,
+    /// Cursor of the last rendered `paragraph` that shows one, where the input method places its candidates.
+    pub caret_area: Option<Area>,
 }
 
 impl SkiaRenderer<'_> {
@@ -47,6 +91,25 @@ impl SkiaRenderer<'_> {
         node_ref: &DioxusNode,
         render_wireframe: bool,
         layout: &Torin<NodeId>,
+    ) {
+        self.render_node(
+            rdom,
+            layout_node,
+            node_ref,
+            render_wireframe,
+            layout,
+            RenderTarget::Canvas,
+        )
+    }
+
+    fn render_node(
+        &mut self,
+        rdom: &DioxusDOM,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        render_wireframe: bool,
+        layout: &Torin<NodeId>,
+        target: RenderTarget,
     ) {
         let area = layout_node.visible_area();
         let node_type = &*node_ref.node_type();
@@ -55,13 +118,62 @@ impl SkiaRenderer<'_> {
                 return;
             };
 
-            let initial_layer = self.canvas.save();
-
             let node_transform = &*node_ref.get::<TransformState>().unwrap();
             let node_style = &*node_ref.get::<StyleState>().unwrap();
 
+            let inside_picture = !matches!(target, RenderTarget::Canvas);
+
+            // Measured even when it's painted along with an ancestor, which might not be painted again this frame
+            if *tag == TagName::Paragraph && !inside_picture {
+                if let Some(caret_area) = measure_caret_area(layout_node, node_ref) {
+                    self.caret_area = Some(caret_area);
+                }
+            }
+
+            // Painted along with the picture or surface of its cached ancestor
+            if node_style.inside_cache && !inside_picture {
+                return;
+            }
+
+            // Painted in the layer of the closest ancestor that blends or masks its subtree
+            let is_layer_root = self.layer_root == Some(node_ref.id());
+            if !is_layer_root && enclosing_layer_root(node_ref, node_style) != self.layer_root {
+                return;
+            }
+
+            // Scrolled nodes only keep their descendants rendered, so they can be moved around
+            let replay_scroll_surface =
+                node_style.cache && !inside_picture && node_style.overflow == OverflowMode::Clip;
+
+            // Its own effects are already recorded in the picture
+            let replay_picture = node_style.cache && !inside_picture && !replay_scroll_surface;
+
+            // Paint its subtree in a single layer, with its own effects applied when recording it
+            let render_layer = !is_layer_root && !replay_picture && node_style.isolates_subtree();
+            let skip_own_effects = replay_picture || render_layer;
+
+            let initial_layer = self.canvas.save();
+
+            let now = Instant::now();
+            if node_style.has_running_transitions(now) {
+                self.has_running_transitions = true;
+            }
+
+            // Pass the movement of the layout transition to children
+            if let Some((x, y)) = self
+                .layout_transitions
+                .offset_at(node_ref.id(), now)
+                .filter(|_| !skip_own_effects)
+            {
+                let mut matrix = Matrix::new_identity();
+                matrix.set_translate((x, y));
+
+                self.matrices.push((matrix, vec![node_ref.id()]));
+                self.has_running_transitions = true;
+            }
+
             // Pass rotate effect to children
-            if let Some(rotate_degs) = node_transform.rotate_degs {
+            if let Some(rotate_degs) = node_transform.rotate_degs.filter(|_| !skip_own_effects) {
                 let mut matrix = Matrix::new_identity();
                 matrix.set_rotate(
                     rotate_degs,
@@ -75,7 +187,7 @@ impl SkiaRenderer<'_> {
             }
 
             // Pass opacity effect to children
-            if let Some(opacity) = node_style.opacity {
+            if let Some(opacity) = node_style.opacity_at(now).filter(|_| !skip_own_effects) {
                 self.opacities.push((opacity, vec![node_ref.id()]));
             }
 
@@ -115,6 +227,11 @@ impl SkiaRenderer<'_> {
             }
 
             for node_id in &node_viewports.viewports {
+                if let RenderTarget::ScrollSurface { ignored_viewports } = target {
+                    if ignored_viewports.contains(node_id) {
+                        continue;
+                    }
+                }
                 let node_ref = rdom.get(*node_id).unwrap();
                 let node_type = node_ref.node_type();
                 let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
@@ -124,15 +241,76 @@ impl SkiaRenderer<'_> {
                 element_utils.clip(layout_node, &node_ref, self.canvas, self.scale_factor);
             }
 
-            element_utils.render(
-                layout_node,
-                node_ref,
-                self.canvas,
-                self.font_collection,
-                self.font_manager,
-                self.default_fonts,
-                self.scale_factor,
-            );
+            if render_layer {
+                if let Some(picture) = self.get_layer_picture(rdom, node_ref, layout, target) {
+                    let mut paint = Paint::default();
+                    paint.set_blend_mode(node_style.blend_mode);
+                    let mask = node_style
+                        .mask_data
+                        .as_ref()
+                        .and_then(|mask_data| self.images_cache.get_mask(mask_data));
+                    if let Some(mask) = mask {
+                        let mask_rect =
+                            Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+                        // Nothing outside of the mask is visible
+                        self.canvas.clip_rect(mask_rect, ClipOp::Intersect, true);
+                        self.canvas
+                            .save_layer(&SaveLayerRec::default().bounds(&mask_rect).paint(&paint));
+                        self.canvas.draw_picture(&picture, None, None);
+
+                        // Only keep what is under the opaque parts of the mask
+                        let mut mask_paint = Paint::default();
+                        mask_paint.set_blend_mode(BlendMode::DstIn);
+                        self.canvas
+                            .draw_image_rect(&mask, None, mask_rect, &mask_paint);
+                    } else {
+                        self.canvas.draw_picture(&picture, None, Some(&paint));
+                    }
+                }
+            } else if replay_picture {
+                if let Some(picture) = self.get_picture(rdom, layout_node, node_ref, layout) {
+                    self.canvas.draw_picture(&picture, None, None);
+                }
+            } else {
+                element_utils.render(
+                    layout_node,
+                    node_ref,
+                    self.canvas,
+                    self.font_collection,
+                    self.font_manager,
+                    self.images_cache,
+                    self.default_fonts,
+                    self.scale_factor,
+                    &self.render_quality,
+                );
+            }
+
+            if replay_scroll_surface && !render_layer {
+                self.canvas.save();
+                element_utils.clip(layout_node, node_ref, self.canvas, self.scale_factor);
+                if let Some((image, origin)) =
+                    self.get_scroll_surface(rdom, layout_node, node_ref, layout)
+                {
+                    self.canvas.draw_image(image, origin, None);
+                }
+                self.canvas.restore();
+            }
+
+            if let Some(focus_visible_id) = self.focus_visible_id.filter(|_| !skip_own_effects) {
+                let node_accessibility = node_ref.get::<AccessibilityNodeState>().unwrap();
+                if node_accessibility.accessibility_id == Some(focus_visible_id)
+                    && node_style.focus_ring.width > 0.0
+                    && node_style.focus_ring.style != BorderStyle::None
+                {
+                    focus_ring_renderer::render_focus_ring(
+                        self.canvas,
+                        &area,
+                        node_style,
+                        self.scale_factor,
+                        &self.render_quality,
+                    );
+                }
+            }
 
             if render_wireframe {
                 wireframe_renderer::render_wireframe(self.canvas, &area);
@@ -141,4 +319,293 @@ impl SkiaRenderer<'_> {
             self.canvas.restore_to_count(initial_layer);
         }
     }
+
+    /// Get the picture of a cached node, recording it again along with its descendants when it's no longer valid.
+    fn get_picture(
+        &mut self,
+        rdom: &DioxusDOM,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        layout: &Torin<NodeId>,
+    ) -> Option<Picture> {
+        let node_id = node_ref.id();
+        let area = layout_node.visible_area();
+
+        if let Some(picture) =
+            self.pictures_cache
+                .get(node_id, area, self.scale_factor, self.focus_visible_id)
+        {
+            return Some(picture);
+        }
+
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(
+            Rect::new(
+                self.canvas_area.min_x(),
+                self.canvas_area.min_y(),
+                self.canvas_area.max_x(),
+                self.canvas_area.max_y(),
+            ),
+            None,
+        );
+
+        let mut subtree_renderer = SkiaRenderer {
+            canvas_area: self.canvas_area,
+            canvas,
+            font_collection: self.font_collection,
+            font_manager: self.font_manager,
+            images_cache: self.images_cache,
+            pictures_cache: self.pictures_cache,
+            matrices: Vec::default(),
+            opacities: Vec::default(),
+            layer_root: None,
+            default_fonts: self.default_fonts,
+            scale_factor: self.scale_factor,
+            render_quality: self.render_quality,
+            focus_visible_id: self.focus_visible_id,
+            layout_transitions: self.layout_transitions,
+            has_running_transitions: false,
+            caret_area: None,
+        };
+
+        for node_id in subtree_paint_order(rdom, node_id) {
+            let Some((node_ref, layout_node)) = rdom.get(node_id).zip(layout.get(node_id)) else {
+                continue;
+            };
+            subtree_renderer.render_node(
+                rdom,
+                layout_node,
+                &node_ref,
+                false,
+                layout,
+                RenderTarget::Picture,
+            );
+        }
+
+        let has_running_transitions = subtree_renderer.has_running_transitions;
+        let picture = recorder.finish_recording_as_picture(None)?;
+
+        // Transitions need to be painted every frame, so the picture is only valid for this one
+        if has_running_transitions {
+            self.has_running_transitions = true;
+        } else {
+            self.pictures_cache.insert(
+                node_id,
+                picture.clone(),
+                area,
+                self.scale_factor,
+                self.focus_visible_id,
+            );
+        }
+
+        Some(picture)
+    }
+
+    /// Record the given node and the descendants painted in its layer into a picture, so they are blended or masked at once.
+    fn get_layer_picture(
+        &mut self,
+        rdom: &DioxusDOM,
+        node_ref: &DioxusNode,
+        layout: &Torin<NodeId>,
+        target: RenderTarget,
+    ) -> Option<Picture> {
+        let node_id = node_ref.id();
+
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(
+            Rect::new(
+                self.canvas_area.min_x(),
+                self.canvas_area.min_y(),
+                self.canvas_area.max_x(),
+                self.canvas_area.max_y(),
+            ),
+            None,
+        );
+
+        let mut subtree_renderer = SkiaRenderer {
+            canvas_area: self.canvas_area,
+            canvas,
+            font_collection: self.font_collection,
+            font_manager: self.font_manager,
+            images_cache: self.images_cache,
+            pictures_cache: self.pictures_cache,
+            matrices: Vec::default(),
+            opacities: Vec::default(),
+            layer_root: Some(node_id),
+            default_fonts: self.default_fonts,
+            scale_factor: self.scale_factor,
+            render_quality: self.render_quality,
+            focus_visible_id: self.focus_visible_id,
+            layout_transitions: self.layout_transitions,
+            has_running_transitions: false,
+            caret_area: None,
+        };
+
+        // Keep ignoring the clipping of the scrolled ancestor it's rendered into
+        let target = match target {
+            RenderTarget::Canvas => RenderTarget::Picture,
+            target => target,
+        };
+
+        for node_id in subtree_paint_order(rdom, node_id) {
+            let Some((node_ref, layout_node)) = rdom.get(node_id).zip(layout.get(node_id)) else {
+                continue;
+            };
+            subtree_renderer.render_node(rdom, layout_node, &node_ref, false, layout, target);
+        }
+
+        if subtree_renderer.has_running_transitions {
+            self.has_running_transitions = true;
+        }
+
+        recorder.finish_recording_as_picture(None)
+    }
+
+    /// Get the rendered descendants of a cached scrolled node and where to draw them,
+    /// rendering them again when they changed or were scrolled beyond the rendered margin.
+    fn get_scroll_surface(
+        &mut self,
+        rdom: &DioxusDOM,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        layout: &Torin<NodeId>,
+    ) -> Option<(Image, (f32, f32))> {
+        let node_id = node_ref.id();
+        let area = layout_node.visible_area();
+        let node_layout = node_ref.get::<LayoutState>().unwrap();
+        let offset = (
+            node_layout.offset_x.get() * self.scale_factor,
+            node_layout.offset_y.get() * self.scale_factor,
+        );
+        let children = node_ref
+            .child_ids()
+            .into_iter()
+            .filter_map(|child_id| {
+                let mut child_area = layout.get(child_id)?.area;
+                child_area.origin.x -= offset.0;
+                child_area.origin.y -= offset.1;
+                Some((child_id, child_area))
+            })
+            .collect();
+        let content = ScrollContent {
+            area,
+            offset,
+            children,
+        };
+
+        if let Some(surface) = self.pictures_cache.get_scroll_surface(
+            node_id,
+            &content,
+            self.scale_factor,
+            self.focus_visible_id,
+        ) {
+            return Some(surface);
+        }
+
+        let margin = SCROLL_SURFACE_MARGIN * self.scale_factor;
+        let bounds = area.inflate(margin, margin).round_out();
+        let mut surface = self.canvas.new_surface(
+            &ImageInfo::new_n32_premul((bounds.width() as i32, bounds.height() as i32), None),
+            Some(&self.render_quality.surface_props()),
+        )?;
+        let canvas = surface.canvas();
+        canvas.translate((-bounds.min_x(), -bounds.min_y()));
+
+        // The clipping of the scrolled node and its ancestors is applied when drawing the surface
+        let mut ignored_viewports = node_ref.get::<ViewportState>().unwrap().viewports.clone();
+        ignored_viewports.push(node_id);
+
+        let mut subtree_renderer = SkiaRenderer {
+            canvas_area: bounds,
+            canvas,
+            font_collection: self.font_collection,
+            font_manager: self.font_manager,
+            images_cache: self.images_cache,
+            pictures_cache: self.pictures_cache,
+            matrices: Vec::default(),
+            opacities: Vec::default(),
+            layer_root: None,
+            default_fonts: self.default_fonts,
+            scale_factor: self.scale_factor,
+            render_quality: self.render_quality,
+            focus_visible_id: self.focus_visible_id,
+            layout_transitions: self.layout_transitions,
+            has_running_transitions: false,
+            caret_area: None,
+        };
+
+        for descendant_id in subtree_paint_order(rdom, node_id) {
+            if descendant_id == node_id {
+                continue;
+            }
+            let Some((descendant_ref, layout_node)) =
+                rdom.get(descendant_id).zip(layout.get(descendant_id))
+            else {
+                continue;
+            };
+            // Skip the descendants that are too far to be scrolled into view
+            if !bounds.intersects(&layout_node.area) {
+                continue;
+            }
+            subtree_renderer.render_node(
+                rdom,
+                layout_node,
+                &descendant_ref,
+                false,
+                layout,
+                RenderTarget::ScrollSurface {
+                    ignored_viewports: &ignored_viewports,
+                },
+            );
+        }
+
+        let has_running_transitions = subtree_renderer.has_running_transitions;
+        let image = surface.image_snapshot();
+        let origin = (bounds.min_x(), bounds.min_y());
+
+        // Transitions need to be rendered every frame, so the surface is only valid for this one
+        if has_running_transitions {
+            self.has_running_transitions = true;
+        } else {
+            self.pictures_cache.insert_scroll_surface(
+                node_id,
+                image.clone(),
+                origin,
+                content,
+                self.scale_factor,
+                self.focus_visible_id,
+            );
+        }
+
+        Some((image, origin))
+    }
+}
+
+/// Get the layer root that paints the given node, which is never the node itself.
+fn enclosing_layer_root(node_ref: &DioxusNode, node_style: &StyleState) -> Option<NodeId> {
+    if node_style.layer_root == Some(node_ref.id()) {
+        let parent = node_ref.parent()?;
+        let parent_style = parent.get::<StyleState>().unwrap();
+        parent_style.layer_root
+    } else {
+        node_style.layer_root
+    }
+}
+
+/// Get the given node and all its descendants in the order they are painted, which is by their layer.
+fn subtree_paint_order(rdom: &DioxusDOM, root_id: NodeId) -> Vec<NodeId> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![root_id];
+    while let Some(node_id) = stack.pop() {
+        let Some(node_ref) = rdom.get(node_id) else {
+            continue;
+        };
+        let layer = node_ref.get::<LayerState>().unwrap().layer;
+        nodes.push((layer, node_id));
+        stack.extend(node_ref.child_ids().into_iter().rev());
+    }
+
+    // Stable sort so nodes of the same layer keep the tree order
+    nodes.sort_by_key(|(layer, _)| *layer);
+    nodes.into_iter().map(|(_, node_id)| node_id).collect()
 }
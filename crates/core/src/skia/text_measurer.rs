@@ -0,0 +1,73 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use freya_node_state::FontStyleState;
+
+use crate::skia::{
+    create_text_paragraph,
+    ParagraphCache,
+    ParagraphKey,
+    RenderQuality,
+};
+
+/// How many measured texts are kept before dropping the least recently used ones.
+const TEXT_MEASURER_CACHE_CAPACITY: usize = 256;
+
+/// Measures text without adding it to the DOM, with the same fonts and fallbacks used to render it.
+///
+/// It's shared with the components of the app as a root context.
+#[derive(Clone)]
+pub struct TextMeasurer {
+    font_collection: FontCollection,
+    default_fonts: Arc<Vec<String>>,
+    cache: Arc<Mutex<ParagraphCache>>,
+}
+
+impl TextMeasurer {
+    pub fn new(font_collection: FontCollection, default_fonts: Vec<String>) -> Self {
+        Self {
+            font_collection,
+            default_fonts: Arc::new(default_fonts),
+            cache: Arc::new(Mutex::new(ParagraphCache::with_capacity(
+                TEXT_MEASURER_CACHE_CAPACITY,
+            ))),
+        }
+    }
+
+    /// Measure the given text laid out like a `label` with the given font styles,
+    /// wrapping its lines at `max_width` if there is any.
+    ///
+    /// Returns its width, height and amount of lines, in logical pixels.
+    pub fn measure_text(
+        &self,
+        text: &str,
+        font_style: &FontStyleState,
+        max_width: Option<f32>,
+    ) -> (f32, f32, usize) {
+        let width = max_width.unwrap_or(f32::MAX);
+        let key = ParagraphKey::from_text(font_style.clone(), text.to_string(), width, 1.0);
+
+        let (size, data) = self.cache.lock().unwrap().get_or_measure(key, |_| {
+            create_text_paragraph(
+                text,
+                font_style,
+                width,
+                &self.font_collection,
+                &self.default_fonts,
+                1.0,
+                &RenderQuality::default(),
+                false,
+            )
+        });
+        let line_count = data
+            .get::<CachedParagraph>()
+            .map(|paragraph| paragraph.0.line_number())
+            .unwrap_or_default();
+
+        (size.width, size.height, line_count)
+    }
+}
@@ -1,12 +1,20 @@
+/// Fonts used after the ones specified with `font_family`, which also act as
+/// the fallback chain for characters missing in them, like emoji or CJK characters.
 pub fn default_fonts() -> Vec<String> {
     let mut fonts = vec!["Noto Sans".to_string(), "Arial".to_string()];
     if cfg!(target_os = "windows") {
         fonts.insert(0, "Segoe UI".to_string());
         fonts.insert(1, "Segoe UI Emoji".to_string());
+        fonts.extend(["Microsoft YaHei".to_string(), "Yu Gothic UI".to_string()]);
     } else if cfg!(target_os = "macos") {
         fonts.insert(0, ".AppleSystemUIFont".to_string());
+        fonts.extend(["Apple Color Emoji".to_string(), "PingFang SC".to_string()]);
     } else if cfg!(target_os = "linux") {
         fonts.insert(0, "Ubuntu".to_string());
+        fonts.extend([
+            "Noto Color Emoji".to_string(),
+            "Noto Sans CJK SC".to_string(),
+        ]);
     }
     fonts
 }
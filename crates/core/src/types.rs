@@ -2,6 +2,7 @@ pub use accesskit::NodeId as AccessibilityId;
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tokio::sync::{
+    broadcast,
     mpsc::{
         UnboundedReceiver,
         UnboundedSender,
@@ -25,6 +26,10 @@ pub type NativePlatformSender = watch::Sender<NativePlatformState>;
 /// Receive updates by the platform
 pub type NativePlatformReceiver = watch::Receiver<NativePlatformState>;
 
+/// Notify the app that the window has been requested to close, e.g with the close button of its title bar.
+/// The window only closes by itself when nothing is listening for these requests.
+pub type CloseRequestSender = broadcast::Sender<()>;
+
 /// Emit events to the VirtualDOM
 pub type EventEmitter = UnboundedSender<Vec<DomEvent>>;
 
@@ -0,0 +1,67 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use freya_core::prelude::ImagesCache;
+use freya_engine::prelude::Color;
+use freya_native_core::NodeId;
+
+/// 1x1 GIF that loops over a red and a blue frame, each shown for 100ms.
+const RED_BLUE_GIF: &[u8] = &[
+    0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x01, 0x00, 0x01, 0x00, 0xF0, 0x00, 0x00, 0xFF, 0x00, 0x00,
+    0x00, 0x00, 0xFF, 0x21, 0xFF, 0x0B, 0x4E, 0x45, 0x54, 0x53, 0x43, 0x41, 0x50, 0x45, 0x32, 0x2E,
+    0x30, 0x03, 0x01, 0x00, 0x00, 0x00, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x2C, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x02, 0x44, 0x01, 0x00, 0x21, 0xF9, 0x04,
+    0x00, 0x0A, 0x00, 0x00, 0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02,
+    0x02, 0x4C, 0x01, 0x00, 0x3B,
+];
+
+fn frame_color(
+    images_cache: &mut ImagesCache,
+    node_id: NodeId,
+    playing: bool,
+    now: Instant,
+) -> Color {
+    let image = images_cache
+        .get_animated_image(node_id, RED_BLUE_GIF, playing, now)
+        .unwrap();
+    let pixels = image.peek_pixels().unwrap();
+    pixels.get_color((0, 0))
+}
+
+#[test]
+pub fn animated_image_playback() {
+    let mut images_cache = ImagesCache::default();
+    let node_id = NodeId::new_from_index_and_gen(1, 0);
+    let start = Instant::now();
+
+    assert_eq!(
+        frame_color(&mut images_cache, node_id, true, start),
+        Color::RED
+    );
+    assert!(images_cache.has_running_animations());
+
+    // Every frame is shown for its delay
+    let now = start + Duration::from_millis(150);
+    assert_eq!(
+        frame_color(&mut images_cache, node_id, true, now),
+        Color::BLUE
+    );
+
+    // And the animation loops
+    let now = start + Duration::from_millis(250);
+    assert_eq!(
+        frame_color(&mut images_cache, node_id, true, now),
+        Color::RED
+    );
+
+    // Paused animations stay in the same frame
+    images_cache.evict_unused();
+    let now = start + Duration::from_millis(400);
+    assert_eq!(
+        frame_color(&mut images_cache, node_id, false, now),
+        Color::RED
+    );
+    assert!(!images_cache.has_running_animations());
+}
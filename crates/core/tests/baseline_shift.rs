@@ -0,0 +1,74 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn shifted_run_is_raised() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                padding: "50",
+                paragraph {
+                    text {
+                        font_size: "40",
+                        color: "black",
+                        "xxx"
+                    }
+                    text {
+                        font_size: "40",
+                        color: "red",
+                        baseline_shift: "20",
+                        "xxx"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Topmost row with a pixel of the given color
+    let top = |matches: fn(Color) -> bool| {
+        (0..500)
+            .find(|y| (0..500).any(|x| matches(pixels.get_color((x, *y)))))
+            .unwrap()
+    };
+    let black_top = top(|color| color.r() < 50 && color.g() < 50 && color.b() < 50);
+    let red_top = top(|color| color.r() > 200 && color.g() < 50 && color.b() < 50);
+
+    assert!(red_top + 15 < black_top);
+}
+
+#[tokio::test]
+pub async fn superscript_is_smaller_and_raised() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                padding: "50",
+                paragraph {
+                    text {
+                        font_size: "40",
+                        "x"
+                    }
+                    text {
+                        font_size: "40",
+                        baseline_shift: "super",
+                        "x"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let superscript = utils.root().get(0).get(0).get(1);
+    let font_style = &superscript.state().font_style;
+    assert_eq!(font_style.font_size, 40.0 * 0.7);
+    assert_eq!(font_style.baseline_shift, 40.0 * 0.33);
+}
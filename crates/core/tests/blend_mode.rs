@@ -0,0 +1,63 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn blend_mode() {
+    fn blend_mode_app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "80",
+                background: "rgb(255, 255, 0)",
+                rect {
+                    width: "100",
+                    height: "20",
+                    background: "rgb(0, 255, 255)",
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    background: "rgb(0, 255, 255)",
+                    blend_mode: "multiply",
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    blend_mode: "multiply",
+                    rect {
+                        width: "100",
+                        height: "20",
+                        background: "rgb(0, 255, 255)",
+                    }
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    background: "rgb(255, 0, 255)",
+                    blend_mode: "multiply",
+                    rect {
+                        width: "100",
+                        height: "20",
+                        background: "rgb(0, 255, 255)",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(blend_mode_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Normal compositing paints over the background
+    assert_eq!(pixels.get_color((50, 10)), Color::from_rgb(0, 255, 255));
+    // Multiplying blends it with the background
+    assert_eq!(pixels.get_color((50, 30)), Color::from_rgb(0, 255, 0));
+    // Descendants are blended too
+    assert_eq!(pixels.get_color((50, 50)), Color::from_rgb(0, 255, 0));
+    // But only once they are painted over the node, not with each other
+    assert_eq!(pixels.get_color((50, 70)), Color::from_rgb(0, 255, 0));
+}
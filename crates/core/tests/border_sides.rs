@@ -0,0 +1,48 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn border_sides() {
+    fn border_sides_app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                rect {
+                    width: "100",
+                    height: "50",
+                    margin: "20",
+                    border_bottom: "4 solid red",
+                }
+                rect {
+                    width: "100",
+                    height: "50",
+                    margin: "20",
+                    border: "4 solid blue",
+                    border_top: "4 solid red",
+                    border_left: "none",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(border_sides_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Only the bottom side is stroked
+    assert_eq!(pixels.get_color((70, 68)), Color::RED);
+    assert_eq!(pixels.get_color((70, 22)), Color::WHITE);
+    assert_eq!(pixels.get_color((22, 45)), Color::WHITE);
+    assert_eq!(pixels.get_color((118, 45)), Color::WHITE);
+
+    // The sides override the border of the element
+    assert_eq!(pixels.get_color((70, 112)), Color::RED);
+    assert_eq!(pixels.get_color((70, 158)), Color::BLUE);
+    assert_eq!(pixels.get_color((118, 135)), Color::BLUE);
+    assert_eq!(pixels.get_color((22, 135)), Color::WHITE);
+}
@@ -0,0 +1,47 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_node_state::Fill;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn current_color_gradient_stops() {
+    fn current_color_app() -> Element {
+        rsx!(
+            for color in ["red", "blue"] {
+                rect {
+                    color,
+                    rect {
+                        width: "100",
+                        height: "100",
+                        background: "linear-gradient(currentColor 0%, transparent 100%)",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(current_color_app);
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    let stop_colors = |node: TestNode| {
+        let Fill::LinearGradient(gradient) = node.style().background else {
+            panic!("Expected a linear gradient");
+        };
+        gradient
+            .stops
+            .iter()
+            .map(|stop| stop.color)
+            .collect::<Vec<_>>()
+    };
+
+    // The stops are resolved against the inherited text color
+    assert_eq!(
+        stop_colors(root.get(0).get(0)),
+        vec![Color::RED, Color::RED.with_a(0)]
+    );
+    assert_eq!(
+        stop_colors(root.get(1).get(0)),
+        vec![Color::BLUE, Color::BLUE.with_a(0)]
+    );
+}
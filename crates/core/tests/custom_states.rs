@@ -0,0 +1,104 @@
+use freya::prelude::*;
+use freya_native_core::{
+    exports::shipyard::Component,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        AttributeName,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+use freya_testing::prelude::*;
+
+/// Elevation of a node over its ancestors, given with the custom `elevation` attribute.
+#[derive(Default, PartialEq, Clone, Debug, Component)]
+struct ElevationState {
+    elevation: u8,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for ElevationState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new().with_attrs(
+        AttributeMaskBuilder::Some(&[AttributeName::Custom("elevation")]),
+    );
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _context: &SendAnyMap,
+    ) -> bool {
+        let mut elevation = parent.map(|(parent,)| parent.elevation).unwrap_or_default();
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                if *attr.attribute == AttributeName::Custom("elevation") {
+                    if let Some(value) = attr.value.as_text().and_then(|v| v.parse::<u8>().ok()) {
+                        elevation += value;
+                    }
+                }
+            }
+        }
+
+        let state = ElevationState { elevation };
+        let changed = &state != self;
+        *self = state;
+        changed
+    }
+}
+
+#[tokio::test]
+pub async fn custom_states() {
+    fn custom_states_app() -> Element {
+        let mut elevation = use_signal(|| 1);
+
+        rsx!(
+            rect {
+                "elevation": "2",
+                rect {
+                    "elevation": "{elevation}",
+                    onclick: move |_| elevation += 1,
+                    width: "100",
+                    height: "100",
+                }
+                rect { }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_custom_states(
+        custom_states_app,
+        TestingConfig::default(),
+        vec![ElevationState::to_type_erased()],
+    );
+    let root = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    let elevation = |node: TestNode| node.get_state::<ElevationState>().unwrap().elevation;
+
+    // Custom attributes are read by the custom states, which are inherited like the built-in ones
+    assert_eq!(elevation(root.clone()), 2);
+    assert_eq!(elevation(root.get(0)), 3);
+    assert_eq!(elevation(root.get(1)), 2);
+
+    // And updated along with the attributes
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(elevation(root.get(0)), 4);
+}
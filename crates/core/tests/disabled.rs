@@ -0,0 +1,87 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use torin::prelude::CursorPoint;
+
+#[tokio::test]
+pub async fn disabled_blocks_events() {
+    fn disabled_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut disabled = use_signal(|| true);
+
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                disabled: "{disabled}",
+                onclick: move |_| clicks += 1,
+                rect {
+                    height: "100%",
+                    width: "100%",
+                    onclick: move |_| clicks += 1,
+                }
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                onclick: move |_| disabled.toggle(),
+                label { "{clicks}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(disabled_app);
+
+    let label = utils.root().get(1).get(0);
+
+    // Neither the disabled element nor its descendants receive the click
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(100.0, 100.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    // Enable it again
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(100.0, 400.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(100.0, 100.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2"));
+}
+
+#[tokio::test]
+pub async fn disabled_dims_content() {
+    fn disabled_app() -> Element {
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                disabled: "true",
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                disabled: "true",
+                disabled_opacity: "1",
+            }
+        )
+    }
+
+    let utils = launch_test(disabled_app);
+
+    let dimmed = utils.root().get(0);
+    let not_dimmed = utils.root().get(1);
+
+    assert_eq!(dimmed.style().opacity, Some(0.5));
+    assert_eq!(not_dimmed.style().opacity, Some(1.0));
+}
@@ -0,0 +1,50 @@
+use freya::prelude::*;
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn ellipsis_middle() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                width: "100",
+                text_overflow: "ellipsis-middle",
+                "MMMM/iiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiii/MMMM"
+            }
+            label {
+                width: "100",
+                max_lines: "1",
+                "MMMM/iiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiii/MMMM"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    // Widths of the first and last visible glyphs
+    let edge_glyphs_widths = |index: usize| {
+        let layout = utils.root().get(index).layout().unwrap();
+        let data = layout.data.unwrap();
+        let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+        assert_eq!(paragraph.line_number(), 1);
+        assert!(paragraph.longest_line() <= 101.0);
+
+        let end = paragraph.get_line_metrics()[0].end_index;
+        let width = |range| {
+            let rects =
+                paragraph.get_rects_for_range(range, RectHeightStyle::Tight, RectWidthStyle::Tight);
+            rects[0].rect.width()
+        };
+        (width(0..1), width(end - 1..end))
+    };
+
+    // Both the start and the end of the text are kept
+    let (first, last) = edge_glyphs_widths(0);
+    assert_eq!(first, last);
+
+    // Unlike when it's clipped
+    let (first, last) = edge_glyphs_widths(1);
+    assert!(first > last);
+}
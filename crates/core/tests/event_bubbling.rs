@@ -184,3 +184,56 @@ pub async fn event_cant_pass_through_wall() {
 
     assert_eq!(label.get(0).text(), Some(""));
 }
+
+#[tokio::test]
+pub async fn nested_pointer_event_stops_propagation() {
+    fn app() -> Element {
+        let mut card_presses = use_signal(|| 0);
+        let mut button_presses = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onpointerup: move |_| card_presses += 1,
+                rect {
+                    height: "100",
+                    width: "100",
+                    onpointerup: move |e: PointerEvent| {
+                        button_presses += 1;
+                        e.stop_propagation();
+                    },
+                }
+                label {
+                    "{card_presses} {button_presses}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // Only the inner handler runs
+    assert_eq!(label.get(0).text(), Some("0 1"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(150.0, 150.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1 1"));
+}
@@ -0,0 +1,39 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn first_line_is_styled() {
+    fn app() -> Element {
+        rsx!(
+            paragraph {
+                width: "200",
+                color: "black",
+                first_line_color: "red",
+                text {
+                    "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore."
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Rows with any pixel of the given color
+    let rows = |matches: fn(Color) -> bool| {
+        (0..500)
+            .filter(|y| (0..500).any(|x| matches(pixels.get_color((x, *y)))))
+            .collect::<Vec<_>>()
+    };
+    let red_rows = rows(|color| color.r() > 200 && color.g() < 50 && color.b() < 50);
+    let black_rows = rows(|color| color.r() < 50 && color.g() < 50 && color.b() < 50);
+
+    // Only the first line is red, and the lines below it are black
+    assert!(!red_rows.is_empty());
+    assert!(!black_rows.is_empty());
+    assert!(red_rows.last() < black_rows.first());
+}
@@ -0,0 +1,57 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn focus_ring_only_with_keyboard() {
+    fn focus_ring_app() -> Element {
+        let mut focus = use_focus();
+
+        rsx!(
+            rect {
+                padding: "10",
+                rect {
+                    focus_id: focus.attribute(),
+                    focus_ring: "4 solid red",
+                    border: "2 solid blue",
+                    border_align: "outer",
+                    width: "50",
+                    height: "50",
+                    onclick: move |_| focus.focus(),
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(focus_ring_app);
+    utils.wait_for_update().await;
+
+    // Focus with the keyboard
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The ring is drawn outside of the border
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+    assert_eq!(pixels.get_color((35, 9)), Color::BLUE);
+    assert_eq!(pixels.get_color((35, 6)), Color::RED);
+
+    // Focus with the mouse
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (35.0, 35.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+    assert_eq!(pixels.get_color((35, 9)), Color::BLUE);
+    assert_eq!(pixels.get_color((35, 6)), Color::WHITE);
+}
@@ -0,0 +1,96 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn focus_trap() {
+    #[allow(non_snake_case)]
+    #[component]
+    fn Focusable(onclick: EventHandler) -> Element {
+        let mut focus = use_focus();
+
+        rsx!(
+            rect {
+                focus_id: focus.attribute(),
+                width: "100%",
+                height: "50",
+                onclick: move |_| {
+                    focus.focus();
+                    onclick.call(());
+                },
+                label {
+                    "{focus.is_focused()}"
+                }
+            }
+        )
+    }
+
+    fn focus_trap_app() -> Element {
+        let mut show_trap = use_signal(|| false);
+
+        rsx!(
+            Focusable {
+                onclick: |_| {}
+            }
+            rect {
+                width: "100%",
+                height: "50",
+                onclick: move |_| show_trap.set(true),
+            }
+            if *show_trap.read() {
+                rect {
+                    focus_trap: "true",
+                    Focusable {
+                        onclick: |_| {}
+                    }
+                    Focusable {
+                        onclick: move |_| show_trap.set(false)
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(focus_trap_app);
+    utils.wait_for_update().await;
+
+    let click = |y: f32| PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, y).into(),
+        button: Some(MouseButton::Left),
+    };
+    let tab = || PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    };
+
+    // Focus the element outside of the trap
+    utils.push_event(click(5.0));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true"));
+
+    // Start the trap
+    utils.push_event(click(75.0));
+    utils.wait_for_update().await;
+    let root = utils.root();
+
+    // The focus moves through the elements of the trap and wraps around without leaving it
+    let trap = root.get(2);
+    for (first, second) in [("true", "false"), ("false", "true"), ("true", "false")] {
+        utils.push_event(tab());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).get(0).text(), Some("false"));
+        assert_eq!(trap.get(0).get(0).get(0).text(), Some(first));
+        assert_eq!(trap.get(1).get(0).get(0).text(), Some(second));
+    }
+
+    // Once the trap is released the focus goes back to where it was before
+    utils.push_event(click(175.0));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true"));
+}
@@ -0,0 +1,54 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    FontMgr,
+    FontStyle,
+};
+use freya_testing::prelude::*;
+
+/// How many characters of the text none of the installed fonts have,
+/// so the test doesn't depend on which fonts are installed.
+fn missing_in_installed_fonts(text: &str) -> usize {
+    let font_mgr = FontMgr::default();
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .filter(|c| {
+            font_mgr
+                .match_family_style_character("", FontStyle::normal(), &[], *c as i32)
+                .is_none()
+        })
+        .count()
+}
+
+#[tokio::test]
+pub async fn missing_glyphs_use_fallback_fonts() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                "Hello 😀"
+            }
+            paragraph {
+                text {
+                    "Hello "
+                }
+                text {
+                    "你好"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    // Every character that any installed font has is shaped, even if it's not in the default fonts
+    assert_eq!(
+        root.get(0).unresolved_glyphs(),
+        Some(missing_in_installed_fonts("Hello 😀"))
+    );
+    assert_eq!(
+        root.get(1).unresolved_glyphs(),
+        Some(missing_in_installed_fonts("Hello 你好"))
+    );
+}
@@ -0,0 +1,47 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn glyph_index_at_coordinates() {
+    fn glyph_app() -> Element {
+        rsx!(
+            paragraph {
+                font_size: "20",
+                text {
+                    "Hello\nWorld"
+                }
+            }
+            rect {}
+        )
+    }
+
+    let mut utils = launch_test(glyph_app);
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0);
+    let area = paragraph.area().unwrap();
+
+    // Start of the first line
+    assert_eq!(paragraph.glyph_index_at((0.0, 0.0)), Some(0));
+
+    // Past the end of the first line
+    assert_eq!(
+        paragraph.glyph_index_at((area.width() as f64 + 50.0, 1.0)),
+        Some(5)
+    );
+
+    // Start of the second line
+    assert_eq!(
+        paragraph.glyph_index_at((0.0, area.height() as f64 - 1.0)),
+        Some(6)
+    );
+
+    // Past the end of the text
+    assert_eq!(
+        paragraph.glyph_index_at((area.width() as f64 + 50.0, area.height() as f64 + 50.0)),
+        Some(11)
+    );
+
+    // Not a text node
+    assert_eq!(utils.root().get(1).glyph_index_at((0.0, 0.0)), None);
+}
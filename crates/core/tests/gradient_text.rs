@@ -0,0 +1,62 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn gradient_text_spans_the_text() {
+    fn app() -> Element {
+        rsx!(label {
+            font_size: "60",
+            color: "linear-gradient(red 0%, blue 100%)",
+            "Freya Freya"
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let area = utils.root().get(0).area().unwrap();
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Colors of the painted pixels, from left to right
+    let painted = (0..area.width() as i32)
+        .flat_map(|x| (0..area.height() as i32).map(move |y| (x, y)))
+        .map(|point| pixels.get_color(point))
+        .filter(|color| *color != Color::WHITE)
+        .collect::<Vec<_>>();
+
+    let first = painted.first().unwrap();
+    let last = painted.last().unwrap();
+
+    // The first glyph is closer to red and the last one closer to blue
+    assert!(first.r() > last.r());
+    assert!(first.b() < last.b());
+}
+
+#[tokio::test]
+pub async fn gradient_text_keeps_shadow_color() {
+    fn app() -> Element {
+        rsx!(label {
+            font_size: "60",
+            color: "linear-gradient(red 0%, blue 100%)",
+            text_shadow: "6 6 0 rgb(0, 255, 0)",
+            "Freya Freya"
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let area = utils.root().get(0).area().unwrap();
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    let has_green = (0..area.width() as i32)
+        .flat_map(|x| (0..area.height() as i32).map(move |y| (x, y)))
+        .map(|point| pixels.get_color(point))
+        .any(|color| color.g() > 200 && color.r() < 50 && color.b() < 50);
+
+    // The shadow isn't tinted by the gradient of the glyphs
+    assert!(has_green);
+}
@@ -0,0 +1,44 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn hit_padding_expands_clickable_area() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                margin: "100",
+                height: "20",
+                width: "20",
+                hit_padding: "10",
+                onclick: move |_| clicks += 1,
+            }
+            label {
+                "{clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1);
+
+    // Just outside the visual area but inside the hit padding
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (95.0, 95.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+
+    // Outside the hit padding
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (85.0, 125.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
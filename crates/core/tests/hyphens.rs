@@ -0,0 +1,80 @@
+use freya::prelude::*;
+use freya_common::{
+    CachedParagraph,
+    InsertedBreaks,
+};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn hyphens_auto_hyphenates_words() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                width: "100",
+                lang: "en",
+                "a incomprehensibilities"
+            }
+            label {
+                width: "100",
+                lang: "en",
+                hyphens: "auto",
+                "a incomprehensibilities"
+            }
+            label {
+                width: "100",
+                lang: "fr",
+                hyphens: "auto",
+                "a incomprehensibilities"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let first_line_end = |index: usize| {
+        let layout = utils.root().get(index).layout().unwrap();
+        let data = layout.data.unwrap();
+        let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+        let breaks = data.get::<InsertedBreaks>().unwrap();
+        assert!(paragraph.longest_line() <= 101.0);
+        let end = paragraph.get_line_metrics()[0].end_index;
+        (breaks.to_text_index(end), breaks.clone())
+    };
+
+    // The long word doesn't fit next to the first one
+    let (end, breaks) = first_line_end(0);
+    assert_eq!(end, 2);
+    assert!(breaks.0.is_empty());
+
+    // Unless it can be hyphenated, then the line is wrapped at one of its hyphenation points
+    let (end, breaks) = first_line_end(1);
+    assert!(end > 2);
+    assert!(breaks.0.contains(&end));
+
+    // Only English text is hyphenated
+    let (end, breaks) = first_line_end(2);
+    assert_eq!(end, 2);
+    assert!(breaks.0.is_empty());
+}
+
+#[tokio::test]
+pub async fn hyphens_keep_text_indices() {
+    fn app() -> Element {
+        rsx!(label {
+            width: "100",
+            lang: "en",
+            hyphens: "auto",
+            "a incomprehensibilities"
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+
+    // The shown hyphens don't shift the indices of the text
+    assert_eq!(label.glyph_index_at((0.0, 5.0)), Some(0));
+    assert_eq!(label.glyph_index_at((1000.0, 1000.0)), Some(23));
+}
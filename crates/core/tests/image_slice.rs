@@ -0,0 +1,75 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Color,
+    EncodedImageFormat,
+    Paint,
+    Rect,
+};
+use freya_testing::prelude::*;
+
+/// Encode a 30x30 PNG with 10px red corners, green edges and a blue center.
+fn nine_patch_png() -> Vec<u8> {
+    let mut surface = raster_n32_premul((30, 30)).unwrap();
+    let canvas = surface.canvas();
+    canvas.clear(Color::GREEN);
+
+    let mut paint = Paint::default();
+    paint.set_color(Color::BLUE);
+    canvas.draw_rect(Rect::from_xywh(10.0, 10.0, 10.0, 10.0), &paint);
+
+    paint.set_color(Color::RED);
+    for (x, y) in [(0.0, 0.0), (20.0, 0.0), (0.0, 20.0), (20.0, 20.0)] {
+        canvas.draw_rect(Rect::from_xywh(x, y, 10.0, 10.0), &paint);
+    }
+
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    image
+        .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_vec()
+}
+
+#[tokio::test]
+pub async fn image_slice() {
+    fn image_slice_app() -> Element {
+        let png = use_hook(nine_patch_png);
+
+        rsx!(
+            image {
+                width: "30",
+                height: "30",
+                image_data: dynamic_bytes(png.clone()),
+                image_slice: "10",
+            }
+            image {
+                width: "90",
+                height: "90",
+                image_data: dynamic_bytes(png),
+                image_slice: "10",
+            }
+        )
+    }
+
+    let mut utils = launch_test(image_slice_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // At its original size the image is drawn as it is
+    assert_eq!(pixels.get_color((5, 5)), Color::RED);
+    assert_eq!(pixels.get_color((15, 5)), Color::GREEN);
+    assert_eq!(pixels.get_color((15, 15)), Color::BLUE);
+
+    // When bigger, the corners keep their size and the edges and center are stretched
+    assert_eq!(pixels.get_color((5, 35)), Color::RED);
+    assert_eq!(pixels.get_color((25, 35)), Color::GREEN);
+    assert_eq!(pixels.get_color((85, 35)), Color::RED);
+    assert_eq!(pixels.get_color((5, 115)), Color::RED);
+    assert_eq!(pixels.get_color((45, 75)), Color::BLUE);
+    assert_eq!(pixels.get_color((85, 115)), Color::RED);
+    assert_eq!(pixels.get_color((65, 115)), Color::GREEN);
+}
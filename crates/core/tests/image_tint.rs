@@ -0,0 +1,61 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Color,
+    EncodedImageFormat,
+};
+use freya_testing::prelude::*;
+
+/// Encode a blue square as a PNG.
+fn blue_square_png() -> Vec<u8> {
+    let mut surface = raster_n32_premul((20, 20)).unwrap();
+    surface.canvas().clear(Color::BLUE);
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    image
+        .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_vec()
+}
+
+#[tokio::test]
+pub async fn image_tint() {
+    fn image_tint_app() -> Element {
+        let png = use_hook(blue_square_png);
+
+        rsx!(
+            image {
+                width: "20",
+                height: "20",
+                image_data: dynamic_bytes(png.clone()),
+            }
+            image {
+                width: "20",
+                height: "20",
+                image_data: dynamic_bytes(png.clone()),
+                image_tint: "red",
+            }
+            image {
+                width: "20",
+                height: "20",
+                image_data: dynamic_bytes(png),
+                image_tint: "red",
+                image_tint_mode: "multiply",
+            }
+        )
+    }
+
+    let mut utils = launch_test(image_tint_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // No tint leaves the image untouched
+    assert_eq!(pixels.get_color((10, 10)), Color::BLUE);
+    // `src-in` replaces the color of the image
+    assert_eq!(pixels.get_color((10, 30)), Color::RED);
+    // `multiply` blends the image with the tint
+    assert_eq!(pixels.get_color((10, 50)), Color::BLACK);
+}
@@ -0,0 +1,80 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn background_interaction_styles() {
+    fn app() -> Element {
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            background: "white",
+            background_hover: "blue",
+            background_active: "red",
+        })
+    }
+
+    let mut utils = launch_test(app);
+    let rect = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(rect.style().background, Fill::Color(Color::WHITE));
+
+    async fn push_mouse_event(utils: &mut TestingHandler, name: EventName, cursor: (f64, f64)) {
+        utils.push_event(PlatformEvent::Mouse {
+            name,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+
+    push_mouse_event(&mut utils, EventName::MouseOver, (50.0, 50.0)).await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+
+    // Pressed takes precedence over hovered
+    push_mouse_event(&mut utils, EventName::MouseDown, (50.0, 50.0)).await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+
+    push_mouse_event(&mut utils, EventName::Click, (50.0, 50.0)).await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+
+    push_mouse_event(&mut utils, EventName::MouseOver, (150.0, 150.0)).await;
+    assert_eq!(rect.style().background, Fill::Color(Color::WHITE));
+}
+
+#[tokio::test]
+pub async fn interaction_styles_of_batched_events() {
+    fn app() -> Element {
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            background: "white",
+            background_hover: "blue",
+            background_active: "red",
+        })
+    }
+
+    let mut utils = launch_test(app);
+    let rect = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    let push_mouse_event = |utils: &mut TestingHandler, name: EventName| {
+        utils.push_event(PlatformEvent::Mouse {
+            name,
+            cursor: (50.0, 50.0).into(),
+            button: Some(MouseButton::Left),
+        });
+    };
+
+    // Every event of the batch is processed, not only the latest one
+    push_mouse_event(&mut utils, EventName::MouseDown);
+    push_mouse_event(&mut utils, EventName::MouseOver);
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+
+    push_mouse_event(&mut utils, EventName::Click);
+    push_mouse_event(&mut utils, EventName::MouseOver);
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+}
@@ -0,0 +1,85 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[allow(non_snake_case)]
+#[component]
+fn Item(name: String) -> Element {
+    let mut count = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            height: "50",
+            width: "100%",
+            onclick: move |_| count += 1,
+            label {
+                "{name}: {count}"
+            }
+        }
+    )
+}
+
+#[tokio::test]
+pub async fn keyed_list() {
+    fn app() -> Element {
+        let mut items = use_signal(|| vec!["a", "b", "c"]);
+
+        rsx!(
+            rect {
+                height: "150",
+                for item in items.read().iter() {
+                    Item {
+                        key: "{item}",
+                        name: item.to_string(),
+                    }
+                }
+            }
+            rect {
+                height: "50",
+                width: "100%",
+                onclick: move |_| items.write().reverse(),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    // Click the first item
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let list = utils.root().get(0);
+    assert_eq!(list.get(0).get(0).get(0).text(), Some("a: 1"));
+
+    // Reverse the list
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 175.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The state follows the key instead of the position
+    let list = utils.root().get(0);
+    assert_eq!(list.children_ids().len(), 3);
+    for (i, text) in ["c: 0", "b: 0", "a: 1"].into_iter().enumerate() {
+        let item = list.get(i);
+        assert_eq!(item.get(0).get(0).text(), Some(text));
+        assert_eq!(item.area().unwrap().min_y(), i as f32 * 50.0);
+    }
+
+    // And the moved items still receive their events
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 105.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let list = utils.root().get(0);
+    assert_eq!(list.get(2).get(0).get(0).text(), Some("a: 2"));
+}
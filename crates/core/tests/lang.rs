@@ -0,0 +1,82 @@
+use freya::prelude::*;
+use freya_common::CachedParagraph;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn lang_is_inherited() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                lang: "ja",
+                paragraph {
+                    text {
+                        "こんにちは"
+                    }
+                    text {
+                        lang: "en",
+                        "Hello"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0).get(0);
+    assert_eq!(paragraph.state().font_style.lang.as_deref(), Some("ja"));
+    assert_eq!(
+        paragraph.get(0).state().font_style.lang.as_deref(),
+        Some("ja")
+    );
+    assert_eq!(
+        paragraph.get(1).state().font_style.lang.as_deref(),
+        Some("en")
+    );
+}
+
+#[tokio::test]
+pub async fn japanese_line_breaking() {
+    fn app() -> Element {
+        let (reference, size) = use_node_signal();
+        // Just enough to fit two characters
+        let width = size.read().area.width() + 2.0;
+
+        rsx!(
+            label {
+                reference,
+                "ああ"
+            }
+            label {
+                width: "{width}",
+                lang: "ja",
+                "ああっ"
+            }
+            label {
+                width: "{width}",
+                lang: "en",
+                "ああっ"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let first_line_width = |node: TestNode| {
+        let layout = node.layout().unwrap();
+        let data = layout.data.unwrap();
+        let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+        paragraph.get_line_metrics()[0].width
+    };
+    let two_characters = root.get(0).area().unwrap().width() as f64;
+
+    // Japanese text can be broken before the small kana, so the first line fits two characters
+    assert!((first_line_width(root.get(1)) - two_characters).abs() < 1.0);
+
+    // But otherwise it's kept with the previous character in the next line
+    assert!(first_line_width(root.get(2)) < two_characters - 1.0);
+}
@@ -0,0 +1,75 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn layout_transition() {
+    fn layout_transition_app() -> Element {
+        let mut items = use_signal(|| vec![0, 1]);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                layout_transition: "1s",
+                onclick: move |_| items.write().reverse(),
+                for item in items.read().iter() {
+                    rect {
+                        key: "{item}",
+                        width: "100",
+                        height: "50",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(layout_transition_app);
+
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    let first = root.get(0).get(0).id();
+    let second = root.get(0).get(1).id();
+
+    // Newly mounted nodes do not move
+    assert!(utils.sdom().get().layout_transitions().get(first).is_none());
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5., 5.).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The layout already has the new positions
+    assert_eq!(root.get(0).get(0).id(), second);
+    assert_eq!(root.get(0).get(0).area().unwrap().min_y(), 0.0);
+    assert_eq!(root.get(0).get(1).area().unwrap().min_y(), 50.0);
+
+    let fdom = utils.sdom().get();
+    let layout_transitions = fdom.layout_transitions();
+
+    // But they are painted from where they were
+    let transition = *layout_transitions.get(second).unwrap();
+    assert_eq!(transition.from, (0.0, 50.0));
+    assert_eq!(
+        layout_transitions.offset_at(second, transition.started_at),
+        Some((0.0, 50.0))
+    );
+
+    // Midway they are in between
+    let midway = transition.started_at + transition.timing.duration / 2;
+    assert_eq!(
+        layout_transitions.offset_at(second, midway),
+        Some((0.0, 25.0))
+    );
+    assert_eq!(
+        layout_transitions.offset_at(first, midway),
+        Some((0.0, -25.0))
+    );
+
+    // And once finished they are in their new position
+    let finished = transition.started_at + transition.timing.duration;
+    assert_eq!(layout_transitions.offset_at(second, finished), None);
+    assert_eq!(layout_transitions.offset_at(first, finished), None);
+}
@@ -0,0 +1,115 @@
+use freya::prelude::*;
+use freya_common::CachedParagraph;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn balanced_line_break() {
+    fn app() -> Element {
+        let (reference, size) = use_node_signal();
+        // Just enough to fit the first four words
+        let width = size.read().area.width() + 2.0;
+
+        rsx!(
+            label {
+                reference,
+                "aaa bbb ccc ddd"
+            }
+            label {
+                width: "{width}",
+                "aaa bbb ccc ddd eee"
+            }
+            label {
+                width: "{width}",
+                line_break: "balanced",
+                "aaa bbb ccc ddd eee"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let lines_widths = |index: usize| {
+        let layout = utils.root().get(index).layout().unwrap();
+        let data = layout.data.unwrap();
+        let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+        paragraph
+            .get_line_metrics()
+            .iter()
+            .map(|line| line.width)
+            .collect::<Vec<_>>()
+    };
+
+    // The last word is left alone in the second line
+    let greedy = lines_widths(1);
+    assert_eq!(greedy.len(), 2);
+    assert!(greedy[1] < greedy[0] / 3.0);
+
+    // Unless the lines are balanced
+    let balanced = lines_widths(2);
+    assert_eq!(balanced.len(), 2);
+    assert!(balanced[1] > balanced[0] / 2.0);
+}
+
+#[tokio::test]
+pub async fn balanced_line_break_alignment() {
+    fn app() -> Element {
+        let (reference, size) = use_node_signal();
+        let (lines_reference, lines) = use_text_lines();
+        // Just enough to fit the first four words
+        let width = size.read().area.width() + 2.0;
+        let centers = lines
+            .read()
+            .iter()
+            .map(|line| (line.left + line.width / 2.0).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        rsx!(
+            label {
+                reference,
+                "aaa bbb ccc ddd"
+            }
+            label {
+                reference: lines_reference,
+                width: "{width}",
+                line_break: "balanced",
+                text_align: "center",
+                "aaa bbb ccc ddd eee"
+            }
+            label {
+                "{centers}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let label = root.get(1);
+    let width = label.area().unwrap().width();
+
+    // The lines are balanced in a narrower width than the label
+    let layout = label.layout().unwrap();
+    let data = layout.data.unwrap();
+    let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+    assert!(paragraph.max_width() < width - 4.0);
+
+    // But they are still centered in the whole label
+    let centers = root
+        .get(2)
+        .get(0)
+        .text()
+        .unwrap()
+        .split(' ')
+        .map(|center| center.parse::<f32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(centers.len(), 2);
+    for center in centers {
+        assert!((center - width / 2.0).abs() < 1.5);
+    }
+}
@@ -0,0 +1,58 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn line_height_behavior() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                line_height: "2",
+                "Hello, World! \n Hello, again!"
+            }
+            label {
+                line_height: "2",
+                line_height_behavior: "normal",
+                "Hello, World! \n Hello, again!"
+            }
+            label {
+                line_height: "2",
+                line_height_behavior: "trim-top",
+                "Hello, World! \n Hello, again!"
+            }
+            label {
+                line_height: "2",
+                line_height_behavior: "trim-bottom",
+                "Hello, World! \n Hello, again!"
+            }
+            rect {
+                line_height: "2",
+                line_height_behavior: "trim-leading",
+                label {
+                    "Hello, World! \n Hello, again!"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let height = |node: TestNode| node.area().unwrap().height();
+    let default = height(root.get(0));
+    let normal = height(root.get(1));
+    let trim_top = height(root.get(2));
+    let trim_bottom = height(root.get(3));
+    let trim_leading = height(root.get(4).get(0));
+
+    // The leading is added at both ends by default
+    assert_eq!(default, normal);
+
+    // Trimming it at either end makes the text shorter
+    assert!(trim_top < normal);
+    assert!(trim_bottom < normal);
+
+    // And trimming it at both ends even more, also when it's inherited
+    assert!(trim_leading < trim_top);
+    assert!(trim_leading < trim_bottom);
+}
@@ -0,0 +1,66 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Color,
+    EncodedImageFormat,
+    Paint,
+    Rect,
+};
+use freya_testing::prelude::*;
+
+/// Encode a square whose left half is opaque and right half transparent as a PNG.
+fn half_mask_png() -> Vec<u8> {
+    let mut surface = raster_n32_premul((20, 20)).unwrap();
+    surface.canvas().clear(Color::TRANSPARENT);
+    let mut paint = Paint::default();
+    paint.set_color(Color::BLACK);
+    surface
+        .canvas()
+        .draw_rect(Rect::new(0.0, 0.0, 10.0, 20.0), &paint);
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    image
+        .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_vec()
+}
+
+#[tokio::test]
+pub async fn mask() {
+    fn mask_app() -> Element {
+        let png = use_hook(half_mask_png);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                background: "red",
+                mask_data: dynamic_bytes(png),
+                rect {
+                    width: "100",
+                    height: "100",
+                    position: "absolute",
+                    position_top: "50",
+                    background: "blue",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(mask_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Visible where the mask is opaque
+    assert_eq!(pixels.get_color((25, 25)), Color::RED);
+    // Hidden where the mask is transparent
+    assert_eq!(pixels.get_color((75, 25)), Color::WHITE);
+    // Descendants are masked too
+    assert_eq!(pixels.get_color((25, 75)), Color::BLUE);
+    assert_eq!(pixels.get_color((75, 75)), Color::WHITE);
+    // And so is anything outside of the mask
+    assert_eq!(pixels.get_color((25, 125)), Color::WHITE);
+}
@@ -0,0 +1,40 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+// Only reported in debug builds
+#[cfg(debug_assertions)]
+#[tokio::test]
+pub async fn missing_font_families_are_reported() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                font_family: "Not A Real Font",
+                "Hello, World!"
+            }
+            paragraph {
+                text {
+                    font_family: "Another Missing Font",
+                    "Hello, World!"
+                }
+            }
+            label {
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    assert_eq!(
+        root.get(0).missing_font_families(),
+        Some(vec!["Not A Real Font".to_string()])
+    );
+    assert_eq!(
+        root.get(1).missing_font_families(),
+        Some(vec!["Another Missing Font".to_string()])
+    );
+    assert_eq!(root.get(2).missing_font_families(), Some(vec![]));
+}
@@ -0,0 +1,57 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn node_at_point() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                overflow: "clip",
+                rect {
+                    width: "200",
+                    height: "50",
+                }
+            }
+            rect {
+                width: "100",
+                height: "100",
+                corner_radius: "50",
+            }
+            rect {
+                layer: "-1",
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "20",
+                height: "20",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    let id_at = |utils: &TestingHandler, point: (f64, f64)| {
+        utils.node_at_point(point).map(|node| node.id())
+    };
+
+    // The topmost element
+    assert_eq!(id_at(&utils, (25., 25.)), Some(root.get(0).get(0).id()));
+    assert_eq!(id_at(&utils, (25., 75.)), Some(root.get(0).id()));
+
+    // Clipped by its parent
+    assert_ne!(id_at(&utils, (150., 25.)), Some(root.get(0).get(0).id()));
+
+    // Outside of the rounded corners
+    assert_eq!(id_at(&utils, (50., 150.)), Some(root.get(1).id()));
+    assert_ne!(id_at(&utils, (2., 102.)), Some(root.get(1).id()));
+
+    // Above the rest because of its layer
+    assert_eq!(id_at(&utils, (60., 60.)), Some(root.get(2).id()));
+
+    // Nothing outside of the window
+    assert_eq!(id_at(&utils, (600., 600.)), None);
+}
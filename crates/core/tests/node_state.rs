@@ -0,0 +1,43 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn node_state() {
+    fn app() -> Element {
+        let mut show = use_signal(|| true);
+
+        rsx!(
+            rect {
+                color: "red",
+                font_size: "20",
+                onclick: move |_| show.toggle(),
+                if show() {
+                    label {
+                        "Hello, World!"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let label_id = utils.root().get(0).children_ids()[0];
+
+    // The state includes the values inherited from its ancestors
+    let state = utils.sdom().get().node_state(label_id).unwrap();
+    assert_eq!(state.font_style.color, Color::RED);
+    assert_eq!(state.font_style.font_size, 20.0);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Unknown Nodes have no state
+    assert!(utils.sdom().get().node_state(label_id).is_none());
+}
@@ -0,0 +1,48 @@
+use freya::prelude::*;
+use freya_native_core::real_dom::NodeImmutable;
+use freya_node_state::LayerState;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn relative_layer() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                layer: "-5",
+                rect {
+                    layer: "2",
+                }
+                rect { }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    let layer_of = |node: &TestNode| {
+        let fdom = utils.sdom().get();
+        let layer_state = fdom
+            .rdom()
+            .get(node.id())
+            .unwrap()
+            .get::<LayerState>()
+            .unwrap()
+            .clone();
+        (layer_state.layer, layer_state.relative_layer)
+    };
+
+    let parent = root.get(0);
+    let (parent_layer, parent_relative_layer) = layer_of(&parent);
+    assert_eq!(parent_relative_layer, -5);
+
+    // Children are resolved from the layer of their parent
+    let (first_layer, first_relative_layer) = layer_of(&parent.get(0));
+    assert_eq!(first_relative_layer, 2);
+    assert_eq!(first_layer, parent_layer + 1 - 2);
+
+    let (second_layer, second_relative_layer) = layer_of(&parent.get(1));
+    assert_eq!(second_relative_layer, 0);
+    assert_eq!(second_layer, parent_layer + 1);
+}
@@ -0,0 +1,70 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn unchanged_text_is_measured_once() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100%",
+                onclick: move |_| count += 1,
+                label {
+                    "Static"
+                }
+                label {
+                    "{count}"
+                }
+                paragraph {
+                    text {
+                        "Static "
+                    }
+                    text {
+                        font_size: "20",
+                        "paragraph"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let measured = utils.paragraph_cache().measured_paragraphs();
+    assert!(measured >= 3);
+
+    // Measuring the layout again reuses the same paragraphs
+    for _ in 0..3 {
+        utils.wait_for_update().await;
+    }
+    assert_eq!(utils.paragraph_cache().measured_paragraphs(), measured);
+
+    // Only the changed label is measured again
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("1"));
+    assert_eq!(utils.paragraph_cache().measured_paragraphs(), measured + 1);
+}
+
+#[tokio::test]
+pub async fn resized_text_is_measured_again() {
+    fn app() -> Element {
+        rsx!(label {
+            "Hello, World!"
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let measured = utils.paragraph_cache().measured_paragraphs();
+
+    utils.resize((300., 300.).into());
+    utils.wait_for_update().await;
+    assert_eq!(utils.paragraph_cache().measured_paragraphs(), measured + 1);
+}
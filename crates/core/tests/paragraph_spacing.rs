@@ -0,0 +1,50 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn paragraph_spacing() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                paragraph_spacing: "20",
+                paragraph {
+                    text {
+                        "First"
+                    }
+                }
+                paragraph {
+                    text {
+                        "Second"
+                    }
+                }
+                rect {
+                    width: "100",
+                    height: "50",
+                }
+                rect {
+                    width: "100",
+                    height: "50",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let container = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    let first = container.get(0).area().unwrap();
+    let second = container.get(1).area().unwrap();
+    let first_rect = container.get(2).area().unwrap();
+    let second_rect = container.get(3).area().unwrap();
+
+    // The blocks of text are followed by the spacing
+    assert_eq!(second.min_y(), first.max_y() + 20.0);
+    assert_eq!(first_rect.min_y(), second.max_y() + 20.0);
+
+    // But other elements are not
+    assert_eq!(second_rect.min_y(), first_rect.max_y());
+
+    // And the container grows with it
+    assert_eq!(container.area().unwrap().height(), second_rect.max_y());
+}
@@ -0,0 +1,56 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn cached_subtree_is_recorded_once() {
+    fn app() -> Element {
+        let mut color = use_signal(|| "red");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                cache: "true",
+                rect {
+                    height: "100%",
+                    width: "100%",
+                    background: "{color}",
+                }
+            }
+            rect {
+                height: "100",
+                width: "100",
+                onclick: move |_| color.set("blue"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    // Unchanged frames replay the same picture
+    for _ in 0..3 {
+        let snapshot = utils.create_snapshot();
+        assert_eq!(
+            snapshot.peek_pixels().unwrap().get_color((50, 50)),
+            Color::RED
+        );
+    }
+    assert_eq!(utils.pictures_cache().recorded_pictures(), 1);
+
+    // Changing a descendant records it again
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (50.0, 150.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    assert_eq!(
+        snapshot.peek_pixels().unwrap().get_color((50, 50)),
+        Color::BLUE
+    );
+    assert_eq!(utils.pictures_cache().recorded_pictures(), 2);
+}
@@ -0,0 +1,20 @@
+use freya_core::skia::RenderQuality;
+use freya_engine::prelude::*;
+
+#[test]
+pub fn subpixel_text_edging() {
+    let subpixel = RenderQuality {
+        subpixel_text: true,
+        ..RenderQuality::default()
+    };
+    assert_eq!(
+        subpixel.font_rastr_settings().edging,
+        Edging::SubpixelAntiAlias
+    );
+
+    let grayscale = RenderQuality {
+        subpixel_text: false,
+        ..RenderQuality::default()
+    };
+    assert_eq!(grayscale.font_rastr_settings().edging, Edging::AntiAlias);
+}
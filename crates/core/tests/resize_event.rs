@@ -0,0 +1,49 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn resize_event() {
+    fn resize_event_app() -> Element {
+        let mut resizes = use_signal(|| 0);
+        let mut size = use_signal(Size2D::default);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    width: "50%",
+                    height: "100",
+                    margin: "10",
+                    onresize: move |e: ResizeEvent| {
+                        resizes += 1;
+                        size.set(e.get_size());
+                    },
+                }
+                label {
+                    "{resizes} {size.read().width}x{size.read().height}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(resize_event_app);
+    let root = utils.root();
+    let label = root.get(0).get(1);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Emitted once it's first measured, not including its margin
+    assert_eq!(label.get(0).text(), Some("1 250x100"));
+
+    // Not emitted again while the size doesn't change
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1 250x100"));
+
+    utils.resize((300., 300.).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("2 150x100"));
+}
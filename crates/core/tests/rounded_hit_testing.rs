@@ -0,0 +1,76 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+async fn click(utils: &mut TestingHandler, cursor: (f64, f64)) {
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: cursor.into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+}
+
+#[tokio::test]
+pub async fn rounded_corners_are_not_hit() {
+    fn app() -> Element {
+        let mut state = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| state.set("behind".to_string()),
+                rect {
+                    height: "200",
+                    width: "200",
+                    corner_radius: "100",
+                    background: "red",
+                    onclick: move |e: MouseEvent| {
+                        state.set("circle".to_string());
+                        e.stop_propagation();
+                    }
+                }
+                rect {
+                    height: "200",
+                    width: "200",
+                    corner_radius: "100",
+                    corner_smoothing: "100%",
+                    overflow: "clip",
+                    rect {
+                        height: "100%",
+                        width: "100%",
+                        background: "red",
+                        onclick: move |e: MouseEvent| {
+                            state.set("clipped".to_string());
+                            e.stop_propagation();
+                        }
+                    }
+                }
+                label {
+                    "{state}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(2);
+
+    // Corner of the circle
+    click(&mut utils, (5.0, 5.0)).await;
+    assert_eq!(label.get(0).text(), Some("behind"));
+
+    // Center of the circle
+    click(&mut utils, (100.0, 100.0)).await;
+    assert_eq!(label.get(0).text(), Some("circle"));
+
+    // Corner of the clipped child
+    click(&mut utils, (5.0, 205.0)).await;
+    assert_eq!(label.get(0).text(), Some("behind"));
+
+    // Center of the clipped child
+    click(&mut utils, (100.0, 300.0)).await;
+    assert_eq!(label.get(0).text(), Some("clipped"));
+}
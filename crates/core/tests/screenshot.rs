@@ -0,0 +1,57 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    Data,
+    Image,
+};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn screenshot() {
+    fn app() -> Element {
+        let platform = use_platform();
+        let (reference, layout) = use_node_signal();
+        let mut sizes = use_signal(Vec::<(i32, i32)>::new);
+
+        let onclick = move |_| {
+            spawn(async move {
+                for area in [None, Some(layout.read().area)] {
+                    if let Some(png) = platform.screenshot(area).await {
+                        let image = Image::from_encoded(Data::new_copy(&png)).unwrap();
+                        sizes.write().push((image.width(), image.height()));
+                    }
+                }
+            });
+        };
+
+        rsx!(
+            rect {
+                reference,
+                width: "100",
+                height: "50",
+                background: "red",
+                onclick,
+            }
+            label {
+                "{sizes:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    for _ in 0..4 {
+        utils.wait_for_update().await;
+    }
+
+    // The whole window is captured, or only the area of the element
+    assert_eq!(
+        utils.root().get(1).get(0).text(),
+        Some("[(500, 500), (100, 50)]")
+    );
+}
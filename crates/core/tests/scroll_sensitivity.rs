@@ -0,0 +1,73 @@
+use freya::prelude::*;
+use freya_core::prelude::ScrollSensitivity;
+use freya_testing::prelude::*;
+use winit::{
+    dpi::PhysicalPosition,
+    event::MouseScrollDelta,
+};
+
+#[test]
+pub fn normalize_wheel_deltas() {
+    let sensitivity = ScrollSensitivity::default();
+
+    // A line scrolls as much as its height in logical pixels, whatever the scale factor
+    let line = MouseScrollDelta::LineDelta(0.0, -1.0);
+    assert_eq!(sensitivity.normalize(line, 1.0), (0.0, -53.0));
+    assert_eq!(sensitivity.normalize(line, 2.0), (0.0, -53.0));
+
+    // Pixels are physical, so they are turned into logical ones
+    let pixels = MouseScrollDelta::PixelDelta(PhysicalPosition::new(20.0, -106.0));
+    assert_eq!(sensitivity.normalize(pixels, 2.0), (10.0, -53.0));
+
+    let sensitivity = ScrollSensitivity {
+        multiplier: 2.0,
+        line_height: 10.0,
+    };
+    assert_eq!(sensitivity.normalize(line, 1.0), (0.0, -20.0));
+    assert_eq!(sensitivity.normalize(pixels, 1.0), (40.0, -212.0));
+}
+
+#[tokio::test]
+pub async fn line_and_pixel_deltas_scroll_the_same() {
+    fn scroll_app() -> Element {
+        rsx!(
+            ScrollView {
+                for i in 0..4 {
+                    rect {
+                        key: "{i}",
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(scroll_app);
+    let root = utils.root();
+    let content = root.get(0).get(0).get(0);
+    utils.wait_for_update().await;
+
+    let sensitivity = ScrollSensitivity::default();
+    let deltas = [
+        (MouseScrollDelta::LineDelta(0.0, -1.0), 1.0),
+        (
+            MouseScrollDelta::PixelDelta(PhysicalPosition::new(0.0, -106.0)),
+            2.0,
+        ),
+    ];
+
+    for (i, (delta, scale_factor)) in deltas.into_iter().enumerate() {
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: sensitivity.normalize(delta, scale_factor).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+
+        // Every delta moves the content the same distance
+        let scrolled = 53.0 * (i + 1) as f32;
+        assert_eq!(content.get(0).area().unwrap().min_y(), -scrolled);
+    }
+}
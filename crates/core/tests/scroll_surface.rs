@@ -0,0 +1,64 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn scrolling_reuses_rendered_surface() {
+    fn app() -> Element {
+        rsx!(
+            ScrollView {
+                cache: true,
+                for i in 0..30 {
+                    rect {
+                        key: "{i}",
+                        height: "100",
+                        width: "100%",
+                        background: if i % 2 == 0 { "red" } else { "blue" },
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    assert_eq!(
+        snapshot.peek_pixels().unwrap().get_color((50, 50)),
+        Color::RED
+    );
+    assert_eq!(utils.pictures_cache().rendered_scroll_surfaces(), 1);
+
+    // Scrolling within the rendered margin only moves the surface
+    utils.push_event(PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., -100.).into(),
+        cursor: (5., 5.).into(),
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    assert_eq!(
+        snapshot.peek_pixels().unwrap().get_color((50, 50)),
+        Color::BLUE
+    );
+    assert_eq!(utils.pictures_cache().rendered_scroll_surfaces(), 1);
+
+    // Scrolling beyond it renders the content again
+    utils.push_event(PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., -300.).into(),
+        cursor: (5., 5.).into(),
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    assert_eq!(
+        snapshot.peek_pixels().unwrap().get_color((50, 50)),
+        Color::RED
+    );
+    assert_eq!(utils.pictures_cache().rendered_scroll_surfaces(), 2);
+}
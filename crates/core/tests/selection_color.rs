@@ -0,0 +1,49 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn selection_colors_are_inherited() {
+    fn app() -> Element {
+        let highlights =
+            AttributeValue::any_value(CustomAttributeValues::TextHighlights(vec![(0, 3)]));
+
+        rsx!(
+            rect {
+                selection_color: "red",
+                selection_text_color: "blue",
+                paragraph {
+                    highlights,
+                    text {
+                        font_size: "50",
+                        color: "black",
+                        "XXX"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0).get(0);
+    assert_eq!(paragraph.state().cursor.highlight_color, Color::RED);
+    assert_eq!(
+        paragraph.state().cursor.selection_text_color,
+        Some(Color::BLUE)
+    );
+
+    let area = paragraph.area().unwrap();
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+    let colors = (0..area.width() as i32)
+        .flat_map(|x| (0..area.height() as i32).map(move |y| (x, y)))
+        .map(|point| pixels.get_color(point))
+        .collect::<Vec<_>>();
+
+    // The selected glyphs are painted blue over the red selection, none are left black
+    assert!(colors.contains(&Color::RED));
+    assert!(colors.contains(&Color::BLUE));
+    assert!(!colors.contains(&Color::BLACK));
+}
@@ -0,0 +1,56 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn shadow_spread() {
+    fn shadow_spread_app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                rect {
+                    width: "50",
+                    height: "50",
+                    margin: "20",
+                    background: "blue",
+                    shadow: "0 0 0 10 red",
+                }
+                rect {
+                    width: "50",
+                    height: "50",
+                    margin: "20",
+                    background: "blue",
+                    shadow: "0 0 0 red",
+                }
+                rect {
+                    width: "50",
+                    height: "50",
+                    margin: "20",
+                    background: "blue",
+                    shadow: "inset 0 0 0 10 red",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(shadow_spread_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // The spread grows the shadow outside of the element
+    assert_eq!(pixels.get_color((45, 15)), Color::RED);
+    assert_eq!(pixels.get_color((45, 45)), Color::BLUE);
+
+    // Without spread or blur the shadow stays behind the element
+    assert_eq!(pixels.get_color((45, 105)), Color::WHITE);
+    assert_eq!(pixels.get_color((45, 135)), Color::BLUE);
+
+    // Inset shadows grow inwards instead
+    assert_eq!(pixels.get_color((45, 203)), Color::RED);
+    assert_eq!(pixels.get_color((45, 225)), Color::BLUE);
+    assert_eq!(pixels.get_color((45, 195)), Color::WHITE);
+}
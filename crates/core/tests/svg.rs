@@ -0,0 +1,75 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+const SQUARE_SVG: &str = r#"<svg width="10" height="10" viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg"><rect width="10" height="10" fill="red"/></svg>"#;
+
+#[tokio::test]
+pub async fn svg_view_box_keeps_aspect_ratio() {
+    fn svg_app() -> Element {
+        rsx!(
+            svg {
+                width: "200",
+                height: "100",
+                svg_content: SQUARE_SVG,
+            }
+            svg {
+                width: "200",
+                height: "100",
+                preserve_aspect_ratio: "slice",
+                svg_content: SQUARE_SVG,
+            }
+        )
+    }
+
+    let mut utils = launch_test(svg_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Scaled to fit the height and centered, instead of drawn at 10x10 or stretched
+    assert_eq!(pixels.get_color((100, 50)), Color::RED);
+    assert_eq!(pixels.get_color((55, 95)), Color::RED);
+    assert_eq!(pixels.get_color((25, 50)), Color::WHITE);
+    assert_eq!(pixels.get_color((175, 50)), Color::WHITE);
+
+    // Scaled to cover the whole element
+    assert_eq!(pixels.get_color((5, 150)), Color::RED);
+    assert_eq!(pixels.get_color((195, 150)), Color::RED);
+}
+
+const STRETCHED_SVG: &str = r#"<svg width="10" height="10" viewBox="0 0 10 10" preserveAspectRatio="none" xmlns="http://www.w3.org/2000/svg"><rect width="10" height="10" fill="red"/></svg>"#;
+
+#[tokio::test]
+pub async fn svg_own_preserve_aspect_ratio() {
+    fn svg_app() -> Element {
+        rsx!(
+            svg {
+                width: "200",
+                height: "100",
+                svg_content: STRETCHED_SVG,
+            }
+            svg {
+                width: "200",
+                height: "100",
+                preserve_aspect_ratio: "meet",
+                svg_content: STRETCHED_SVG,
+            }
+        )
+    }
+
+    let mut utils = launch_test(svg_app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // The SVG's own preserveAspectRatio is kept when the attribute is not specified
+    assert_eq!(pixels.get_color((5, 50)), Color::RED);
+    assert_eq!(pixels.get_color((195, 50)), Color::RED);
+
+    // And overridden when it is
+    assert_eq!(pixels.get_color((5, 150)), Color::WHITE);
+    assert_eq!(pixels.get_color((100, 150)), Color::RED);
+}
@@ -0,0 +1,83 @@
+use freya_core::prelude::ImagesCache;
+use freya_engine::prelude::*;
+use freya_node_state::{
+    AttributesBytes,
+    HashedBytes,
+    SvgAspectRatio,
+};
+
+const SVG_BYTES: &[u8] = br#"<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg"><rect width="10" height="10" fill="red"/></svg>"#;
+
+#[test]
+pub fn svg_rasterized_once() {
+    let font_mgr = FontMgr::default();
+    let svg = HashedBytes::new(AttributesBytes::Static(SVG_BYTES));
+    let mut images_cache = ImagesCache::default();
+
+    let image = images_cache
+        .get_svg(&svg, (50, 50), 1.0, Some(SvgAspectRatio::Meet), &font_mgr)
+        .unwrap();
+
+    // Repeated identical frames reuse the same image
+    for _ in 0..5 {
+        images_cache.evict_unused();
+        let cached_image = images_cache
+            .get_svg(&svg, (50, 50), 1.0, Some(SvgAspectRatio::Meet), &font_mgr)
+            .unwrap();
+        assert_eq!(cached_image.unique_id(), image.unique_id());
+    }
+    assert_eq!(images_cache.rasterized_svgs(), 1);
+
+    // A new size, scale factor or aspect ratio rasterizes the SVG again
+    images_cache.get_svg(&svg, (100, 100), 1.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    assert_eq!(images_cache.rasterized_svgs(), 2);
+    images_cache.get_svg(&svg, (100, 100), 2.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    assert_eq!(images_cache.rasterized_svgs(), 3);
+    images_cache.get_svg(
+        &svg,
+        (100, 100),
+        2.0,
+        Some(SvgAspectRatio::Slice),
+        &font_mgr,
+    );
+    assert_eq!(images_cache.rasterized_svgs(), 4);
+
+    // Images not used since the last eviction are dropped once new images are added
+    images_cache.evict_unused();
+    images_cache.evict_unused();
+    images_cache.get_svg(&svg, (50, 50), 1.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    assert_eq!(images_cache.rasterized_svgs(), 4);
+    images_cache.get_svg(&svg, (30, 30), 1.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    images_cache.evict_unused();
+    images_cache.get_svg(&svg, (50, 50), 1.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    images_cache.get_svg(&svg, (100, 100), 1.0, Some(SvgAspectRatio::Meet), &font_mgr);
+    assert_eq!(images_cache.rasterized_svgs(), 6);
+}
+
+#[test]
+pub fn svg_rasterized_at_scale_factor() {
+    let font_mgr = FontMgr::default();
+    let mut images_cache = ImagesCache::default();
+    // Without a viewBox the SVG is drawn at its own size
+    let svg = HashedBytes::new(AttributesBytes::Static(
+        br#"<svg width="10" height="10" xmlns="http://www.w3.org/2000/svg"><rect width="10" height="10" fill="red"/></svg>"#,
+    ));
+
+    let color_at = |image: Image, (x, y): (i32, i32)| {
+        let pixels = image.peek_pixels().unwrap();
+        pixels.get_color((x, y))
+    };
+
+    let image = images_cache
+        .get_svg(&svg, (40, 40), 1.0, Some(SvgAspectRatio::Meet), &font_mgr)
+        .unwrap();
+    assert_eq!(color_at(image.clone(), (5, 5)), Color::RED);
+    assert_eq!(color_at(image, (15, 15)), Color::TRANSPARENT);
+
+    // It keeps its logical size when the scale factor is bigger
+    let image = images_cache
+        .get_svg(&svg, (40, 40), 2.0, Some(SvgAspectRatio::Meet), &font_mgr)
+        .unwrap();
+    assert_eq!(color_at(image.clone(), (15, 15)), Color::RED);
+    assert_eq!(color_at(image, (25, 25)), Color::TRANSPARENT);
+}
@@ -0,0 +1,50 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+use torin::prelude::Area;
+
+#[tokio::test]
+pub async fn stroked_text_differs_from_plain_fill() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                font_size: "50",
+                "Freya"
+            }
+            label {
+                font_size: "50",
+                text_stroke_width: "4",
+                text_stroke_color: "red",
+                "Freya"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let plain = utils.root().get(0).area().unwrap();
+    let stroked = utils.root().get(1).area().unwrap();
+    assert_eq!(plain.size, stroked.size);
+
+    let snapshot = utils.create_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    // Colors of the painted pixels of each label
+    let painted = |area: Area| {
+        (0..area.width() as i32)
+            .flat_map(|x| (0..area.height() as i32).map(move |y| (x, y)))
+            .map(|(x, y)| pixels.get_color((area.min_x() as i32 + x, area.min_y() as i32 + y)))
+            .filter(|color| *color != Color::WHITE)
+            .collect::<Vec<_>>()
+    };
+    let is_red = |color: &Color| color.r() > 200 && color.g() < 100 && color.b() < 100;
+
+    let plain = painted(plain);
+    let stroked = painted(stroked);
+
+    // The outline covers more pixels than the plain fill, and in its own color
+    assert!(stroked.len() > plain.len());
+    assert!(!plain.iter().any(is_red));
+    assert!(stroked.iter().any(is_red));
+}
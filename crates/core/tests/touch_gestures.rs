@@ -0,0 +1,110 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn touch(utils: &mut TestingHandler, finger_id: u64, location: (f64, f64), phase: TouchPhase) {
+    let name = match phase {
+        TouchPhase::Started => EventName::TouchStart,
+        TouchPhase::Moved => EventName::TouchMove,
+        TouchPhase::Ended => EventName::TouchEnd,
+        TouchPhase::Cancelled => EventName::TouchCancel,
+    };
+    utils.push_event(PlatformEvent::Touch {
+        name,
+        location: location.into(),
+        finger_id,
+        phase,
+        force: None,
+    });
+}
+
+#[tokio::test]
+pub async fn two_fingers_pinch_and_pan() {
+    fn app() -> Element {
+        let mut scale = use_signal(|| 1.0);
+        let mut translation = use_signal(|| (0.0, 0.0));
+        let mut phase = use_signal(|| None);
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpinch: move |e: GestureEvent| {
+                    scale *= e.get_scale();
+                    phase.set(Some(e.get_phase()));
+                },
+                onpan: move |e: GestureEvent| {
+                    let delta = e.get_translation();
+                    translation.with_mut(|(x, y)| {
+                        *x += delta.x;
+                        *y += delta.y;
+                    });
+                },
+            }
+            label {
+                "{scale} {translation:?} {phase:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(1);
+    utils.wait_for_update().await;
+
+    // A single finger is not a gesture
+    touch(&mut utils, 0, (50.0, 50.0), TouchPhase::Started);
+    touch(&mut utils, 0, (40.0, 50.0), TouchPhase::Moved);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1 (0.0, 0.0) None"));
+
+    // The second finger starts the gesture
+    touch(&mut utils, 1, (60.0, 50.0), TouchPhase::Started);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1 (0.0, 0.0) Some(Started)"));
+
+    // Moving the fingers apart zooms in, and their center moves to the right
+    touch(&mut utils, 1, (80.0, 50.0), TouchPhase::Moved);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2 (10.0, 0.0) Some(Moved)"));
+
+    // Lifting the second finger ends it
+    touch(&mut utils, 1, (80.0, 50.0), TouchPhase::Ended);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2 (10.0, 0.0) Some(Ended)"));
+
+    // Only the first finger is left
+    touch(&mut utils, 0, (20.0, 50.0), TouchPhase::Moved);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2 (10.0, 0.0) Some(Ended)"));
+}
+
+#[tokio::test]
+pub async fn gestures_go_to_the_element_under_the_primary_touch() {
+    fn app() -> Element {
+        let mut target = use_signal(|| "none");
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                onpinch: move |_| target.set("first"),
+            }
+            rect {
+                height: "100",
+                width: "100",
+                onpinch: move |_| target.set("second"),
+            }
+            label {
+                "{target}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(2);
+    utils.wait_for_update().await;
+
+    touch(&mut utils, 0, (50.0, 150.0), TouchPhase::Started);
+    touch(&mut utils, 1, (50.0, 50.0), TouchPhase::Started);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("second"));
+}
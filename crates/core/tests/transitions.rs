@@ -0,0 +1,53 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn transition_background() {
+    fn transition_app() -> Element {
+        let mut toggled = use_signal(|| false);
+
+        let background = if *toggled.read() { "red" } else { "blue" };
+
+        rsx!(rect {
+            height: "100%",
+            width: "100%",
+            transition: "background 1s",
+            background: "{background}",
+            onclick: move |_| toggled.set(true),
+        })
+    }
+
+    let mut utils = launch_test(transition_app);
+
+    let root = utils.root();
+
+    // Newly mounted nodes do not transition
+    assert_eq!(root.get(0).style().background, Fill::Color(Color::BLUE));
+    assert!(root.get(0).style().background_transition.is_none());
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5., 5.).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    let style = root.get(0).style();
+    assert_eq!(style.background, Fill::Color(Color::RED));
+
+    let transition = style.background_transition.unwrap();
+    assert_eq!(transition.from, Color::BLUE);
+
+    // Midway the background is somewhere in between
+    let midway = transition.started_at + transition.timing.duration / 2;
+    assert_ne!(style.background_at(midway), Fill::Color(Color::BLUE));
+    assert_ne!(style.background_at(midway), Fill::Color(Color::RED));
+    assert!(style.has_running_transitions(midway));
+
+    // And once finished it is the new value
+    let finished = transition.started_at + transition.timing.duration;
+    assert_eq!(style.background_at(finished), Fill::Color(Color::RED));
+    assert!(!style.has_running_transitions(finished));
+}
@@ -0,0 +1,102 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn window_drag() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "50",
+                window_drag: "true",
+                onclick: move |_| clicks += 1,
+                rect {
+                    width: "50",
+                    height: "50",
+                    background: "red",
+                }
+            }
+            label {
+                "{clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    fn press(utils: &mut TestingHandler, moves: &[(f64, f64)], x: f64) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (x, 25.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        for cursor in moves {
+            utils.push_event(PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                cursor: (*cursor).into(),
+                button: None,
+            });
+        }
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: moves.last().copied().unwrap_or((x, 25.0)).into(),
+            button: Some(MouseButton::Left),
+        });
+    }
+
+    // Barely moving while pressing is still a click
+    press(&mut utils, &[(201.0, 26.0)], 200.0);
+    utils.wait_for_update().await;
+    assert_eq!(utils.window_drags(), 0);
+    assert_eq!(root.get(1).get(0).text(), Some("1"));
+
+    // Moving further drags the window
+    press(&mut utils, &[(220.0, 25.0)], 200.0);
+    utils.wait_for_update().await;
+    assert_eq!(utils.window_drags(), 1);
+
+    // Elements with a background inside of the drag region don't drag the window
+    press(&mut utils, &[(45.0, 25.0)], 25.0);
+    utils.wait_for_update().await;
+    assert_eq!(utils.window_drags(), 1);
+}
+
+#[tokio::test]
+pub async fn window_drag_releases_pressed_state() {
+    fn app() -> Element {
+        rsx!(rect {
+            width: "fill",
+            height: "50",
+            window_drag: "true",
+            background: "white",
+            background_hover: "blue",
+            background_active: "red",
+        })
+    }
+
+    let mut utils = launch_test(app);
+    let rect = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    async fn push_mouse_event(utils: &mut TestingHandler, name: EventName, cursor: (f64, f64)) {
+        utils.push_event(PlatformEvent::Mouse {
+            name,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+
+    push_mouse_event(&mut utils, EventName::MouseDown, (50.0, 25.0)).await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+
+    // The window doesn't report the release of the button once it's being dragged, so it's no longer pressed
+    push_mouse_event(&mut utils, EventName::MouseOver, (80.0, 25.0)).await;
+    assert_eq!(utils.window_drags(), 1);
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+}
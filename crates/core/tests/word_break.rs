@@ -0,0 +1,56 @@
+use freya::prelude::*;
+use freya_common::CachedParagraph;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn break_all_breaks_inside_words() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                width: "100",
+                "a bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+            }
+            label {
+                width: "100",
+                word_break: "break-all",
+                "a bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let first_line_end = |index: usize| {
+        let layout = utils.root().get(index).layout().unwrap();
+        let data = layout.data.unwrap();
+        let CachedParagraph(paragraph) = data.get::<CachedParagraph>().unwrap();
+        assert!(paragraph.longest_line() <= 101.0);
+        paragraph.get_line_metrics()[0].end_index
+    };
+
+    // The long word doesn't fit next to the first one
+    assert_eq!(first_line_end(0), 2);
+    // Unless it can be broken anywhere
+    assert!(first_line_end(1) > 4);
+}
+
+#[tokio::test]
+pub async fn break_all_keeps_text_indices() {
+    fn app() -> Element {
+        rsx!(label {
+            width: "100",
+            word_break: "break-all",
+            "a bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0);
+
+    // The glyphs are found at the indices of the text, not of the laid out text with the inserted breaks
+    assert_eq!(label.glyph_index_at((0.0, 5.0)), Some(0));
+    assert_eq!(label.glyph_index_at((1000.0, 1000.0)), Some(32));
+}
@@ -1,4 +1,7 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    sync::atomic::Ordering,
+};
 
 use dioxus::prelude::*;
 use dioxus_radio::prelude::*;
@@ -17,7 +20,10 @@ use freya_hooks::{
 };
 use freya_native_core::NodeId;
 use freya_renderer::{
-    devtools::DevtoolsReceiver,
+    devtools::{
+        DevtoolsReceiver,
+        PaintOrderOverlay,
+    },
     HoveredNode,
 };
 use state::{
@@ -42,6 +48,7 @@ pub fn with_devtools(
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
     hovered_node: HoveredNode,
+    paint_order_overlay: PaintOrderOverlay,
 ) -> VirtualDom {
     VirtualDom::new_with_props(
         AppWithDevtools,
@@ -49,6 +56,7 @@ pub fn with_devtools(
             root,
             devtools_receiver,
             hovered_node,
+            paint_order_overlay,
         },
     )
 }
@@ -58,6 +66,7 @@ struct AppWithDevtoolsProps {
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
     hovered_node: HoveredNode,
+    paint_order_overlay: PaintOrderOverlay,
 }
 
 impl PartialEq for AppWithDevtoolsProps {
@@ -72,6 +81,7 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
     let Root = props.root;
     let devtools_receiver = props.devtools_receiver;
     let hovered_node = props.hovered_node;
+    let paint_order_overlay = props.paint_order_overlay;
 
     rsx!(
         NativeContainer {
@@ -92,7 +102,8 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
                     ThemeProvider {
                         DevTools {
                             devtools_receiver,
-                            hovered_node
+                            hovered_node,
+                            paint_order_overlay
                         }
                     }
                 }
@@ -105,6 +116,7 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
 pub struct DevToolsProps {
     devtools_receiver: DevtoolsReceiver,
     hovered_node: HoveredNode,
+    paint_order_overlay: PaintOrderOverlay,
 }
 
 impl PartialEq for DevToolsProps {
@@ -120,6 +132,7 @@ pub fn DevTools(props: DevToolsProps) -> Element {
         hovered_node: props.hovered_node.clone(),
         devtools_receiver: props.devtools_receiver.clone(),
         devtools_tree: HashSet::default(),
+        paint_order_overlay: props.paint_order_overlay.clone(),
     });
 
     let theme = theme.read();
@@ -138,19 +151,51 @@ pub fn DevTools(props: DevToolsProps) -> Element {
 #[component]
 #[allow(non_snake_case)]
 pub fn DevtoolsBar() -> Element {
+    let platform = use_platform();
+    let radio = use_radio(DevtoolsChannel::Global);
+    let mut show_paint_order =
+        use_signal(|| radio.read().paint_order_overlay.load(Ordering::Relaxed));
+
     rsx!(
-        Tabsbar {
-            Link {
-                to: Route::DOMInspector { },
-                ActivableRoute {
-                    route: Route::DOMInspector { },
-                    Tab {
-                        label {
-                            "Elements"
+        rect {
+            width: "fill",
+            direction: "horizontal",
+            cross_align: "center",
+            Tabsbar {
+                Link {
+                    to: Route::DOMInspector { },
+                    ActivableRoute {
+                        route: Route::DOMInspector { },
+                        Tab {
+                            label {
+                                "Elements"
+                            }
                         }
                     }
                 }
             }
+            rect {
+                width: "fill",
+                direction: "horizontal",
+                main_align: "end",
+                cross_align: "center",
+                padding: "0 8",
+                label {
+                    margin: "0 6 0 0",
+                    "Paint order"
+                }
+                Switch {
+                    enabled: *show_paint_order.read(),
+                    ontoggled: move |_| {
+                        show_paint_order.toggle();
+                        radio
+                            .read()
+                            .paint_order_overlay
+                            .store(*show_paint_order.read(), Ordering::Relaxed);
+                        platform.request_animation_frame();
+                    }
+                }
+            }
         }
         Outlet::<Route> {}
     )
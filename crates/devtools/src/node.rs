@@ -53,6 +53,7 @@ pub fn NodeElement(
         rect {
             corner_radius: "7",
             padding: "5 5 5 0",
+            box_sizing: "border-box",
             background,
             width: "100%",
             height: "27",
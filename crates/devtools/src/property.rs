@@ -17,6 +17,7 @@ pub fn Property(name: String, value: String) -> Element {
             width: "100%",
             direction: "horizontal",
             padding: "10",
+            box_sizing: "border-box",
             paragraph {
                 width: "100%",
                 text {
@@ -77,6 +78,7 @@ pub fn ColorProperty(name: String, fill: Fill) -> Element {
             width: "100%",
             direction: "horizontal",
             padding: "10",
+            box_sizing: "border-box",
             label {
                 font_size: "15",
                 color: "rgb(71, 180, 240)",
@@ -96,6 +98,7 @@ pub fn ColorProperty(name: String, fill: Fill) -> Element {
                 corner_radius: "5",
                 background: "white",
                 padding: "2.5",
+                box_sizing: "border-box",
                 rect {
                     corner_radius: "3",
                     width: "100%",
@@ -125,6 +128,7 @@ pub fn ShadowProperty(name: String, shadow: Shadow) -> Element {
             width: "100%",
             direction: "horizontal",
             padding: "10",
+            box_sizing: "border-box",
             paragraph {
                 text {
                     font_size: "15",
@@ -151,6 +155,7 @@ pub fn ShadowProperty(name: String, shadow: Shadow) -> Element {
                 corner_radius: "5",
                 background: "white",
                 padding: "2.5",
+                box_sizing: "border-box",
                 rect {
                     corner_radius: "3",
                     width: "100%",
@@ -181,6 +186,7 @@ pub fn BorderProperty(name: String, border: Border) -> Element {
             width: "100%",
             direction: "horizontal",
             padding: "10",
+            box_sizing: "border-box",
             paragraph {
                 text {
                     font_size: "15",
@@ -207,6 +213,7 @@ pub fn BorderProperty(name: String, border: Border) -> Element {
                 corner_radius: "5",
                 background: "white",
                 padding: "2.5",
+                box_sizing: "border-box",
                 rect {
                     corner_radius: "3",
                     width: "100%",
@@ -237,6 +244,7 @@ pub fn TextShadowProperty(name: String, text_shadow: TextShadow) -> Element {
             width: "100%",
             direction: "horizontal",
             padding: "10",
+            box_sizing: "border-box",
             paragraph {
                 text {
                     font_size: "15",
@@ -263,6 +271,7 @@ pub fn TextShadowProperty(name: String, text_shadow: TextShadow) -> Element {
                 corner_radius: "5",
                 background: "white",
                 padding: "2.5",
+                box_sizing: "border-box",
                 rect {
                     corner_radius: "3",
                     width: "100%",
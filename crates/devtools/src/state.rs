@@ -3,7 +3,10 @@ use std::collections::HashSet;
 use dioxus_radio::prelude::*;
 use freya_native_core::prelude::NodeId;
 use freya_renderer::{
-    devtools::DevtoolsReceiver,
+    devtools::{
+        DevtoolsReceiver,
+        PaintOrderOverlay,
+    },
     HoveredNode,
 };
 
@@ -11,6 +14,7 @@ pub struct DevtoolsState {
     pub(crate) hovered_node: HoveredNode,
     pub(crate) devtools_receiver: DevtoolsReceiver,
     pub(crate) devtools_tree: HashSet<NodeId>,
+    pub(crate) paint_order_overlay: PaintOrderOverlay,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
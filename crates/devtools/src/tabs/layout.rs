@@ -40,6 +40,7 @@ pub fn NodeInspectorLayout(node_id: String) -> Element {
                 width: "100%",
                 height: "200",
                 padding: "20",
+                box_sizing: "border-box",
                 label {
                     height: "25",
                     "Area: {area}"
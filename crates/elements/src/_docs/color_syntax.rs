@@ -21,3 +21,4 @@
 //! - With HSL: `hsl(28deg, 80%, 50%)`
 //! - With HSL and alpha: `hsl(28deg, 80%, 50%, 25%)`
 //! - With Hex: `#E93323`
+//! - With Hex and alpha: `#E93323B3`
@@ -4,16 +4,27 @@
 //!
 //! - `color`
 //! - `font_family`
+//! - `lang`
 //! - `font_size`
 //! - `font_style`
 //! - `font_weight`
 //! - `font_width`
 //! - `line_height`
+//! - `line_height_behavior`
 //! - `align`
 //! - `max_lines`
 //! - `letter_spacing`
 //! - `word_spacing`
+//! - `word_break`
+//! - `hyphens`
+//! - `line_break`
 //! - `decoration`
 //! - `decoration_style`
 //! - `decoration_color`
 //! - `text_shadow`
+//! - `text_stroke_width`
+//! - `text_stroke_color`
+//! - `baseline_shift`
+//! - `selection_color`
+//! - `selection_text_color`
+//! - `selectable`
@@ -185,9 +185,25 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/background.md")]
         background: String,
+        #[doc = include_str!("_docs/attributes/background_states.md")]
+        background_hover: String,
+        #[doc = include_str!("_docs/attributes/background_states.md")]
+        background_active: String,
+        #[doc = include_str!("_docs/attributes/background_states.md")]
+        background_focus: String,
         #[doc = include_str!("_docs/attributes/border.md")]
         border: String,
+        #[doc = include_str!("_docs/attributes/border_sides.md")]
+        border_top: String,
+        #[doc = include_str!("_docs/attributes/border_sides.md")]
+        border_right: String,
+        #[doc = include_str!("_docs/attributes/border_sides.md")]
+        border_bottom: String,
+        #[doc = include_str!("_docs/attributes/border_sides.md")]
+        border_left: String,
         border_align: String,
+        #[doc = include_str!("_docs/attributes/focus_ring.md")]
+        focus_ring: String,
         #[doc = include_str!("_docs/attributes/direction.md")]
         direction: String,
         #[doc = include_str!("_docs/attributes/shadow.md")]
@@ -201,6 +217,8 @@ builder_constructors! {
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
         font_family: String,
+        #[doc = include_str!("_docs/attributes/lang.md")]
+        lang: String,
         #[doc = include_str!("_docs/attributes/font_style.md")]
         font_style: String,
         #[doc = include_str!("_docs/attributes/font_weight.md")]
@@ -226,13 +244,43 @@ builder_constructors! {
         position_left: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blend_mode.md")]
+        blend_mode: String,
+        #[doc = include_str!("_docs/attributes/mask_data.md")]
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/disabled.md")]
+        disabled: String,
+        disabled_opacity: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/window_drag.md")]
+        window_drag: String,
+        #[doc = include_str!("_docs/attributes/cache.md")]
+        cache: String,
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/layout_transition.md")]
+        layout_transition: String,
         #[doc = include_str!("_docs/attributes/content.md")]
         content: String,
+        #[doc = include_str!("_docs/attributes/box_sizing.md")]
+        box_sizing: String,
+        #[doc = include_str!("_docs/attributes/snap_align.md")]
+        snap_align: String,
+        #[doc = include_str!("_docs/attributes/paragraph_spacing.md")]
+        paragraph_spacing: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
+        #[doc = include_str!("_docs/attributes/line_height_behavior.md")]
+        line_height_behavior: String,
+        #[doc = include_str!("_docs/attributes/selection_color.md")]
+        selection_color: String,
+        selection_text_color: String,
 
         name: String,
         focusable: String,
+        #[doc = include_str!("_docs/attributes/focus_trap.md")]
+        focus_trap: String,
         role: String,
         focus_id: AccessibilityId,
         alt: String,
@@ -242,6 +290,8 @@ builder_constructors! {
         offset_x: String,
         reference: Reference,
         cursor_reference: CursorReference,
+        #[doc = include_str!("_docs/attributes/selectable.md")]
+        selectable: String,
     };
     /// `label` simply let's you display some text.
     ///
@@ -271,6 +321,8 @@ builder_constructors! {
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
         font_family: String,
+        #[doc = include_str!("_docs/attributes/lang.md")]
+        lang: String,
         #[doc = include_str!("_docs/attributes/font_style.md")]
         font_style: String,
         #[doc = include_str!("_docs/attributes/font_weight.md")]
@@ -285,10 +337,18 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
+        #[doc = include_str!("_docs/attributes/line_height_behavior.md")]
+        line_height_behavior: String,
         #[doc = include_str!("_docs/attributes/letter_spacing.md")]
         letter_spacing: String,
         #[doc = include_str!("_docs/attributes/word_spacing.md")]
         word_spacing: String,
+        #[doc = include_str!("_docs/attributes/word_break.md")]
+        word_break: String,
+        #[doc = include_str!("_docs/attributes/hyphens.md")]
+        hyphens: String,
+        #[doc = include_str!("_docs/attributes/line_break.md")]
+        line_break: String,
         #[doc = include_str!("_docs/attributes/decoration.md")]
         decoration: String,
         #[doc = include_str!("_docs/attributes/decoration_style.md")]
@@ -297,17 +357,32 @@ builder_constructors! {
         decoration_color: String,
         #[doc = include_str!("_docs/attributes/text_overflow.md")]
         text_overflow: String,
+        #[doc = include_str!("_docs/attributes/text_stroke.md")]
+        text_stroke_width: String,
+        text_stroke_color: String,
+        #[doc = include_str!("_docs/attributes/baseline_shift.md")]
+        baseline_shift: String,
         focusable: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blend_mode.md")]
+        blend_mode: String,
+        #[doc = include_str!("_docs/attributes/mask_data.md")]
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/disabled.md")]
+        disabled: String,
+        disabled_opacity: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
 
         layer: String,
         role: String,
         alt: String,
         focus_id: AccessibilityId,
         name: String,
+        reference: Reference,
     };
     /// `paragraph` element let's you build texts with different styles.
     ///
@@ -350,6 +425,8 @@ builder_constructors! {
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
         font_family: String,
+        #[doc = include_str!("_docs/attributes/lang.md")]
+        lang: String,
         #[doc = include_str!("_docs/attributes/font_style.md")]
         font_style: String,
         #[doc = include_str!("_docs/attributes/font_weight.md")]
@@ -358,16 +435,33 @@ builder_constructors! {
         font_width: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
+        #[doc = include_str!("_docs/attributes/line_height_behavior.md")]
+        line_height_behavior: String,
         #[doc = include_str!("_docs/attributes/letter_spacing.md")]
         letter_spacing: String,
         #[doc = include_str!("_docs/attributes/word_spacing.md")]
         word_spacing: String,
+        #[doc = include_str!("_docs/attributes/word_break.md")]
+        word_break: String,
+        #[doc = include_str!("_docs/attributes/hyphens.md")]
+        hyphens: String,
+        #[doc = include_str!("_docs/attributes/line_break.md")]
+        line_break: String,
+        #[doc = include_str!("_docs/attributes/first_line.md")]
+        first_line_color: String,
+        first_line_font_size: String,
+        first_line_font_weight: String,
         #[doc = include_str!("_docs/attributes/decoration.md")]
         decoration: String,
         #[doc = include_str!("_docs/attributes/decoration_style.md")]
         decoration_style: String,
         #[doc = include_str!("_docs/attributes/decoration_color.md")]
         text_overflow: String,
+        #[doc = include_str!("_docs/attributes/text_stroke.md")]
+        text_stroke_width: String,
+        text_stroke_color: String,
+        #[doc = include_str!("_docs/attributes/baseline_shift.md")]
+        baseline_shift: String,
         #[doc = include_str!("_docs/attributes/overflow.md")]
         overflow: String,
         focusable: String,
@@ -375,9 +469,20 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blend_mode.md")]
+        blend_mode: String,
+        #[doc = include_str!("_docs/attributes/mask_data.md")]
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/disabled.md")]
+        disabled: String,
+        disabled_opacity: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
 
         layer: String,
         cursor_index: String,
+        #[doc = include_str!("_docs/attributes/cursor_affinity.md")]
+        cursor_affinity: String,
         max_lines: String,
         cursor_color: String,
         cursor_mode: String,
@@ -388,7 +493,13 @@ builder_constructors! {
         focus_id: AccessibilityId,
         highlights: String,
         highlight_color: String,
+        #[doc = include_str!("_docs/attributes/selection_color.md")]
+        selection_color: String,
+        selection_text_color: String,
         highlight_mode: String,
+        #[doc = include_str!("_docs/attributes/selectable.md")]
+        selectable: String,
+        reference: Reference,
     };
     /// `text` element is simply a text span used for the `paragraph` element.
     text {
@@ -403,6 +514,8 @@ builder_constructors! {
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
         font_family: String,
+        #[doc = include_str!("_docs/attributes/lang.md")]
+        lang: String,
         #[doc = include_str!("_docs/attributes/font_style.md")]
         font_style: String,
         #[doc = include_str!("_docs/attributes/font_weight.md")]
@@ -415,12 +528,21 @@ builder_constructors! {
         letter_spacing: String,
         #[doc = include_str!("_docs/attributes/word_spacing.md")]
         word_spacing: String,
+        #[doc = include_str!("_docs/attributes/word_break.md")]
+        word_break: String,
+        #[doc = include_str!("_docs/attributes/hyphens.md")]
+        hyphens: String,
         #[doc = include_str!("_docs/attributes/decoration.md")]
         decoration: String,
         #[doc = include_str!("_docs/attributes/decoration_style.md")]
         decoration_style: String,
         #[doc = include_str!("_docs/attributes/decoration_color.md")]
         decoration_color: String,
+        #[doc = include_str!("_docs/attributes/text_stroke.md")]
+        text_stroke_width: String,
+        text_stroke_color: String,
+        #[doc = include_str!("_docs/attributes/baseline_shift.md")]
+        baseline_shift: String,
     };
     /// `image` element let's you show an image.
     ///
@@ -449,8 +571,24 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blend_mode.md")]
+        blend_mode: String,
+        #[doc = include_str!("_docs/attributes/mask_data.md")]
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/disabled.md")]
+        disabled: String,
+        disabled_opacity: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
 
         image_data: String,
+        #[doc = include_str!("_docs/attributes/image_tint.md")]
+        image_tint: String,
+        image_tint_mode: String,
+        #[doc = include_str!("_docs/attributes/image_slice.md")]
+        image_slice: String,
+        #[doc = include_str!("_docs/attributes/image_playback.md")]
+        image_playback: String,
         image_reference: String,
         role: String,
         focus_id: AccessibilityId,
@@ -488,9 +626,20 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blend_mode.md")]
+        blend_mode: String,
+        #[doc = include_str!("_docs/attributes/mask_data.md")]
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/disabled.md")]
+        disabled: String,
+        disabled_opacity: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
 
         svg_data: String,
         svg_content: String,
+        #[doc = include_str!("_docs/attributes/preserve_aspect_ratio.md")]
+        preserve_aspect_ratio: String,
         role: String,
         focus_id: AccessibilityId,
         alt: String,
@@ -613,6 +762,13 @@ pub mod events {
         onkeyup
     ];
 
+    impl_event! [
+        ImePreeditData;
+
+        #[doc = include_str!("_docs/events/imepreedit.md")]
+        onimepreedit
+    ];
+
     impl_event! [
         TouchData;
 
@@ -626,6 +782,22 @@ pub mod events {
         ontouchstart
     ];
 
+    impl_event! [
+        GestureData;
+
+        #[doc = include_str!("_docs/events/pinch.md")]
+        onpinch
+        #[doc = include_str!("_docs/events/pan.md")]
+        onpan
+    ];
+
+    impl_event! [
+        ResizeData;
+
+        #[doc = include_str!("_docs/events/resize.md")]
+        onresize
+    ];
+
     impl_event! [
         PointerData;
 
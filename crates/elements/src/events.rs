@@ -1,15 +1,21 @@
 pub mod file;
+pub mod gesture;
+pub mod ime;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
+pub mod resize;
 pub mod touch;
 pub mod wheel;
 
 use dioxus_core::Event;
 pub use file::*;
+pub use gesture::*;
+pub use ime::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use resize::*;
 pub use touch::*;
 pub use wheel::*;
 
@@ -18,3 +24,6 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type GestureEvent = Event<GestureData>;
+pub type ImePreeditEvent = Event<ImePreeditData>;
+pub type ResizeEvent = Event<ResizeData>;
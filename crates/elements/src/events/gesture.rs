@@ -0,0 +1,68 @@
+use torin::geometry::CursorPoint;
+use winit::event::TouchPhase;
+
+use crate::definitions::PlatformEventData;
+
+/// Data of a Pinch or Pan gesture event.
+///
+/// Gestures are recognized from the movement of the first two fingers touching the screen,
+/// or reported directly by the trackpad.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub scale: f64,
+    pub translation: CursorPoint,
+    pub phase: TouchPhase,
+}
+
+impl GestureData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        scale: f64,
+        translation: CursorPoint,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            scale,
+            translation,
+            phase,
+        }
+    }
+
+    /// Get the coordinates of the primary touch, or the cursor for trackpads, relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the coordinates of the primary touch, or the cursor for trackpads, relative to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get how much the distance between the fingers changed since the last event, e.g `1.1` when zooming in by a 10%.
+    /// This is always `1.0` for `pan` events.
+    pub fn get_scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get how much the fingers moved since the last event.
+    /// This is always `(0, 0)` for `pinch` events.
+    pub fn get_translation(&self) -> CursorPoint {
+        self.translation
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&PlatformEventData> for GestureData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<GestureData>().cloned().unwrap()
+    }
+}
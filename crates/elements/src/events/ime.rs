@@ -0,0 +1,39 @@
+use crate::definitions::PlatformEventData;
+
+/// Data of an IME composition event.
+///
+/// While composing, the input method shows the text being composed (the preedit) before it's committed.
+/// Once committed, the text is sent as a regular `keydown` event and the preedit is cleared with an empty one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImePreeditData {
+    pub preedit: String,
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl ImePreeditData {
+    pub fn new(preedit: String, cursor: Option<(usize, usize)>) -> Self {
+        Self { preedit, cursor }
+    }
+
+    /// Get the text being composed, or an empty text once the composition ends.
+    pub fn get_preedit(&self) -> &str {
+        &self.preedit
+    }
+
+    /// Get the cursor of the input method inside the preedit, as a range of char indices.
+    /// It is `None` when the cursor must be hidden.
+    pub fn get_cursor(&self) -> Option<(usize, usize)> {
+        self.cursor
+    }
+
+    /// Check if there is no text being composed.
+    pub fn is_empty(&self) -> bool {
+        self.preedit.is_empty()
+    }
+}
+
+impl From<&PlatformEventData> for ImePreeditData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<ImePreeditData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,26 @@
+use torin::geometry::Size2D;
+
+use crate::definitions::PlatformEventData;
+
+/// Data of a `resize` event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResizeData {
+    pub size: Size2D,
+}
+
+impl ResizeData {
+    pub fn new(size: Size2D) -> Self {
+        Self { size }
+    }
+
+    /// Get the new size of the element, not including its margin.
+    pub fn get_size(&self) -> Size2D {
+        self.size
+    }
+}
+
+impl From<&PlatformEventData> for ResizeData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<ResizeData>().cloned().unwrap()
+    }
+}
@@ -1,16 +1,49 @@
-use crate::definitions::PlatformEventData;
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+    Arc,
+    Mutex,
+};
+
+use crate::{
+    definitions::PlatformEventData,
+    events::keyboard::Modifiers,
+};
 
 /// Data of a Wheel event.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Wheel events bubble up from the element under the cursor to its ancestors, so the listeners of an element
+/// inside a `ScrollView` run before the ScrollView scrolls, and they can stop it with [`WheelData::prevent_default`].
+///
+/// Scrollable elements consume the part of the delta they can scroll and leave the rest to their scrollable ancestors.
+#[derive(Debug, Clone)]
 pub struct WheelData {
-    #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    modifiers: Modifiers,
+    default_prevented: Arc<AtomicBool>,
+    remaining_delta: Arc<Mutex<(f64, f64)>>,
+}
+
+impl PartialEq for WheelData {
+    fn eq(&self, other: &Self) -> bool {
+        self.delta_x == other.delta_x
+            && self.delta_y == other.delta_y
+            && self.modifiers == other.modifiers
+    }
 }
 
 impl WheelData {
-    pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+    pub fn new(delta_x: f64, delta_y: f64, modifiers: Modifiers) -> Self {
+        Self {
+            delta_x,
+            delta_y,
+            modifiers,
+            default_prevented: Arc::new(AtomicBool::new(false)),
+            remaining_delta: Arc::new(Mutex::new((delta_x, delta_y))),
+        }
     }
 }
 
@@ -24,6 +57,42 @@ impl WheelData {
     pub fn get_delta_y(&self) -> f64 {
         self.delta_y
     }
+
+    /// Get the X delta that was not consumed yet by the previous listeners.
+    pub fn get_remaining_delta_x(&self) -> f64 {
+        self.remaining_delta.lock().unwrap().0
+    }
+
+    /// Get the Y delta that was not consumed yet by the previous listeners.
+    pub fn get_remaining_delta_y(&self) -> f64 {
+        self.remaining_delta.lock().unwrap().1
+    }
+
+    /// Mark part of the X delta as consumed, e.g by scrolling, so the next listeners only get the rest.
+    pub fn consume_delta_x(&self, delta: f64) {
+        self.remaining_delta.lock().unwrap().0 -= delta;
+    }
+
+    /// Mark part of the Y delta as consumed, e.g by scrolling, so the next listeners only get the rest.
+    pub fn consume_delta_y(&self, delta: f64) {
+        self.remaining_delta.lock().unwrap().1 -= delta;
+    }
+
+    /// Get the keyboard modifiers that were pressed while scrolling, e.g `Ctrl` to zoom.
+    pub fn get_modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Stop the built-in scrolling of the ancestors, like the `ScrollView`, from handling this event.
+    /// Unlike `stop_propagation`, the listeners of the ancestors still receive it.
+    pub fn prevent_default(&self) {
+        self.default_prevented.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if [`WheelData::prevent_default`] was called by any of the previous listeners.
+    pub fn is_default_prevented(&self) -> bool {
+        self.default_prevented.load(Ordering::Relaxed)
+    }
 }
 
 impl From<&PlatformEventData> for WheelData {
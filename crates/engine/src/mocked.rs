@@ -199,6 +199,10 @@ impl Matrix {
     pub fn rotate_deg_pivot(_degrees: f32, _pivot: impl Into<Point>) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_translate(&mut self, _v: impl Into<Point>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -436,6 +440,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_foreground_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clear_foreground_color(&mut self) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -632,6 +640,157 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_color_filter(&mut self, _color_filter: impl Into<Option<ColorFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_blend_mode(&mut self, _mode: BlendMode) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(i32)]
+pub enum BlendMode {
+    Clear = 0,
+    Src = 1,
+    Dst = 2,
+    #[default]
+    SrcOver = 3,
+    DstOver = 4,
+    SrcIn = 5,
+    DstIn = 6,
+    SrcOut = 7,
+    DstOut = 8,
+    SrcATop = 9,
+    DstATop = 10,
+    Xor = 11,
+    Plus = 12,
+    Modulate = 13,
+    Screen = 14,
+    Overlay = 15,
+    Darken = 16,
+    Lighten = 17,
+    ColorDodge = 18,
+    ColorBurn = 19,
+    HardLight = 20,
+    SoftLight = 21,
+    Difference = 22,
+    Exclusion = 23,
+    Multiply = 24,
+    Hue = 25,
+    Saturation = 26,
+    Color = 27,
+    Luminosity = 28,
+}
+
+#[derive(Clone)]
+pub struct ColorFilter;
+
+#[derive(Clone)]
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: impl Into<(i32, i32)>,
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn new_n32_premul(
+        _dimensions: impl Into<(i32, i32)>,
+        _color_space: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub mod codec {
+    use super::{
+        Data,
+        IRect,
+        Image,
+        ImageInfo,
+    };
+
+    pub struct Codec;
+
+    impl Codec {
+        pub fn from_data(_data: impl Into<Data>) -> Option<Self> {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn info(&self) -> ImageInfo {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn get_frame_count(&mut self) -> usize {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn get_frame_info(&mut self, _index: usize) -> Option<FrameInfo> {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn get_image<'a>(
+            &mut self,
+            _info: impl Into<Option<ImageInfo>>,
+            _options: impl Into<Option<&'a Options>>,
+        ) -> std::result::Result<Image, Result> {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Result {
+        Success,
+        IncompleteInput,
+        ErrorInInput,
+        InvalidConversion,
+        InvalidScale,
+        InvalidParameters,
+        InvalidInput,
+        CouldNotRewind,
+        InternalError,
+        Unimplemented,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum ZeroInitialized {
+        Yes,
+        No,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Options {
+        pub zero_initialized: ZeroInitialized,
+        pub subset: Option<IRect>,
+        pub frame_index: usize,
+        pub prior_frame: Option<usize>,
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct FrameInfo {
+        pub required_frame: i32,
+        pub duration: i32,
+        pub fully_received: bool,
+    }
+}
+
+pub mod color_filters {
+    use super::{
+        BlendMode,
+        Color,
+        ColorFilter,
+    };
+
+    pub fn blend(_color: impl Into<Color>, _mode: BlendMode) -> Option<ColorFilter> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -646,12 +805,30 @@ impl FontStyle {
     pub fn new(_weight: Weight, _width: Width, _slant: Slant) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn normal() -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[derive(Default, Clone)]
 pub struct FontMgr;
 
 impl FontMgr {
+    pub fn match_family(&self, _family_name: impl AsRef<str>) -> FontStyleSet {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn match_family_style_character(
+        &self,
+        _family_name: impl AsRef<str>,
+        _style: FontStyle,
+        _bcp_47: &[&str],
+        _character: i32,
+    ) -> Option<Typeface> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn new_from_data(
         &self,
         _bytes: &[u8],
@@ -663,6 +840,14 @@ impl FontMgr {
 
 pub struct FontFeature;
 
+pub struct FontStyleSet;
+
+impl FontStyleSet {
+    pub fn count(&mut self) -> usize {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct TypefaceFontProvider;
 
 impl TypefaceFontProvider {
@@ -704,6 +889,14 @@ impl FontCollection {
     pub fn set_dynamic_font_manager(&mut self, _font_manager: impl Into<Option<FontMgr>>) {
         unimplemented!("This is mocked")
     }
+
+    pub fn enable_font_fallback(&mut self) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn fallback_manager(&self) -> Option<FontMgr> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Paragraph;
@@ -821,6 +1014,30 @@ impl Paragraph {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FontRastrSettings {
+    pub edging: Edging,
+    pub subpixel: bool,
+    pub hinting: FontHinting,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Edging {
+    Alias = 0,
+    AntiAlias = 1,
+    SubpixelAntiAlias = 2,
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FontHinting {
+    None = 0,
+    Slight = 1,
+    Normal = 2,
+    Full = 3,
+}
+
 #[derive(Default)]
 pub struct ParagraphStyle;
 
@@ -861,6 +1078,17 @@ impl ParagraphStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn font_rastr_settings(&self) -> &FontRastrSettings {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_font_rastr_settings(
+        &mut self,
+        _font_rastr_settings: FontRastrSettings,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn max_lines(&self) -> Option<usize> {
         unimplemented!("This is mocked")
     }
@@ -968,7 +1196,14 @@ impl From<&FontCollection> for FontCollection {
 
 pub struct StrutStyle;
 
-pub struct TextHeightBehavior;
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TextHeightBehavior {
+    All = 0,
+    DisableFirstAscent = 1,
+    DisableLastDescent = 2,
+    DisableAll = 3,
+}
 
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -1017,6 +1252,34 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_picture(
+        &self,
+        _picture: impl AsRef<Picture>,
+        _matrix: Option<&Matrix>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image(
+        &self,
+        _image: impl AsRef<Image>,
+        _left_top: impl Into<Point>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image_rect(
+        &self,
+        _image: impl AsRef<Image>,
+        _src: Option<(&Rect, SrcRectConstraint)>,
+        _dst: impl AsRef<Rect>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_rect(&self, _rect: Rect, _paint: &Paint) -> &Self {
         unimplemented!("This is mocked")
     }
@@ -1038,6 +1301,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn new_surface(&self, _info: &ImageInfo, _props: Option<&SurfaceProps>) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn scale(&self, _: impl Into<Point>) {
         unimplemented!("This is mocked")
     }
@@ -1057,6 +1324,33 @@ impl Canvas {
     pub fn save_layer_alpha_f(&self, bounds: impl Into<Option<Rect>>, alpha: f32) -> usize {
         unimplemented!("This is mocked")
     }
+
+    pub fn save_layer(&self, _layer_rec: &SaveLayerRec) -> usize {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum SrcRectConstraint {
+    Strict = 0,
+    Fast = 1,
+}
+
+#[derive(Default)]
+pub struct SaveLayerRec<'a> {
+    _bounds: Option<&'a Rect>,
+    _paint: Option<&'a Paint>,
+}
+
+impl<'a> SaveLayerRec<'a> {
+    pub fn bounds(self, _bounds: &'a Rect) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn paint(self, _paint: &'a Paint) -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1095,7 +1389,21 @@ pub enum RectWidthStyle {
     Max,
 }
 
-pub struct LineMetrics;
+pub struct LineMetrics {
+    pub start_index: usize,
+    pub end_index: usize,
+    pub end_excluding_whitespaces: usize,
+    pub end_including_newline: usize,
+    pub hard_break: bool,
+    pub ascent: f64,
+    pub descent: f64,
+    pub unscaled_ascent: f64,
+    pub height: f64,
+    pub width: f64,
+    pub left: f64,
+    pub baseline: f64,
+    pub line_number: usize,
+}
 
 pub struct GlyphClusterInfo;
 
@@ -1109,6 +1417,14 @@ pub struct FontInfo;
 
 pub struct PositionWithAffinity {
     pub position: i32,
+    pub affinity: Affinity,
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Default)]
+pub enum Affinity {
+    Upstream,
+    #[default]
+    Downstream,
 }
 
 pub struct RuntimeEffect;
@@ -1160,18 +1476,52 @@ pub struct Rect {
     pub bottom: f32,
 }
 
+impl AsRef<Rect> for Rect {
+    fn as_ref(&self) -> &Rect {
+        self
+    }
+}
+
 impl Rect {
     pub fn new(_left: f32, _top: f32, _right: f32, _bottom: f32) -> Self {
         unimplemented!("This is mocked")
     }
 }
 
+#[derive(Clone)]
 pub struct Image;
 
 impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn unique_id(&self) -> u32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn encode<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
+}
+
+impl AsRef<Image> for Image {
+    fn as_ref(&self) -> &Image {
+        self
+    }
 }
 
 pub struct Data;
@@ -1184,6 +1534,10 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1199,6 +1553,12 @@ pub struct IRect {
     pub bottom: i32,
 }
 
+impl AsRef<IRect> for IRect {
+    fn as_ref(&self) -> &IRect {
+        self
+    }
+}
+
 impl IRect {
     pub fn new(_left: i32, _top: i32, _right: i32, _bottom: i32) -> Self {
         unimplemented!("This is mocked")
@@ -1223,6 +1583,10 @@ impl Path {
         unimplemented!("This is mocked")
     }
 
+    pub fn contains(&self, _p: impl Into<Point>) -> bool {
+        unimplemented!("This is mocked")
+    }
+
     pub fn add_path(
         &mut self,
         _src: &Path,
@@ -1371,6 +1735,7 @@ pub mod svg {
     use super::{
         Canvas,
         FontMgr,
+        Rect,
         Size,
     };
 
@@ -1381,6 +1746,10 @@ pub mod svg {
             unimplemented!("This is mocked")
         }
 
+        pub fn root(&self) -> Svg {
+            unimplemented!("This is mocked")
+        }
+
         pub fn set_container_size(&mut self, _size: impl Into<Size>) {
             unimplemented!("This is mocked")
         }
@@ -1389,6 +1758,76 @@ pub mod svg {
             unimplemented!("This is mocked")
         }
     }
+
+    pub struct Svg;
+
+    impl Svg {
+        pub fn view_box(&self) -> Option<Rect> {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn set_width(&mut self, _width: Length) {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn set_height(&mut self, _height: Length) {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn set_preserve_aspect_ratio(&mut self, _preserve_aspect_ratio: PreserveAspectRatio) {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    pub struct Length;
+
+    impl Length {
+        pub fn new(_value: f32, _unit: LengthUnit) -> Self {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum LengthUnit {
+        Unknown,
+        Number,
+        Percentage,
+        EMS,
+        EXS,
+        PX,
+        CM,
+        MM,
+        IN,
+        PT,
+        PC,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub struct PreserveAspectRatio {
+        pub align: Align,
+        pub scale: Scale,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum Align {
+        XMinYMin,
+        XMidYMin,
+        XMaxYMin,
+        XMinYMid,
+        XMidYMid,
+        XMaxYMid,
+        XMinYMax,
+        XMidYMax,
+        XMaxYMax,
+        None,
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum Scale {
+        Meet,
+        Slice,
+    }
 }
 
 #[repr(C)]
@@ -1414,10 +1853,40 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src: impl Into<(i32, i32)>,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+
     pub fn swap_buffers(&self, _: &PossiblyCurrentContext) {
         unimplemented!("This is mocked")
     }
 
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn image_snapshot_with_bounds(&mut self, _bounds: impl AsRef<IRect>) -> Option<Image> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn direct_context(&self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn from_backend_render_target(
         _context: &mut RecordingContext,
         _backend_render_target: &BackendRenderTarget,
@@ -1432,14 +1901,59 @@ impl Surface {
 
 pub struct ColorSpace;
 
+pub fn raster(
+    _image_info: &ImageInfo,
+    _row_bytes: impl Into<Option<usize>>,
+    _surface_props: Option<&SurfaceProps>,
+) -> Option<Surface> {
+    unimplemented!("This is mocked")
+}
+
+pub fn raster_n32_premul(_size: impl Into<(i32, i32)>) -> Option<Surface> {
+    unimplemented!("This is mocked")
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
 pub enum ColorType {
     RGBA8888 = 4,
+    BGRA8888 = 6,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
 }
 
 pub struct SurfaceProps;
 
+impl SurfaceProps {
+    pub fn new(_flags: SurfacePropsFlags, _pixel_geometry: PixelGeometry) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct SurfacePropsFlags: u32 {
+        const USE_DEVICE_INDEPENDENT_FONTS = 1;
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum PixelGeometry {
+    Unknown = 0,
+    RGBH = 1,
+    BGRH = 2,
+    RGBV = 3,
+    BGRV = 4,
+}
+
 use std::ops::{
     Deref,
     DerefMut,
@@ -1502,6 +2016,28 @@ impl DirectContext {
     pub fn abandon(&self) {
         unimplemented!("This is mocked")
     }
+
+    pub fn free_gpu_resources(&mut self) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn resource_cache_limit(&self) -> usize {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_resource_cache_limit(&mut self, _max_resource_bytes: usize) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn resource_cache_usage(&self) -> ResourceCacheUsage {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ResourceCacheUsage {
+    pub resource_count: usize,
+    pub resource_bytes: usize,
 }
 
 use std::ffi::c_void;
@@ -1615,6 +2151,8 @@ pub fn set_resource_cache_single_allocation_byte_limit(new_limit: Option<usize>)
     unimplemented!("This is mocked")
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
 pub enum EncodedImageFormat {
     BMP = 0,
     GIF = 1,
@@ -1631,3 +2169,40 @@ pub enum EncodedImageFormat {
     AVIF = 12,
     JPEGXL = 13,
 }
+
+#[derive(Clone)]
+pub struct Picture;
+
+impl Picture {
+    pub fn unique_id(&self) -> u32 {
+        unimplemented!("This is mocked")
+    }
+}
+
+impl AsRef<Picture> for Picture {
+    fn as_ref(&self) -> &Picture {
+        self
+    }
+}
+
+pub struct BBHFactory;
+
+pub struct PictureRecorder;
+
+impl PictureRecorder {
+    pub fn new() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn begin_recording(
+        &mut self,
+        _bounds: impl AsRef<Rect>,
+        _bbh_factory: Option<&mut BBHFactory>,
+    ) -> &Canvas {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn finish_recording_as_picture(&mut self, _cull_rect: Option<&Rect>) -> Option<Picture> {
+        unimplemented!("This is mocked")
+    }
+}
@@ -1,4 +1,11 @@
 pub use skia_safe::{
+    canvas::{
+        SaveLayerRec,
+        SrcRectConstraint,
+    },
+    codec,
+    color_filters,
+    font::Edging,
     font_style::{
         Slant,
         Weight,
@@ -16,6 +23,7 @@ pub use skia_safe::{
         BackendRenderTarget,
         DirectContext,
         RecordingContext,
+        ResourceCacheUsage,
         SurfaceOrigin,
     },
     gradient_shader::GradientShaderColors,
@@ -26,13 +34,18 @@ pub use skia_safe::{
     path::ArcSize,
     rrect::Corner,
     runtime_effect::Uniform,
-    surfaces::raster_n32_premul,
+    surfaces::{
+        raster,
+        raster_n32_premul,
+    },
     svg,
     textlayout::{
         paragraph::GlyphClusterInfo,
+        Affinity,
         Decoration,
         FontCollection,
         FontFeature,
+        FontRastrSettings,
         LineMetrics,
         Paragraph,
         ParagraphBuilder,
@@ -55,32 +68,42 @@ pub use skia_safe::{
         TextStyle,
         TypefaceFontProvider,
     },
+    AlphaType,
+    BlendMode,
     BlurStyle,
     Canvas,
     ClipOp,
     Color,
+    ColorFilter,
     ColorSpace,
     ColorType,
     Data,
     EncodedImageFormat,
     FilterMode,
     FontArguments,
+    FontHinting,
     FontMgr,
     FontStyle,
     IRect,
     Image,
+    ImageInfo,
     MaskFilter,
     Matrix,
     Paint,
     PaintStyle,
     Path,
     PathDirection,
+    Picture,
+    PictureRecorder,
+    PixelGeometry,
     Point,
     RRect,
     Rect,
     RuntimeEffect,
     Shader,
     Surface,
+    SurfaceProps,
+    SurfacePropsFlags,
     TileMode,
     Typeface,
     HSV,
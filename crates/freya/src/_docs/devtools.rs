@@ -8,3 +8,10 @@
 //! [dependencies]
 //! freya = { .., features = ["devtools"] }
 //! ```
+//!
+//! ## Paint order
+//!
+//! Enable the `Paint order` switch in the devtools bar to outline every element of your app over the window,
+//! labeled with its position in the paint order and its resolved and relative layers, e.g `#12 layer 7 relative -2`.
+//! Elements with a higher position are painted on top of the ones with a lower position,
+//! and the relative layer is the value of their `layer` attribute, so you can see how the overlapping of elements was resolved.
@@ -23,4 +23,26 @@
 //! # }
 //! ```
 //!
+//! Then run your app with the `hot-reload` feature of Freya enabled, e.g `cargo run --features freya/hot-reload`.
+//!
 //! That is it!
+//!
+//! ## What can be hot reloaded
+//!
+//! Only the markup inside `rsx!` can be hot reloaded:
+//!
+//! - Adding, removing or reordering elements.
+//! - Changing the values of attributes, including formatted ones like `"{count}"` as long as they use the same variables.
+//! - Changing the text of labels.
+//!
+//! Once a change is received, the components that use the edited `rsx!` are rendered again with it,
+//! and its elements get their styles and layout measured again as if they had been changed by the components themselves.
+//! The components keep their state, so there is no need to go back to where you were.
+//!
+//! ## What can't be hot reloaded
+//!
+//! Anything that is Rust code, like the logic of the components, their hooks, event handlers or
+//! expressions that use new variables, needs to be compiled again.
+//!
+//! These changes are not applied to the running app, which keeps showing the last version of the markup that could be hot reloaded.
+//! Restart your app with `cargo run` to see them, the hot-reload context will start watching the files again.
@@ -1,7 +1,5 @@
 //! # Theming
 //!
-//! <div class="warning">⚠️ As of 2023-12-19, extending the base theme is not supported.</div>
-//!
 //! Freya has built-in support for Theming.
 //!
 //! ### Accessing the current theme
@@ -94,6 +92,59 @@
 //! }
 //! ```
 //!
+//! The theme can also follow the preferred theme of the system, as the `ThemeProvider` updates its children whenever its `theme` changes:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     let preferred_theme = use_preferred_theme();
+//!     let theme = match *preferred_theme.read() {
+//!         PreferredTheme::Light => LIGHT_THEME,
+//!         PreferredTheme::Dark => DARK_THEME,
+//!     };
+//!
+//!     rsx!(
+//!         ThemeProvider {
+//!             theme,
+//!             Button {
+//!                 label {
+//!                     "Hello, World!"
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! ## Nested themes
+//!
+//! A `ThemeProvider` without a `theme` keeps providing the theme of the closest `ThemeProvider` above it.
+//! To only change parts of it for some section of the app, pass the parts to override with `overrides`:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! #[allow(non_snake_case)]
+//! fn Sidebar() -> Element {
+//!     rsx!(
+//!         ThemeProvider {
+//!             overrides: ThemeWith {
+//!                 button: Some(theme_with!(ButtonTheme {
+//!                     background: "rgb(30, 30, 30)".into(),
+//!                 })),
+//!                 ..Default::default()
+//!             },
+//!             Button {
+//!                 label {
+//!                     "Settings"
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! The overrides are applied on top of the outer theme, so they are kept when it changes.
+//!
 //! ## Change theme for an individual component
 //!
 //! Most built-in components have their own theme "override."
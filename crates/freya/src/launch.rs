@@ -186,7 +186,8 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
         SafeDOM,
     };
 
-    let fdom = FreyaDOM::default();
+    let mut config = config;
+    let fdom = FreyaDOM::new(std::mem::take(&mut config.custom_states));
     let sdom = SafeDOM::new(fdom);
 
     #[cfg(feature = "log")]
@@ -216,7 +217,12 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
 
             let hovered_node = Some(Arc::new(Mutex::new(None)));
             let (devtools, devtools_receiver) = Devtools::new();
-            let vdom = with_devtools(app, devtools_receiver.clone(), hovered_node.clone());
+            let vdom = with_devtools(
+                app,
+                devtools_receiver.clone(),
+                hovered_node.clone(),
+                devtools.paint_order_overlay(),
+            );
             (vdom, Some(devtools), hovered_node)
         }
 
@@ -108,7 +108,10 @@ pub mod prelude {
     };
     pub use dioxus_signals::*;
     pub use freya_components::*;
-    pub use freya_core::prelude::PreferredTheme;
+    pub use freya_core::prelude::{
+        PreferredTheme,
+        RenderQuality,
+    };
     pub use freya_elements::{
         elements as dioxus_elements,
         events::*,
@@ -118,6 +121,8 @@ pub mod prelude {
         dynamic_bytes,
         static_bytes,
         CustomAttributeValues,
+        FontStyleState,
+        ReferenceChildren,
     };
     pub use freya_renderer::*;
     pub use torin::prelude::*;
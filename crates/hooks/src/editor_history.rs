@@ -1,5 +1,16 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
 use ropey::Rope;
 
+/// Max amount of changes kept by default in an [`EditorHistory`].
+pub const DEFAULT_MAX_HISTORY_CHANGES: usize = 1000;
+
+/// Max time between typed characters for them to be grouped into the same change.
+const GROUP_TIMEOUT: Duration = Duration::from_millis(500);
+
 #[derive(Clone)]
 pub enum HistoryChange {
     InsertChar { idx: usize, char: char },
@@ -7,12 +18,28 @@ pub enum HistoryChange {
     Remove { idx: usize, text: String },
 }
 
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct EditorHistory {
     pub changes: Vec<HistoryChange>,
     pub current_change: usize,
     // Incremental counter for every change.
     pub version: usize,
+    // Max amount of changes to keep, older changes are discarded.
+    pub max_changes: usize,
+    // When the last change that can still be extended by typing or deleting was made.
+    last_change_at: Option<Instant>,
+}
+
+impl Default for EditorHistory {
+    fn default() -> Self {
+        Self {
+            changes: Vec::default(),
+            current_change: 0,
+            version: 0,
+            max_changes: DEFAULT_MAX_HISTORY_CHANGES,
+            last_change_at: None,
+        }
+    }
 }
 
 impl EditorHistory {
@@ -20,12 +47,44 @@ impl EditorHistory {
         Self::default()
     }
 
+    /// Specify the max amount of changes to keep.
+    pub fn with_max_changes(mut self, max_changes: usize) -> Self {
+        self.max_changes = max_changes;
+        self
+    }
+
     pub fn push_change(&mut self, change: HistoryChange) {
         if self.can_redo() {
             self.changes.drain(self.current_change..);
+            self.last_change_at = None;
+        }
+
+        let now = Instant::now();
+        let groupable = self
+            .last_change_at
+            .map(|last_change_at| now.duration_since(last_change_at) < GROUP_TIMEOUT)
+            .unwrap_or_default();
+
+        let change = match self.changes.last_mut() {
+            Some(last_change) if groupable => match merge_changes(last_change, change) {
+                Ok(()) => None,
+                Err(change) => Some(change),
+            },
+            _ => Some(change),
+        };
+
+        if let Some(change) = change {
+            self.last_change_at = is_groupable(&change).then_some(now);
+            self.changes.push(change);
+
+            if self.changes.len() > self.max_changes {
+                let overflow = self.changes.len() - self.max_changes;
+                self.changes.drain(..overflow);
+            }
+        } else {
+            self.last_change_at = Some(now);
         }
 
-        self.changes.push(change);
         self.current_change = self.changes.len();
 
         self.version += 1;
@@ -59,17 +118,18 @@ impl EditorHistory {
                     rope.insert(*idx, text);
                     idx + text.chars().count()
                 }
-                HistoryChange::InsertChar { idx, char: ch } => {
-                    rope.remove(*idx..*idx + ch.len_utf8());
+                HistoryChange::InsertChar { idx, .. } => {
+                    rope.remove(*idx..*idx + 1);
                     *idx
                 }
                 HistoryChange::InsertText { idx, text } => {
-                    rope.remove(*idx..idx + text.len());
+                    rope.remove(*idx..idx + text.chars().count());
                     *idx
                 }
             };
             self.current_change -= 1;
             self.version += 1;
+            self.last_change_at = None;
             Some(idx_end)
         } else {
             None
@@ -99,6 +159,7 @@ impl EditorHistory {
             };
             self.current_change += 1;
             self.version += 1;
+            self.last_change_at = None;
             Some(idx_end)
         } else {
             None
@@ -106,6 +167,79 @@ impl EditorHistory {
     }
 }
 
+/// Typed characters and single-character deletions can be grouped together.
+fn is_groupable(change: &HistoryChange) -> bool {
+    match change {
+        HistoryChange::InsertChar { .. } => true,
+        HistoryChange::Remove { text, .. } => text.chars().count() == 1,
+        HistoryChange::InsertText { .. } => false,
+    }
+}
+
+/// Try to merge `change` into `last_change`, giving it back if they can't be merged.
+fn merge_changes(
+    last_change: &mut HistoryChange,
+    change: HistoryChange,
+) -> Result<(), HistoryChange> {
+    match (&mut *last_change, change) {
+        // Typing right after the previous character, until a new word starts
+        (
+            HistoryChange::InsertChar {
+                idx: last_idx,
+                char: last_char,
+            },
+            HistoryChange::InsertChar { idx, char },
+        ) if idx == *last_idx + 1 && !starts_word(*last_char, char) => {
+            let idx = *last_idx;
+            let mut text = last_char.to_string();
+            text.push(char);
+            *last_change = HistoryChange::InsertText { idx, text };
+            Ok(())
+        }
+        (
+            HistoryChange::InsertText {
+                idx: last_idx,
+                text: last_text,
+            },
+            HistoryChange::InsertChar { idx, char },
+        ) if idx == *last_idx + last_text.chars().count()
+            && !starts_word(last_text.chars().last().unwrap_or(char), char) =>
+        {
+            last_text.push(char);
+            Ok(())
+        }
+        // Deleting backwards
+        (
+            HistoryChange::Remove {
+                idx: last_idx,
+                text: last_text,
+            },
+            HistoryChange::Remove { idx, text },
+        ) if text.chars().count() == 1 && idx + 1 == *last_idx => {
+            last_text.insert_str(0, &text);
+            *last_idx = idx;
+            Ok(())
+        }
+        // Deleting forwards
+        (
+            HistoryChange::Remove {
+                idx: last_idx,
+                text: last_text,
+            },
+            HistoryChange::Remove { idx, text },
+        ) if text.chars().count() == 1 && idx == *last_idx => {
+            last_text.push_str(&text);
+            Ok(())
+        }
+        (_, change) => Err(change),
+    }
+}
+
+/// A new word starts when a non-whitespace character follows a whitespace.
+fn starts_word(previous: char, next: char) -> bool {
+    previous.is_whitespace() && !next.is_whitespace()
+}
+
 #[cfg(test)]
 mod test {
     use ropey::Rope;
@@ -205,4 +339,63 @@ mod test {
         history.push_change(HistoryChange::InsertChar { idx: 0, char: '.' });
         assert_eq!(history.any_pending_changes(), 0);
     }
+
+    #[test]
+    fn group_typing() {
+        let mut rope = Rope::new();
+        let mut history = EditorHistory::new();
+
+        // Type two words
+        for (idx, ch) in "Hello World".chars().enumerate() {
+            rope.insert_char(idx, ch);
+            history.push_change(HistoryChange::InsertChar { idx, char: ch });
+        }
+
+        // Each word is a change
+        assert_eq!(history.changes.len(), 2);
+
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello ");
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "");
+        assert!(!history.can_undo());
+
+        history.redo(&mut rope);
+        history.redo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello World");
+
+        // Delete backwards
+        for idx in (6..11).rev() {
+            let text = rope.slice(idx..idx + 1).to_string();
+            rope.remove(idx..idx + 1);
+            history.push_change(HistoryChange::Remove { idx, text });
+        }
+        assert_eq!(rope.to_string(), "Hello ");
+        assert_eq!(history.changes.len(), 3);
+
+        // Deleted text comes back at once
+        history.undo(&mut rope);
+        assert_eq!(rope.to_string(), "Hello World");
+    }
+
+    #[test]
+    fn max_changes() {
+        let mut rope = Rope::new();
+        let mut history = EditorHistory::new().with_max_changes(2);
+
+        for (idx, text) in ["a", "b", "c"].into_iter().enumerate() {
+            rope.insert(idx, text);
+            history.push_change(HistoryChange::InsertText {
+                idx,
+                text: text.to_owned(),
+            });
+        }
+
+        // The oldest change was discarded
+        assert_eq!(history.changes.len(), 2);
+        history.undo(&mut rope);
+        history.undo(&mut rope);
+        assert!(!history.can_undo());
+        assert_eq!(rope.to_string(), "a");
+    }
 }
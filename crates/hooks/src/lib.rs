@@ -2,6 +2,7 @@
 //! A collection of hooks to be used in Freya.
 
 mod editor_history;
+mod measure_text;
 mod rope_editor;
 mod shader_uniforms;
 mod text_editor;
@@ -10,20 +11,28 @@ mod theming;
 mod use_activable_route;
 mod use_animation;
 mod use_asset_cacher;
+mod use_breakpoints;
 mod use_canvas;
+mod use_clipboard;
+mod use_close_request;
+mod use_debounce;
 mod use_editable;
 mod use_focus;
+mod use_frame_timings;
 mod use_init_native_platform;
 mod use_node;
 mod use_platform;
 mod use_platform_information;
 mod use_preferred_theme;
 mod use_theme;
+mod use_throttle;
+mod use_user_event;
 
 #[cfg(feature = "use_camera")]
 mod use_camera;
 
 pub use editor_history::*;
+pub use measure_text::*;
 pub use rope_editor::*;
 pub use shader_uniforms::*;
 pub use text_editor::*;
@@ -31,14 +40,21 @@ pub use theming::*;
 pub use use_activable_route::*;
 pub use use_animation::*;
 pub use use_asset_cacher::*;
+pub use use_breakpoints::*;
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
 pub use use_canvas::*;
+pub use use_clipboard::*;
+pub use use_close_request::*;
+pub use use_debounce::*;
 pub use use_editable::*;
 pub use use_focus::*;
+pub use use_frame_timings::*;
 pub use use_init_native_platform::*;
 pub use use_node::*;
 pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_preferred_theme::*;
 pub use use_theme::*;
+pub use use_throttle::*;
+pub use use_user_event::*;
@@ -0,0 +1,42 @@
+use dioxus_core::prelude::consume_context;
+use freya_core::prelude::TextMeasurer;
+use freya_node_state::FontStyleState;
+
+/// Measure how much space the given text takes with the given font styles, without rendering it.
+///
+/// The text is laid out like a `label`, with the same fonts and fallbacks it's rendered with,
+/// wrapping its lines at `max_width` if there is any. Returns its width, height and amount of lines, in logical pixels.
+/// Useful to size a column to its longest text, or to decide whether some text needs to be truncated.
+///
+/// Measurements are cached, but it still lays out text that wasn't measured before, so avoid calling it for every frame.
+/// It must be called from a component, e.g in its body or the handlers of its events.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let font_style = FontStyleState {
+///         font_size: 18.0,
+///         ..FontStyleState::default()
+///     };
+///     let (width, _, _) = measure_text("Some long column title", &font_style, None);
+///
+///     rsx!(
+///         rect {
+///             width: "{width}",
+///             label {
+///                 font_size: "18",
+///                 "Some long column title"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn measure_text(
+    text: &str,
+    font_style: &FontStyleState,
+    max_width: Option<f32>,
+) -> (f32, f32, usize) {
+    consume_context::<TextMeasurer>().measure_text(text, font_style, max_width)
+}
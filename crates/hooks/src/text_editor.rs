@@ -6,20 +6,26 @@ use std::{
 };
 
 use dioxus_sdk::clipboard::UseClipboard;
+use freya_common::CursorAffinity;
 use freya_elements::events::keyboard::{
     Code,
     Key,
     Modifiers,
 };
 
-/// Holds the position of a cursor in a text
+/// Holds the position of a cursor in a text, and which side of a line wrap it's shown at.
 #[derive(Clone, Default, PartialEq, Debug)]
-pub struct TextCursor(usize);
+pub struct TextCursor(usize, CursorAffinity);
 
 impl TextCursor {
     /// Construct a new [TextCursor]
     pub fn new(pos: usize) -> Self {
-        Self(pos)
+        Self(pos, CursorAffinity::Downstream)
+    }
+
+    /// Construct a new [TextCursor] shown at the given side of a line wrap.
+    pub fn with_affinity(pos: usize, affinity: CursorAffinity) -> Self {
+        Self(pos, affinity)
     }
 
     /// Get the position
@@ -27,13 +33,26 @@ impl TextCursor {
         self.0
     }
 
-    /// Set the position
+    /// Get which side of a line wrap the cursor is shown at.
+    pub fn affinity(&self) -> CursorAffinity {
+        self.1
+    }
+
+    /// Set the position, shown at the start of its line.
     pub fn set(&mut self, pos: usize) {
         self.0 = pos;
+        self.1 = CursorAffinity::Downstream;
     }
 
-    /// Write the position
+    /// Set the position along with which side of a line wrap it's shown at.
+    pub fn set_with_affinity(&mut self, pos: usize, affinity: CursorAffinity) {
+        self.0 = pos;
+        self.1 = affinity;
+    }
+
+    /// Write the position, shown at the start of its line.
     pub fn write(&mut self) -> &mut usize {
+        self.1 = CursorAffinity::Downstream;
         &mut self.0
     }
 }
@@ -441,6 +460,16 @@ pub trait TextEditor {
                         }
                     }
 
+                    // Redo last change
+                    Code::KeyZ if meta_or_ctrl && modifiers.contains(Modifiers::SHIFT) => {
+                        let redo_result = self.redo();
+
+                        if let Some(idx) = redo_result {
+                            self.set_cursor_pos(idx);
+                            event.insert(TextEvent::TEXT_CHANGED);
+                        }
+                    }
+
                     // Undo last change
                     Code::KeyZ if meta_or_ctrl => {
                         let undo_result = self.undo();
@@ -507,4 +536,68 @@ pub trait TextEditor {
     fn get_selection_range(&self) -> Option<(usize, usize)>;
 
     fn get_identation(&self) -> u8;
+
+    /// Get the range of the word, or run of whitespaces or punctuation, at the given char position.
+    fn find_word_boundaries(&self, pos: usize) -> (usize, usize) {
+        let row = self.char_to_line(pos);
+        let line_start = self.line_to_char(row);
+        let Some(line) = self.line(row) else {
+            return (pos, pos);
+        };
+        let chars = line
+            .text
+            .chars()
+            .filter(|c| c != &'\r' && c != &'\n')
+            .collect::<Vec<char>>();
+        if chars.is_empty() {
+            return (pos, pos);
+        }
+
+        let col = (pos - line_start).min(chars.len() - 1);
+        let kind = CharKind::from(chars[col]);
+        let start = chars[..col]
+            .iter()
+            .rposition(|c| CharKind::from(*c) != kind)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let end = chars[col..]
+            .iter()
+            .position(|c| CharKind::from(*c) != kind)
+            .map(|i| col + i)
+            .unwrap_or(chars.len());
+
+        (line_start + start, line_start + end)
+    }
+
+    /// Get the range of the line at the given char position, without its line break.
+    fn find_line_boundaries(&self, pos: usize) -> (usize, usize) {
+        let row = self.char_to_line(pos);
+        let line_start = self.line_to_char(row);
+        let len = self
+            .line(row)
+            .map(|line| line.len_chars())
+            .unwrap_or_default();
+
+        (line_start, line_start + len)
+    }
+}
+
+/// Kind of char used to find the boundaries of words.
+#[derive(PartialEq)]
+enum CharKind {
+    Word,
+    Whitespace,
+    Punctuation,
+}
+
+impl From<char> for CharKind {
+    fn from(c: char) -> Self {
+        if c.is_alphanumeric() || c == '_' {
+            Self::Word
+        } else if c.is_whitespace() {
+            Self::Whitespace
+        } else {
+            Self::Punctuation
+        }
+    }
 }
@@ -605,6 +605,141 @@ pub struct Theme {
     pub bottom_tab: BottomTabTheme,
 }
 
+/// Parts of a [`Theme`] to override, used by nested theme providers to only change some of the themes of their parent.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct ThemeWith {
+    pub name: Option<&'static str>,
+    pub body: Option<BodyThemeWith>,
+    pub button: Option<ButtonThemeWith>,
+    pub switch: Option<SwitchThemeWith>,
+    pub scroll_bar: Option<ScrollBarThemeWith>,
+    pub scroll_view: Option<ScrollViewThemeWith>,
+    pub slider: Option<SliderThemeWith>,
+    pub tooltip: Option<TooltipThemeWith>,
+    pub dropdown: Option<DropdownThemeWith>,
+    pub dropdown_item: Option<DropdownItemThemeWith>,
+    pub accordion: Option<AccordionThemeWith>,
+    pub loader: Option<LoaderThemeWith>,
+    pub link: Option<LinkThemeWith>,
+    pub progress_bar: Option<ProgressBarThemeWith>,
+    pub table: Option<TableThemeWith>,
+    pub input: Option<InputThemeWith>,
+    pub canvas: Option<CanvasThemeWith>,
+    pub graph: Option<GraphThemeWith>,
+    pub network_image: Option<NetworkImageThemeWith>,
+    pub icon: Option<IconThemeWith>,
+    pub sidebar: Option<SidebarThemeWith>,
+    pub sidebar_item: Option<SidebarItemThemeWith>,
+    pub tile: Option<TileThemeWith>,
+    pub radio: Option<RadioThemeWith>,
+    pub checkbox: Option<CheckboxThemeWith>,
+    pub menu_item: Option<MenuItemThemeWith>,
+    pub menu_container: Option<MenuContainerThemeWith>,
+    pub snackbar: Option<SnackBarThemeWith>,
+    pub popup: Option<PopupThemeWith>,
+    pub tab: Option<TabThemeWith>,
+    pub bottom_tab: Option<BottomTabThemeWith>,
+}
+
+impl Theme {
+    /// Override the parts of this [`Theme`] that are specified in the given [`ThemeWith`].
+    pub fn apply_optional(&mut self, optional: &ThemeWith) {
+        if let Some(name) = optional.name {
+            self.name = name;
+        }
+        if let Some(body) = &optional.body {
+            self.body.apply_optional(body);
+        }
+        if let Some(button) = &optional.button {
+            self.button.apply_optional(button);
+        }
+        if let Some(switch) = &optional.switch {
+            self.switch.apply_optional(switch);
+        }
+        if let Some(scroll_bar) = &optional.scroll_bar {
+            self.scroll_bar.apply_optional(scroll_bar);
+        }
+        if let Some(scroll_view) = &optional.scroll_view {
+            self.scroll_view.apply_optional(scroll_view);
+        }
+        if let Some(slider) = &optional.slider {
+            self.slider.apply_optional(slider);
+        }
+        if let Some(tooltip) = &optional.tooltip {
+            self.tooltip.apply_optional(tooltip);
+        }
+        if let Some(dropdown) = &optional.dropdown {
+            self.dropdown.apply_optional(dropdown);
+        }
+        if let Some(dropdown_item) = &optional.dropdown_item {
+            self.dropdown_item.apply_optional(dropdown_item);
+        }
+        if let Some(accordion) = &optional.accordion {
+            self.accordion.apply_optional(accordion);
+        }
+        if let Some(loader) = &optional.loader {
+            self.loader.apply_optional(loader);
+        }
+        if let Some(link) = &optional.link {
+            self.link.apply_optional(link);
+        }
+        if let Some(progress_bar) = &optional.progress_bar {
+            self.progress_bar.apply_optional(progress_bar);
+        }
+        if let Some(table) = &optional.table {
+            self.table.apply_optional(table);
+        }
+        if let Some(input) = &optional.input {
+            self.input.apply_optional(input);
+        }
+        if let Some(canvas) = &optional.canvas {
+            self.canvas.apply_optional(canvas);
+        }
+        if let Some(graph) = &optional.graph {
+            self.graph.apply_optional(graph);
+        }
+        if let Some(network_image) = &optional.network_image {
+            self.network_image.apply_optional(network_image);
+        }
+        if let Some(icon) = &optional.icon {
+            self.icon.apply_optional(icon);
+        }
+        if let Some(sidebar) = &optional.sidebar {
+            self.sidebar.apply_optional(sidebar);
+        }
+        if let Some(sidebar_item) = &optional.sidebar_item {
+            self.sidebar_item.apply_optional(sidebar_item);
+        }
+        if let Some(tile) = &optional.tile {
+            self.tile.apply_optional(tile);
+        }
+        if let Some(radio) = &optional.radio {
+            self.radio.apply_optional(radio);
+        }
+        if let Some(checkbox) = &optional.checkbox {
+            self.checkbox.apply_optional(checkbox);
+        }
+        if let Some(menu_item) = &optional.menu_item {
+            self.menu_item.apply_optional(menu_item);
+        }
+        if let Some(menu_container) = &optional.menu_container {
+            self.menu_container.apply_optional(menu_container);
+        }
+        if let Some(snackbar) = &optional.snackbar {
+            self.snackbar.apply_optional(snackbar);
+        }
+        if let Some(popup) = &optional.popup {
+            self.popup.apply_optional(popup);
+        }
+        if let Some(tab) = &optional.tab {
+            self.tab.apply_optional(tab);
+        }
+        if let Some(bottom_tab) = &optional.bottom_tab {
+            self.bottom_tab.apply_optional(bottom_tab);
+        }
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
         LIGHT_THEME
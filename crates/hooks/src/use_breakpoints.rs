@@ -0,0 +1,79 @@
+use dioxus_core::prelude::{
+    try_consume_context,
+    use_hook,
+};
+use dioxus_signals::Readable;
+use freya_core::prelude::NativePlatformReceiver;
+
+use crate::use_platform_information;
+
+/// Values that depend on the width of the window, to be resolved with [`use_breakpoints`].
+///
+/// Starting from a base value, every breakpoint declares the value to use when the window
+/// is at least as wide as its minimum width, in logical pixels.
+/// When more than one matches, the one with the largest minimum width wins, regardless of the order they were declared in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakpoints<T> {
+    base: T,
+    breakpoints: Vec<(f32, T)>,
+}
+
+impl<T> Breakpoints<T> {
+    /// Create the breakpoints with the value to use when none of them match.
+    pub fn new(base: T) -> Self {
+        Self {
+            base,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Use `value` when the window is at least `min_width` wide.
+    pub fn min_width(mut self, min_width: f32, value: T) -> Self {
+        self.breakpoints.push((min_width, value));
+        self
+    }
+
+    /// Get the value for the given window width.
+    pub fn resolve(&self, width: f32) -> &T {
+        self.breakpoints
+            .iter()
+            .filter(|(min_width, _)| width >= *min_width)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, value)| value)
+            .unwrap_or(&self.base)
+    }
+}
+
+/// Pick a value depending on the width of the window, like the attributes of a responsive layout.
+/// The component is rendered again with the new value when the window is resized across a breakpoint.
+///
+/// The breakpoints are only created once.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     // A column in narrow windows and a row in windows at least 600 wide
+///     let direction = use_breakpoints(|| Breakpoints::new("vertical").min_width(600.0, "horizontal"));
+///
+///     rsx!(
+///         rect {
+///             direction,
+///             label { "Sidebar" }
+///             label { "Content" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_breakpoints<T: Clone + 'static>(init: impl FnOnce() -> Breakpoints<T>) -> T {
+    let breakpoints = use_hook(init);
+    let platform_information = use_platform_information();
+
+    let scale_factor = use_hook(try_consume_context::<NativePlatformReceiver>)
+        .map(|platform| platform.borrow().scale_factor)
+        .unwrap_or(1.0) as f32;
+    let width = platform_information.read().viewport_size.width / scale_factor;
+
+    breakpoints.resolve(width).clone()
+}
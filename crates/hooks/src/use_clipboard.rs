@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+
+use arboard::{
+    Clipboard as ImageClipboard,
+    ImageData,
+};
+use bytes::Bytes;
+use dioxus_core::use_hook;
+use dioxus_sdk::clipboard::{
+    use_clipboard as use_system_clipboard,
+    UseClipboard as SystemClipboard,
+};
+use dioxus_signals::{
+    Signal,
+    Writable,
+};
+
+/// Errors of [`UseClipboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UseClipboardError {
+    /// The clipboard could not be written.
+    FailedToSet,
+    /// The bytes of the image don't match its size.
+    InvalidImage,
+}
+
+/// An image in the clipboard, as RGBA pixels with 8 bits per channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    pub width: usize,
+    pub height: usize,
+    /// Pixels of the image, row by row from the top left corner.
+    pub bytes: Bytes,
+}
+
+/// Handle to read and write the system clipboard. Use [`use_clipboard`] to create one.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseClipboard {
+    clipboard: SystemClipboard,
+    // Created when first used, and kept alive as some platforms drop the images written by it along with it
+    images_clipboard: Signal<Option<ImageClipboard>>,
+}
+
+impl UseClipboard {
+    /// Get the text in the clipboard.
+    ///
+    /// Returns `None` if the clipboard is not available, empty, or it contains something other than text.
+    pub fn get_text(&self) -> Option<String> {
+        self.clipboard.get().ok().filter(|text| !text.is_empty())
+    }
+
+    /// Replace the content of the clipboard with the given text.
+    pub fn set_text(&mut self, text: &str) -> Result<(), UseClipboardError> {
+        self.clipboard
+            .set(text.to_string())
+            .map_err(|_| UseClipboardError::FailedToSet)
+    }
+
+    /// Get the image in the clipboard.
+    ///
+    /// Returns `None` if the clipboard is not available, empty, or it contains something other than an image.
+    pub fn get_image(&self) -> Option<ClipboardImage> {
+        let image = self.with_images_clipboard(|clipboard| clipboard.get_image().ok())??;
+
+        Some(ClipboardImage {
+            width: image.width,
+            height: image.height,
+            bytes: Bytes::from(image.bytes.into_owned()),
+        })
+    }
+
+    /// Replace the content of the clipboard with the given image.
+    pub fn set_image(&mut self, image: &ClipboardImage) -> Result<(), UseClipboardError> {
+        if image.bytes.len() != image.width * image.height * 4 {
+            return Err(UseClipboardError::InvalidImage);
+        }
+
+        self.with_images_clipboard(|clipboard| {
+            clipboard.set_image(ImageData {
+                width: image.width,
+                height: image.height,
+                bytes: Cow::Borrowed(&image.bytes),
+            })
+        })
+        .and_then(Result::ok)
+        .ok_or(UseClipboardError::FailedToSet)
+    }
+
+    fn with_images_clipboard<T>(&self, cb: impl FnOnce(&mut ImageClipboard) -> T) -> Option<T> {
+        let mut images_clipboard = self.images_clipboard;
+        let mut images_clipboard = images_clipboard.write();
+        if images_clipboard.is_none() {
+            *images_clipboard = ImageClipboard::new().ok();
+        }
+        images_clipboard.as_mut().map(cb)
+    }
+}
+
+/// Access the system clipboard from any component.
+///
+/// ## Usage
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut clipboard = use_clipboard();
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| {
+///                 clipboard.set_text("https://freyaui.dev").ok();
+///             },
+///             label {
+///                 "Copy link"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_clipboard() -> UseClipboard {
+    let clipboard = use_system_clipboard();
+    let images_clipboard = use_hook(|| Signal::new(None));
+
+    UseClipboard {
+        clipboard,
+        images_clipboard,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use crate::{
+        use_clipboard,
+        ClipboardImage,
+        UseClipboardError,
+    };
+
+    #[tokio::test]
+    pub async fn set_invalid_image() {
+        fn use_clipboard_app() -> Element {
+            let mut clipboard = use_clipboard();
+            let result = use_hook(|| {
+                clipboard.set_image(&ClipboardImage {
+                    width: 2,
+                    height: 2,
+                    bytes: vec![0; 3].into(),
+                })
+            });
+            let is_invalid = result == Err(UseClipboardError::InvalidImage);
+
+            rsx!(label { "{is_invalid}" })
+        }
+
+        let mut utils = launch_test(use_clipboard_app);
+        utils.wait_for_update().await;
+
+        // Images whose bytes don't match their size are never written
+        assert_eq!(utils.root().get(0).get(0).text(), Some("true"));
+    }
+}
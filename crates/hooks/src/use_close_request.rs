@@ -0,0 +1,136 @@
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    rc::Rc,
+};
+
+use dioxus_core::{
+    prelude::{
+        consume_context,
+        provide_context,
+        spawn,
+        try_consume_context,
+        use_drop,
+        use_hook,
+    },
+    ScopeId,
+};
+use freya_core::prelude::CloseRequestSender;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::UsePlatform;
+
+type CloseRequestHandler = Box<dyn FnMut() -> bool>;
+
+/// Handlers of all the components using [use_close_request], shared from the root scope.
+#[derive(Clone, Default)]
+struct CloseRequestHandlers {
+    handlers: Rc<RefCell<Vec<(usize, CloseRequestHandler)>>>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl CloseRequestHandlers {
+    /// Listen for the close requests of the window from the root scope, so it outlives the components using them.
+    fn listen() -> Self {
+        ScopeId::ROOT.in_runtime(|| {
+            let handlers = provide_context(CloseRequestHandlers::default());
+            let platform = UsePlatform::new();
+            let mut receiver = consume_context::<CloseRequestSender>().subscribe();
+
+            let listener = handlers.clone();
+            spawn(async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(()) | Err(RecvError::Lagged(_)) => {
+                            if listener.allow_close() {
+                                platform.exit();
+                            }
+                        }
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+
+            handlers
+        })
+    }
+
+    fn insert(&self, handler: CloseRequestHandler) -> usize {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.handlers.borrow_mut().push((id, handler));
+        id
+    }
+
+    fn remove(&self, id: usize) {
+        self.handlers
+            .borrow_mut()
+            .retain(|(handler_id, _)| *handler_id != id);
+    }
+
+    /// Call every handler, so all of them know about the request, and only allow it if none of them kept the window open.
+    fn allow_close(&self) -> bool {
+        self.handlers
+            .borrow_mut()
+            .iter_mut()
+            .fold(true, |allowed, (_, handler)| handler() && allowed)
+    }
+}
+
+/// Decide what happens when the window is requested to close, e.g with the close button of its title bar.
+///
+/// The `handler` is called every time the window is requested to close, and the app exits if it returns `true`.
+/// Returning `false` keeps the window open, so it can ask for a confirmation first and then close the app
+/// with [`UsePlatform::exit`](crate::UsePlatform::exit) once it's confirmed.
+/// While there is any component using this hook the window doesn't close by itself. If several handlers
+/// are used all of them are called, and the app only exits if none of them returns `false`.
+///
+/// The `handler` is only read when the hook is first created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let unsaved_changes = use_signal(|| true);
+///     let mut show_dialog = use_signal(|| false);
+///
+///     use_close_request(move || {
+///         if *unsaved_changes.read() {
+///             // Keep the window open and ask first
+///             show_dialog.set(true);
+///             false
+///         } else {
+///             true
+///         }
+///     });
+///
+///     rsx!(
+///         if *show_dialog.read() {
+///             label { "You have unsaved changes, close anyway?" }
+///             Button {
+///                 onpress: move |_| platform.exit(),
+///                 label { "Close" }
+///             }
+///             Button {
+///                 onpress: move |_| show_dialog.set(false),
+///                 label { "Cancel" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_close_request(handler: impl FnMut() -> bool + 'static) {
+    let (handlers, id) = use_hook(move || {
+        let handlers = try_consume_context::<CloseRequestHandlers>()
+            .unwrap_or_else(CloseRequestHandlers::listen);
+        let id = handlers.insert(Box::new(handler));
+        (handlers, id)
+    });
+
+    // The window closes by itself again once no component is using it
+    use_drop(move || handlers.remove(id));
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Task,
+};
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use tokio::time::sleep;
+
+/// Debounced callback. Use [`use_debounce`] to create one.
+pub struct UseDebounce<T: 'static> {
+    callback: Signal<Box<dyn FnMut(T)>>,
+    task: Signal<Option<Task>>,
+    delay: Duration,
+}
+
+impl<T: 'static> Clone for UseDebounce<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseDebounce<T> {}
+
+impl<T: 'static> PartialEq for UseDebounce<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback && self.task == other.task && self.delay == other.delay
+    }
+}
+
+impl<T: 'static> UseDebounce<T> {
+    /// Schedule the callback to be called with `data` once the delay has passed,
+    /// discarding any call that was still pending.
+    pub fn action(&self, data: T) {
+        self.cancel();
+
+        let mut callback = self.callback;
+        let mut task = self.task;
+        let delay = self.delay;
+
+        let new_task = spawn(async move {
+            sleep(delay).await;
+            task.write().take();
+            (*callback.write())(data);
+        });
+
+        task.write().replace(new_task);
+    }
+
+    /// Cancel the pending call, if any.
+    pub fn cancel(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    }
+
+    /// Check if there is a call waiting for the delay to pass.
+    pub fn is_pending(&self) -> bool {
+        self.task.read().is_some()
+    }
+}
+
+/// Wrap a callback so it only gets called once its calls have stopped for the given `delay`.
+///
+/// Useful for things like search-as-you-type, where only the last input matters.
+/// Pending calls are cancelled once the component is dropped.
+///
+/// The `delay` is only read when the hook is first created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     let mut value = use_signal(String::new);
+///     let debounce = use_debounce(Duration::from_millis(300), move |text: String| {
+///         query.set(text);
+///     });
+///
+///     rsx!(
+///         Input {
+///             value: value.read().clone(),
+///             onchange: move |text: String| {
+///                 value.set(text.clone());
+///                 debounce.action(text);
+///             }
+///         }
+///         label {
+///             "Searching: {query}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_debounce<T: 'static>(
+    delay: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseDebounce<T> {
+    let debounce = use_hook(|| UseDebounce {
+        callback: Signal::new(Box::new(callback) as Box<dyn FnMut(T)>),
+        task: Signal::new(None),
+        delay,
+    });
+
+    use_drop(move || debounce.cancel());
+
+    debounce
+}
@@ -1,7 +1,16 @@
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 use dioxus_core::{
-    prelude::spawn,
+    prelude::{
+        spawn,
+        try_consume_context,
+    },
     use_hook,
     AttributeValue,
 };
@@ -12,12 +21,16 @@ use dioxus_signals::{
     Writable,
 };
 use freya_common::{
+    CursorAffinity,
     CursorLayoutResponse,
+    CursorLineMovement,
     EventMessage,
     TextGroupMeasurement,
 };
 use freya_elements::events::{
+    keyboard::Modifiers,
     Code,
+    ImePreeditData,
     KeyboardData,
     MouseData,
 };
@@ -33,12 +46,19 @@ use crate::{
     use_platform,
     EditorHistory,
     RopeEditor,
+    SelectionDragMark,
     TextCursor,
     TextEditor,
     TextEvent,
     UsePlatform,
 };
 
+/// Maximum time between two clicks for them to be grouped in a double or triple click.
+const MULTI_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Maximum distance between two clicks for them to be grouped in a double or triple click.
+const MULTI_CLICK_DISTANCE: f64 = 4.0;
+
 /// Events emitted to the [`UseEditable`].
 pub enum EditableEvent {
     Click,
@@ -46,6 +66,7 @@ pub enum EditableEvent {
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
     KeyUp(Rc<KeyboardData>),
+    ImePreedit(Rc<ImePreeditData>),
 }
 
 /// How the editable content must behave.
@@ -116,6 +137,15 @@ impl TextDragging {
     }
 }
 
+/// What to select around the cursor once the layout has measured it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum SelectionGranularity {
+    /// Select the word under the cursor, after a double click.
+    Word,
+    /// Select the line under the cursor, after a triple click.
+    Line,
+}
+
 /// Manage an editable content.
 #[derive(Clone, Copy, PartialEq)]
 pub struct UseEditable {
@@ -124,6 +154,14 @@ pub struct UseEditable {
     pub(crate) dragging: Signal<TextDragging>,
     pub(crate) platform: UsePlatform,
     pub(crate) allow_tabs: bool,
+    pub(crate) visual_line_navigation: bool,
+    /// `cursor_id` of the paragraph the cursor is moved across lines in, the last one interacted with.
+    pub(crate) cursor_id: Signal<usize>,
+    pub(crate) cursor_line_x: Signal<Option<f32>>,
+    pub(crate) last_click: Signal<Option<(Instant, CursorPoint, usize)>>,
+    pub(crate) pending_selection: Signal<Option<SelectionGranularity>>,
+    pub(crate) ime_preedit: Signal<ImePreeditData>,
+    pub(crate) selection_drag: Option<Signal<SelectionDragMark>>,
 }
 
 impl UseEditable {
@@ -137,6 +175,22 @@ impl UseEditable {
         &mut self.editor
     }
 
+    /// Text being composed with the IME of the platform, which must be shown at the cursor until it's committed.
+    pub fn ime_preedit(&self) -> &Signal<ImePreeditData> {
+        &self.ime_preedit
+    }
+
+    /// Position of the cursor in the visible text with the IME preedit inserted at the editor cursor.
+    pub fn visible_cursor_pos_with_preedit(&self) -> usize {
+        let cursor_pos = self.editor.read().visible_cursor_pos();
+        let preedit = self.ime_preedit.read();
+        let preedit_cursor = preedit
+            .get_cursor()
+            .map(|(_, end)| end)
+            .unwrap_or_else(|| preedit.get_preedit().chars().count());
+        cursor_pos + preedit_cursor
+    }
+
     /// Create a cursor attribute.
     pub fn cursor_attr(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::CursorReference(
@@ -144,6 +198,11 @@ impl UseEditable {
         ))
     }
 
+    /// Which side of a line wrap the cursor is shown at, to be used in the `cursor_affinity` attribute.
+    pub fn cursor_affinity(&self) -> CursorAffinity {
+        self.editor.read().cursor().affinity()
+    }
+
     /// Create a highlights attribute.
     pub fn highlights_attr(&self, editor_id: usize) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::TextHighlights(
@@ -159,12 +218,44 @@ impl UseEditable {
     pub fn process_event(&mut self, edit_event: &EditableEvent) {
         let res = match edit_event {
             EditableEvent::MouseDown(e, id) => {
+                if *self.cursor_id.peek() != *id {
+                    self.cursor_id.set(*id);
+                }
                 let coords = e.get_element_coordinates();
+                let clicks = self.count_click(e.get_screen_coordinates());
+                let shift_selecting = matches!(
+                    &*self.dragging.peek(),
+                    TextDragging::FromCursorToPoint { shift: true, .. }
+                );
 
-                self.dragging.write().set_cursor_coords(coords);
                 self.editor.write().clear_selection();
+                self.cursor_line_x.set(None);
 
-                Some((*id, Some(coords), None))
+                if clicks == 1 || shift_selecting {
+                    self.dragging.write().set_cursor_coords(coords);
+
+                    Some((*id, Some(coords), None))
+                } else {
+                    // Select the word or line under the cursor once the layout measures it
+                    *self.dragging.write() = TextDragging::None;
+                    self.pending_selection.set(Some(if clicks == 2 {
+                        SelectionGranularity::Word
+                    } else {
+                        SelectionGranularity::Line
+                    }));
+
+                    self.platform
+                        .send(EventMessage::RemeasureTextGroup(TextGroupMeasurement {
+                            text_id: self.cursor_reference.peek().text_id,
+                            cursor_id: *id,
+                            cursor_position: Some(coords),
+                            cursor_selection: None,
+                            cursor_line_movement: None,
+                        }))
+                        .unwrap();
+
+                    None
+                }
             }
             EditableEvent::MouseOver(e, id) => {
                 if let Some(src) = self.dragging.peek().get_cursor_coords() {
@@ -212,8 +303,17 @@ impl UseEditable {
                     }
                     // Do not write Tabs
                     Code::Tab if !self.allow_tabs => {}
+                    // Move across the visual lines of the paragraph
+                    Code::ArrowUp | Code::ArrowDown if self.visual_line_navigation => {
+                        let lines = if e.code == Code::ArrowUp { -1 } else { 1 };
+                        self.move_cursor_across_lines(
+                            lines,
+                            e.modifiers.contains(Modifiers::SHIFT),
+                        );
+                    }
                     // Handle editing
                     _ => {
+                        self.cursor_line_x.set(None);
                         let event = self
                             .editor
                             .write()
@@ -237,10 +337,35 @@ impl UseEditable {
                     *self.dragging.write() = TextDragging::None;
                 }
 
+                None
+            }
+            EditableEvent::ImePreedit(e) => {
+                if *self.ime_preedit.peek() != **e {
+                    self.ime_preedit.set((**e).clone());
+                }
+
                 None
             }
         };
 
+        // Let the scroll views know where the text selection being dragged was started
+        if let Some(mut selection_drag) = self.selection_drag {
+            let started_at = match edit_event {
+                EditableEvent::MouseDown(e, _) => self
+                    .dragging
+                    .peek()
+                    .get_cursor_coords()
+                    .map(|_| e.get_screen_coordinates()),
+                _ if self.dragging.peek().get_cursor_coords().is_some() => {
+                    selection_drag.peek().started_at()
+                }
+                _ => None,
+            };
+            if selection_drag.peek().started_at() != started_at {
+                selection_drag.write().set_started_at(started_at);
+            }
+        }
+
         if let Some((cursor_id, cursor_position, cursor_selection)) = res {
             if self.dragging.peek().has_cursor_coords() {
                 self.platform
@@ -249,11 +374,57 @@ impl UseEditable {
                         cursor_id,
                         cursor_position,
                         cursor_selection,
+                        cursor_line_movement: None,
                     }))
                     .unwrap()
             }
         }
     }
+
+    /// Count the consecutive clicks close in time and position to the previous one,
+    /// going back to a single click after a triple click.
+    fn count_click(&mut self, position: CursorPoint) -> usize {
+        let clicks = match *self.last_click.peek() {
+            Some((time, last_position, clicks))
+                if time.elapsed() <= MULTI_CLICK_INTERVAL
+                    && (position - last_position).length() <= MULTI_CLICK_DISTANCE =>
+            {
+                clicks % 3 + 1
+            }
+            _ => 1,
+        };
+        self.last_click
+            .set(Some((Instant::now(), position, clicks)));
+        clicks
+    }
+
+    /// Ask the layout to move the cursor the given amount of visual lines, keeping its horizontal offset.
+    fn move_cursor_across_lines(&mut self, lines: isize, select: bool) {
+        let (position, affinity) = {
+            let mut editor = self.editor.write();
+            if select {
+                editor.expand_selection_to_cursor();
+            } else {
+                editor.clear_selection();
+            }
+            (editor.visible_cursor_pos(), editor.cursor().affinity())
+        };
+
+        self.platform
+            .send(EventMessage::RemeasureTextGroup(TextGroupMeasurement {
+                text_id: self.cursor_reference.peek().text_id,
+                cursor_id: *self.cursor_id.peek(),
+                cursor_position: None,
+                cursor_selection: None,
+                cursor_line_movement: Some(CursorLineMovement {
+                    position,
+                    affinity,
+                    lines,
+                    x: *self.cursor_line_x.peek(),
+                }),
+            }))
+            .unwrap()
+    }
 }
 
 /// Create a configuration for a [`UseEditable`].
@@ -262,6 +433,8 @@ pub struct EditableConfig {
     pub(crate) cursor: TextCursor,
     pub(crate) identation: u8,
     pub(crate) allow_tabs: bool,
+    pub(crate) visual_line_navigation: bool,
+    pub(crate) cursor_id: usize,
 }
 
 impl EditableConfig {
@@ -272,6 +445,8 @@ impl EditableConfig {
             cursor: TextCursor::default(),
             identation: 4,
             allow_tabs: false,
+            visual_line_navigation: false,
+            cursor_id: 0,
         }
     }
 
@@ -292,6 +467,24 @@ impl EditableConfig {
         self.allow_tabs = allow_tabs;
         self
     }
+
+    /// Specify whether the Up and Down arrows should move the cursor across the visual lines
+    /// of the paragraph, wrapped lines included, instead of the lines of the text.
+    ///
+    /// Only supported by [`EditableMode::MultipleLinesSingleEditor`].
+    pub fn with_visual_line_navigation(mut self, visual_line_navigation: bool) -> Self {
+        self.visual_line_navigation = visual_line_navigation;
+        self
+    }
+
+    /// Specify the `cursor_id` of the paragraph of the editor, `0` by default.
+    ///
+    /// It's used to move the cursor across the visual lines until the paragraph is pressed,
+    /// which then tells its id.
+    pub fn with_cursor_id(mut self, cursor_id: usize) -> Self {
+        self.cursor_id = cursor_id;
+        self
+    }
 }
 
 /// Create a virtual text editor with it's own cursor and rope.
@@ -311,6 +504,8 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
             EditorHistory::new(),
         ));
         let dragging = Signal::new(TextDragging::None);
+        let mut cursor_line_x = Signal::new(None);
+        let mut pending_selection = Signal::new(None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
             text_id,
@@ -321,10 +516,33 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
             while let Some(message) = cursor_receiver.recv().await {
                 match message {
                     // Update the cursor position calculated by the layout
-                    CursorLayoutResponse::CursorPosition { position, id } => {
+                    CursorLayoutResponse::CursorPosition {
+                        position,
+                        affinity,
+                        id,
+                    } => {
                         let mut text_editor = editor.write();
-                        let new_cursor = text_editor
-                            .measure_new_cursor(text_editor.utf16_cu_to_char(position), id);
+                        let new_cursor = TextCursor::with_affinity(
+                            text_editor
+                                .measure_new_cursor(text_editor.utf16_cu_to_char(position), id)
+                                .pos(),
+                            affinity,
+                        );
+
+                        // Select the word or line under the cursor after a double or triple click
+                        if let Some(granularity) = pending_selection.write().take() {
+                            let (from, to) = match granularity {
+                                SelectionGranularity::Word => {
+                                    text_editor.find_word_boundaries(new_cursor.pos())
+                                }
+                                SelectionGranularity::Line => {
+                                    text_editor.find_line_boundaries(new_cursor.pos())
+                                }
+                            };
+                            text_editor.set_selection((from, to));
+                            text_editor.set_cursor_pos(to);
+                            continue;
+                        }
 
                         // Only update and clear the selection if the cursor has changed
                         if *text_editor.cursor() != new_cursor {
@@ -339,6 +557,23 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
                             }
                         }
                     }
+                    // Update the cursor moved across lines by the layout
+                    CursorLayoutResponse::CursorLineMoved {
+                        position,
+                        affinity,
+                        x,
+                        ..
+                    } => {
+                        let mut text_editor = editor.write();
+                        let new_cursor = text_editor.utf16_cu_to_char(position);
+                        text_editor
+                            .cursor_mut()
+                            .set_with_affinity(new_cursor, affinity);
+                        if text_editor.has_any_selection() {
+                            text_editor.expand_selection_to_cursor();
+                        }
+                        cursor_line_x.set(Some(x));
+                    }
                     // Update the text selections calculated by the layout
                     CursorLayoutResponse::TextSelection { from, to, id } => {
                         let current_cursor = editor.peek().cursor().clone();
@@ -378,6 +613,14 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
             dragging,
             platform,
             allow_tabs: config.allow_tabs,
+            visual_line_navigation: config.visual_line_navigation
+                && mode == EditableMode::MultipleLinesSingleEditor,
+            cursor_id: Signal::new(config.cursor_id),
+            cursor_line_x,
+            last_click: Signal::new(None),
+            pending_selection,
+            ime_preedit: Signal::new(ImePreeditData::default()),
+            selection_drag: try_consume_context(),
         }
     })
 }
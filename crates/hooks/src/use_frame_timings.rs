@@ -0,0 +1,35 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Signal,
+    Writable,
+};
+use freya_core::prelude::{
+    FrameTimings,
+    FrameTimingsReceiver,
+};
+
+/// Subscribe to the [FrameTimings] of every rendered frame, useful to know whether an app is layout or render bound.
+///
+/// The renderer only collects them when the `frame-timings` feature is enabled, otherwise they stay at their defaults.
+/// Keep in mind that showing them will make the component rerender, and so the app render a new frame, every frame.
+pub fn use_frame_timings() -> ReadOnlySignal<FrameTimings> {
+    use_hook(|| {
+        let mut timings = Signal::new(FrameTimings::default());
+
+        if let Some(mut receiver) = try_consume_context::<FrameTimingsReceiver>() {
+            spawn(async move {
+                while receiver.changed().await.is_ok() {
+                    let frame_timings = *receiver.borrow();
+                    timings.set(frame_timings);
+                }
+            });
+        }
+
+        timings.into()
+    })
+}
@@ -18,6 +18,7 @@ use dioxus_signals::{
     Writable,
 };
 use freya_core::prelude::NativePlatformReceiver;
+use torin::geometry::CursorPoint;
 
 use crate::use_init_asset_cacher;
 pub type AccessibilityIdCounter = Rc<RefCell<u64>>;
@@ -35,6 +36,20 @@ impl NavigationMark {
     }
 }
 
+/// Where the text selection being dragged was started, so scroll views only auto scroll while selecting text.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct SelectionDragMark(Option<CursorPoint>);
+
+impl SelectionDragMark {
+    pub fn started_at(&self) -> Option<CursorPoint> {
+        self.0
+    }
+
+    pub fn set_started_at(&mut self, started_at: Option<CursorPoint>) {
+        self.0 = started_at;
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct UsePlatformEvents {
     pub navigation_mark: Signal<NavigationMark>,
@@ -51,6 +66,9 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
     // Init the NavigationMark signal
     let navigation_mark = use_context_provider(|| Signal::new(NavigationMark(true)));
 
+    // Init the SelectionDragMark signal
+    use_context_provider(|| Signal::new(SelectionDragMark::default()));
+
     // Init the signals with platform values
     use_hook(|| {
         let mut platform_receiver = consume_context::<NativePlatformReceiver>();
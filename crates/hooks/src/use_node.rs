@@ -11,14 +11,21 @@ use dioxus_signals::{
     Signal,
     Writable,
 };
-use freya_common::NodeReferenceLayout;
+use freya_common::{
+    NodeReferenceLayout,
+    TextLineMetrics,
+};
 use freya_node_state::{
     CustomAttributeValues,
     NodeReference,
+    ReferenceChildren,
 };
 use tokio::sync::watch::channel;
 
 /// Subscribe to a Node layout changes.
+///
+/// The area is in window coordinates, so it's also updated when any of the ancestors of the Node
+/// is moved, resized or scrolled.
 pub fn use_node() -> (AttributeValue, NodeReferenceLayout) {
     let (tx, signal) = use_hook(|| {
         let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
@@ -64,12 +71,191 @@ pub fn use_node_signal() -> (AttributeValue, ReadOnlySignal<NodeReferenceLayout>
     )
 }
 
+/// Like [use_node_signal], but the layout also reports what's specified by `children` about the
+/// children of the Node, e.g the snap points of a scroll container.
+pub fn use_node_signal_with_children(
+    children: ReferenceChildren,
+) -> (AttributeValue, ReadOnlySignal<NodeReferenceLayout>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(NodeReferenceLayout::default());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                if *signal.peek() != *rx.borrow() {
+                    signal.set(rx.borrow().clone());
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::ChildrenReference(
+            NodeReference(tx),
+            children,
+        )),
+        signal.into(),
+    )
+}
+
+/// Subscribe to whether a Node is visible, i.e if any part of it is inside of the window and of all
+/// its ancestors that clip it, like a `ScrollView`.
+///
+/// `buffer` extends the area of the Node in every direction, so it's considered visible a bit before it
+/// actually gets scrolled into view.
+pub fn use_node_visibility(buffer: f32) -> (AttributeValue, ReadOnlySignal<bool>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(false);
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                let is_visible = rx.borrow().is_visible(buffer);
+                if *signal.peek() != is_visible {
+                    signal.set(is_visible);
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx))),
+        signal.into(),
+    )
+}
+
+/// Subscribe to the metrics of the visual lines of a `label` or `paragraph`, wrapped lines included.
+///
+/// Useful to paint something aligned to the lines of a text, like a gutter with the line numbers of a code editor
+/// or a highlight behind the line of the cursor. They are measured from the same text layout used to render it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, text_lines) = use_text_lines();
+///
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             rect {
+///                 width: "30",
+///                 for line in text_lines.read().iter() {
+///                     label {
+///                         key: "{line.line_number}",
+///                         position: "absolute",
+///                         position_top: "{line.top()}",
+///                         "{line.line_number + 1}"
+///                     }
+///                 }
+///             }
+///             paragraph {
+///                 reference,
+///                 width: "200",
+///                 text {
+///                     "Hello, World! This text is long enough to be wrapped."
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_lines() -> (AttributeValue, ReadOnlySignal<Vec<TextLineMetrics>>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(Vec::new());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                if *signal.peek() != rx.borrow().text_lines {
+                    signal.set(rx.borrow().text_lines.clone());
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx))),
+        signal.into(),
+    )
+}
+
+/// Subscribe to whether the text of a `label` or `paragraph` has more lines than its `max_lines`,
+/// so some of them are cut off.
+///
+/// Useful to only offer expanding a text when it doesn't fit in its `max_lines`. It's measured from
+/// the same text layout used to render it, so it changes along with the width of the text.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, exceeded_max_lines) = use_exceeded_max_lines();
+///     let mut expanded = use_signal(|| false);
+///     let is_expanded = *expanded.read();
+///     let max_lines = if is_expanded { "none" } else { "2" };
+///     let button_text = if is_expanded { "Show less" } else { "Show more" };
+///
+///     rsx!(
+///         paragraph {
+///             reference,
+///             width: "200",
+///             max_lines: "{max_lines}",
+///             text_overflow: "ellipsis",
+///             text {
+///                 "Hello, World! This text is long enough to need more than two lines to be shown completely."
+///             }
+///         }
+///         if is_expanded || *exceeded_max_lines.read() {
+///             Button {
+///                 onpress: move |_| expanded.set(!is_expanded),
+///                 label { "{button_text}" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_exceeded_max_lines() -> (AttributeValue, ReadOnlySignal<bool>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut signal = Signal::new(false);
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                let exceeded_max_lines = rx.borrow().exceeded_max_lines;
+                if *signal.peek() != exceeded_max_lines {
+                    signal.set(exceeded_max_lines);
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx))),
+        signal.into(),
+    )
+}
+
 #[cfg(test)]
 mod test {
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
-    use crate::use_node;
+    use crate::{
+        use_exceeded_max_lines,
+        use_node,
+        use_text_lines,
+    };
 
     #[tokio::test]
     pub async fn track_size() {
@@ -112,4 +298,162 @@ mod test {
             Ok(300.0 * 0.5)
         );
     }
+
+    #[tokio::test]
+    pub async fn track_window_coordinates() {
+        fn use_node_app() -> Element {
+            let (reference, size) = use_node();
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "300",
+                        width: "100%",
+                    }
+                    rect {
+                        padding: "20 30",
+                        rect {
+                            reference: reference,
+                            width: "100",
+                            height: "100",
+                        }
+                    }
+                    rect {
+                        height: "300",
+                        width: "100%",
+                    }
+                }
+                label {
+                    "{size.area.min_x()} {size.area.min_y()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_node_app);
+        utils.wait_for_update().await;
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("30 320"));
+
+        // Scrolling an ancestor moves it too
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -200.).into(),
+            cursor: (5., 5.).into(),
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("30 120"));
+    }
+
+    #[tokio::test]
+    pub async fn text_lines() {
+        fn use_text_lines_app() -> Element {
+            let (reference, text_lines) = use_text_lines();
+            let lines = text_lines.read();
+            let same_height = lines
+                .windows(2)
+                .all(|pair| pair[0].height == pair[1].height);
+            let contiguous = lines
+                .windows(2)
+                .all(|pair| (pair[1].top() - pair[0].bottom()).abs() < 0.01);
+            let starts_at_top = lines.first().is_some_and(|line| line.top().abs() < 0.01);
+            let total_height = lines.iter().map(|line| line.height).sum::<f32>();
+
+            rsx!(
+                label {
+                    reference,
+                    width: "100",
+                    "Rustaceans Rustaceans Rustaceans"
+                }
+                label {
+                    "{lines.len()} {same_height} {contiguous} {starts_at_top}"
+                }
+                label {
+                    "{total_height}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_text_lines_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // One line per word, stacked one below the other
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("3 true true true"));
+
+        // That take the whole height of the text
+        let text_area = utils.root().get(0).area().unwrap();
+        let total_height = utils.root().get(2).get(0).text().unwrap().parse::<f32>();
+        assert_eq!(total_height.map(f32::round), Ok(text_area.height().round()));
+    }
+
+    #[tokio::test]
+    pub async fn exceeded_max_lines() {
+        fn use_exceeded_max_lines_app() -> Element {
+            let (long_reference, long_exceeded) = use_exceeded_max_lines();
+            let (short_reference, short_exceeded) = use_exceeded_max_lines();
+
+            rsx!(
+                label {
+                    reference: long_reference,
+                    width: "100",
+                    max_lines: "1",
+                    "Rustaceans Rustaceans Rustaceans"
+                }
+                label {
+                    reference: short_reference,
+                    width: "100",
+                    max_lines: "1",
+                    "Rustaceans"
+                }
+                label {
+                    "{long_exceeded} {short_exceeded}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_exceeded_max_lines_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Only the text that needs more lines than its `max_lines` is cut off
+        let label = utils.root().get(2);
+        assert_eq!(label.get(0).text(), Some("true false"));
+    }
+
+    #[tokio::test]
+    pub async fn exceeded_max_lines_with_middle_ellipsis() {
+        fn use_exceeded_max_lines_app() -> Element {
+            let (long_reference, long_exceeded) = use_exceeded_max_lines();
+            let (short_reference, short_exceeded) = use_exceeded_max_lines();
+
+            rsx!(
+                label {
+                    reference: long_reference,
+                    width: "100",
+                    text_overflow: "ellipsis-middle",
+                    "Rustaceans Rustaceans Rustaceans"
+                }
+                label {
+                    reference: short_reference,
+                    width: "100",
+                    text_overflow: "ellipsis-middle",
+                    "Rustaceans"
+                }
+                label {
+                    "{long_exceeded} {short_exceeded}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_exceeded_max_lines_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The text trimmed from its middle to fit in one line also exceeded it
+        let label = utils.root().get(2);
+        assert_eq!(label.get(0).text(), Some("true false"));
+    }
 }
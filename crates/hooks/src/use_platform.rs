@@ -10,9 +10,16 @@ use dioxus_signals::{
     Signal,
 };
 use freya_common::EventMessage;
+use freya_engine::prelude::DirectContext;
+use freya_native_core::NodeId;
 use tokio::sync::{
     broadcast,
     mpsc::UnboundedSender,
+    oneshot,
+};
+use torin::prelude::{
+    Area,
+    CursorPoint,
 };
 use winit::{
     event_loop::EventLoopProxy,
@@ -125,6 +132,123 @@ impl UsePlatform {
         self.send(EventMessage::RequestRerender).ok();
     }
 
+    /// Get a [RedrawHandle], which unlike [UsePlatform] can be sent to other threads and async tasks.
+    pub fn redraw_handle(&self) -> RedrawHandle {
+        RedrawHandle {
+            event_loop_proxy: self.event_loop_proxy.peek().clone(),
+            platform_emitter: self.platform_emitter.peek().clone(),
+        }
+    }
+
+    /// Emit a mouse movement at the current cursor position, useful after moving content under the cursor,
+    /// e.g when auto scrolling while selecting text.
+    pub fn replay_cursor_movement(&self) {
+        self.send(EventMessage::ReplayCursorMovement).ok();
+    }
+
+    /// Get the raw [EventLoopProxy] of the app, for integrations that need to wake up or drive the winit event loop.
+    /// This is `None` when no event loop is running, e.g in headless tests.
+    /// Prefer [`use_user_event`](crate::use_user_event) to send custom events into the components.
+    pub fn event_loop_proxy(&self) -> Option<EventLoopProxy<EventMessage>> {
+        self.event_loop_proxy.peek().clone()
+    }
+
+    /// Capture the app as it is rendered in the next frame, encoded as a PNG image at the current size and scale of the Window.
+    /// Pass an area, e.g the one of an element from [`use_node_signal`](crate::use_node_signal), to only capture that part of the Window.
+    ///
+    /// Returns `None` if it couldn't be captured, e.g when the area is outside of the Window.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///
+    ///     let onclick = move |_| {
+    ///         spawn(async move {
+    ///             if let Some(png) = platform.screenshot(None).await {
+    ///                 std::fs::write("screenshot.png", png).ok();
+    ///             }
+    ///         });
+    ///     };
+    ///
+    ///     rsx!(Button {
+    ///         onclick,
+    ///         label { "Save as image" }
+    ///     })
+    /// }
+    /// ```
+    pub async fn screenshot(&self, area: Option<Area>) -> Option<Vec<u8>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventMessage::CaptureScreenshot(
+            area,
+            Box::new(move |png| {
+                sender.send(png).ok();
+            }),
+        ))
+        .ok()?;
+        receiver.await.ok().flatten()
+    }
+
+    /// Get the topmost element at the given point of the Window, found just like the element that receives the events at that point.
+    /// Their `layer`, `corner_radius`, `hit_padding` and the clipping of their ancestors are all taken into account,
+    /// so this is useful for custom drag and drop or tooltips logic.
+    ///
+    /// Returns `None` if there is no element at that point.
+    pub async fn node_at_point(&self, point: CursorPoint) -> Option<NodeId> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventMessage::NodeAtPoint(
+            point,
+            Box::new(move |node_id| {
+                sender.send(node_id).ok();
+            }),
+        ))
+        .ok()?;
+        receiver.await.ok().flatten()
+    }
+
+    /// Access the GPU context the Window is rendered with, e.g to manage the memory taken by its resources.
+    ///
+    /// The context can't leave the thread the Window is rendered in, so the callback is sent to and run in that thread, once the current events are processed.
+    /// It's not called if the app is not rendered with the GPU, e.g in headless tests or with software rendering.
+    pub fn with_gpu_context(&self, cb: impl FnOnce(&mut DirectContext) + 'static + Send + Sync) {
+        self.send(EventMessage::WithGpuContext(Box::new(cb))).ok();
+    }
+
+    /// Submit the pending GPU work of the renderer.
+    pub fn flush_gpu(&self) {
+        self.with_gpu_context(|gr_context| gr_context.flush_and_submit());
+    }
+
+    /// Free all the GPU resources cached by the renderer, e.g the textures of images that are no longer shown.
+    /// The ones still in use are created again in the next frame, so this is meant for when the app is under memory pressure
+    /// or after rendering a lot of transient content.
+    pub fn purge_gpu_resources(&self) {
+        self.with_gpu_context(|gr_context| gr_context.free_gpu_resources());
+    }
+
+    /// Set how many bytes the GPU resources cache can take before the least recently used resources start being freed.
+    pub fn set_gpu_resource_cache_limit(&self, bytes: usize) {
+        self.with_gpu_context(move |gr_context| gr_context.set_resource_cache_limit(bytes));
+    }
+
+    /// Get how much memory the GPU resources cache is taking.
+    ///
+    /// Returns `None` if the app is not rendered with the GPU.
+    pub async fn gpu_resource_cache_usage(&self) -> Option<GpuResourceCacheUsage> {
+        let (sender, receiver) = oneshot::channel();
+        self.with_gpu_context(move |gr_context| {
+            let usage = gr_context.resource_cache_usage();
+            sender
+                .send(GpuResourceCacheUsage {
+                    resources: usage.resource_count,
+                    bytes: usage.resource_bytes,
+                    limit: gr_context.resource_cache_limit(),
+                })
+                .ok();
+        });
+        receiver.await.ok()
+    }
+
     pub fn new_ticker(&self) -> Ticker {
         Ticker {
             inner: self.ticker.peek().resubscribe(),
@@ -137,11 +261,51 @@ impl UsePlatform {
     }
 }
 
+/// Memory taken by the GPU resources cache of the renderer, see [UsePlatform::gpu_resource_cache_usage].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GpuResourceCacheUsage {
+    /// How many resources are cached.
+    pub resources: usize,
+    /// How many bytes the cached resources take.
+    pub bytes: usize,
+    /// How many bytes the cache can take.
+    pub limit: usize,
+}
+
 /// Get access to information and features of the platform.
 pub fn use_platform() -> UsePlatform {
     use_hook(UsePlatform::new)
 }
 
+/// Get a [RedrawHandle] to request new frames from anywhere, e.g a task spawned in a custom Tokio runtime.
+pub fn use_redraw_handle() -> RedrawHandle {
+    use_hook(|| UsePlatform::new().redraw_handle())
+}
+
+/// Requests the app to render a new frame once, so the renderer can otherwise sleep while nothing changes.
+///
+/// Unlike [UsePlatform] it can be sent to other threads and async tasks, so they can wake the UI up
+/// after updating some state that is not tracked by the components, e.g once an async result arrives.
+/// Continuous animations should use [UsePlatform::new_ticker] instead, which ticks on every frame.
+#[derive(Clone)]
+pub struct RedrawHandle {
+    event_loop_proxy: Option<EventLoopProxy<EventMessage>>,
+    platform_emitter: Option<UnboundedSender<EventMessage>>,
+}
+
+impl RedrawHandle {
+    /// Request the app to render a new frame.
+    pub fn request_redraw(&self) {
+        if let Some(event_loop_proxy) = &self.event_loop_proxy {
+            event_loop_proxy
+                .send_event(EventMessage::RequestRerender)
+                .ok();
+        } else if let Some(platform_emitter) = &self.platform_emitter {
+            platform_emitter.send(EventMessage::RequestRerender).ok();
+        }
+    }
+}
+
 pub struct Ticker {
     inner: broadcast::Receiver<()>,
 }
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_drop,
+    use_hook,
+    Task,
+};
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use tokio::time::{
+    sleep_until,
+    Instant,
+};
+
+/// Throttled callback. Use [`use_throttle`] to create one.
+pub struct UseThrottle<T: 'static> {
+    callback: Signal<Box<dyn FnMut(T)>>,
+    last_run: Signal<Option<Instant>>,
+    pending: Signal<Option<T>>,
+    task: Signal<Option<Task>>,
+    delay: Duration,
+}
+
+impl<T: 'static> Clone for UseThrottle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseThrottle<T> {}
+
+impl<T: 'static> PartialEq for UseThrottle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback
+            && self.last_run == other.last_run
+            && self.pending == other.pending
+            && self.task == other.task
+            && self.delay == other.delay
+    }
+}
+
+impl<T: 'static> UseThrottle<T> {
+    /// Call the callback with `data` right away if it hasn't been called in the last `delay`,
+    /// otherwise schedule it for the end of the current window with the most recent `data`.
+    pub fn action(&self, data: T) {
+        let mut callback = self.callback;
+        let mut last_run = self.last_run;
+        let mut pending = self.pending;
+        let mut task = self.task;
+        let delay = self.delay;
+
+        let now = Instant::now();
+        let last = *last_run.peek();
+        let next_run = last.map(|last_run| last_run + delay);
+
+        match next_run {
+            Some(next_run) if next_run > now => {
+                pending.set(Some(data));
+
+                if task.peek().is_none() {
+                    let new_task = spawn(async move {
+                        sleep_until(next_run).await;
+                        task.write().take();
+                        if let Some(data) = pending.write().take() {
+                            last_run.set(Some(Instant::now()));
+                            (*callback.write())(data);
+                        }
+                    });
+                    task.write().replace(new_task);
+                }
+            }
+            _ => {
+                last_run.set(Some(now));
+                (*callback.write())(data);
+            }
+        }
+    }
+
+    /// Cancel the call scheduled for the end of the current window, if any.
+    pub fn cancel(&self) {
+        let mut task = self.task;
+        let mut pending = self.pending;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        pending.write().take();
+    }
+
+    /// Check if there is a call scheduled for the end of the current window.
+    pub fn is_pending(&self) -> bool {
+        self.task.read().is_some()
+    }
+}
+
+/// Wrap a callback so it gets called at most once every `delay`.
+///
+/// The first call runs immediately, calls made during the following `delay` are
+/// merged into a single call with the latest data once the window ends.
+/// Useful for high-frequency events like scroll or mouse movement.
+/// Pending calls are cancelled once the component is dropped.
+///
+/// The `delay` is only read when the hook is first created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut position = use_signal(|| (0.0, 0.0));
+///     let throttle = use_throttle(Duration::from_millis(100), move |coords: (f64, f64)| {
+///         position.set(coords);
+///     });
+///
+///     rsx!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             onmouseover: move |e: MouseEvent| {
+///                 let coords = e.get_screen_coordinates();
+///                 throttle.action((coords.x, coords.y));
+///             },
+///             label {
+///                 "{position:?}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_throttle<T: 'static>(
+    delay: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseThrottle<T> {
+    let throttle = use_hook(|| UseThrottle {
+        callback: Signal::new(Box::new(callback) as Box<dyn FnMut(T)>),
+        last_run: Signal::new(None),
+        pending: Signal::new(None),
+        task: Signal::new(None),
+        delay,
+    });
+
+    use_drop(move || throttle.cancel());
+
+    throttle
+}
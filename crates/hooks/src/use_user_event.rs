@@ -0,0 +1,90 @@
+use dioxus_core::prelude::{
+    spawn,
+    use_hook,
+};
+use tokio::sync::mpsc::{
+    unbounded_channel,
+    UnboundedSender,
+};
+
+/// Send events of type `T` into the UI thread from anywhere. Use [`use_user_event`] to create one.
+///
+/// Unlike the components state it can be sent to other threads, so it's a way of bridging
+/// background services or custom Tokio runtimes into the app without polling.
+pub struct UserEventSender<T> {
+    sender: UnboundedSender<T>,
+}
+
+impl<T> Clone for UserEventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UserEventSender<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sender.same_channel(&other.sender)
+    }
+}
+
+impl<T> UserEventSender<T> {
+    /// Send an event to the handler of the component, waking up the event loop.
+    /// The event is given back if the component has been dropped.
+    pub fn send(&self, event: T) -> Result<(), T> {
+        self.sender.send(event).map_err(|err| err.0)
+    }
+}
+
+/// Handle events of type `T` sent from outside of the components, e.g other threads.
+///
+/// The `handler` is called in the UI thread with every event sent through the returned [`UserEventSender`],
+/// so it can update signals and anything else that isn't `Send`.
+/// Events stop being handled once the component is dropped.
+///
+/// The `handler` is only read when the hook is first created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use std::{thread, time::Duration};
+/// # use freya::prelude::*;
+/// enum ServiceEvent {
+///     Progress(u8),
+/// }
+///
+/// fn app() -> Element {
+///     let mut progress = use_signal(|| 0);
+///     let sender = use_user_event(move |event: ServiceEvent| match event {
+///         ServiceEvent::Progress(value) => progress.set(value),
+///     });
+///
+///     use_hook(move || {
+///         thread::spawn(move || {
+///             for value in 0..=100 {
+///                 thread::sleep(Duration::from_millis(50));
+///                 if sender.send(ServiceEvent::Progress(value)).is_err() {
+///                     break;
+///                 }
+///             }
+///         });
+///     });
+///
+///     rsx!(label { "Progress: {progress}%" })
+/// }
+/// ```
+pub fn use_user_event<T: 'static>(mut handler: impl FnMut(T) + 'static) -> UserEventSender<T> {
+    use_hook(move || {
+        let (sender, mut receiver) = unbounded_channel::<T>();
+
+        // Receiving wakes up the event loop, which then polls this task in the UI thread
+        spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                handler(event);
+            }
+        });
+
+        UserEventSender { sender }
+    })
+}
@@ -0,0 +1,48 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn measure_text_like_labels() {
+    fn measure_text_app() -> Element {
+        let font_style = FontStyleState {
+            font_size: 20.0,
+            ..FontStyleState::default()
+        };
+        let (width, height, lines) = measure_text("Hello, World!", &font_style, None);
+        let (_, wrapped_height, wrapped_lines) =
+            measure_text("Hello, World!", &font_style, Some(width / 2.0));
+
+        rsx!(
+            label {
+                font_size: "20",
+                "Hello, World!"
+            }
+            label {
+                "{width} {height} {lines} {wrapped_height} {wrapped_lines}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(measure_text_app);
+    let root = utils.root();
+    utils.wait_for_update().await;
+
+    let measured = root.get(1).get(0).text().unwrap().to_string();
+    let measured = measured
+        .split(' ')
+        .map(|value| value.parse::<f32>().unwrap())
+        .collect::<Vec<_>>();
+    let [width, height, lines, wrapped_height, wrapped_lines] = measured[..] else {
+        panic!("Unexpected measurement {measured:?}");
+    };
+
+    // Same size as the rendered label
+    let label = root.get(0).area().unwrap();
+    assert!((width - label.width()).abs() <= 1.0);
+    assert!((height - label.height()).abs() <= 1.0);
+    assert_eq!(lines, 1.0);
+
+    // Wraps in the given width
+    assert_eq!(wrapped_lines, 2.0);
+    assert!(wrapped_height > height);
+}
@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn resolve_breakpoints() {
+    let breakpoints = Breakpoints::new("small")
+        .min_width(900.0, "large")
+        .min_width(600.0, "medium");
+
+    assert_eq!(*breakpoints.resolve(300.0), "small");
+    assert_eq!(*breakpoints.resolve(600.0), "medium");
+    // The largest matching breakpoint wins
+    assert_eq!(*breakpoints.resolve(1000.0), "large");
+}
+
+#[tokio::test]
+async fn use_breakpoints() {
+    fn use_breakpoints_app() -> Element {
+        let direction =
+            use_breakpoints(|| Breakpoints::new("vertical").min_width(600.0, "horizontal"));
+
+        rsx!(rect { direction })
+    }
+
+    let mut utils = launch_test_with_config(
+        use_breakpoints_app,
+        TestingConfig {
+            size: (500.0, 400.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(0).state().size.direction,
+        DirectionMode::Vertical
+    );
+
+    utils.resize((700.0, 400.0).into());
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(0).state().size.direction,
+        DirectionMode::Horizontal
+    );
+}
@@ -0,0 +1,113 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn close_without_handler() {
+    fn app() -> Element {
+        rsx!(rect {})
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    utils.request_close();
+    assert!(utils.has_exited());
+}
+
+#[tokio::test]
+async fn confirm_close_request() {
+    fn use_close_request_app() -> Element {
+        let mut requests = use_signal(|| 0);
+
+        use_close_request(move || {
+            requests += 1;
+            // Only close once it has been requested twice
+            *requests.peek() > 1
+        });
+
+        rsx!(label { "{requests}" })
+    }
+
+    let mut utils = launch_test(use_close_request_app);
+    utils.wait_for_update().await;
+
+    // The handler keeps the window open
+    utils.request_close();
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("1"));
+    assert!(!utils.has_exited());
+
+    // And then allows it to close
+    utils.request_close();
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("2"));
+    assert!(utils.has_exited());
+}
+
+#[tokio::test]
+async fn exit_after_close_request() {
+    fn use_close_request_app() -> Element {
+        let platform = use_platform();
+
+        use_close_request(|| false);
+
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            onclick: move |_| platform.exit(),
+        })
+    }
+
+    let mut utils = launch_test(use_close_request_app);
+    utils.wait_for_update().await;
+
+    utils.request_close();
+    utils.wait_for_update().await;
+    assert!(!utils.has_exited());
+
+    // Close it later, e.g once confirmed in a dialog
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (50.0, 50.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert!(utils.has_exited());
+}
+
+#[tokio::test]
+async fn any_handler_keeps_the_window_open() {
+    #[component]
+    fn Handler(allow: bool) -> Element {
+        let mut requests = use_signal(|| 0);
+
+        use_close_request(move || {
+            requests += 1;
+            allow
+        });
+
+        rsx!(label { "{requests}" })
+    }
+
+    fn use_close_request_app() -> Element {
+        rsx!(
+            Handler { allow: true }
+            Handler { allow: false }
+        )
+    }
+
+    let mut utils = launch_test(use_close_request_app);
+    utils.wait_for_update().await;
+
+    utils.request_close();
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Both handlers were asked, but one of them keeps the window open
+    assert_eq!(utils.root().get(0).get(0).text(), Some("1"));
+    assert_eq!(utils.root().get(1).get(0).text(), Some("1"));
+    assert!(!utils.has_exited());
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use freya::{
+    events::pointer::MouseButton,
+    prelude::*,
+};
+use freya_testing::prelude::*;
+use tokio::time::advance;
+
+#[tokio::test(start_paused = true)]
+pub async fn debounce_calls() {
+    fn use_debounce_app() -> Element {
+        let mut calls = use_signal(|| 0);
+        let mut last = use_signal(|| 0);
+        let mut clicks = use_signal(|| 0);
+        let debounce = use_debounce(Duration::from_millis(50), move |n: i32| {
+            calls += 1;
+            last.set(n);
+        });
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    clicks += 1;
+                    debounce.action(*clicks.read());
+                },
+                label {
+                    "{calls} {last}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_debounce_app);
+
+    // Initial state
+    utils.wait_for_update().await;
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("0 0"));
+
+    // Click a few times in a row
+    for _ in 0..3 {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+
+    // Nothing has been called yet
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("0 0"));
+
+    // Only the last call went through
+    advance(Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0).get(0);
+    assert_eq!(label.get(0).text(), Some("1 3"));
+}
@@ -1261,3 +1261,462 @@ pub async fn replace_text() {
         assert_eq!(cursor.text(), Some("0:6"));
     }
 }
+
+#[tokio::test]
+pub async fn visual_lines_single_editor() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || {
+                EditableConfig::new("Hello Rustaceans Hello Rustaceans".to_string())
+                    .with_cursor(3)
+                    .with_visual_line_navigation(true)
+            },
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+
+        let onkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                cursor_reference: cursor_attr,
+                paragraph {
+                    height: "50%",
+                    width: "100",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    onkeydown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{editor.cursor_row()}:{editor.cursor_pos()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    // Initial state
+    let root = utils.root().get(0);
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:3"));
+
+    // Move cursor to the wrapped line below
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::ArrowDown,
+        key: Key::ArrowDown,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The cursor is still in the first line of the text, but further in it
+    let cursor = root.get(1).get(0);
+    let (row, pos) = cursor.text().unwrap().split_once(':').unwrap();
+    assert_eq!(row, "0");
+    assert!(pos.parse::<usize>().unwrap() > 6);
+
+    // Move cursor back up
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::ArrowUp,
+        key: Key::ArrowUp,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The cursor came back to the same column
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:3"));
+
+    // Insert a new line
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::Enter,
+        key: Key::Enter,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("Hel\nlo Rustaceans Hello Rustaceans"));
+    assert_eq!(cursor.text(), Some("1:4"));
+}
+
+#[tokio::test]
+pub async fn visual_lines_with_cursor_id() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || {
+                EditableConfig::new("Hello Rustaceans Hello Rustaceans".to_string())
+                    .with_cursor(3)
+                    .with_cursor_id(1)
+                    .with_visual_line_navigation(true)
+            },
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+
+        let onkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                cursor_reference: cursor_attr,
+                paragraph {
+                    height: "50%",
+                    width: "100",
+                    cursor_id: "1",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    onkeydown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{editor.cursor_row()}:{editor.cursor_pos()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:3"));
+
+    // Move cursor to the wrapped line below
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::ArrowDown,
+        key: Key::ArrowDown,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The paragraph with the editor `cursor_id` moved it
+    let cursor = root.get(1).get(0);
+    let (row, pos) = cursor.text().unwrap().split_once(':').unwrap();
+    assert_eq!(row, "0");
+    assert!(pos.parse::<usize>().unwrap() > 6);
+}
+
+#[tokio::test]
+pub async fn double_and_triple_click_selection() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\n".repeat(2)),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let highlights = editable.highlights_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onmouseover = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseOver(e.data, 0));
+        };
+
+        let onclick = move |_: MouseEvent| {
+            editable.process_event(&EditableEvent::Click);
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                cursor_reference,
+                paragraph {
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    highlights,
+                    onclick,
+                    onmousedown,
+                    onmouseover,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+            }
+        )
+    }
+
+    async fn click(utils: &mut TestingHandler) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (80.0, 3.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (80.0, 3.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    let mut utils = launch_test(use_editable_app);
+    let root = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    // A single click only moves the cursor
+    click(&mut utils).await;
+    let highlights = root.child(0).unwrap().state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![]));
+
+    // A double click selects the word under the cursor
+    click(&mut utils).await;
+    let highlights = root.child(0).unwrap().state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![(6, 16)]));
+
+    // A triple click selects the whole line
+    click(&mut utils).await;
+    let highlights = root.child(0).unwrap().state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![(0, 16)]));
+}
+
+#[tokio::test]
+pub async fn cursor_affinity_at_line_wraps() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || {
+                EditableConfig::new("Hello Rustaceans Hello Rustaceans".to_string())
+                    .with_visual_line_navigation(true)
+            },
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+        let cursor_affinity = editable.cursor_affinity();
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                cursor_reference: cursor_attr,
+                paragraph {
+                    height: "50%",
+                    width: "100",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_affinity: "{cursor_affinity}",
+                    cursor_color: "red",
+                    cursor_mode: "editable",
+                    onmousedown,
+                    onkeydown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{editor.cursor_pos()}:{cursor_affinity}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+    let root = utils.root().get(0);
+
+    // Topmost row painted with the cursor
+    let cursor_top = |utils: &mut TestingHandler| {
+        let snapshot = utils.create_snapshot();
+        let pixels = snapshot.peek_pixels().unwrap();
+        (0..250)
+            .find(|y| {
+                (0..500).any(|x| {
+                    let color = pixels.get_color((x, *y));
+                    color.r() > 200 && color.g() < 150 && color.b() < 150
+                })
+            })
+            .unwrap()
+    };
+
+    // Click past the end of the first visual line, "Hello "
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (99.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The cursor is at the start of the next line in the text, but stays at the end of the clicked line
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("6:upstream"));
+    let first_line_top = cursor_top(&mut utils);
+    assert!(first_line_top < 5);
+
+    // Move cursor to the line below
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::ArrowDown,
+        key: Key::ArrowDown,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // It moves to the second line, "Rustaceans ", instead of skipping it
+    let cursor = root.get(1).get(0);
+    let (pos, _) = cursor.text().unwrap().split_once(':').unwrap();
+    let pos = pos.parse::<usize>().unwrap();
+    assert!(pos > 6 && pos < 17);
+    assert!(cursor_top(&mut utils) > first_line_top + 10);
+
+    // Moving with the arrows shows the cursor at the start of the lines
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        code: Code::ArrowLeft,
+        key: Key::ArrowLeft,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    assert_eq!(
+        cursor.text(),
+        Some(format!("{}:downstream", pos - 1).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn inherited_non_selectable() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\n".repeat(2)),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let highlights = editable.highlights_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onmouseover = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseOver(e.data, 0));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                selectable: "false",
+                cursor_reference,
+                paragraph {
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    highlights,
+                    onmousedown,
+                    onmouseover,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                rect {
+                    selectable: "true",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+
+    utils.wait_for_update().await;
+
+    // The paragraph inherits it from its parent, unless overridden
+    assert!(!root.get(0).state().cursor.selectable);
+    assert!(root.get(1).state().cursor.selectable);
+
+    // Click cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (35.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // Move cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (80.0, 20.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Nothing is selected
+    let highlights = root.get(0).state().cursor.highlights.clone();
+    assert!(highlights
+        .unwrap_or_default()
+        .iter()
+        .all(|(from, to)| from == to));
+}
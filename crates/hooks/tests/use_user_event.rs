@@ -0,0 +1,33 @@
+use std::thread;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test(start_paused = true)]
+async fn events_from_other_threads() {
+    fn use_user_event_app() -> Element {
+        let mut values = use_signal(Vec::<u32>::new);
+        let sender = use_user_event(move |value: u32| values.write().push(value));
+
+        use_hook(move || {
+            thread::spawn(move || {
+                for value in 1..=3 {
+                    sender.send(value).unwrap();
+                }
+            })
+            .join()
+            .unwrap();
+        });
+
+        let values = values.read();
+
+        rsx!(label { "{values:?}" })
+    }
+
+    let mut utils = launch_test(use_user_event_app);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("[1, 2, 3]"));
+}
@@ -10,8 +10,17 @@ pub enum AttributeName {
     MaxHeight,
     Padding,
     Background,
+    BackgroundHover,
+    BackgroundActive,
+    BackgroundFocus,
+    Interaction,
     Border,
+    BorderTop,
+    BorderRight,
+    BorderBottom,
+    BorderLeft,
     BorderAlign,
+    FocusRing,
     Direction,
     Shadow,
     CornerRadius,
@@ -28,12 +37,23 @@ pub enum AttributeName {
     TextShadow,
     MaxLines,
     LineHeight,
+    LineHeightBehavior,
     LetterSpacing,
     WordSpacing,
     Decoration,
     DecorationColor,
     DecorationStyle,
     TextOverflow,
+    TextStrokeWidth,
+    TextStrokeColor,
+    BaselineShift,
+    WordBreak,
+    Hyphens,
+    LineBreak,
+    Lang,
+    FirstLineColor,
+    FirstLineFontSize,
+    FirstLineFontWeight,
     Rotate,
     Overflow,
     Margin,
@@ -43,9 +63,22 @@ pub enum AttributeName {
     PositionBottom,
     PositionLeft,
     Opacity,
+    BlendMode,
+    MaskData,
+    Disabled,
+    DisabledOpacity,
+    HitPadding,
+    WindowDrag,
+    Cache,
+    Transition,
+    LayoutTransition,
     Content,
+    BoxSizing,
+    SnapAlign,
+    ParagraphSpacing,
     Name,
     Focusable,
+    FocusTrap,
     Role,
     FocusId,
     Alt,
@@ -56,16 +89,34 @@ pub enum AttributeName {
     Reference,
     CursorReference,
     CursorIndex,
+    CursorAffinity,
+    Selectable,
     CursorColor,
     CursorMode,
     CursorId,
     Highlights,
     HighlightColor,
+    SelectionColor,
+    SelectionTextColor,
     HighlightMode,
     ImageReference,
     ImageData,
+    ImageTint,
+    ImageTintMode,
+    ImageSlice,
+    ImagePlayback,
     SvgData,
     SvgContent,
+    PreserveAspectRatio,
+    /// Any other attribute, e.g `"elevation": "2"`, which can be read by custom states.
+    Custom(&'static str),
+}
+
+impl AttributeName {
+    /// Get the attribute with the given name, which is a [AttributeName::Custom] if it's not a built-in one.
+    pub fn from_static(name: &'static str) -> Self {
+        Self::from_str(name).unwrap_or(Self::Custom(name))
+    }
 }
 
 impl FromStr for AttributeName {
@@ -81,8 +132,17 @@ impl FromStr for AttributeName {
             "max_height" => Ok(AttributeName::MaxHeight),
             "padding" => Ok(AttributeName::Padding),
             "background" => Ok(AttributeName::Background),
+            "background_hover" => Ok(AttributeName::BackgroundHover),
+            "background_active" => Ok(AttributeName::BackgroundActive),
+            "background_focus" => Ok(AttributeName::BackgroundFocus),
+            "interaction" => Ok(AttributeName::Interaction),
             "border" => Ok(AttributeName::Border),
+            "border_top" => Ok(AttributeName::BorderTop),
+            "border_right" => Ok(AttributeName::BorderRight),
+            "border_bottom" => Ok(AttributeName::BorderBottom),
+            "border_left" => Ok(AttributeName::BorderLeft),
             "border_align" => Ok(AttributeName::BorderAlign),
+            "focus_ring" => Ok(AttributeName::FocusRing),
             "direction" => Ok(AttributeName::Direction),
             "shadow" => Ok(AttributeName::Shadow),
             "corner_radius" => Ok(AttributeName::CornerRadius),
@@ -99,12 +159,23 @@ impl FromStr for AttributeName {
             "text_shadow" => Ok(AttributeName::TextShadow),
             "max_lines" => Ok(AttributeName::MaxLines),
             "line_height" => Ok(AttributeName::LineHeight),
+            "line_height_behavior" => Ok(AttributeName::LineHeightBehavior),
             "letter_spacing" => Ok(AttributeName::LetterSpacing),
             "word_spacing" => Ok(AttributeName::WordSpacing),
             "decoration" => Ok(AttributeName::Decoration),
             "decoration_color" => Ok(AttributeName::DecorationColor),
             "decoration_style" => Ok(AttributeName::DecorationStyle),
             "text_overflow" => Ok(AttributeName::TextOverflow),
+            "text_stroke_width" => Ok(AttributeName::TextStrokeWidth),
+            "text_stroke_color" => Ok(AttributeName::TextStrokeColor),
+            "baseline_shift" => Ok(AttributeName::BaselineShift),
+            "word_break" => Ok(AttributeName::WordBreak),
+            "hyphens" => Ok(AttributeName::Hyphens),
+            "line_break" => Ok(AttributeName::LineBreak),
+            "lang" => Ok(AttributeName::Lang),
+            "first_line_color" => Ok(AttributeName::FirstLineColor),
+            "first_line_font_size" => Ok(AttributeName::FirstLineFontSize),
+            "first_line_font_weight" => Ok(AttributeName::FirstLineFontWeight),
             "rotate" => Ok(AttributeName::Rotate),
             "overflow" => Ok(AttributeName::Overflow),
             "margin" => Ok(AttributeName::Margin),
@@ -114,9 +185,22 @@ impl FromStr for AttributeName {
             "position_bottom" => Ok(AttributeName::PositionBottom),
             "position_left" => Ok(AttributeName::PositionLeft),
             "opacity" => Ok(AttributeName::Opacity),
+            "blend_mode" => Ok(AttributeName::BlendMode),
+            "mask_data" => Ok(AttributeName::MaskData),
+            "disabled" => Ok(AttributeName::Disabled),
+            "disabled_opacity" => Ok(AttributeName::DisabledOpacity),
+            "hit_padding" => Ok(AttributeName::HitPadding),
+            "window_drag" => Ok(AttributeName::WindowDrag),
+            "cache" => Ok(AttributeName::Cache),
+            "transition" => Ok(AttributeName::Transition),
+            "layout_transition" => Ok(AttributeName::LayoutTransition),
             "content" => Ok(AttributeName::Content),
+            "box_sizing" => Ok(AttributeName::BoxSizing),
+            "snap_align" => Ok(AttributeName::SnapAlign),
+            "paragraph_spacing" => Ok(AttributeName::ParagraphSpacing),
             "name" => Ok(AttributeName::Name),
             "focusable" => Ok(AttributeName::Focusable),
+            "focus_trap" => Ok(AttributeName::FocusTrap),
             "role" => Ok(AttributeName::Role),
             "focus_id" => Ok(AttributeName::FocusId),
             "alt" => Ok(AttributeName::Alt),
@@ -127,16 +211,25 @@ impl FromStr for AttributeName {
             "reference" => Ok(AttributeName::Reference),
             "cursor_reference" => Ok(AttributeName::CursorReference),
             "cursor_index" => Ok(AttributeName::CursorIndex),
+            "cursor_affinity" => Ok(AttributeName::CursorAffinity),
+            "selectable" => Ok(AttributeName::Selectable),
             "cursor_color" => Ok(AttributeName::CursorColor),
             "cursor_mode" => Ok(AttributeName::CursorMode),
             "cursor_id" => Ok(AttributeName::CursorId),
             "highlights" => Ok(AttributeName::Highlights),
             "highlight_color" => Ok(AttributeName::HighlightColor),
+            "selection_color" => Ok(AttributeName::SelectionColor),
+            "selection_text_color" => Ok(AttributeName::SelectionTextColor),
             "highlight_mode" => Ok(AttributeName::HighlightMode),
             "image_reference" => Ok(AttributeName::ImageReference),
             "image_data" => Ok(AttributeName::ImageData),
+            "image_tint" => Ok(AttributeName::ImageTint),
+            "image_tint_mode" => Ok(AttributeName::ImageTintMode),
+            "image_slice" => Ok(AttributeName::ImageSlice),
+            "image_playback" => Ok(AttributeName::ImagePlayback),
             "svg_data" => Ok(AttributeName::SvgData),
             "svg_content" => Ok(AttributeName::SvgContent),
+            "preserve_aspect_ratio" => Ok(AttributeName::PreserveAspectRatio),
             _ => Err(format!("{attr} not supported.")),
         }
     }
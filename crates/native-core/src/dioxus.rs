@@ -61,6 +61,11 @@ impl DioxusState {
         self.node_id_mapping.get(element_id.0).copied().flatten()
     }
 
+    /// The last `m` nodes in the stack, which the next mutation that inserts `m` nodes will take.
+    pub fn stack_nodes(&self, m: usize) -> &[NodeId] {
+        &self.stack[self.stack.len() - m..]
+    }
+
     /// Create a mutation writer for the RealDom
     pub fn create_mutation_writer<'a, V: FromAnyValue + Send + Sync>(
         &'a mut self,
@@ -113,9 +118,17 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
             let id = create_template_node(self.rdom, root);
             template_root_ids.push(id);
         }
-        self.state
+        let previous_root_ids = self
+            .state
             .templates
             .insert(template.name.to_string(), template_root_ids);
+
+        // Templates replaced by hot reloading are not used anymore, the nodes cloned from them are independent
+        for root_id in previous_root_ids.into_iter().flatten() {
+            if let Some(mut root) = self.rdom.get_mut(root_id) {
+                root.remove();
+            }
+        }
     }
 
     fn append_children(&mut self, id: ElementId, m: usize) {
@@ -204,7 +217,8 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
         let new_nodes = self.state.stack.split_off(self.state.stack.len() - m);
         let old_node_id = self.state.element_to_node_id(id);
         for new in new_nodes {
-            self.rdom.tree_mut().insert_before(old_node_id, new);
+            let mut node = self.rdom.get_mut(new).unwrap();
+            node.insert_before(old_node_id);
         }
     }
 
@@ -219,7 +233,7 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
         let mut node = self.rdom.get_mut(node_id).unwrap();
         let mut node_type_mut = node.node_type_mut();
         if let NodeTypeMut::Element(element) = &mut node_type_mut {
-            let attribute = AttributeName::from_str(name).expect("Unexpected");
+            let attribute = AttributeName::from_static(name);
             if let AttributeValue::None = &value {
                 element.remove_attribute(&attribute);
             } else {
@@ -277,7 +291,7 @@ fn create_template_node<V: FromAnyValue + Send + Sync>(
                     .iter()
                     .filter_map(|attr| match attr {
                         dioxus_core::TemplateAttribute::Static { name, value, .. } => Some((
-                            AttributeName::from_str(name).expect("Unexpected."),
+                            AttributeName::from_static(name),
                             OwnedAttributeValue::Text(value.to_string()),
                         )),
                         dioxus_core::TemplateAttribute::Dynamic { .. } => None,
@@ -24,11 +24,16 @@ pub enum EventName {
     KeyDown,
     KeyUp,
 
+    ImePreedit,
+
     TouchCancel,
     TouchStart,
     TouchMove,
     TouchEnd,
 
+    Pinch,
+    Pan,
+
     GlobalClick,
     GlobalPointerUp,
     GlobalMouseDown,
@@ -37,6 +42,8 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+
+    Resize,
 }
 
 impl FromStr for EventName {
@@ -59,10 +66,13 @@ impl FromStr for EventName {
             "pointerup" => Ok(EventName::PointerUp),
             "keydown" => Ok(EventName::KeyDown),
             "keyup" => Ok(EventName::KeyUp),
+            "imepreedit" => Ok(EventName::ImePreedit),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
             "touchend" => Ok(EventName::TouchEnd),
+            "pinch" => Ok(EventName::Pinch),
+            "pan" => Ok(EventName::Pan),
             "globalclick" => Ok(EventName::GlobalClick),
             "globalpointerup" => Ok(EventName::GlobalPointerUp),
             "globalmousedown" => Ok(EventName::GlobalMouseDown),
@@ -70,6 +80,7 @@ impl FromStr for EventName {
             "filedrop" => Ok(EventName::FileDrop),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "resize" => Ok(EventName::Resize),
             _ => Err(()),
         }
     }
@@ -93,10 +104,13 @@ impl From<EventName> for &str {
             EventName::PointerUp => "pointerup",
             EventName::KeyDown => "keydown",
             EventName::KeyUp => "keyup",
+            EventName::ImePreedit => "imepreedit",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
             EventName::TouchEnd => "touchend",
+            EventName::Pinch => "pinch",
+            EventName::Pan => "pan",
             EventName::GlobalClick => "globalclick",
             EventName::GlobalPointerUp => "globalpointerup",
             EventName::GlobalMouseDown => "globalmousedown",
@@ -104,6 +118,7 @@ impl From<EventName> for &str {
             EventName::FileDrop => "filedrop",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::Resize => "resize",
         }
     }
 }
@@ -197,25 +212,28 @@ impl EventName {
     }
 
     // Bubble all events except:
-    // - Keyboard events
+    // - Keyboard and IME events
     // - Mouse movements events
+    // - Resize events
     pub fn does_bubble(&self) -> bool {
         !matches!(
             self,
             Self::KeyDown
                 | Self::KeyUp
+                | Self::ImePreedit
                 | Self::MouseLeave
                 | Self::PointerLeave
                 | Self::MouseEnter
                 | Self::PointerEnter
                 | Self::MouseOver
                 | Self::PointerOver
+                | Self::Resize
         )
     }
 
     // Only let events that do not move the mouse, go through solid nodes
     pub fn does_go_through_solid(&self) -> bool {
-        matches!(self, Self::KeyDown | Self::KeyUp)
+        matches!(self, Self::KeyDown | Self::KeyUp | Self::ImePreedit)
     }
 
     // Check if this event can change the hover state of a Node.
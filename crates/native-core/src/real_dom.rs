@@ -622,6 +622,7 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
             self.dom.dirty_nodes.mark_child_changed(parent_id);
             self.dom.dirty_nodes.mark_parent_added_or_removed(id);
         }
+        self.mark_moved_from_parent(parent_id);
         self.dom.tree_mut().insert_after(old, id);
     }
 
@@ -634,9 +635,18 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
             self.dom.dirty_nodes.mark_child_changed(parent_id);
             self.dom.dirty_nodes.mark_parent_added_or_removed(id);
         }
+        self.mark_moved_from_parent(parent_id);
         self.dom.tree_mut().insert_before(old, id);
     }
 
+    /// Mark the current parent of this node as having a child removed if it's being moved to another parent
+    fn mark_moved_from_parent(&mut self, new_parent_id: Option<NodeId>) {
+        let old_parent_id = self.dom.tree_ref().parent_id(self.id());
+        if let Some(old_parent_id) = old_parent_id.filter(|id| Some(*id) != new_parent_id) {
+            self.dom.dirty_nodes.mark_child_changed(old_parent_id);
+        }
+    }
+
     /// Remove this node from the RealDom
     #[inline]
     pub fn remove(&mut self) {
@@ -229,6 +229,7 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 
     fn insert_before(&mut self, old_id: NodeId, new_id: NodeId) {
+        detach(self, new_id);
         let parent_id = {
             let old_node = self.1.get(old_id).unwrap();
             old_node.parent.expect("tried to insert before root")
@@ -248,6 +249,7 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 
     fn insert_after(&mut self, old_id: NodeId, new_id: NodeId) {
+        detach(self, new_id);
         let mut node_state = &mut self.1;
         let old_node = node_state.get(old_id).unwrap();
         let parent_id = old_node.parent.expect("tried to insert before root");
@@ -340,6 +342,16 @@ fn child_height(parent: &Node, tree: &impl TreeRef) -> u16 {
     }
 }
 
+/// Remove the node from the children of its current parent, so it can be moved somewhere else.
+fn detach(tree: &mut TreeMutView<'_>, id: NodeId) {
+    let mut node_data_mut = &mut tree.1;
+    let parent = (&mut node_data_mut).get(id).unwrap().parent.take();
+    if let Some(parent) = parent {
+        let parent = (&mut node_data_mut).get(parent).unwrap();
+        parent.children.retain(|&child| child != id);
+    }
+}
+
 /// Sets the height of a node and updates the height of all its children
 fn set_height(tree: &mut TreeMutView<'_>, node: NodeId, height: u16) {
     let (shadow_tree, light_tree, children) = {
@@ -554,6 +566,41 @@ fn insertion() {
     assert_eq!(tree.children_ids(parent), &[before, child, after]);
 }
 
+#[test]
+fn moving() {
+    use shipyard::World;
+    #[allow(dead_code)]
+    #[derive(Component)]
+    struct Num(i32);
+
+    let mut world = World::new();
+    let parent = world.add_entity(Num(0));
+    let first = world.add_entity(Num(1));
+    let second = world.add_entity(Num(2));
+    let third = world.add_entity(Num(3));
+
+    let mut tree = world.borrow::<TreeMutView>().unwrap();
+
+    tree.create_node(parent);
+    tree.create_node(first);
+    tree.create_node(second);
+    tree.create_node(third);
+
+    tree.add_child(parent, first);
+    tree.add_child(parent, second);
+    tree.add_child(parent, third);
+
+    // Inserting a node that already has a parent moves it
+    tree.insert_before(first, third);
+    assert_eq!(tree.children_ids(parent), &[third, first, second]);
+
+    tree.insert_after(second, first);
+    assert_eq!(tree.children_ids(parent), &[third, second, first]);
+
+    assert_eq!(tree.parent_id(first).unwrap(), parent);
+    assert_eq!(tree.height(first), Some(1));
+}
+
 #[test]
 fn deletion() {
     use shipyard::World;
@@ -443,3 +443,86 @@ fn up_pass() {
     let grandchild2 = tree.get(grandchild2).unwrap();
     assert_eq!(grandchild2.get().as_deref(), Some(&AddNumber(1)));
 }
+
+#[test]
+fn move_across_parents() {
+    // Tree before:
+    // 2=\
+    //   1=\
+    //     0
+    //   1=\
+    //     0
+    // Tree after moving the first grandchild next to the second one:
+    // 2=\
+    //   0
+    //   2=\
+    //     0
+    //     0
+
+    #[derive(Debug, Default, Clone, PartialEq, Component)]
+    struct ChildCount(usize);
+
+    #[partial_derive_state]
+    impl State for ChildCount {
+        type ChildDependencies = (ChildCount,);
+        type NodeDependencies = ();
+        type ParentDependencies = ();
+
+        const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new();
+
+        fn update<'a>(
+            &mut self,
+            _: NodeView,
+            _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+            _: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+            children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+            _: &SendAnyMap,
+        ) -> bool {
+            let changed = self.0 != children.len();
+            self.0 = children.len();
+            changed
+        }
+
+        fn create<'a>(
+            node_view: NodeView<()>,
+            node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+            parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+            children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+            context: &SendAnyMap,
+        ) -> Self {
+            let mut myself = Self::default();
+            myself.update(node_view, node, parent, children, context);
+            myself
+        }
+    }
+
+    let mut tree: RealDom = RealDom::new([ChildCount::to_type_erased()]);
+    let grandchild1 = tree.create_node(create_blank_element());
+    let grandchild1 = grandchild1.id();
+    let mut child1 = tree.create_node(create_blank_element());
+    child1.add_child(grandchild1);
+    let child1 = child1.id();
+    let grandchild2 = tree.create_node(create_blank_element());
+    let grandchild2 = grandchild2.id();
+    let mut child2 = tree.create_node(create_blank_element());
+    child2.add_child(grandchild2);
+    let child2 = child2.id();
+    let mut parent = tree.get_mut(tree.root_id()).unwrap();
+    parent.add_child(child1);
+    parent.add_child(child2);
+
+    tree.update_state(SendAnyMap::new());
+
+    let child1_count = tree.get(child1).unwrap().get::<ChildCount>().unwrap().0;
+    assert_eq!(child1_count, 1);
+
+    tree.get_mut(grandchild1).unwrap().insert_after(grandchild2);
+    tree.update_state(SendAnyMap::new());
+
+    // Both the old and the new parent are updated
+    let child1 = tree.get(child1).unwrap();
+    assert_eq!(child1.get().as_deref(), Some(&ChildCount(0)));
+
+    let child2 = tree.get(child2).unwrap();
+    assert_eq!(child2.get().as_deref(), Some(&ChildCount(2)));
+}
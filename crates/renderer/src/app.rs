@@ -6,14 +6,19 @@ use dioxus_core::{
 };
 use freya_common::{
     EventMessage,
+    ScreenshotCallback,
     TextGroupMeasurement,
 };
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
 use freya_native_core::{
-    prelude::NodeImmutableDioxusExt,
+    prelude::{
+        NodeImmutable,
+        NodeImmutableDioxusExt,
+    },
     NodeId,
 };
+use freya_node_state::LayerState;
 use futures_task::Waker;
 use futures_util::Future;
 use pin_utils::pin_mut;
@@ -29,13 +34,24 @@ use torin::geometry::{
     Area,
     Size2D,
 };
-use tracing::info;
+use tracing::{
+    info,
+    warn,
+};
 use winit::{
-    dpi::PhysicalSize,
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
     event_loop::EventLoopProxy,
     window::Window,
 };
 
+#[cfg(feature = "frame-timings")]
+use crate::frame_timer::{
+    FrameStage,
+    FrameTimer,
+};
 use crate::{
     accessibility::AccessKitManager,
     devtools::Devtools,
@@ -60,11 +76,20 @@ pub struct Application {
     pub(crate) accessibility: AccessKitManager,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
+    pub(crate) images_cache: ImagesCache,
+    pub(crate) pictures_cache: PicturesCache,
+    pub(crate) paragraph_cache: ParagraphCache,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) close_request_sender: CloseRequestSender,
     pub(crate) plugins: PluginsManager,
     pub(crate) measure_layout_on_next_render: bool,
     pub(crate) default_fonts: Vec<String>,
     pub(crate) queued_focus_node: Option<AccessibilityId>,
+    pub(crate) render_quality: RenderQuality,
+    pub(crate) queued_screenshots: Vec<(Option<Area>, ScreenshotCallback)>,
+    pub(crate) caret_area: Option<Area>,
+    #[cfg(feature = "frame-timings")]
+    pub(crate) frame_timer: FrameTimer,
 }
 
 impl Application {
@@ -78,6 +103,7 @@ impl Application {
         fonts_config: EmbeddedFonts,
         mut plugins: PluginsManager,
         default_fonts: Vec<String>,
+        render_quality: RenderQuality,
     ) -> Self {
         let accessibility = AccessKitManager::new(window, proxy.clone());
 
@@ -87,13 +113,18 @@ impl Application {
         let mut provider = TypefaceFontProvider::new();
 
         for (font_name, font_data) in fonts_config {
-            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
-            provider.register_typeface(ft_type, Some(font_name));
+            if let Some(ft_type) = def_mgr.new_from_data(font_data, None) {
+                provider.register_typeface(ft_type, Some(font_name));
+            } else {
+                warn!("Failed to parse the embedded font {font_name}, skipping it");
+            }
         }
 
         let font_mgr: FontMgr = provider.into();
         font_collection.set_default_font_manager(def_mgr, None);
         font_collection.set_dynamic_font_manager(font_mgr.clone());
+        // Substitute the characters missing in the requested fonts (e.g emoji or CJK) with any system font that has them
+        font_collection.enable_font_fallback();
 
         let (event_emitter, event_receiver) = mpsc::unbounded_channel();
         let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
@@ -121,11 +152,20 @@ impl Application {
             platform_receiver,
             font_collection,
             font_mgr,
+            images_cache: ImagesCache::default(),
+            pictures_cache: PicturesCache::default(),
+            paragraph_cache: ParagraphCache::default(),
             ticker_sender: broadcast::channel(5).0,
+            close_request_sender: broadcast::channel(1).0,
             plugins,
             measure_layout_on_next_render: false,
             default_fonts,
             queued_focus_node: None,
+            render_quality,
+            queued_screenshots: Vec::new(),
+            caret_area: None,
+            #[cfg(feature = "frame-timings")]
+            frame_timer: FrameTimer::new(),
         }
     }
 
@@ -140,6 +180,16 @@ impl Application {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(TextMeasurer::new(
+                self.font_collection.clone(),
+                self.default_fonts.clone(),
+            )));
+        #[cfg(feature = "frame-timings")]
+        self.vdom
+            .insert_any_root_context(Box::new(self.frame_timer.subscribe()));
     }
 
     /// Make the first build of the VirtualDOM and sync it with the RealDOM.
@@ -155,12 +205,25 @@ impl Application {
     /// Update the RealDOM, layout and others with the latest changes from the VirtualDOM
     pub fn render_mutations(&mut self, scale_factor: f32) -> (bool, bool) {
         self.plugins.send(PluginEvent::StartedUpdatingDOM);
+        #[cfg(feature = "frame-timings")]
+        self.frame_timer.start();
+
+        let (repaint, relayout) = {
+            let mut fdom = self.sdom.get_mut();
+            let focused_id = self.platform_receiver.borrow().focused_id;
+            self.nodes_state
+                .apply_interactions(fdom.rdom_mut(), focused_id);
+            let changes = fdom.render_mutations(&mut self.vdom, scale_factor);
+            let invalidated_nodes = fdom.take_invalidated_nodes();
+            #[cfg(feature = "frame-timings")]
+            self.frame_timer.add_dirty_nodes(invalidated_nodes.len());
+            self.pictures_cache
+                .invalidate(fdom.rdom(), invalidated_nodes);
+            changes
+        };
 
-        let (repaint, relayout) = self
-            .sdom
-            .get_mut()
-            .render_mutations(&mut self.vdom, scale_factor);
-
+        #[cfg(feature = "frame-timings")]
+        self.frame_timer.finish(FrameStage::DomUpdates);
         self.plugins.send(PluginEvent::FinishedUpdatingDOM);
 
         if repaint {
@@ -219,22 +282,33 @@ impl Application {
         }
     }
 
-    /// Process the events queue
-    pub fn process_events(&mut self, scale_factor: f64) {
+    /// Process the events queue, and apply the interaction states they caused right away.
+    /// Returns whether it must be repainted and whether its layout must be measured again.
+    pub fn process_events(&mut self, scale_factor: f64) -> (bool, bool) {
         process_events(
             &self.sdom.get(),
             &mut self.events,
             &self.event_emitter,
             &mut self.nodes_state,
             scale_factor,
-        )
+        );
+
+        let mut fdom = self.sdom.get_mut();
+        let focused_id = self.platform_receiver.borrow().focused_id;
+        self.nodes_state
+            .apply_interactions(fdom.rdom_mut(), focused_id);
+        let changes = fdom.update_states();
+        let invalidated_nodes = fdom.take_invalidated_nodes();
+        self.pictures_cache
+            .invalidate(fdom.rdom(), invalidated_nodes);
+        changes
     }
 
     /// Create the Accessibility tree
     /// This will iterater the DOM ordered by layers (top to bottom)
     /// and add every element with an accessibility ID to the Accessibility Tree
     pub fn process_accessibility(&mut self, window: &Window) {
-        {
+        let restore_focus = {
             let fdom = &self.sdom.get();
             let layout = fdom.layout();
             let rdom = fdom.rdom();
@@ -243,7 +317,12 @@ impl Application {
                 &layout,
                 rdom,
                 &mut self.accessibility.accessibility_manager().lock().unwrap(),
-            );
+            )
+        };
+
+        // Focus again what was focused before a released focus trap
+        if let Some(node_id) = restore_focus {
+            self.focus_node(node_id, window)
         }
 
         if let Some(node_id) = self.queued_focus_node.take() {
@@ -251,10 +330,11 @@ impl Application {
         }
     }
 
-    /// Send an event
-    pub fn send_event(&mut self, event: PlatformEvent, scale_factor: f64) {
+    /// Send an event.
+    /// Returns whether it must be repainted and whether its layout must be measured again.
+    pub fn send_event(&mut self, event: PlatformEvent, scale_factor: f64) -> (bool, bool) {
         self.events.push(event);
-        self.process_events(scale_factor);
+        self.process_events(scale_factor)
     }
 
     /// Replace a VirtualDOM Template
@@ -269,22 +349,54 @@ impl Application {
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
         });
+        #[cfg(feature = "frame-timings")]
+        self.frame_timer.start();
 
-        self.start_render(
+        let keep_rendering = self.start_render(
             hovered_node,
             canvas,
             window.inner_size(),
             window.scale_factor() as f32,
         );
+        #[cfg(feature = "frame-timings")]
+        {
+            self.frame_timer.finish(FrameStage::Render);
+            self.frame_timer.finish_frame();
+        }
 
         self.accessibility
             .render_accessibility(window.title().as_str());
 
+        // Place the candidates window of the input method next to the caret
+        if let Some(caret_area) = self.caret_area.take() {
+            window.set_ime_cursor_area(
+                PhysicalPosition::new(caret_area.min_x(), caret_area.min_y()),
+                PhysicalSize::new(caret_area.width(), caret_area.height()),
+            );
+        }
+
         self.plugins.send(PluginEvent::AfterRender {
             canvas,
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
         });
+
+        // Keep rendering frames until all the transitions have finished and animated images are paused
+        if keep_rendering {
+            window.request_redraw();
+        }
+    }
+
+    /// Capture a screenshot of the next rendered frame.
+    pub fn queue_screenshot(&mut self, area: Option<Area>, callback: ScreenshotCallback) {
+        self.queued_screenshots.push((area, callback));
+    }
+
+    /// Capture the queued screenshots from the frame that was just rendered into the given surface.
+    pub fn capture_screenshots(&mut self, surface: &mut Surface) {
+        for (area, callback) in self.queued_screenshots.drain(..) {
+            callback(encode_surface_png(surface, area));
+        }
     }
 
     /// Resize the Window
@@ -338,6 +450,8 @@ impl Application {
 
             self.plugins
                 .send(PluginEvent::StartedLayout(&fdom.layout()));
+            #[cfg(feature = "frame-timings")]
+            self.frame_timer.start();
 
             process_layout(
                 &fdom,
@@ -346,12 +460,25 @@ impl Application {
                     inner_size.height as f32,
                 ))),
                 &mut self.font_collection,
+                &self.font_mgr,
                 scale_factor,
                 &self.default_fonts,
+                self.render_quality,
+                &mut self.paragraph_cache,
             );
 
+            #[cfg(feature = "frame-timings")]
+            self.frame_timer.finish(FrameStage::Layout);
+
             self.plugins
                 .send(PluginEvent::FinishedLayout(&fdom.layout()));
+
+            process_resize_events(
+                &fdom,
+                &self.event_emitter,
+                &mut self.nodes_state,
+                scale_factor,
+            );
         }
 
         if let Some(devtools) = &self.devtools {
@@ -366,15 +493,21 @@ impl Application {
         );
     }
 
-    /// Start rendering the RealDOM to Window
+    /// Start rendering the RealDOM to Window.
+    /// Returns whether there are transitions or animated images still running.
     pub fn start_render(
         &mut self,
         hovered_node: &HoveredNode,
         canvas: &Canvas,
         windows_size: PhysicalSize<u32>,
         scale_factor: f32,
-    ) {
+    ) -> bool {
         let fdom = self.sdom.get();
+        let layout_transitions = fdom.layout_transitions();
+
+        let platform_state = self.platform_sender.borrow().clone();
+        let focus_visible_id = (platform_state.navigation_mode == NavigationMode::Keyboard)
+            .then_some(platform_state.focused_id);
 
         let matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
         let opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
@@ -386,12 +519,28 @@ impl Application {
             canvas,
             font_collection: &mut self.font_collection,
             font_manager: &self.font_mgr,
+            images_cache: &mut self.images_cache,
+            pictures_cache: &mut self.pictures_cache,
             matrices,
             opacities,
+            layer_root: None,
             default_fonts: &self.default_fonts,
             scale_factor,
+            render_quality: self.render_quality,
+            focus_visible_id,
+            layout_transitions: &layout_transitions,
+            has_running_transitions: false,
+            caret_area: None,
         };
 
+        // Nodes of the app in the order they are painted, to show them over it
+        let paint_order_nodes = self
+            .devtools
+            .as_ref()
+            .filter(|devtools| devtools.is_showing_paint_order())
+            .map(|devtools| devtools.inspected_nodes());
+        let mut painted_nodes = Vec::new();
+
         process_render(&fdom, |fdom, node_id, layout_node, layout| {
             let render_wireframe = if let Some(hovered_node) = &hovered_node {
                 hovered_node
@@ -410,7 +559,37 @@ impl Application {
                     render_wireframe,
                     layout,
                 );
+
+                if let Some(paint_order_nodes) = &paint_order_nodes {
+                    if paint_order_nodes.contains(node_id) {
+                        let layer_state = dioxus_node.get::<LayerState>().unwrap();
+                        painted_nodes.push(PaintedNode {
+                            area: layout_node.visible_area(),
+                            layer: layer_state.layer,
+                            relative_layer: layer_state.relative_layer,
+                        });
+                    }
+                }
             }
         });
+
+        let has_running_transitions = skia_renderer.has_running_transitions;
+        self.caret_area = skia_renderer.caret_area;
+
+        if paint_order_nodes.is_some() {
+            render_paint_order(
+                canvas,
+                &self.font_collection,
+                &self.default_fonts,
+                scale_factor,
+                &painted_nodes,
+            );
+        }
+        let has_running_animations = self.images_cache.has_running_animations();
+
+        self.images_cache.evict_unused();
+        self.pictures_cache.evict_unused();
+
+        has_running_transitions || has_running_animations
     }
 }
@@ -1,18 +1,33 @@
 use std::{
+    fmt,
     io::Cursor,
     sync::Arc,
+    time::Duration,
 };
 
 use freya_core::{
+    events::ScrollSensitivity,
     plugins::{
         FreyaPlugin,
         PluginsManager,
     },
+    skia::RenderQuality,
     style::default_fonts,
 };
-use freya_engine::prelude::Color;
-use freya_node_state::Parse;
+use freya_engine::prelude::{
+    Color,
+    FontMgr,
+};
+use freya_native_core::prelude::{
+    State,
+    TypeErasedState,
+};
+use freya_node_state::{
+    CustomAttributeValues,
+    Parse,
+};
 use image::ImageReader;
+use tracing::warn;
 use winit::window::{
     Icon,
     Window,
@@ -22,6 +37,25 @@ use winit::window::{
 pub type WindowBuilderHook = Box<dyn Fn(WindowAttributes) -> WindowAttributes>;
 pub type EmbeddedFonts<'a> = Vec<(&'a str, &'a [u8])>;
 
+/// Error returned when embedding a font that can't be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// The data of the font with the given name is not a valid font.
+    InvalidFontData(String),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidFontData(font_name) => {
+                write!(f, "The data of the font `{font_name}` is not a valid font")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
 /// Configuration for a Window.
 pub struct WindowConfig {
     /// Size of the Window.
@@ -36,7 +70,10 @@ pub struct WindowConfig {
     pub title: &'static str,
     /// Make the Window transparent or not.
     pub transparent: bool,
-    /// Background color of the Window.
+    /// Blur what is behind the transparent Window, where supported.
+    pub blur: bool,
+    /// Color the Window is cleared with before painting the app, every frame.
+    /// It's what is seen where the app doesn't paint, and while the Window is being resized.
     pub background: Color,
     /// The Icon of the Window.
     pub icon: Option<Icon>,
@@ -46,6 +83,42 @@ pub struct WindowConfig {
     pub on_exit: Option<WindowCallback>,
     /// Hook function called with the Window Attributes.
     pub window_attributes_hook: Option<WindowBuilderHook>,
+    /// Wait for the display refresh before presenting every frame.
+    pub vsync: bool,
+    /// Maximum amount of frames rendered per second.
+    pub max_fps: Option<u32>,
+    /// Log a warning for every frame that takes longer than this. Requires the `frame-timings` feature.
+    pub frame_budget: Option<Duration>,
+    /// Quality of the antialiasing of the elements and text.
+    pub render_quality: RenderQuality,
+    /// Samples per pixel of the multisample antialiasing (MSAA) of the Window surface.
+    pub msaa: Option<u8>,
+    /// Render with the CPU instead of the GPU.
+    pub software_rendering: bool,
+    /// How the wheel deltas are turned into scroll offsets.
+    pub scroll_sensitivity: ScrollSensitivity,
+}
+
+impl WindowConfig {
+    /// Minimum time between two frames, if the frame rate is capped.
+    pub(crate) fn frame_interval(&self) -> Option<Duration> {
+        self.max_fps
+            .filter(|max_fps| *max_fps > 0)
+            .map(|max_fps| Duration::from_secs(1) / max_fps)
+    }
+
+    /// Requested samples per pixel of the MSAA, or `0` to not use it.
+    /// Only powers of two up to `16` are valid, other amounts disable it.
+    pub(crate) fn msaa_samples(&self) -> u8 {
+        match self.msaa {
+            Some(samples) if samples.is_power_of_two() && samples <= 16 => samples,
+            Some(samples) => {
+                warn!("MSAA with {samples} samples is not valid, use 2, 4, 8 or 16 samples");
+                0
+            }
+            None => 0,
+        }
+    }
 }
 
 impl Default for WindowConfig {
@@ -57,11 +130,19 @@ impl Default for WindowConfig {
             decorations: true,
             title: "Freya app",
             transparent: false,
+            blur: false,
             background: Color::WHITE,
             icon: None,
             on_setup: None,
             on_exit: None,
             window_attributes_hook: None,
+            vsync: true,
+            max_fps: None,
+            frame_budget: None,
+            render_quality: RenderQuality::default(),
+            msaa: None,
+            software_rendering: false,
+            scroll_sensitivity: ScrollSensitivity::default(),
         }
     }
 }
@@ -73,6 +154,7 @@ pub struct LaunchConfig<'a, T: Clone = ()> {
     pub embedded_fonts: EmbeddedFonts<'a>,
     pub plugins: PluginsManager,
     pub default_fonts: Vec<String>,
+    pub custom_states: Vec<TypeErasedState<CustomAttributeValues>>,
 }
 
 impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
@@ -83,6 +165,7 @@ impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
             embedded_fonts: Default::default(),
             plugins: Default::default(),
             default_fonts: default_fonts(),
+            custom_states: Vec::new(),
         }
     }
 }
@@ -142,8 +225,28 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
     }
 
     /// Make the Window transparent or not.
+    ///
+    /// A transparent Window is cleared with a transparent background, so the desktop is seen through
+    /// the areas that the app doesn't paint. Use [LaunchConfig::with_background] after this to tint it with a translucent color instead.
+    /// When the GPU doesn't support transparent surfaces, the Window stays opaque and a warning is logged.
     pub fn with_transparency(mut self, transparency: bool) -> Self {
         self.window_config.transparent = transparency;
+        if transparency {
+            self.window_config.background = Color::TRANSPARENT;
+        }
+        self
+    }
+
+    /// Blur what is behind the transparent areas of the Window, like the acrylic or vibrancy effects of the platform.
+    /// It also makes the Window transparent, see [LaunchConfig::with_transparency].
+    ///
+    /// Only supported on macOS and some Wayland compositors (e.g KDE), on the other platforms
+    /// the Window is still transparent but what is behind it is not blurred.
+    pub fn with_blur(mut self, blur: bool) -> Self {
+        self.window_config.blur = blur;
+        if blur {
+            self = self.with_transparency(true);
+        }
         self
     }
 
@@ -153,18 +256,43 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
-    /// Specify the Window background color.
+    /// Specify the color the Window is cleared with before painting the app, `white` by default.
+    ///
+    /// Set it to the background of the app to avoid seeing a different color where the root element
+    /// doesn't cover the Window, or while the Window is being resized.
+    /// Transparent and translucent colors are only seen through when the Window is transparent,
+    /// so call this after [LaunchConfig::with_transparency], which resets it to `transparent`.
+    ///
+    /// Colors that can't be parsed are logged as a warning and `white` is used instead.
     pub fn with_background(mut self, background: &str) -> Self {
-        self.window_config.background = Color::parse(background).unwrap_or(Color::WHITE);
+        self.window_config.background = Color::parse(background).unwrap_or_else(|_| {
+            warn!("Background color `{background}` is not valid, using white instead");
+            Color::WHITE
+        });
         self
     }
 
-    /// Embed a font.
+    /// Embed a font, so it can be used with `font_family: "{font_name}"`.
+    ///
+    /// Embedding several fonts with the same name (e.g the regular, bold and italic files of a family)
+    /// makes them a single family, where `font_weight`, `font_width` and `font_slant` pick the closest one.
+    /// Embedded fonts are looked up before the system fonts, which are still used for
+    /// families that were not embedded and for characters missing in the embedded fonts.
+    ///
+    /// Fonts that can't be parsed are skipped, use [LaunchConfig::try_with_font] to handle them.
     pub fn with_font(mut self, font_name: &'a str, font: &'a [u8]) -> Self {
         self.embedded_fonts.push((font_name, font));
         self
     }
 
+    /// Same as [LaunchConfig::with_font], but fails if the font can't be parsed.
+    pub fn try_with_font(self, font_name: &'a str, font: &'a [u8]) -> Result<Self, FontError> {
+        FontMgr::default()
+            .new_from_data(font, None)
+            .ok_or_else(|| FontError::InvalidFontData(font_name.to_string()))?;
+        Ok(self.with_font(font_name, font))
+    }
+
     /// Clear default fonts.
     pub fn without_default_fonts(mut self) -> Self {
         self.default_fonts.clear();
@@ -172,6 +300,9 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
     }
 
     /// Regiter a default font.
+    /// Default fonts are used after the ones specified with `font_family`, and are also the fallback chain
+    /// for characters missing in them, so this can be used to add custom (e.g embedded) fallback fonts.
+    /// Characters missing in all of them are still substituted with any system font that has them.
     pub fn with_default_font(mut self, font_name: &str) -> Self {
         self.default_fonts.push(font_name.to_string());
         self
@@ -201,6 +332,87 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Register a custom state, computed for every node along with the built-in ones (e.g layout or style).
+    ///
+    /// Custom states are created just like the built-in states, with `freya-native-core` and its `partial_derive_state` macro.
+    /// They can depend on other states and read any attribute, including custom ones like `"elevation": "2"`
+    /// that are not built into the elements, which are given as an [AttributeName::Custom](freya_native_core::prelude::AttributeName::Custom).
+    ///
+    /// They are read back from the nodes of the DOM, e.g by a [FreyaPlugin] when rendering: `node.get::<MyState>()`.
+    pub fn with_custom_state<S: State<CustomAttributeValues>>(mut self) -> Self {
+        self.custom_states.push(S::to_type_erased());
+        self
+    }
+
+    /// Enable or disable VSync, enabled by default.
+    /// Disabling it can lower the input latency at the cost of tearing and a higher power usage.
+    pub fn with_vsync(mut self, vsync: bool) -> Self {
+        self.window_config.vsync = vsync;
+        self
+    }
+
+    /// Cap the frame rate to the given frames per second, e.g `30` for battery sensitive apps.
+    ///
+    /// Frames are only rendered when something changes (DOM updates, events, running animations or transitions),
+    /// so an idle app doesn't render any frame regardless of this cap. Capping the frame rate
+    /// saves power while animating, at the cost of less smooth animations.
+    pub fn with_max_fps(mut self, max_fps: u32) -> Self {
+        self.window_config.max_fps = Some(max_fps);
+        self
+    }
+
+    /// Log a warning whenever a frame takes longer than the given budget, e.g `16ms` to find the frames that miss 60 FPS.
+    ///
+    /// The warning tells which stage of the frame (DOM updates, layout or render) took the most time
+    /// and how many nodes changed in that frame. Requires the `frame-timings` feature, otherwise nothing is logged.
+    pub fn with_frame_budget(mut self, budget: Duration) -> Self {
+        self.window_config.frame_budget = Some(budget);
+        self
+    }
+
+    /// Specify the quality of the antialiasing, see [RenderQuality].
+    pub fn with_render_quality(mut self, render_quality: RenderQuality) -> Self {
+        self.window_config.render_quality = render_quality;
+        self
+    }
+
+    /// Smooth the edges of everything in the Window with multisample antialiasing (MSAA),
+    /// using the given samples per pixel: `2`, `4`, `8` or `16`. Disabled by default.
+    ///
+    /// More samples look smoother but use more GPU memory and time, and the elements are already antialiased
+    /// unless disabled with [RenderQuality::anti_alias], so it is mostly useful when that is disabled on capable GPUs.
+    /// When the GPU doesn't support the given samples, the closest supported amount is used instead.
+    pub fn with_msaa(mut self, samples: u8) -> Self {
+        self.window_config.msaa = Some(samples);
+        self
+    }
+
+    /// Render with the CPU instead of the GPU, disabled by default.
+    ///
+    /// Software rendering is already used with a warning when the GPU can't be used, e.g in VMs or remote desktops
+    /// without OpenGL, so this is meant to force it in CI or to rule out GPU issues.
+    /// It's slower, and the Window can't be transparent or use MSAA with it.
+    pub fn with_software_rendering(mut self, software_rendering: bool) -> Self {
+        self.window_config.software_rendering = software_rendering;
+        self
+    }
+
+    /// Multiply how much every wheel movement scrolls, `1.0` by default.
+    ///
+    /// It applies to both mouse wheels and touchpads, and every `ScrollView` can adjust it further
+    /// with its own `scroll_sensitivity`.
+    pub fn with_scroll_sensitivity(mut self, multiplier: f32) -> Self {
+        self.window_config.scroll_sensitivity.multiplier = multiplier;
+        self
+    }
+
+    /// Specify the logical pixels scrolled for every line of the mouse wheels that move by lines,
+    /// `53` by default. Touchpads already move by pixels so they are not affected by it.
+    pub fn with_scroll_line_height(mut self, line_height: f32) -> Self {
+        self.window_config.scroll_sensitivity.line_height = line_height;
+        self
+    }
+
     /// Register a Window Attributes hook.
     pub fn with_window_attributes(
         mut self,
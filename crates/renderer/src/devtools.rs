@@ -1,3 +1,14 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
 use freya_core::prelude::{
     get_node_state,
     FreyaDOM,
@@ -15,16 +26,41 @@ use torin::prelude::LayoutNode;
 
 pub type DevtoolsReceiver = watch::Receiver<Vec<NodeInfo>>;
 
+/// Whether the paint order of the nodes is shown over the app, toggled from the devtools.
+pub type PaintOrderOverlay = Arc<AtomicBool>;
+
 #[derive(Clone)]
 pub struct Devtools {
     sender: watch::Sender<Vec<NodeInfo>>,
+    paint_order_overlay: PaintOrderOverlay,
 }
 
 impl Devtools {
     pub fn new() -> (Self, DevtoolsReceiver) {
         let (sender, receiver) = watch::channel(Vec::new());
 
-        (Self { sender }, receiver)
+        (
+            Self {
+                sender,
+                paint_order_overlay: PaintOrderOverlay::default(),
+            },
+            receiver,
+        )
+    }
+
+    /// Get the toggle of the paint order overlay.
+    pub fn paint_order_overlay(&self) -> PaintOrderOverlay {
+        self.paint_order_overlay.clone()
+    }
+
+    /// Whether the paint order overlay is enabled.
+    pub fn is_showing_paint_order(&self) -> bool {
+        self.paint_order_overlay.load(Ordering::Relaxed)
+    }
+
+    /// Get the nodes of the app that are being inspected, leaving out the devtools themselves.
+    pub fn inspected_nodes(&self) -> HashSet<NodeId> {
+        self.sender.borrow().iter().map(|node| node.id).collect()
     }
 
     pub fn update(&self, fdom: &FreyaDOM) {
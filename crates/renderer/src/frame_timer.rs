@@ -0,0 +1,120 @@
+use std::{
+    collections::VecDeque,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use freya_core::prelude::{
+    FrameTimings,
+    FrameTimingsReceiver,
+    FrameTimingsSender,
+};
+use tokio::sync::watch;
+use tracing::warn;
+
+/// Stage of a frame measured by the [FrameTimer].
+pub enum FrameStage {
+    DomUpdates,
+    Layout,
+    Render,
+}
+
+/// Collects how long every stage of a frame takes and sends the [FrameTimings] once the frame is rendered.
+///
+/// Frames that take longer than the budget, if any, are logged as warnings.
+pub struct FrameTimer {
+    sender: FrameTimingsSender,
+    timings: FrameTimings,
+    stage_started: Option<Instant>,
+    frames: VecDeque<Instant>,
+    budget: Option<Duration>,
+    dirty_nodes: usize,
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameTimer {
+    pub fn new() -> Self {
+        Self {
+            sender: watch::channel(FrameTimings::default()).0,
+            timings: FrameTimings::default(),
+            stage_started: None,
+            frames: VecDeque::new(),
+            budget: None,
+            dirty_nodes: 0,
+        }
+    }
+
+    /// Warn about the frames that take longer than the given budget.
+    pub fn set_budget(&mut self, budget: Option<Duration>) {
+        self.budget = budget;
+    }
+
+    /// Count Nodes that changed in the frame being measured.
+    pub fn add_dirty_nodes(&mut self, dirty_nodes: usize) {
+        self.dirty_nodes += dirty_nodes;
+    }
+
+    /// Get a receiver for the timings of every rendered frame.
+    pub fn subscribe(&self) -> FrameTimingsReceiver {
+        self.sender.subscribe()
+    }
+
+    pub fn start(&mut self) {
+        self.stage_started = Some(Instant::now());
+    }
+
+    /// Finish the started stage. DOM updates can happen several times per frame, so they are added up.
+    pub fn finish(&mut self, stage: FrameStage) {
+        let Some(started) = self.stage_started.take() else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        match stage {
+            FrameStage::DomUpdates => self.timings.dom_updates += elapsed,
+            FrameStage::Layout => self.timings.layout += elapsed,
+            FrameStage::Render => self.timings.render += elapsed,
+        }
+    }
+
+    /// Send the timings of the frame that just got rendered and start measuring the next one.
+    pub fn finish_frame(&mut self) {
+        let now = Instant::now();
+        while self
+            .frames
+            .front()
+            .is_some_and(|frame| now.duration_since(*frame) >= Duration::from_secs(1))
+        {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(now);
+
+        let mut timings = std::mem::take(&mut self.timings);
+        timings.frame = timings.dom_updates + timings.layout + timings.render;
+        timings.fps = self.frames.len();
+        let dirty_nodes = std::mem::take(&mut self.dirty_nodes);
+
+        if let Some(budget) = self.budget.filter(|budget| timings.frame > *budget) {
+            let (stage, duration) = [
+                ("DOM updates", timings.dom_updates),
+                ("layout", timings.layout),
+                ("render", timings.render),
+            ]
+            .into_iter()
+            .max_by_key(|(_, duration)| *duration)
+            .unwrap();
+            warn!(
+                "Frame took {:?}, over the budget of {budget:?}. Most of it was spent in the {stage} ({duration:?}), with {dirty_nodes} dirty nodes",
+                timings.frame
+            );
+        }
+
+        self.sender.send_replace(timings);
+    }
+}
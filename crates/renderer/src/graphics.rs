@@ -0,0 +1,472 @@
+use std::{
+    error::Error,
+    ffi::CString,
+    num::NonZeroU32,
+    panic::{
+        catch_unwind,
+        resume_unwind,
+        AssertUnwindSafe,
+    },
+};
+
+use freya_engine::prelude::*;
+use gl::{
+    types::*,
+    *,
+};
+use glutin::{
+    config::{
+        Config,
+        ConfigTemplateBuilder,
+        GlConfig,
+    },
+    context::{
+        ContextApi,
+        ContextAttributesBuilder,
+        GlProfile,
+        NotCurrentGlContext,
+        PossiblyCurrentContext,
+        PossiblyCurrentGlContext,
+    },
+    display::{
+        GetGlDisplay,
+        GlDisplay,
+    },
+    surface::{
+        GlSurface,
+        Surface as GlutinSurface,
+        SurfaceAttributesBuilder,
+        SwapInterval,
+        WindowSurface,
+    },
+};
+use glutin_winit::DisplayBuilder;
+use tracing::warn;
+use winit::{
+    event_loop::ActiveEventLoop,
+    raw_window_handle::{
+        DisplayHandle,
+        HandleError,
+        HasDisplayHandle,
+        HasWindowHandle,
+        RawDisplayHandle,
+        RawWindowHandle,
+        WindowHandle,
+    },
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+use crate::config::WindowConfig;
+
+/// How the frames of the Window are rendered and presented.
+pub enum GraphicsDriver {
+    /// Rendered with the GPU through OpenGL.
+    Gl(GlDriver),
+    /// Rendered with the CPU, when the GPU can't be used or software rendering is forced.
+    Software(SoftwareDriver),
+}
+
+impl GraphicsDriver {
+    /// Create the Window along with its graphics driver.
+    ///
+    /// Software rendering is used with a warning if the GPU can't be used.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+        window_config: &WindowConfig,
+    ) -> (Window, Self) {
+        if window_config.software_rendering {
+            let window = event_loop
+                .create_window(window_attributes)
+                .expect("Could not create window");
+            let driver = SoftwareDriver::new(&window, window_config);
+            return (window, Self::Software(driver));
+        }
+
+        let msaa_samples = window_config.msaa_samples();
+
+        let (window, gl_config) = match create_gl_window(
+            event_loop,
+            window_attributes.clone(),
+            window_config,
+            msaa_samples,
+        ) {
+            Ok(created) => created,
+            Err(err) => {
+                warn!(
+                    "Could not create an OpenGL window ({err}), falling back to software rendering"
+                );
+                let window = event_loop
+                    .create_window(window_attributes)
+                    .expect("Could not create window");
+                let driver = SoftwareDriver::new(&window, window_config);
+                return (window, Self::Software(driver));
+            }
+        };
+
+        match GlDriver::new(&window, &gl_config, window_config, msaa_samples) {
+            Ok(driver) => (window, Self::Gl(driver)),
+            Err(err) => {
+                warn!("Could not create an OpenGL context ({err}), falling back to software rendering");
+                let driver = SoftwareDriver::new(&window, window_config);
+                (window, Self::Software(driver))
+            }
+        }
+    }
+
+    /// Create the surface for Skia to render in, with the current size of the Window.
+    pub fn create_surface(&mut self, window: &Window, surface_props: &SurfaceProps) -> Surface {
+        match self {
+            Self::Gl(driver) => driver.create_surface(window, surface_props),
+            Self::Software(driver) => driver.create_surface(window, surface_props),
+        }
+    }
+
+    /// Present the frame rendered in the given surface.
+    pub fn present(&mut self, surface: &mut Surface) {
+        match self {
+            Self::Gl(driver) => driver.present(),
+            Self::Software(driver) => driver.present(surface),
+        }
+    }
+
+    /// The GPU context, if rendered with the GPU.
+    pub fn gr_context(&mut self) -> Option<&mut DirectContext> {
+        match self {
+            Self::Gl(driver) => Some(&mut driver.gr_context),
+            Self::Software(_) => None,
+        }
+    }
+
+    /// Make the GPU context current so its resources can be freed, or abandon them if it can't be.
+    pub fn release(&mut self) {
+        if let Self::Gl(GlDriver {
+            gl_context,
+            gl_surface,
+            gr_context,
+            ..
+        }) = self
+        {
+            if !gl_context.is_current() && gl_context.make_current(gl_surface).is_err() {
+                gr_context.abandon();
+            }
+        }
+    }
+}
+
+/// Unwinding payload of the config picker when there are no OpenGL configs to pick from.
+struct NoGlConfigs;
+
+/// Build the Window with the OpenGL config that is the closest to the requested one.
+fn create_gl_window(
+    event_loop: &ActiveEventLoop,
+    window_attributes: WindowAttributes,
+    window_config: &WindowConfig,
+    msaa_samples: u8,
+) -> Result<(Window, Config), Box<dyn Error>> {
+    let template = ConfigTemplateBuilder::new()
+        .with_alpha_size(8)
+        .with_transparency(window_config.transparent);
+
+    let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
+
+    // The picker must always return a config, so unwind out of it when there is none.
+    // `resume_unwind` doesn't run the panic hook, so nothing gets printed.
+    let built = catch_unwind(AssertUnwindSafe(|| {
+        display_builder.build(event_loop, template, |configs| {
+            // Pick the config with the closest amount of samples to the requested ones
+            configs
+                .reduce(|accum, config| {
+                    let transparency_check = config.supports_transparency().unwrap_or(false)
+                        & !accum.supports_transparency().unwrap_or(false);
+                    let samples_check = config.num_samples().abs_diff(msaa_samples)
+                        < accum.num_samples().abs_diff(msaa_samples);
+
+                    if transparency_check || samples_check {
+                        config
+                    } else {
+                        accum
+                    }
+                })
+                .unwrap_or_else(|| resume_unwind(Box::new(NoGlConfigs)))
+        })
+    }));
+
+    let (window, gl_config) = match built {
+        Ok(built) => built?,
+        Err(payload) if payload.is::<NoGlConfigs>() => {
+            return Err("No OpenGL config is available".into())
+        }
+        Err(payload) => resume_unwind(payload),
+    };
+
+    let window = window.ok_or("Could not create window with OpenGL context")?;
+
+    Ok((window, gl_config))
+}
+
+pub struct GlDriver {
+    pub(crate) gr_context: DirectContext,
+    pub(crate) gl_surface: GlutinSurface<WindowSurface>,
+    pub(crate) gl_context: PossiblyCurrentContext,
+    pub(crate) fb_info: FramebufferInfo,
+    pub(crate) num_samples: usize,
+    pub(crate) stencil_size: usize,
+}
+
+impl GlDriver {
+    fn new(
+        window: &Window,
+        gl_config: &Config,
+        window_config: &WindowConfig,
+        msaa_samples: u8,
+    ) -> Result<Self, Box<dyn Error>> {
+        let window_handle = window.window_handle()?;
+
+        let context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .build(Some(window_handle.as_raw()));
+
+        let fallback_context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .with_context_api(ContextApi::Gles(None))
+            .build(Some(window_handle.as_raw()));
+
+        let not_current_gl_context = unsafe {
+            gl_config
+                .display()
+                .create_context(gl_config, &context_attributes)
+                .or_else(|_| {
+                    gl_config
+                        .display()
+                        .create_context(gl_config, &fallback_context_attributes)
+                })?
+        };
+
+        let (width, height): (u32, u32) = window.inner_size().into();
+
+        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+            window_handle.as_raw(),
+            NonZeroU32::new(width.max(1)).unwrap(),
+            NonZeroU32::new(height.max(1)).unwrap(),
+        );
+
+        let gl_surface = unsafe {
+            gl_config
+                .display()
+                .create_window_surface(gl_config, &attrs)?
+        };
+
+        let gl_context = not_current_gl_context.make_current(&gl_surface)?;
+
+        // Try setting vsync.
+        let swap_interval = if window_config.vsync {
+            SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+        } else {
+            SwapInterval::DontWait
+        };
+        gl_surface
+            .set_swap_interval(&gl_context, swap_interval)
+            .ok();
+
+        load_with(|s| {
+            gl_config
+                .display()
+                .get_proc_address(CString::new(s).unwrap().as_c_str())
+        });
+        let interface = Interface::new_load_with(|name| {
+            if name == "eglGetCurrentDisplay" {
+                return std::ptr::null();
+            }
+            gl_config
+                .display()
+                .get_proc_address(CString::new(name).unwrap().as_c_str())
+        })
+        .ok_or("Could not create interface")?;
+
+        let gr_context =
+            direct_contexts::make_gl(interface, None).ok_or("Could not create direct context")?;
+
+        let fb_info = {
+            let mut fboid: GLint = 0;
+            unsafe { GetIntegerv(FRAMEBUFFER_BINDING, &mut fboid) };
+
+            FramebufferInfo {
+                fboid: fboid.try_into().unwrap(),
+                format: Format::RGBA8.into(),
+                ..Default::default()
+            }
+        };
+
+        if window_config.transparent && !gl_config.supports_transparency().unwrap_or(false) {
+            warn!("Transparent windows are not supported by the GPU, the window will be opaque");
+        }
+
+        if msaa_samples > 0 && gl_config.num_samples() != msaa_samples {
+            warn!(
+                "MSAA with {msaa_samples} samples is not supported, using {} samples instead",
+                gl_config.num_samples()
+            );
+        }
+
+        Ok(Self {
+            gr_context,
+            gl_surface,
+            gl_context,
+            fb_info,
+            num_samples: gl_config.num_samples() as usize,
+            stencil_size: gl_config.stencil_size() as usize,
+        })
+    }
+
+    fn create_surface(&mut self, window: &Window, surface_props: &SurfaceProps) -> Surface {
+        let size = window.inner_size();
+
+        self.gl_surface.resize(
+            &self.gl_context,
+            NonZeroU32::new(size.width.max(1)).unwrap(),
+            NonZeroU32::new(size.height.max(1)).unwrap(),
+        );
+
+        let size = (
+            size.width.try_into().expect("Could not convert width"),
+            size.height.try_into().expect("Could not convert height"),
+        );
+        let backend_render_target = backend_render_targets::make_gl(
+            size,
+            self.num_samples,
+            self.stencil_size,
+            self.fb_info,
+        );
+        wrap_backend_render_target(
+            &mut self.gr_context,
+            &backend_render_target,
+            SurfaceOrigin::BottomLeft,
+            ColorType::RGBA8888,
+            None,
+            Some(surface_props),
+        )
+        .expect("Could not create skia surface")
+    }
+
+    fn present(&mut self) {
+        self.gr_context.flush_and_submit();
+        self.gl_surface.swap_buffers(&self.gl_context).unwrap();
+    }
+}
+
+/// Raw handles of the Window, so the software surface can be stored next to it.
+#[derive(Clone, Copy)]
+pub struct RawWindow {
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+}
+
+impl HasWindowHandle for RawWindow {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        // SAFETY: The software surface is always dropped before the Window
+        Ok(unsafe { WindowHandle::borrow_raw(self.window) })
+    }
+}
+
+impl HasDisplayHandle for RawWindow {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        // SAFETY: The software surface is always dropped before the Window
+        Ok(unsafe { DisplayHandle::borrow_raw(self.display) })
+    }
+}
+
+pub struct SoftwareDriver {
+    surface: softbuffer::Surface<RawWindow, RawWindow>,
+    _context: softbuffer::Context<RawWindow>,
+    pixels: Vec<u8>,
+}
+
+impl SoftwareDriver {
+    fn new(window: &Window, window_config: &WindowConfig) -> Self {
+        let raw_window = RawWindow {
+            window: window
+                .window_handle()
+                .expect("Could not get the window handle")
+                .as_raw(),
+            display: window
+                .display_handle()
+                .expect("Could not get the display handle")
+                .as_raw(),
+        };
+        let context =
+            softbuffer::Context::new(raw_window).expect("Could not create software context");
+        let surface = softbuffer::Surface::new(&context, raw_window)
+            .expect("Could not create software surface");
+
+        if window_config.transparent {
+            warn!("Transparent windows are not supported with software rendering, the window will be opaque");
+        }
+
+        if window_config.msaa_samples() > 0 {
+            warn!("MSAA is not supported with software rendering");
+        }
+
+        Self {
+            surface,
+            _context: context,
+            pixels: Vec::new(),
+        }
+    }
+
+    fn create_surface(&mut self, window: &Window, surface_props: &SurfaceProps) -> Surface {
+        let size = window.inner_size();
+        let (width, height) = (size.width.max(1), size.height.max(1));
+
+        self.surface
+            .resize(
+                NonZeroU32::new(width).unwrap(),
+                NonZeroU32::new(height).unwrap(),
+            )
+            .expect("Could not resize software surface");
+
+        let image_info = ImageInfo::new(
+            (width as i32, height as i32),
+            ColorType::BGRA8888,
+            AlphaType::Premul,
+            None,
+        );
+        raster(&image_info, None, Some(surface_props)).expect("Could not create skia surface")
+    }
+
+    fn present(&mut self, surface: &mut Surface) {
+        let (width, height) = (surface.width(), surface.height());
+        let image_info = ImageInfo::new(
+            (width, height),
+            ColorType::BGRA8888,
+            AlphaType::Premul,
+            None,
+        );
+        let row_bytes = width as usize * 4;
+        self.pixels.resize(row_bytes * height as usize, 0);
+        if !surface.read_pixels(&image_info, &mut self.pixels, row_bytes, (0, 0)) {
+            warn!("Could not read the pixels of the frame");
+            return;
+        }
+
+        let mut buffer = match self.surface.buffer_mut() {
+            Ok(buffer) => buffer,
+            Err(err) => {
+                warn!("Could not present the frame: {err}");
+                return;
+            }
+        };
+
+        // The frame is in BGRA, and the software buffer takes 0RGB in native endian
+        for (pixel, bgra) in buffer.iter_mut().zip(self.pixels.chunks_exact(4)) {
+            *pixel = u32::from_be_bytes([0, bgra[2], bgra[1], bgra[0]]);
+        }
+
+        if let Err(err) = buffer.present() {
+            warn!("Could not present the frame: {err}");
+        }
+    }
+}
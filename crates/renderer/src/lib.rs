@@ -14,6 +14,9 @@ mod accessibility;
 mod app;
 mod config;
 pub mod devtools;
+#[cfg(feature = "frame-timings")]
+mod frame_timer;
+mod graphics;
 mod renderer;
 mod window_state;
 mod winit_waker;
@@ -1,6 +1,6 @@
 use std::{
-    num::NonZeroU32,
     path::PathBuf,
+    time::Instant,
 };
 
 use dioxus_core::VirtualDom;
@@ -9,8 +9,10 @@ use freya_core::{
     accessibility::AccessibilityFocusDirection,
     dom::SafeDOM,
     events::{
+        measure_node_at_point,
         EventName,
         PlatformEvent,
+        TouchGestures,
     },
     prelude::NavigationMode,
 };
@@ -21,10 +23,6 @@ use freya_elements::events::{
     Code,
     Key,
 };
-use glutin::prelude::{
-    GlSurface,
-    PossiblyCurrentGlContext,
-};
 use torin::geometry::CursorPoint;
 use winit::{
     application::ApplicationHandler,
@@ -33,13 +31,13 @@ use winit::{
         Ime,
         KeyEvent,
         MouseButton,
-        MouseScrollDelta,
         StartCause,
         Touch,
         TouchPhase,
         WindowEvent,
     },
     event_loop::{
+        ControlFlow,
         EventLoop,
         EventLoopProxy,
     },
@@ -49,7 +47,6 @@ use winit::{
 use crate::{
     devtools::Devtools,
     window_state::{
-        create_surface,
         CreatedState,
         NotCreatedState,
         WindowState,
@@ -58,8 +55,6 @@ use crate::{
     LaunchConfig,
 };
 
-const WHEEL_SPEED_MODIFIER: f32 = 53.0;
-
 /// Desktop renderer using Skia, Glutin and Winit
 pub struct DesktopRenderer<'a, State: Clone + 'static> {
     pub(crate) event_loop_proxy: EventLoopProxy<EventMessage>,
@@ -69,6 +64,7 @@ pub struct DesktopRenderer<'a, State: Clone + 'static> {
     pub(crate) mouse_state: ElementState,
     pub(crate) modifiers_state: ModifiersState,
     pub(crate) dropped_file_path: Option<PathBuf>,
+    pub(crate) touch_gestures: TouchGestures,
 }
 
 impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
@@ -126,16 +122,29 @@ impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
             mouse_state: ElementState::Released,
             modifiers_state: ModifiersState::default(),
             dropped_file_path: None,
+            touch_gestures: TouchGestures::default(),
         }
     }
 
     // Send and process an event
     fn send_event(&mut self, event: PlatformEvent) {
         let scale_factor = self.scale_factor();
-        self.state
-            .created_state()
-            .app
-            .send_event(event, scale_factor);
+        let CreatedState { app, window, .. } = self.state.created_state();
+        let (must_repaint, must_relayout) = app.send_event(event, scale_factor);
+
+        // The interaction styles of the elements changed
+        if must_relayout {
+            app.measure_layout_on_next_render = true;
+        }
+
+        if must_relayout || must_repaint {
+            window.request_redraw();
+        }
+
+        // An element with `window_drag` was dragged
+        if app.nodes_state.take_window_drag() {
+            window.drag_window().ok();
+        }
     }
 
     /// Get the current scale factor of the Window
@@ -173,19 +182,41 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
 
     fn new_events(
         &mut self,
-        _event_loop: &winit::event_loop::ActiveEventLoop,
+        event_loop: &winit::event_loop::ActiveEventLoop,
         cause: winit::event::StartCause,
     ) {
-        if cause == StartCause::Init {
-            self.event_loop_proxy
-                .send_event(EventMessage::PollVDOM)
-                .ok();
+        match cause {
+            StartCause::Init => {
+                self.event_loop_proxy
+                    .send_event(EventMessage::PollVDOM)
+                    .ok();
+            }
+            // Render the frame that was postponed by the frame rate cap
+            StartCause::ResumeTimeReached { .. } if self.state.has_been_created() => {
+                event_loop.set_control_flow(ControlFlow::Wait);
+                self.state.created_state().window.request_redraw();
+            }
+            _ => {}
         }
     }
 
     fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: EventMessage) {
+        if let EventMessage::ReplayCursorMovement = event {
+            self.send_event(PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                cursor: self.cursor_pos,
+                button: None,
+            });
+            return;
+        }
+
         let scale_factor = self.scale_factor();
-        let CreatedState { window, app, .. } = self.state.created_state();
+        let CreatedState {
+            window,
+            app,
+            graphics,
+            ..
+        } = self.state.created_state();
         match event {
             EventMessage::FocusAccessibilityNode(id) => {
                 app.focus_node(id, window);
@@ -205,6 +236,18 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 app.accessibility.process_initial_tree();
             }
             EventMessage::SetCursorIcon(icon) => window.set_cursor(icon),
+            EventMessage::CaptureScreenshot(area, callback) => {
+                app.queue_screenshot(area, callback);
+                window.request_redraw();
+            }
+            EventMessage::NodeAtPoint(point, callback) => {
+                let scale_factor = window.scale_factor();
+                callback(measure_node_at_point(
+                    &app.sdom.get(),
+                    point * scale_factor,
+                    scale_factor,
+                ));
+            }
             EventMessage::FocusPrevAccessibilityNode => {
                 app.set_navigation_mode(NavigationMode::Keyboard);
                 app.focus_next_node(AccessibilityFocusDirection::Backward, window);
@@ -214,6 +257,11 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 app.focus_next_node(AccessibilityFocusDirection::Forward, window);
             }
             EventMessage::WithWindow(use_window) => (use_window)(window),
+            EventMessage::WithGpuContext(use_gpu_context) => {
+                if let Some(gr_context) = graphics.gr_context() {
+                    (use_gpu_context)(gr_context)
+                }
+            }
             EventMessage::QueueFocusAccessibilityNode(node_id) => {
                 app.queue_focus_node(node_id);
             }
@@ -240,17 +288,13 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
     ) {
         let scale_factor = self.scale_factor();
         let CreatedState {
-            gr_context,
             surface,
-            gl_surface,
-            gl_context,
+            graphics,
             window,
             app,
             window_config,
-            fb_info,
-            num_samples,
-            stencil_size,
             is_window_focused,
+            last_frame_at,
             ..
         } = self.state.created_state();
         app.accessibility
@@ -261,7 +305,12 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     state.preferred_theme = theme.into();
                 });
             }
-            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::CloseRequested => {
+                // Let the app decide whether to close if it's listening for close requests
+                if app.close_request_sender.send(()).is_err() {
+                    event_loop.exit();
+                }
+            }
             WindowEvent::Ime(Ime::Commit(text)) => {
                 self.send_event(PlatformEvent::Keyboard {
                     name: EventName::KeyDown,
@@ -270,7 +319,42 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     modifiers: map_winit_modifiers(self.modifiers_state),
                 });
             }
+            WindowEvent::Ime(Ime::Preedit(preedit, cursor)) => {
+                // winit gives byte indices but the editors work with chars
+                let cursor = cursor.map(|(start, end)| {
+                    (
+                        preedit[..start].chars().count(),
+                        preedit[..end].chars().count(),
+                    )
+                });
+                self.send_event(PlatformEvent::Ime {
+                    name: EventName::ImePreedit,
+                    preedit,
+                    cursor,
+                });
+            }
+            WindowEvent::Ime(Ime::Disabled) => {
+                // Drop any unfinished composition
+                self.send_event(PlatformEvent::Ime {
+                    name: EventName::ImePreedit,
+                    preedit: String::new(),
+                    cursor: None,
+                });
+            }
             WindowEvent::RedrawRequested => {
+                if let Some(frame_interval) = window_config.frame_interval() {
+                    let now = Instant::now();
+                    let next_frame_at = last_frame_at.map(|at| at + frame_interval);
+                    match next_frame_at {
+                        Some(next_frame_at) if next_frame_at > now => {
+                            // Too early, render it once the interval is over
+                            event_loop.set_control_flow(ControlFlow::WaitUntil(next_frame_at));
+                            return;
+                        }
+                        _ => *last_frame_at = Some(now),
+                    }
+                }
+
                 app.platform_sender.send_if_modified(|state| {
                     let scale_factor_is_different = state.scale_factor == scale_factor;
                     state.scale_factor = scale_factor;
@@ -285,10 +369,10 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 }
                 surface.canvas().clear(window_config.background);
                 app.render(&self.hovered_node, surface.canvas(), window);
+                app.capture_screenshots(surface);
                 app.event_loop_tick();
                 window.pre_present_notify();
-                gr_context.flush_and_submit();
-                gl_surface.swap_buffers(gl_context).unwrap();
+                graphics.present(surface);
             }
             WindowEvent::MouseInput { state, button, .. } => {
                 app.set_navigation_mode(NavigationMode::NotKeyboard);
@@ -313,20 +397,15 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
             }
             WindowEvent::MouseWheel { delta, phase, .. } => {
                 if TouchPhase::Moved == phase {
-                    let scroll_data = {
-                        match delta {
-                            MouseScrollDelta::LineDelta(x, y) => (
-                                (x * WHEEL_SPEED_MODIFIER) as f64,
-                                (y * WHEEL_SPEED_MODIFIER) as f64,
-                            ),
-                            MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
-                        }
-                    };
+                    let scroll_data = window_config
+                        .scroll_sensitivity
+                        .normalize(delta, scale_factor);
 
                     self.send_event(PlatformEvent::Wheel {
                         name: EventName::Wheel,
                         scroll: CursorPoint::from(scroll_data),
                         cursor: self.cursor_pos,
+                        modifiers: map_winit_modifiers(self.modifiers_state),
                     });
                 }
             }
@@ -409,16 +488,37 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     phase,
                     force,
                 });
+
+                for gesture in self
+                    .touch_gestures
+                    .process_touch(id, self.cursor_pos, phase)
+                {
+                    self.send_event(gesture);
+                }
+            }
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                self.send_event(PlatformEvent::Gesture {
+                    name: EventName::Pinch,
+                    cursor: self.cursor_pos,
+                    scale: 1.0 + delta,
+                    translation: CursorPoint::zero(),
+                    phase,
+                });
             }
-            WindowEvent::Resized(size) => {
+            WindowEvent::PanGesture { delta, phase, .. } => {
+                self.send_event(PlatformEvent::Gesture {
+                    name: EventName::Pan,
+                    cursor: self.cursor_pos,
+                    scale: 1.0,
+                    translation: CursorPoint::new(delta.x as f64, delta.y as f64),
+                    phase,
+                });
+            }
+            WindowEvent::Resized(_) => {
                 *surface =
-                    create_surface(window, *fb_info, gr_context, *num_samples, *stencil_size);
-
-                gl_surface.resize(
-                    gl_context,
-                    NonZeroU32::new(size.width.max(1)).unwrap(),
-                    NonZeroU32::new(size.height.max(1)).unwrap(),
-                );
+                    graphics.create_surface(window, &window_config.render_quality.surface_props());
+                // Until the next frame is rendered the new surface only shows the background
+                surface.canvas().clear(window_config.background);
 
                 window.request_redraw();
 
@@ -455,16 +555,8 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
 
 impl<T: Clone> Drop for DesktopRenderer<'_, T> {
     fn drop(&mut self) {
-        if let WindowState::Created(CreatedState {
-            gl_context,
-            gl_surface,
-            gr_context,
-            ..
-        }) = &mut self.state
-        {
-            if !gl_context.is_current() && gl_context.make_current(gl_surface).is_err() {
-                gr_context.abandon();
-            }
+        if let WindowState::Created(CreatedState { graphics, .. }) = &mut self.state {
+            graphics.release();
         }
     }
 }
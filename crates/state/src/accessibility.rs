@@ -30,6 +30,8 @@ pub struct AccessibilityNodeState {
     pub alt: Option<String>,
     pub name: Option<String>,
     pub focusable: bool,
+    /// Whether the keyboard focus is kept within this node and its descendants.
+    pub focus_trap: bool,
 }
 
 impl ParseAttribute for AccessibilityNodeState {
@@ -68,6 +70,11 @@ impl ParseAttribute for AccessibilityNodeState {
                     self.focusable = attr.parse().unwrap_or_default()
                 }
             }
+            AttributeName::FocusTrap => {
+                if let OwnedAttributeValue::Text(attr) = attr.value {
+                    self.focus_trap = attr.parse().map_err(|_| ParseError)?
+                }
+            }
             _ => {}
         }
 
@@ -90,6 +97,7 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::Alt,
             AttributeName::Name,
             AttributeName::Focusable,
+            AttributeName::FocusTrap,
         ]));
 
     fn update<'a>(
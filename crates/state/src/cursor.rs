@@ -1,4 +1,7 @@
-use freya_common::ParagraphElements;
+use freya_common::{
+    CursorAffinity,
+    ParagraphElements,
+};
 use freya_engine::prelude::*;
 use freya_native_core::{
     attributes::AttributeName,
@@ -30,12 +33,19 @@ use crate::{
 #[derive(Clone, Debug, PartialEq, Component)]
 pub struct CursorState {
     pub position: Option<i32>,
+    /// Which side of a line wrap the cursor is shown at.
+    pub affinity: CursorAffinity,
     pub color: Color,
     pub mode: CursorMode,
     pub cursor_id: Option<usize>,
     pub highlights: Option<Vec<(usize, usize)>>,
+    /// Background of the highlighted text, set with `highlight_color` or `selection_color`.
     pub highlight_color: Color,
+    /// Color of the highlighted glyphs, they keep their own color if not specified.
+    pub selection_text_color: Option<Color>,
     pub highlight_mode: HighlightMode,
+    /// Whether the text can be selected with the mouse, inherited from the ancestors unless specified.
+    pub selectable: bool,
     pub cursor_ref: Option<CursorReference>,
 }
 
@@ -43,12 +53,15 @@ impl Default for CursorState {
     fn default() -> Self {
         Self {
             position: None,
+            affinity: CursorAffinity::default(),
             color: Color::BLACK,
             mode: CursorMode::None,
             cursor_id: None,
             highlights: None,
             highlight_color: Color::from_rgb(87, 108, 188),
+            selection_text_color: None,
             highlight_mode: HighlightMode::default(),
+            selectable: true,
             cursor_ref: None,
         }
     }
@@ -67,6 +80,11 @@ impl ParseAttribute for CursorState {
                     }
                 }
             }
+            AttributeName::CursorAffinity => {
+                if let Some(value) = attr.value.as_text() {
+                    self.affinity = CursorAffinity::parse(value)?;
+                }
+            }
             AttributeName::CursorColor => {
                 if let Some(value) = attr.value.as_text() {
                     self.color = Color::parse(value)?;
@@ -89,16 +107,26 @@ impl ParseAttribute for CursorState {
                     self.highlights = Some(highlights.clone());
                 }
             }
-            AttributeName::HighlightColor => {
+            AttributeName::HighlightColor | AttributeName::SelectionColor => {
                 if let Some(value) = attr.value.as_text() {
                     self.highlight_color = Color::parse(value)?;
                 }
             }
+            AttributeName::SelectionTextColor => {
+                if let Some(value) = attr.value.as_text() {
+                    self.selection_text_color = Some(Color::parse(value)?);
+                }
+            }
             AttributeName::HighlightMode => {
                 if let Some(value) = attr.value.as_text() {
                     self.highlight_mode = HighlightMode::parse(value)?;
                 }
             }
+            AttributeName::Selectable => {
+                if let Some(value) = attr.value.as_text() {
+                    self.selectable = value.parse::<bool>().map_err(|_| ParseError)?;
+                }
+            }
             AttributeName::CursorReference => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::CursorReference(
                     reference,
@@ -125,12 +153,16 @@ impl State<CustomAttributeValues> for CursorState {
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::CursorIndex,
+            AttributeName::CursorAffinity,
             AttributeName::CursorColor,
             AttributeName::CursorMode,
             AttributeName::CursorId,
             AttributeName::Highlights,
             AttributeName::HighlightColor,
+            AttributeName::SelectionColor,
+            AttributeName::SelectionTextColor,
             AttributeName::HighlightMode,
+            AttributeName::Selectable,
             AttributeName::CursorReference,
         ]))
         .with_tag();
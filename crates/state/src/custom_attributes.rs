@@ -4,6 +4,10 @@ use std::{
         Debug,
         Display,
     },
+    hash::{
+        Hash,
+        Hasher,
+    },
     sync::{
         Arc,
         Mutex,
@@ -19,6 +23,7 @@ use freya_common::{
 };
 use freya_engine::prelude::*;
 use freya_native_core::node::FromAnyValue;
+use rustc_hash::FxHasher;
 use tokio::sync::{
     mpsc::UnboundedSender,
     watch,
@@ -58,6 +63,18 @@ impl Display for NodeReference {
     }
 }
 
+/// What a [NodeReference] reports about the children of its Node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReferenceChildren {
+    /// Nothing, only the layout of the Node itself.
+    #[default]
+    None,
+    /// The snap points of its children, used by scroll containers.
+    SnapPoints,
+    /// The areas of its children laid out in its flow, and their snap points.
+    All,
+}
+
 pub type CanvasRunner = dyn Fn(&Canvas, &mut FontCollection, Area, f32) + Sync + Send + 'static;
 
 /// Canvas Reference
@@ -112,28 +129,71 @@ impl AttributesBytes {
     }
 }
 
+/// Bytes of an attribute along with their hash, computed once when the attribute is parsed
+/// so the rendered result can be cached without hashing the bytes every frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashedBytes {
+    pub bytes: AttributesBytes,
+    pub hash: u64,
+}
+
+impl HashedBytes {
+    pub fn new(bytes: AttributesBytes) -> Self {
+        let mut hasher = FxHasher::default();
+        bytes.as_slice().hash(&mut hasher);
+
+        Self {
+            hash: hasher.finish(),
+            bytes,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+/// States of a Node caused by the interaction of the user, used to resolve its interaction styles (e.g `background_hover`).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interaction {
+    /// The cursor is over the Node.
+    pub hovered: bool,
+    /// The Node was pressed and it hasn't been released yet.
+    pub pressed: bool,
+    /// The Node is focused.
+    pub focused: bool,
+}
+
 /// Group all the custom attribute types
 #[derive(Clone, PartialEq)]
 pub enum CustomAttributeValues {
     Reference(NodeReference),
+    ChildrenReference(NodeReference, ReferenceChildren),
     CursorReference(CursorReference),
     Bytes(AttributesBytes),
     ImageReference(ImageReference),
     AccessibilityId(AccessibilityId),
     TextHighlights(Vec<(usize, usize)>),
     Canvas(CanvasReference),
+    Interaction(Interaction),
 }
 
 impl Debug for CustomAttributeValues {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Reference(_) => f.debug_tuple("Reference").finish(),
+            Self::ChildrenReference(_, children) => {
+                f.debug_tuple("ChildrenReference").field(children).finish()
+            }
             Self::CursorReference(_) => f.debug_tuple("CursorReference").finish(),
             Self::Bytes(_) => f.debug_tuple("Bytes").finish(),
             Self::ImageReference(_) => f.debug_tuple("ImageReference").finish(),
             Self::AccessibilityId(_) => f.debug_tuple("AccessibilityId").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
+            Self::Interaction(interaction) => {
+                f.debug_tuple("Interaction").field(interaction).finish()
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use std::sync::{
     Arc,
     Mutex,
+    OnceLock,
 };
 
 use freya_engine::prelude::*;
@@ -23,14 +24,80 @@ use torin::torin::Torin;
 use crate::{
     CustomAttributeValues,
     ExtSplit,
+    Fill,
+    Hyphens,
+    LineBreak,
+    LineHeightBehavior,
     Parse,
     ParseAttribute,
+    ParseError,
     TextOverflow,
+    WordBreak,
 };
 
+/// How much `baseline_shift: "super"` raises the text, relative to its font size.
+const SUPERSCRIPT_SHIFT: f32 = 0.33;
+/// How much `baseline_shift: "sub"` lowers the text, relative to its font size.
+const SUBSCRIPT_SHIFT: f32 = 0.2;
+/// Font size of the text shifted with `super` or `sub`, relative to its original font size.
+const SCRIPT_FONT_SIZE: f32 = 0.7;
+
+/// Language of the system, as a BCP 47 tag like `en-US`, taken from the usual locale environment variables.
+///
+/// These are only commonly set on Unix systems, on Windows (and macOS apps not launched from a terminal)
+/// this is usually `None`, so the text engine uses its own default. Specify `lang` to be explicit.
+pub fn system_locale() -> Option<&'static str> {
+    static SYSTEM_LOCALE: OnceLock<Option<String>> = OnceLock::new();
+
+    SYSTEM_LOCALE
+        .get_or_init(|| {
+            ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .into_iter()
+                .filter_map(|var| std::env::var(var).ok())
+                .find(|value| !value.is_empty())
+                .and_then(|value| {
+                    // e.g `ja_JP.UTF-8` or `en_US@euro`
+                    let locale = value.split(['.', '@']).next()?.replace('_', "-");
+                    (!locale.is_empty() && locale != "C" && locale != "POSIX").then_some(locale)
+                })
+        })
+        .as_deref()
+}
+
+/// Styles that override the ones of the first line of a `paragraph`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FirstLineStyle {
+    pub color: Option<Color>,
+    pub font_size: Option<f32>,
+    pub font_weight: Option<Weight>,
+}
+
+impl FirstLineStyle {
+    /// Check if any style is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.color.is_none() && self.font_size.is_none() && self.font_weight.is_none()
+    }
+
+    /// Override the given font style with the ones of the first line.
+    pub fn apply(&self, font_style: &mut FontStyleState) {
+        if let Some(color) = self.color {
+            font_style.color = color;
+            font_style.fill = None;
+        }
+        if let Some(font_size) = self.font_size {
+            font_style.font_size = font_size;
+        }
+        if let Some(font_weight) = self.font_weight {
+            font_style.font_weight = font_weight;
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Component)]
 pub struct FontStyleState {
     pub color: Color,
+    /// Gradient used to fill the text instead of `color`.
+    pub fill: Option<Fill>,
     pub text_shadows: Vec<TextShadow>,
     pub font_family: Vec<String>,
     pub font_size: f32,
@@ -38,15 +105,107 @@ pub struct FontStyleState {
     pub font_weight: Weight,
     pub font_width: Width,
     pub line_height: f32, // https://developer.mozilla.org/en-US/docs/Web/CSS/line-height,
+    /// Whether the `line_height` adds space above the first line and below the last one.
+    pub line_height_behavior: LineHeightBehavior,
     pub decoration: Decoration,
     pub word_spacing: f32,
     pub letter_spacing: f32,
     pub text_align: TextAlign,
     pub max_lines: Option<usize>,
     pub text_overflow: TextOverflow,
+    /// Width of the outline drawn around the glyphs, `0.0` to not draw it.
+    pub text_stroke_width: f32,
+    pub text_stroke_color: Color,
+    /// Distance the text is raised above the baseline, or lowered below it when negative.
+    pub baseline_shift: f32,
+    pub word_break: WordBreak,
+    pub hyphens: Hyphens,
+    pub line_break: LineBreak,
+    /// Language of the text, as a BCP 47 tag like `en` or `ja`. Uses the [`system_locale`] if not specified.
+    pub lang: Option<String>,
+    /// Styles of the first line of a `paragraph`, not inherited.
+    pub first_line: FirstLineStyle,
 }
 
 impl FontStyleState {
+    /// Set the color of the text, like the `color` attribute.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self.fill = None;
+        self
+    }
+
+    /// Set the font families, like the `font_family` attribute.
+    pub fn with_font_family(mut self, font_family: Vec<String>) -> Self {
+        self.font_family = font_family;
+        self
+    }
+
+    /// Set the font size, like the `font_size` attribute.
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set the font slant, like the `font_style` attribute.
+    pub fn with_font_slant(mut self, font_slant: Slant) -> Self {
+        self.font_slant = font_slant;
+        self
+    }
+
+    /// Set the font weight, like the `font_weight` attribute.
+    pub fn with_font_weight(mut self, font_weight: Weight) -> Self {
+        self.font_weight = font_weight;
+        self
+    }
+
+    /// Set the font width, like the `font_width` attribute.
+    pub fn with_font_width(mut self, font_width: Width) -> Self {
+        self.font_width = font_width;
+        self
+    }
+
+    /// Set the line height, like the `line_height` attribute.
+    pub fn with_line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Set the alignment of the text, like the `text_align` attribute.
+    pub fn with_text_align(mut self, text_align: TextAlign) -> Self {
+        self.text_align = text_align;
+        self
+    }
+
+    /// Set the maximum amount of lines, like the `max_lines` attribute.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Set how the text overflows, like the `text_overflow` attribute.
+    pub fn with_text_overflow(mut self, text_overflow: TextOverflow) -> Self {
+        self.text_overflow = text_overflow;
+        self
+    }
+
+    /// Set the spacing between the letters, like the `letter_spacing` attribute.
+    pub fn with_letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Set the spacing between the words, like the `word_spacing` attribute.
+    pub fn with_word_spacing(mut self, word_spacing: f32) -> Self {
+        self.word_spacing = word_spacing;
+        self
+    }
+
+    /// Language of the text, falling back to the [`system_locale`].
+    pub fn resolved_lang(&self) -> Option<&str> {
+        self.lang.as_deref().or_else(system_locale)
+    }
+
     pub fn text_style(&self, default_font_family: &[String], scale_factor: f32) -> TextStyle {
         let mut text_style = TextStyle::new();
         let mut font_family = self.font_family.clone();
@@ -65,22 +224,77 @@ impl FontStyleState {
             .set_word_spacing(self.word_spacing)
             .set_letter_spacing(self.letter_spacing)
             .set_height_override(true)
-            .set_height(self.line_height);
+            .set_height(self.line_height)
+            // Skia shifts the glyphs down with positive values
+            .set_baseline_shift(-self.baseline_shift * scale_factor);
 
-        for text_shadow in self.text_shadows.iter() {
-            text_style.add_shadow(*text_shadow);
+        if let Some(lang) = self.resolved_lang() {
+            text_style.set_locale(lang);
+        }
+
+        // The shadows of text with a fill are painted along with its outline instead, so the fill doesn't tint them
+        if self.fill.is_none() {
+            for text_shadow in self.text_shadows.iter() {
+                text_style.add_shadow(*text_shadow);
+            }
         }
 
         text_style.set_decoration(&self.decoration);
 
         text_style
     }
+
+    /// Check if an outline should be drawn around the glyphs.
+    pub fn has_text_stroke(&self) -> bool {
+        self.text_stroke_width > 0.0
+    }
+
+    /// Check if the text has shadows and a fill, so its shadows are painted with [`FontStyleState::stroke_text_style`].
+    pub fn has_fill_shadows(&self) -> bool {
+        self.fill.is_some() && !self.text_shadows.is_empty()
+    }
+
+    /// Same as [`FontStyleState::text_style`] but painting the outline of the glyphs instead of filling them.
+    /// Text without an outline is laid out the same way but left transparent.
+    /// The shadows of text with a fill are painted too, as it doesn't paint them.
+    pub fn stroke_text_style(
+        &self,
+        default_font_family: &[String],
+        scale_factor: f32,
+    ) -> TextStyle {
+        let mut text_style = self.text_style(default_font_family, scale_factor);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(self.text_stroke_width * scale_factor);
+        if self.has_text_stroke() {
+            paint.set_color(self.text_stroke_color);
+        } else {
+            paint.set_color(Color::TRANSPARENT);
+        }
+
+        text_style.set_foreground_paint(&paint);
+        text_style.reset_shadows();
+        if self.fill.is_some() {
+            for text_shadow in self.text_shadows.iter() {
+                text_style.add_shadow(*text_shadow);
+            }
+        }
+        text_style.set_decoration(&Decoration {
+            ty: TextDecoration::NO_DECORATION,
+            ..self.decoration
+        });
+
+        text_style
+    }
 }
 
 impl Default for FontStyleState {
     fn default() -> Self {
         Self {
             color: Color::BLACK,
+            fill: None,
             text_shadows: Vec::new(),
             font_family: Vec::new(),
             font_size: 16.0,
@@ -88,6 +302,7 @@ impl Default for FontStyleState {
             font_slant: Slant::Upright,
             font_width: Width::NORMAL,
             line_height: 1.2,
+            line_height_behavior: LineHeightBehavior::default(),
             word_spacing: 0.0,
             letter_spacing: 0.0,
             decoration: Decoration {
@@ -97,6 +312,14 @@ impl Default for FontStyleState {
             text_align: TextAlign::default(),
             max_lines: None,
             text_overflow: TextOverflow::default(),
+            text_stroke_width: 0.0,
+            text_stroke_color: Color::BLACK,
+            baseline_shift: 0.0,
+            word_break: WordBreak::default(),
+            hyphens: Hyphens::default(),
+            line_break: LineBreak::default(),
+            lang: None,
+            first_line: FirstLineStyle::default(),
         }
     }
 }
@@ -112,7 +335,13 @@ impl ParseAttribute for FontStyleState {
                     // Make an exception for the "inherit" as in this case we don't want to pass
                     //  a color at all but use the inherited one.
                     if value != "inherit" {
-                        self.color = Color::parse(value)?;
+                        match Fill::parse(value)? {
+                            Fill::Color(color) => {
+                                self.color = color;
+                                self.fill = None;
+                            }
+                            fill => self.fill = Some(fill),
+                        }
                     }
                 }
             }
@@ -147,6 +376,11 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::LineHeightBehavior => {
+                if let Some(value) = attr.value.as_text() {
+                    self.line_height_behavior = LineHeightBehavior::parse(value)?;
+                }
+            }
             AttributeName::TextAlign => {
                 if let Some(value) = attr.value.as_text() {
                     if let Ok(text_align) = TextAlign::parse(value) {
@@ -229,6 +463,74 @@ impl ParseAttribute for FontStyleState {
                     }
                 }
             }
+            AttributeName::TextStrokeWidth => {
+                if let Some(value) = attr.value.as_text() {
+                    if let Ok(text_stroke_width) = value.parse::<f32>() {
+                        self.text_stroke_width = text_stroke_width.max(0.0);
+                    }
+                }
+            }
+            AttributeName::TextStrokeColor => {
+                if let Some(value) = attr.value.as_text() {
+                    if let Ok(text_stroke_color) = Color::parse(value) {
+                        self.text_stroke_color = text_stroke_color;
+                    }
+                }
+            }
+            AttributeName::FirstLineColor => {
+                if let Some(value) = attr.value.as_text() {
+                    self.first_line.color = Some(Color::parse(value)?);
+                }
+            }
+            AttributeName::FirstLineFontSize => {
+                if let Some(value) = attr.value.as_text() {
+                    self.first_line.font_size = Some(value.parse().map_err(|_| ParseError)?);
+                }
+            }
+            AttributeName::FirstLineFontWeight => {
+                if let Some(value) = attr.value.as_text() {
+                    self.first_line.font_weight = Some(Weight::parse(value)?);
+                }
+            }
+            AttributeName::Lang => {
+                if let Some(value) = attr.value.as_text() {
+                    self.lang = Some(value.trim().to_string());
+                }
+            }
+            AttributeName::WordBreak => {
+                if let Some(value) = attr.value.as_text() {
+                    self.word_break = WordBreak::parse(value)?;
+                }
+            }
+            AttributeName::Hyphens => {
+                if let Some(value) = attr.value.as_text() {
+                    self.hyphens = Hyphens::parse(value)?;
+                }
+            }
+            AttributeName::LineBreak => {
+                if let Some(value) = attr.value.as_text() {
+                    self.line_break = LineBreak::parse(value)?;
+                }
+            }
+            AttributeName::BaselineShift => {
+                if let Some(value) = attr.value.as_text() {
+                    match value {
+                        "super" => {
+                            self.baseline_shift = self.font_size * SUPERSCRIPT_SHIFT;
+                            self.font_size *= SCRIPT_FONT_SIZE;
+                        }
+                        "sub" => {
+                            self.baseline_shift = -self.font_size * SUBSCRIPT_SHIFT;
+                            self.font_size *= SCRIPT_FONT_SIZE;
+                        }
+                        value => {
+                            if let Ok(baseline_shift) = value.parse::<f32>() {
+                                self.baseline_shift = baseline_shift;
+                            }
+                        }
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -252,6 +554,7 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::FontSize,
             AttributeName::FontFamily,
             AttributeName::LineHeight,
+            AttributeName::LineHeightBehavior,
             AttributeName::MaxLines,
             AttributeName::FontStyle,
             AttributeName::FontWeight,
@@ -262,6 +565,16 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::DecorationColor,
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
+            AttributeName::TextStrokeWidth,
+            AttributeName::TextStrokeColor,
+            AttributeName::BaselineShift,
+            AttributeName::WordBreak,
+            AttributeName::Hyphens,
+            AttributeName::LineBreak,
+            AttributeName::Lang,
+            AttributeName::FirstLineColor,
+            AttributeName::FirstLineFontSize,
+            AttributeName::FirstLineFontWeight,
         ]));
 
     fn update<'a>(
@@ -275,9 +588,13 @@ impl State<CustomAttributeValues> for FontStyleState {
         let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
 
         let mut font_style = parent.map(|(v,)| v.clone()).unwrap_or_default();
+        font_style.first_line = FirstLineStyle::default();
 
         if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
+            // `super` and `sub` depend on the font size, so they are parsed after it
+            let (baseline_shift, attributes): (Vec<_>, Vec<_>) =
+                attributes.partition(|attr| *attr.attribute == AttributeName::BaselineShift);
+            for attr in attributes.into_iter().chain(baseline_shift) {
                 font_style.parse_safe(attr);
             }
         }
@@ -23,6 +23,8 @@ use crate::{
 pub struct LayerState {
     pub layer: i16,
     pub layer_for_children: i16,
+    /// Value of the `layer` attribute, relative to the layer of the parent.
+    pub relative_layer: i16,
 }
 
 impl ParseAttribute for LayerState {
@@ -37,6 +39,7 @@ impl ParseAttribute for LayerState {
                     let layer = value.parse::<i16>().map_err(|_| ParseError)?;
                     self.layer -= layer;
                     self.layer_for_children += layer;
+                    self.relative_layer = layer;
                 }
             }
             _ => {}
@@ -76,6 +79,7 @@ impl State<CustomAttributeValues> for LayerState {
         let mut layer_state = LayerState {
             layer: node_view.height() as i16 - inherited_layer,
             layer_for_children: inherited_layer,
+            relative_layer: 0,
         };
 
         if let Some(attributes) = node_view.attributes() {
@@ -3,6 +3,7 @@ use std::sync::{
     Mutex,
 };
 
+use freya_common::SnapAlign;
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
@@ -22,11 +23,13 @@ use freya_native_core_macro::partial_derive_state;
 use torin::prelude::*;
 
 use crate::{
+    parse_margin,
     CustomAttributeValues,
     NodeReference,
     Parse,
     ParseAttribute,
     ParseError,
+    ReferenceChildren,
 };
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
@@ -39,6 +42,7 @@ pub struct LayoutState {
     pub maximum_width: Size,
     pub padding: Gaps,
     pub margin: Gaps,
+    pub auto_margin: AutoMargins,
     pub direction: DirectionMode,
     pub offset_y: Length,
     pub offset_x: Length,
@@ -46,7 +50,11 @@ pub struct LayoutState {
     pub cross_alignment: Alignment,
     pub position: Position,
     pub content: Content,
+    pub box_sizing: BoxSizing,
+    pub snap_align: Option<SnapAlign>,
+    pub paragraph_spacing: Length,
     pub node_ref: Option<NodeReference>,
+    pub node_ref_children: ReferenceChildren,
     pub node_id: NodeId,
 }
 
@@ -93,7 +101,7 @@ impl ParseAttribute for LayoutState {
             }
             AttributeName::Margin => {
                 if let Some(value) = attr.value.as_text() {
-                    self.margin = Gaps::parse(value)?;
+                    (self.margin, self.auto_margin) = parse_margin(value)?;
                 }
             }
             AttributeName::Direction => {
@@ -126,8 +134,11 @@ impl ParseAttribute for LayoutState {
             }
             AttributeName::Position => {
                 if let Some(value) = attr.value.as_text() {
-                    if self.position.is_empty() {
-                        self.position = Position::parse(value)?;
+                    let position = Position::parse(value)?;
+                    if position.is_sticky() {
+                        self.position.make_sticky();
+                    } else if self.position.is_empty() {
+                        self.position = position;
                     }
                 }
             }
@@ -160,13 +171,35 @@ impl ParseAttribute for LayoutState {
                     self.content = Content::parse(value)?;
                 }
             }
-            AttributeName::Reference => {
-                if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(reference)) =
-                    attr.value
-                {
-                    self.node_ref = Some(reference.clone());
+            AttributeName::BoxSizing => {
+                if let Some(value) = attr.value.as_text() {
+                    self.box_sizing = BoxSizing::parse(value)?;
+                }
+            }
+            AttributeName::SnapAlign => {
+                if let Some(value) = attr.value.as_text() {
+                    self.snap_align = Some(SnapAlign::parse(value)?);
+                }
+            }
+            AttributeName::ParagraphSpacing => {
+                if let Some(value) = attr.value.as_text() {
+                    self.paragraph_spacing =
+                        Length::new(value.parse::<f32>().map_err(|_| ParseError)?.max(0.0));
                 }
             }
+            AttributeName::Reference => match attr.value {
+                OwnedAttributeValue::Custom(CustomAttributeValues::Reference(reference)) => {
+                    self.node_ref = Some(reference.clone());
+                }
+                OwnedAttributeValue::Custom(CustomAttributeValues::ChildrenReference(
+                    reference,
+                    children,
+                )) => {
+                    self.node_ref = Some(reference.clone());
+                    self.node_ref_children = *children;
+                }
+                _ => {}
+            },
             _ => {}
         }
         Ok(())
@@ -203,6 +236,9 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::PositionBottom,
             AttributeName::PositionLeft,
             AttributeName::Content,
+            AttributeName::BoxSizing,
+            AttributeName::SnapAlign,
+            AttributeName::ParagraphSpacing,
         ]));
 
     fn update<'a>(
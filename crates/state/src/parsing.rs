@@ -30,8 +30,14 @@ pub trait ParseAttribute: Sized {
             }
         }
 
+        // Only reported when debug logs are enabled, so release apps are not flooded
         #[cfg(not(debug_assertions))]
-        self.parse_attribute(attr).ok();
+        {
+            let attribute = *attr.attribute;
+            if self.parse_attribute(attr).is_err() {
+                tracing::debug!("Failed to parse attribute '{attribute:?}'");
+            }
+        }
     }
 }
 
@@ -1,3 +1,10 @@
+use std::time::Instant;
+
+use freya_common::LayoutTransitionNodes;
+use freya_engine::prelude::{
+    BlendMode,
+    Color,
+};
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
@@ -9,35 +16,230 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    NodeId,
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::gaps::Gaps;
 
 use crate::{
+    lerp,
+    lerp_color,
     parsing::ExtSplit,
+    ActiveTransition,
     AttributesBytes,
     Border,
     BorderAlignment,
+    BorderSides,
+    BorderStyle,
     CornerRadius,
     CustomAttributeValues,
     Fill,
+    FontStyleState,
+    HashedBytes,
+    ImagePlayback,
+    ImageTintMode,
+    Interaction,
     OverflowMode,
     Parse,
     ParseAttribute,
     ParseError,
     Shadow,
+    SvgAspectRatio,
+    TransitionProperty,
+    TransitionTiming,
+    Transitions,
 };
 
+/// Opacity applied to disabled nodes unless `disabled_opacity` says otherwise.
+pub const DEFAULT_DISABLED_OPACITY: f32 = 0.5;
+
 #[derive(Default, Debug, Clone, PartialEq, Component)]
 pub struct StyleState {
     pub background: Fill,
+    pub background_hover: Option<Fill>,
+    pub background_active: Option<Fill>,
+    pub background_focus: Option<Fill>,
+    /// Interaction states of this node, tracked by the events of the window.
+    pub interaction: Interaction,
     pub border: Border,
+    /// Borders of the individual sides, they take precedence over `border`.
+    pub border_sides: BorderSides,
+    pub focus_ring: Border,
     pub shadows: Vec<Shadow>,
     pub corner_radius: CornerRadius,
     pub image_data: Option<AttributesBytes>,
-    pub svg_data: Option<AttributesBytes>,
+    pub image_tint: Option<Color>,
+    pub image_tint_mode: ImageTintMode,
+    pub image_slice: Option<Gaps>,
+    pub image_playback: ImagePlayback,
+    pub svg_data: Option<HashedBytes>,
+    pub preserve_aspect_ratio: Option<SvgAspectRatio>,
     pub overflow: OverflowMode,
     pub opacity: Option<f32>,
+    /// How this node and its descendants are blended with what's painted behind them.
+    pub blend_mode: BlendMode,
+    /// Closest node, this one included, that blends or masks its subtree in a single layer.
+    pub layer_root: Option<NodeId>,
+    /// Encoded image whose alpha masks this node and its descendants.
+    pub mask_data: Option<HashedBytes>,
+    /// Whether this node or any of its ancestors is disabled.
+    pub disabled: bool,
+    pub disabled_opacity: Option<f32>,
+    /// Extra area around the node that still receives pointer events.
+    pub hit_padding: Gaps,
+    /// Whether dragging this node moves the window.
+    pub window_drag: bool,
+    /// Whether this node and its descendants are painted once into a picture and replayed in next frames.
+    pub cache: bool,
+    /// Whether any ancestor of this node is cached, so it gets painted along with it.
+    pub inside_cache: bool,
+    pub transitions: Transitions,
+    pub background_transition: Option<ActiveTransition<Color>>,
+    pub opacity_transition: Option<ActiveTransition<f32>>,
+    /// Timing of the movement of the children when their position in the layout changes.
+    pub layout_transition: Option<TransitionTiming>,
+    /// Inherited text color, which `currentColor` resolves to in the backgrounds.
+    pub current_color: Color,
+}
+
+impl StyleState {
+    /// Set the background, like the `background` attribute.
+    pub fn with_background(mut self, background: Fill) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Set the border, like the `border` attribute.
+    pub fn with_border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Set the shadows, like the `shadow` attribute.
+    pub fn with_shadows(mut self, shadows: Vec<Shadow>) -> Self {
+        self.shadows = shadows;
+        self
+    }
+
+    /// Set the corner radius, like the `corner_radius` attribute.
+    pub fn with_corner_radius(mut self, corner_radius: CornerRadius) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// Set the overflow mode, like the `overflow` attribute.
+    pub fn with_overflow(mut self, overflow: OverflowMode) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the opacity, like the `opacity` attribute.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Set the blend mode, like the `blend_mode` attribute.
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Check if this node and its descendants are painted together in a single layer before being blended or masked.
+    pub fn isolates_subtree(&self) -> bool {
+        self.blend_mode != BlendMode::SrcOver || self.mask_data.is_some()
+    }
+
+    /// Check if this node has any style that depends on its interaction states.
+    pub fn has_interaction_styles(&self) -> bool {
+        self.background_hover.is_some()
+            || self.background_active.is_some()
+            || self.background_focus.is_some()
+    }
+
+    /// Replace the styles with the ones matching its interaction states.
+    /// Pressed styles take precedence over hovered ones, and these over focused ones.
+    fn resolve_interaction_styles(&mut self) {
+        let Interaction {
+            hovered,
+            pressed,
+            focused,
+        } = self.interaction;
+
+        let background = [
+            (pressed, &self.background_active),
+            (hovered, &self.background_hover),
+            (focused, &self.background_focus),
+        ]
+        .into_iter()
+        .find_map(|(is_active, background)| background.as_ref().filter(|_| is_active));
+
+        if let Some(background) = background {
+            self.background = background.clone();
+        }
+    }
+
+    /// Background to paint at the given instant, taking running transitions into account.
+    pub fn background_at(&self, now: Instant) -> Fill {
+        match (&self.background_transition, &self.background) {
+            (Some(transition), Fill::Color(to)) if !transition.is_finished(now) => {
+                Fill::Color(lerp_color(transition.from, *to, transition.progress(now)))
+            }
+            _ => self.background.clone(),
+        }
+    }
+
+    /// Opacity to apply at the given instant, taking running transitions into account.
+    pub fn opacity_at(&self, now: Instant) -> Option<f32> {
+        match &self.opacity_transition {
+            Some(transition) if !transition.is_finished(now) => {
+                let to = self.opacity.unwrap_or(1.0);
+                Some(lerp(transition.from, to, transition.progress(now)))
+            }
+            _ => self.opacity,
+        }
+    }
+
+    /// Check if any of the transitions of this node is still running.
+    pub fn has_running_transitions(&self, now: Instant) -> bool {
+        self.background_transition
+            .map(|transition| !transition.is_finished(now))
+            .unwrap_or_default()
+            || self
+                .opacity_transition
+                .map(|transition| !transition.is_finished(now))
+                .unwrap_or_default()
+    }
+
+    /// Start (or carry over) the transitions given the previous state of this node.
+    ///
+    /// Transitions only start once the node has already been styled with the `transition`
+    /// attribute, so newly mounted nodes do not animate from their default values.
+    fn resolve_transitions(&mut self, previous: &StyleState, now: Instant) {
+        if previous.transitions.is_empty() {
+            return;
+        }
+
+        if let Some(timing) = self.transitions.get(TransitionProperty::Background) {
+            if previous.background == self.background {
+                self.background_transition = previous.background_transition;
+            } else if let (Fill::Color(from), Fill::Color(_)) =
+                (previous.background_at(now), &self.background)
+            {
+                self.background_transition = Some(ActiveTransition::new(from, now, *timing));
+            }
+        }
+
+        if let Some(timing) = self.transitions.get(TransitionProperty::Opacity) {
+            if previous.opacity == self.opacity {
+                self.opacity_transition = previous.opacity_transition;
+            } else {
+                let from = previous.opacity_at(now).unwrap_or(1.0);
+                self.opacity_transition = Some(ActiveTransition::new(from, now, *timing));
+            }
+        }
+    }
 }
 
 impl ParseAttribute for StyleState {
@@ -51,7 +253,33 @@ impl ParseAttribute for StyleState {
                     if value == "none" {
                         return Ok(());
                     }
-                    self.background = Fill::parse(value)?;
+                    self.background = Fill::parse_with_current_color(value, self.current_color)?;
+                }
+            }
+            AttributeName::BackgroundHover => {
+                if let Some(value) = attr.value.as_text() {
+                    self.background_hover =
+                        Some(Fill::parse_with_current_color(value, self.current_color)?);
+                }
+            }
+            AttributeName::BackgroundActive => {
+                if let Some(value) = attr.value.as_text() {
+                    self.background_active =
+                        Some(Fill::parse_with_current_color(value, self.current_color)?);
+                }
+            }
+            AttributeName::BackgroundFocus => {
+                if let Some(value) = attr.value.as_text() {
+                    self.background_focus =
+                        Some(Fill::parse_with_current_color(value, self.current_color)?);
+                }
+            }
+            AttributeName::Interaction => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::Interaction(
+                    interaction,
+                )) = attr.value
+                {
+                    self.interaction = *interaction;
                 }
             }
             AttributeName::Border => {
@@ -61,11 +289,46 @@ impl ParseAttribute for StyleState {
                     self.border = border;
                 }
             }
+            AttributeName::BorderTop => {
+                if let Some(value) = attr.value.as_text() {
+                    self.border_sides.top = Some(Border::parse(value)?);
+                }
+            }
+            AttributeName::BorderRight => {
+                if let Some(value) = attr.value.as_text() {
+                    self.border_sides.right = Some(Border::parse(value)?);
+                }
+            }
+            AttributeName::BorderBottom => {
+                if let Some(value) = attr.value.as_text() {
+                    self.border_sides.bottom = Some(Border::parse(value)?);
+                }
+            }
+            AttributeName::BorderLeft => {
+                if let Some(value) = attr.value.as_text() {
+                    self.border_sides.left = Some(Border::parse(value)?);
+                }
+            }
             AttributeName::BorderAlign => {
                 if let Some(value) = attr.value.as_text() {
                     self.border.alignment = BorderAlignment::parse(value)?;
                 }
             }
+            AttributeName::FocusRing => {
+                if let Some(value) = attr.value.as_text() {
+                    self.focus_ring = if value == "auto" {
+                        // Accessible default, visible on both light and dark backgrounds
+                        Border {
+                            fill: Fill::Color(Color::from_rgb(0, 95, 204)),
+                            style: BorderStyle::Solid,
+                            width: 2.0,
+                            alignment: BorderAlignment::Outer,
+                        }
+                    } else {
+                        Border::parse(value)?
+                    };
+                }
+            }
             AttributeName::Shadow => {
                 if let Some(value) = attr.value.as_text() {
                     self.shadows = value
@@ -98,16 +361,48 @@ impl ParseAttribute for StyleState {
                     self.image_data = Some(bytes.clone());
                 }
             }
+            AttributeName::ImageTint => {
+                if let Some(value) = attr.value.as_text() {
+                    if value == "none" {
+                        return Ok(());
+                    }
+                    self.image_tint = Some(Color::parse(value)?);
+                }
+            }
+            AttributeName::ImageTintMode => {
+                if let Some(value) = attr.value.as_text() {
+                    self.image_tint_mode = ImageTintMode::parse(value)?;
+                }
+            }
+            AttributeName::ImageSlice => {
+                if let Some(value) = attr.value.as_text() {
+                    if value == "none" {
+                        return Ok(());
+                    }
+                    self.image_slice = Some(Gaps::parse(value)?);
+                }
+            }
+            AttributeName::ImagePlayback => {
+                if let Some(value) = attr.value.as_text() {
+                    self.image_playback = ImagePlayback::parse(value)?;
+                }
+            }
             AttributeName::SvgData => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) = attr.value
                 {
-                    self.svg_data = Some(bytes.clone());
+                    self.svg_data = Some(HashedBytes::new(bytes.clone()));
                 }
             }
             AttributeName::SvgContent => {
                 let text = attr.value.as_text();
-                self.svg_data =
-                    text.map(|v| AttributesBytes::Dynamic(v.as_bytes().to_vec().into()));
+                self.svg_data = text.map(|v| {
+                    HashedBytes::new(AttributesBytes::Dynamic(v.as_bytes().to_vec().into()))
+                });
+            }
+            AttributeName::PreserveAspectRatio => {
+                if let Some(value) = attr.value.as_text() {
+                    self.preserve_aspect_ratio = Some(SvgAspectRatio::parse(value)?);
+                }
             }
             AttributeName::Overflow => {
                 if let Some(value) = attr.value.as_text() {
@@ -119,6 +414,52 @@ impl ParseAttribute for StyleState {
                     self.opacity = Some(value.parse::<f32>().map_err(|_| ParseError)?);
                 }
             }
+            AttributeName::BlendMode => {
+                if let Some(value) = attr.value.as_text() {
+                    self.blend_mode = BlendMode::parse(value)?;
+                }
+            }
+            AttributeName::MaskData => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) = attr.value
+                {
+                    self.mask_data = Some(HashedBytes::new(bytes.clone()));
+                }
+            }
+            AttributeName::Disabled => {
+                if let Some(value) = attr.value.as_text() {
+                    self.disabled = value.parse::<bool>().map_err(|_| ParseError)?;
+                }
+            }
+            AttributeName::DisabledOpacity => {
+                if let Some(value) = attr.value.as_text() {
+                    self.disabled_opacity = Some(value.parse::<f32>().map_err(|_| ParseError)?);
+                }
+            }
+            AttributeName::HitPadding => {
+                if let Some(value) = attr.value.as_text() {
+                    self.hit_padding = Gaps::parse(value)?;
+                }
+            }
+            AttributeName::WindowDrag => {
+                if let Some(value) = attr.value.as_text() {
+                    self.window_drag = value.parse::<bool>().map_err(|_| ParseError)?;
+                }
+            }
+            AttributeName::Cache => {
+                if let Some(value) = attr.value.as_text() {
+                    self.cache = value.parse::<bool>().map_err(|_| ParseError)?;
+                }
+            }
+            AttributeName::Transition => {
+                if let Some(value) = attr.value.as_text() {
+                    self.transitions = Transitions::parse(value)?;
+                }
+            }
+            AttributeName::LayoutTransition => {
+                if let Some(value) = attr.value.as_text() {
+                    self.layout_transition = Some(TransitionTiming::parse(value)?);
+                }
+            }
             _ => {}
         }
 
@@ -132,33 +473,61 @@ impl State<CustomAttributeValues> for StyleState {
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (FontStyleState,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Background,
+            AttributeName::BackgroundHover,
+            AttributeName::BackgroundActive,
+            AttributeName::BackgroundFocus,
+            AttributeName::Interaction,
             AttributeName::Layer,
             AttributeName::Border,
+            AttributeName::BorderTop,
+            AttributeName::BorderRight,
+            AttributeName::BorderBottom,
+            AttributeName::BorderLeft,
             AttributeName::BorderAlign,
+            AttributeName::FocusRing,
             AttributeName::Shadow,
             AttributeName::CornerRadius,
             AttributeName::CornerSmoothing,
             AttributeName::ImageData,
+            AttributeName::ImageTint,
+            AttributeName::ImageTintMode,
+            AttributeName::ImageSlice,
+            AttributeName::ImagePlayback,
             AttributeName::SvgData,
             AttributeName::SvgContent,
+            AttributeName::PreserveAspectRatio,
             AttributeName::Overflow,
             AttributeName::Opacity,
+            AttributeName::BlendMode,
+            AttributeName::MaskData,
+            AttributeName::Disabled,
+            AttributeName::DisabledOpacity,
+            AttributeName::HitPadding,
+            AttributeName::WindowDrag,
+            AttributeName::Cache,
+            AttributeName::Transition,
+            AttributeName::LayoutTransition,
         ]));
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
-        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
-        let mut style = StyleState::default();
+        let layout_transition_nodes = context.get::<LayoutTransitionNodes>().unwrap();
+        let (font_style,) = node;
+        let mut style = StyleState {
+            current_color: font_style.color,
+            ..StyleState::default()
+        };
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -166,8 +535,38 @@ impl State<CustomAttributeValues> for StyleState {
             }
         }
 
+        // Dim the disabled node, its descendants are already dimmed along with it
+        if style.disabled {
+            let disabled_opacity = style.disabled_opacity.unwrap_or(DEFAULT_DISABLED_OPACITY);
+            style.opacity = Some(style.opacity.unwrap_or(1.0) * disabled_opacity);
+        }
+
+        if let Some((parent,)) = parent {
+            style.disabled |= parent.disabled;
+            style.inside_cache = parent.cache || parent.inside_cache;
+            style.layer_root = parent.layer_root;
+        }
+
+        if style.isolates_subtree() {
+            style.layer_root = Some(node_view.node_id());
+        }
+
+        if !style.disabled {
+            style.resolve_interaction_styles();
+        }
+
+        style.resolve_transitions(self, Instant::now());
+
         let changed = &style != self;
 
+        if changed {
+            if style.layout_transition.is_some() {
+                layout_transition_nodes.insert_node(node_view.node_id());
+            } else {
+                layout_transition_nodes.remove_node(node_view.node_id());
+            }
+        }
+
         *self = style;
         changed
     }
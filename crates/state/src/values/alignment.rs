@@ -1,3 +1,4 @@
+use freya_common::SnapAlign;
 use torin::alignment::Alignment;
 
 use crate::{
@@ -17,3 +18,14 @@ impl Parse for Alignment {
         })
     }
 }
+
+impl Parse for SnapAlign {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "start" => Ok(SnapAlign::Start),
+            "center" => Ok(SnapAlign::Center),
+            "end" => Ok(SnapAlign::End),
+            _ => Err(ParseError),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use freya_engine::prelude::BlendMode;
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for BlendMode {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "normal" => BlendMode::SrcOver,
+            "multiply" => BlendMode::Multiply,
+            "screen" => BlendMode::Screen,
+            "overlay" => BlendMode::Overlay,
+            "darken" => BlendMode::Darken,
+            "lighten" => BlendMode::Lighten,
+            "color-dodge" => BlendMode::ColorDodge,
+            "color-burn" => BlendMode::ColorBurn,
+            "hard-light" => BlendMode::HardLight,
+            "soft-light" => BlendMode::SoftLight,
+            "difference" => BlendMode::Difference,
+            "exclusion" => BlendMode::Exclusion,
+            "hue" => BlendMode::Hue,
+            "saturation" => BlendMode::Saturation,
+            "color" => BlendMode::Color,
+            "luminosity" => BlendMode::Luminosity,
+            "plus-lighter" => BlendMode::Plus,
+            _ => return Err(ParseError),
+        })
+    }
+}
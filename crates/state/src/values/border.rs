@@ -23,6 +23,39 @@ pub struct Border {
     pub alignment: BorderAlignment,
 }
 
+impl Border {
+    /// Whether there is anything to paint for this border.
+    pub fn is_visible(&self) -> bool {
+        self.width > 0.0 && self.style != BorderStyle::None
+    }
+}
+
+/// Borders of the individual sides of an element, overriding its `border` on their side.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BorderSides {
+    pub top: Option<Border>,
+    pub right: Option<Border>,
+    pub bottom: Option<Border>,
+    pub left: Option<Border>,
+}
+
+impl BorderSides {
+    /// Whether no side overrides the `border` of the element.
+    pub fn is_empty(&self) -> bool {
+        self.top.is_none() && self.right.is_none() && self.bottom.is_none() && self.left.is_none()
+    }
+
+    /// Border of every side clockwise starting from the top, falling back to the given `border`.
+    /// All of them use the alignment of the `border`.
+    pub fn resolve(&self, border: &Border) -> [Border; 4] {
+        [&self.top, &self.right, &self.bottom, &self.left].map(|side| {
+            let mut side = side.clone().unwrap_or_else(|| border.clone());
+            side.alignment = border.alignment;
+            side
+        })
+    }
+}
+
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum BorderAlignment {
     #[default]
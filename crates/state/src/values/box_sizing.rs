@@ -0,0 +1,16 @@
+use torin::box_sizing::BoxSizing;
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for BoxSizing {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "border-box" => Ok(BoxSizing::BorderBox),
+            "content-box" => Ok(BoxSizing::ContentBox),
+            _ => Err(ParseError),
+        }
+    }
+}
@@ -187,12 +187,130 @@ fn parse_hsl(color: &str) -> Result<Color, ParseError> {
 }
 
 fn parse_hex_color(color: &str) -> Result<Color, ParseError> {
-    if color.len() == 7 {
-        let r = u8::from_str_radix(&color[1..3], 16).map_err(|_| ParseError)?;
-        let g = u8::from_str_radix(&color[3..5], 16).map_err(|_| ParseError)?;
-        let b = u8::from_str_radix(&color[5..7], 16).map_err(|_| ParseError)?;
-        Ok(Color::from_rgb(r, g, b))
+    if !color.is_ascii() {
+        return Err(ParseError);
+    }
+
+    match color.len() {
+        7 => {
+            let r = u8::from_str_radix(&color[1..3], 16).map_err(|_| ParseError)?;
+            let g = u8::from_str_radix(&color[3..5], 16).map_err(|_| ParseError)?;
+            let b = u8::from_str_radix(&color[5..7], 16).map_err(|_| ParseError)?;
+            Ok(Color::from_rgb(r, g, b))
+        }
+        9 => {
+            let r = u8::from_str_radix(&color[1..3], 16).map_err(|_| ParseError)?;
+            let g = u8::from_str_radix(&color[3..5], 16).map_err(|_| ParseError)?;
+            let b = u8::from_str_radix(&color[5..7], 16).map_err(|_| ParseError)?;
+            let a = u8::from_str_radix(&color[7..9], 16).map_err(|_| ParseError)?;
+            Ok(Color::from_argb(a, r, g, b))
+        }
+        _ => Err(ParseError),
+    }
+}
+
+/// Serialize a [`Color`] into its canonical `#RRGGBBAA` form.
+///
+/// This is the inverse of the hex syntax accepted by [`Parse`], so any color
+/// can be turned into a string and parsed back without losing information.
+pub fn color_to_string(color: Color) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}{:02X}",
+        color.r(),
+        color.g(),
+        color.b(),
+        color.a()
+    )
+}
+
+/// Color space used when interpolating between two colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorInterpolation {
+    /// Interpolate every sRGB channel linearly.
+    #[default]
+    Linear,
+    /// Interpolate in the perceptual OkLab space, which avoids muddy midpoints.
+    OkLab,
+}
+
+/// Linearly interpolate between two colors, including their alpha channel.
+///
+/// `t` is clamped between `0.0` and `1.0`.
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    lerp_color_with(a, b, t, ColorInterpolation::Linear)
+}
+
+/// Interpolate between two colors in the given [`ColorInterpolation`] space.
+///
+/// `t` is clamped between `0.0` and `1.0`. Alpha is always interpolated linearly.
+pub fn lerp_color_with(a: Color, b: Color, t: f32, interpolation: ColorInterpolation) -> Color {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let alpha = lerp_channel(a.a() as f32, b.a() as f32, t);
+
+    match interpolation {
+        ColorInterpolation::Linear => Color::from_argb(
+            alpha,
+            lerp_channel(a.r() as f32, b.r() as f32, t),
+            lerp_channel(a.g() as f32, b.g() as f32, t),
+            lerp_channel(a.b() as f32, b.b() as f32, t),
+        ),
+        ColorInterpolation::OkLab => {
+            let [l1, a1, b1] = srgb_to_oklab(a);
+            let [l2, a2, b2] = srgb_to_oklab(b);
+            let (r, g, b) =
+                oklab_to_srgb([l1 + (l2 - l1) * t, a1 + (a2 - a1) * t, b1 + (b2 - b1) * t]);
+            Color::from_argb(alpha, r, g, b)
+        }
+    }
+}
+
+fn lerp_channel(from: f32, to: f32, t: f32) -> u8 {
+    (from + (to - from) * t).round().clamp(0.0, 255.0) as u8
+}
+
+fn srgb_to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
     } else {
-        Err(ParseError)
+        ((c + 0.055) / 1.055).powf(2.4)
     }
 }
+
+fn linear_to_srgb(channel: f32) -> u8 {
+    let c = channel.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn srgb_to_oklab(color: Color) -> [f32; 3] {
+    let r = srgb_to_linear(color.r());
+    let g = srgb_to_linear(color.g());
+    let b = srgb_to_linear(color.b());
+
+    let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+    ]
+}
+
+fn oklab_to_srgb([l, a, b]: [f32; 3]) -> (u8, u8, u8) {
+    let l_ = (l + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m_ = (l - 0.105561346 * a - 0.06385417 * b).powi(3);
+    let s_ = (l - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+    (
+        linear_to_srgb(4.0767417 * l_ - 3.3077116 * m_ + 0.23096994 * s_),
+        linear_to_srgb(-1.268438 * l_ + 2.6097574 * m_ - 0.34131938 * s_),
+        linear_to_srgb(-0.0041960863 * l_ - 0.7034186 * m_ + 1.7076147 * s_),
+    )
+}
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use freya_common::CursorAffinity;
+
 use crate::{
     Parse,
     ParseError,
@@ -20,6 +22,16 @@ impl Parse for CursorMode {
     }
 }
 
+impl Parse for CursorAffinity {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "upstream" => Ok(CursorAffinity::Upstream),
+            "downstream" => Ok(CursorAffinity::Downstream),
+            _ => Err(ParseError),
+        }
+    }
+}
+
 impl fmt::Display for CursorMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
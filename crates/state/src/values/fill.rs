@@ -31,6 +31,33 @@ impl From<Color> for Fill {
     }
 }
 
+impl Fill {
+    /// Parse a fill that can use the `currentColor` keyword, as a color or in the stops of a gradient,
+    /// resolved to the given color. This is usually the inherited text color.
+    pub fn parse_with_current_color(value: &str, current_color: Color) -> Result<Self, ParseError> {
+        Ok(if value.starts_with("linear-gradient(") {
+            Self::LinearGradient(LinearGradient::parse_with_current_color(
+                value,
+                current_color,
+            )?)
+        } else if value.starts_with("radial-gradient(") {
+            Self::RadialGradient(RadialGradient::parse_with_current_color(
+                value,
+                current_color,
+            )?)
+        } else if value.starts_with("conic-gradient(") {
+            Self::ConicGradient(ConicGradient::parse_with_current_color(
+                value,
+                current_color,
+            )?)
+        } else if value == "currentColor" {
+            Self::Color(current_color)
+        } else {
+            Self::Color(Color::parse(value)?)
+        })
+    }
+}
+
 impl Parse for Fill {
     fn parse(value: &str) -> Result<Self, ParseError> {
         Ok(if value.starts_with("linear-gradient(") {
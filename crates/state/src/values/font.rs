@@ -87,13 +87,15 @@ pub enum TextOverflow {
     #[default]
     Clip,
     Ellipsis,
+    /// Keep the start and the end of a single line of text, replacing its middle with an ellipsis.
+    EllipsisMiddle,
     Custom(String),
 }
 
 impl TextOverflow {
     pub fn get_ellipsis(&self) -> Option<&str> {
         match self {
-            Self::Clip => None,
+            Self::Clip | Self::EllipsisMiddle => None,
             Self::Ellipsis => Some("..."),
             Self::Custom(custom) => Some(custom),
         }
@@ -103,15 +105,155 @@ impl TextOverflow {
         match self {
             TextOverflow::Clip => "clip".to_string(),
             TextOverflow::Ellipsis => "ellipsis".to_string(),
+            TextOverflow::EllipsisMiddle => "ellipsis-middle".to_string(),
             TextOverflow::Custom(text_overflow) => text_overflow.to_string(),
         }
     }
 }
 
+/// Where lines can be broken when wrapping text.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordBreak {
+    /// Break lines between words.
+    #[default]
+    Normal,
+    /// Break lines between any two characters.
+    BreakAll,
+    /// Break lines between words, and inside the words that don't fit in a line by themselves.
+    BreakWord,
+}
+
+impl WordBreak {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::BreakAll => "break-all".to_string(),
+            Self::BreakWord => "break-word".to_string(),
+        }
+    }
+}
+
+impl Parse for WordBreak {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "normal" => Ok(Self::Normal),
+            "break-all" => Ok(Self::BreakAll),
+            "break-word" => Ok(Self::BreakWord),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Whether words can be hyphenated when wrapping text.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hyphens {
+    /// Never hyphenate words.
+    #[default]
+    None,
+    /// Hyphenate words at the hyphenation points of their language.
+    Auto,
+}
+
+impl Hyphens {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Auto => "auto".to_string(),
+        }
+    }
+}
+
+impl Parse for Hyphens {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "none" => Ok(Self::None),
+            "auto" => Ok(Self::Auto),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// How the lines of a wrapping text are broken.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineBreak {
+    /// Fit as many words as possible in every line.
+    #[default]
+    Greedy,
+    /// Even out the width of the lines, so the last one isn't much shorter than the others.
+    Balanced,
+}
+
+impl LineBreak {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Greedy => "greedy".to_string(),
+            Self::Balanced => "balanced".to_string(),
+        }
+    }
+}
+
+impl Parse for LineBreak {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "greedy" => Ok(Self::Greedy),
+            "balanced" => Ok(Self::Balanced),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Whether the extra height given to the lines by the `line_height` is added above the first line and below the last one.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineHeightBehavior {
+    /// Add it above the first line and below the last one, like between the other lines.
+    #[default]
+    Normal,
+    /// Don't add it above the first line.
+    TrimTop,
+    /// Don't add it below the last line.
+    TrimBottom,
+    /// Don't add it above the first line nor below the last one, so the text is as tall as its glyphs.
+    TrimLeading,
+}
+
+impl LineHeightBehavior {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::TrimTop => "trim-top".to_string(),
+            Self::TrimBottom => "trim-bottom".to_string(),
+            Self::TrimLeading => "trim-leading".to_string(),
+        }
+    }
+
+    /// Skia's equivalent, used when laying out the paragraphs.
+    pub fn text_height_behavior(&self) -> TextHeightBehavior {
+        match self {
+            Self::Normal => TextHeightBehavior::All,
+            Self::TrimTop => TextHeightBehavior::DisableFirstAscent,
+            Self::TrimBottom => TextHeightBehavior::DisableLastDescent,
+            Self::TrimLeading => TextHeightBehavior::DisableAll,
+        }
+    }
+}
+
+impl Parse for LineHeightBehavior {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "normal" => Ok(Self::Normal),
+            "trim-top" => Ok(Self::TrimTop),
+            "trim-bottom" => Ok(Self::TrimBottom),
+            "trim-leading" => Ok(Self::TrimLeading),
+            _ => Err(ParseError),
+        }
+    }
+}
+
 impl Parse for TextOverflow {
     fn parse(value: &str) -> Result<Self, ParseError> {
         Ok(match value {
             "ellipsis" => TextOverflow::Ellipsis,
+            "ellipsis-middle" => TextOverflow::EllipsisMiddle,
             "clip" => TextOverflow::Clip,
             value => TextOverflow::Custom(value.to_string()),
         })
@@ -19,8 +19,15 @@ pub struct GradientStop {
     pub offset: f32,
 }
 
-impl Parse for GradientStop {
-    fn parse(value: &str) -> Result<Self, ParseError> {
+impl GradientStop {
+    /// Parse a stop whose color can be `currentColor`, resolved to the given color.
+    /// `transparent` is resolved to a transparent version of it, so fading out keeps its tint.
+    /// Gradients only do this when one of their stops uses `currentColor`.
+    pub fn parse_with_current_color(value: &str, current_color: Color) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, Some(current_color))
+    }
+
+    fn parse_resolving(value: &str, current_color: Option<Color>) -> Result<Self, ParseError> {
         let mut split = value.split_ascii_whitespace_excluding_group('(', ')');
         let color_str = split.next().ok_or(ParseError)?;
 
@@ -35,10 +42,19 @@ impl Parse for GradientStop {
             .map_err(|_| ParseError)?
             / 100.0;
 
-        Ok(GradientStop {
-            color: Color::parse(color_str).map_err(|_| ParseError)?,
-            offset,
-        })
+        let color = match (color_str, current_color) {
+            ("currentColor", Some(current_color)) => current_color,
+            ("transparent", Some(current_color)) => current_color.with_a(0),
+            _ => Color::parse(color_str).map_err(|_| ParseError)?,
+        };
+
+        Ok(GradientStop { color, offset })
+    }
+}
+
+impl Parse for GradientStop {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, None)
     }
 }
 
@@ -78,12 +94,20 @@ impl LinearGradient {
     }
 }
 
-impl Parse for LinearGradient {
-    fn parse(value: &str) -> Result<Self, ParseError> {
+impl LinearGradient {
+    /// Parse a linear gradient whose stops can use `currentColor`, see [`GradientStop::parse_with_current_color`].
+    pub fn parse_with_current_color(value: &str, current_color: Color) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, Some(current_color))
+    }
+
+    fn parse_resolving(value: &str, current_color: Option<Color>) -> Result<Self, ParseError> {
         if !value.starts_with("linear-gradient(") || !value.ends_with(')') {
             return Err(ParseError);
         }
 
+        // Only gradients that use `currentColor` tint their `transparent` stops with it
+        let current_color = current_color.filter(|_| value.contains("currentColor"));
+
         let mut gradient = LinearGradient::default();
         let mut value = value.replacen("linear-gradient(", "", 1);
         value.remove(value.rfind(')').ok_or(ParseError)?);
@@ -97,19 +121,28 @@ impl Parse for LinearGradient {
                 gradient.angle = angle;
             }
         } else {
-            gradient
-                .stops
-                .push(GradientStop::parse(angle_or_first_stop)?);
+            gradient.stops.push(GradientStop::parse_resolving(
+                angle_or_first_stop,
+                current_color,
+            )?);
         }
 
         for stop in split {
-            gradient.stops.push(GradientStop::parse(stop)?);
+            gradient
+                .stops
+                .push(GradientStop::parse_resolving(stop, current_color)?);
         }
 
         Ok(gradient)
     }
 }
 
+impl Parse for LinearGradient {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, None)
+    }
+}
+
 impl fmt::Display for LinearGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -149,25 +182,41 @@ impl RadialGradient {
     }
 }
 
-impl Parse for RadialGradient {
-    fn parse(value: &str) -> Result<Self, ParseError> {
+impl RadialGradient {
+    /// Parse a radial gradient whose stops can use `currentColor`, see [`GradientStop::parse_with_current_color`].
+    pub fn parse_with_current_color(value: &str, current_color: Color) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, Some(current_color))
+    }
+
+    fn parse_resolving(value: &str, current_color: Option<Color>) -> Result<Self, ParseError> {
         if !value.starts_with("radial-gradient(") || !value.ends_with(')') {
             return Err(ParseError);
         }
 
+        // Only gradients that use `currentColor` tint their `transparent` stops with it
+        let current_color = current_color.filter(|_| value.contains("currentColor"));
+
         let mut gradient = RadialGradient::default();
         let mut value = value.replacen("radial-gradient(", "", 1);
 
         value.remove(value.rfind(')').ok_or(ParseError)?);
 
         for stop in value.split_excluding_group(',', '(', ')') {
-            gradient.stops.push(GradientStop::parse(stop)?);
+            gradient
+                .stops
+                .push(GradientStop::parse_resolving(stop, current_color)?);
         }
 
         Ok(gradient)
     }
 }
 
+impl Parse for RadialGradient {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, None)
+    }
+}
+
 impl fmt::Display for RadialGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -211,12 +260,20 @@ impl ConicGradient {
     }
 }
 
-impl Parse for ConicGradient {
-    fn parse(value: &str) -> Result<Self, ParseError> {
+impl ConicGradient {
+    /// Parse a conic gradient whose stops can use `currentColor`, see [`GradientStop::parse_with_current_color`].
+    pub fn parse_with_current_color(value: &str, current_color: Color) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, Some(current_color))
+    }
+
+    fn parse_resolving(value: &str, current_color: Option<Color>) -> Result<Self, ParseError> {
         if !value.starts_with("conic-gradient(") || !value.ends_with(')') {
             return Err(ParseError);
         }
 
+        // Only gradients that use `currentColor` tint their `transparent` stops with it
+        let current_color = current_color.filter(|_| value.contains("currentColor"));
+
         let mut gradient = ConicGradient::default();
         let mut value = value.replacen("conic-gradient(", "", 1);
 
@@ -231,9 +288,10 @@ impl Parse for ConicGradient {
                 gradient.angle = Some(angle);
             }
         } else {
-            gradient
-                .stops
-                .push(GradientStop::parse(angle_or_first_stop).map_err(|_| ParseError)?);
+            gradient.stops.push(GradientStop::parse_resolving(
+                angle_or_first_stop,
+                current_color,
+            )?);
         }
 
         if let Some(angles_or_second_stop) = split.next().map(str::trim) {
@@ -254,20 +312,29 @@ impl Parse for ConicGradient {
                     gradient.angles = Some((start, end));
                 }
             } else {
-                gradient
-                    .stops
-                    .push(GradientStop::parse(angles_or_second_stop)?);
+                gradient.stops.push(GradientStop::parse_resolving(
+                    angles_or_second_stop,
+                    current_color,
+                )?);
             }
         }
 
         for stop in split {
-            gradient.stops.push(GradientStop::parse(stop)?);
+            gradient
+                .stops
+                .push(GradientStop::parse_resolving(stop, current_color)?);
         }
 
         Ok(gradient)
     }
 }
 
+impl Parse for ConicGradient {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Self::parse_resolving(value, None)
+    }
+}
+
 impl fmt::Display for ConicGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "conic-gradient(")?;
@@ -0,0 +1,53 @@
+use freya_engine::prelude::BlendMode;
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+/// How the `image_tint` color is blended with the image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImageTintMode {
+    /// Replace the color of the image, keeping its transparency.
+    #[default]
+    SrcIn,
+    /// Multiply the color of the image with the tint.
+    Multiply,
+}
+
+impl ImageTintMode {
+    pub fn blend_mode(&self) -> BlendMode {
+        match self {
+            Self::SrcIn => BlendMode::SrcIn,
+            Self::Multiply => BlendMode::Multiply,
+        }
+    }
+}
+
+impl Parse for ImageTintMode {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "src-in" => Ok(Self::SrcIn),
+            "multiply" => Ok(Self::Multiply),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Whether an animated image (e.g GIF) is playing or paused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ImagePlayback {
+    #[default]
+    Play,
+    Pause,
+}
+
+impl Parse for ImagePlayback {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "play" => Ok(Self::Play),
+            "pause" => Ok(Self::Pause),
+            _ => Err(ParseError),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use torin::{
+    gaps::Gaps,
+    margin::AutoMargins,
+};
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+/// Parse a margin just like [`Gaps`], but also allowing `auto` in any of its sides.
+pub fn parse_margin(value: &str) -> Result<(Gaps, AutoMargins), ParseError> {
+    let map_sides = |map: fn(&str) -> &str| {
+        value
+            .split_ascii_whitespace()
+            .map(map)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    // `auto` sides have no length until they are resolved
+    let margin = Gaps::parse(&map_sides(|side| if side == "auto" { "0" } else { side }))?;
+    // Expand the `auto` sides just like the lengths
+    let auto_sides = Gaps::parse(&map_sides(|side| if side == "auto" { "1" } else { "0" }))?;
+
+    Ok((
+        margin,
+        AutoMargins::new(
+            auto_sides.top() > 0.0,
+            auto_sides.right() > 0.0,
+            auto_sides.bottom() > 0.0,
+            auto_sides.left() > 0.0,
+        ),
+    ))
+}
@@ -1,5 +1,7 @@
 mod alignment;
+mod blend_mode;
 mod border;
+mod box_sizing;
 mod color;
 mod content;
 mod corner_radius;
@@ -10,11 +12,15 @@ mod font;
 mod gaps;
 mod gradient;
 mod highlight;
+mod image;
+mod margin;
 mod overflow;
 mod position;
 mod shadow;
 mod size;
+mod svg;
 mod text_shadow;
+mod transition;
 
 pub use border::*;
 pub use color::*;
@@ -24,6 +30,10 @@ pub use fill::*;
 pub use font::*;
 pub use gradient::*;
 pub use highlight::*;
+pub use image::*;
+pub use margin::*;
 pub use overflow::*;
 pub use shadow::*;
 pub use size::*;
+pub use svg::*;
+pub use transition::*;
@@ -9,6 +9,8 @@ impl Parse for Position {
     fn parse(value: &str) -> Result<Self, ParseError> {
         Ok(match value {
             "absolute" => Position::new_absolute(),
+            "sticky" => Position::new_sticky(),
+            "global" => Position::new_global(),
             _ => Position::Stacked,
         })
     }
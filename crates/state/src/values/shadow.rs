@@ -63,14 +63,17 @@ impl Parse for Shadow {
             shadow.fill = Fill::parse(spread_or_fill).map_err(|_| ParseError)?;
         }
 
-        if let Some(fill) = shadow_values.next() {
-            if !already_filled {
-                shadow.fill = Fill::parse(fill).map_err(|_| ParseError)?
-            } else {
-                return Err(ParseError);
+        // The fill is always the last value, transparent if missing
+        if !already_filled {
+            if let Some(fill) = shadow_values.next() {
+                shadow.fill = Fill::parse(fill).map_err(|_| ParseError)?;
             }
         }
 
+        if shadow_values.next().is_some() {
+            return Err(ParseError);
+        }
+
         Ok(shadow)
     }
 }
@@ -19,6 +19,13 @@ impl Parse for Size {
             Ok(Size::Fill)
         } else if value == "fill-min" {
             Ok(Size::FillMinimum)
+        } else if ["min(", "max(", "clamp("]
+            .iter()
+            .any(|function| value.starts_with(function))
+        {
+            Ok(Size::DynamicCalculations(Box::new(parse_calc_expression(
+                value,
+            )?)))
         } else if value.contains("calc") {
             Ok(Size::DynamicCalculations(Box::new(parse_calc(value)?)))
         } else if value.contains('%') {
@@ -51,17 +58,58 @@ impl Parse for Size {
 }
 
 pub fn parse_calc(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
-    let mut calcs = Vec::new();
-
     value = value
         .strip_prefix("calc(")
         .ok_or(ParseError)?
         .strip_suffix(')')
         .ok_or(ParseError)?;
 
-    let values = value.split_whitespace();
+    parse_calc_expression(value)
+}
+
+/// Parse a chain of operations, whose values can also be `min()`, `max()` or `clamp()` functions,
+/// with their arguments placed between the start and the end of the function.
+fn parse_calc_expression(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
+    let mut calcs = Vec::new();
+
+    loop {
+        value = value.trim_start();
+        if value.is_empty() {
+            break;
+        }
+
+        if let Some((name, args, rest)) = split_calc_function(value)? {
+            let expected_args = match name {
+                "min" => {
+                    calcs.push(DynamicCalculation::Min);
+                    None
+                }
+                "max" => {
+                    calcs.push(DynamicCalculation::Max);
+                    None
+                }
+                _ => {
+                    calcs.push(DynamicCalculation::Clamp);
+                    Some(3)
+                }
+            };
+            if expected_args.is_some_and(|expected_args| expected_args != args.len()) {
+                return Err(ParseError);
+            }
+            for (i, arg) in args.into_iter().enumerate() {
+                if i > 0 {
+                    calcs.push(DynamicCalculation::FunctionSeparator);
+                }
+                calcs.extend(parse_calc_argument(arg)?);
+            }
+            calcs.push(DynamicCalculation::FunctionEnd);
+            value = rest;
+            continue;
+        }
+
+        let (val, rest) = value.split_at(value.find(char::is_whitespace).unwrap_or(value.len()));
+        value = rest;
 
-    for val in values {
         if val.contains('%') {
             calcs.push(DynamicCalculation::Percentage(
                 val.replace('%', "").parse().map_err(|_| ParseError)?,
@@ -83,3 +131,52 @@ pub fn parse_calc(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseError
 
     Ok(calcs)
 }
+
+/// Parse an argument of a `min()`, `max()` or `clamp()` function, which can also be a `calc()`.
+fn parse_calc_argument(value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
+    let value = value.trim();
+    if value.starts_with("calc(") {
+        parse_calc(value)
+    } else {
+        let calcs = parse_calc_expression(value)?;
+        if calcs.is_empty() {
+            return Err(ParseError);
+        }
+        Ok(calcs)
+    }
+}
+
+/// Split the `min()`, `max()` or `clamp()` function the value starts with into its name,
+/// its comma separated arguments and the rest of the value after it.
+fn split_calc_function(value: &str) -> Result<Option<(&str, Vec<&str>, &str)>, ParseError> {
+    let Some(name) = ["min", "max", "clamp"]
+        .into_iter()
+        .find(|name| value.starts_with(name) && value[name.len()..].starts_with('('))
+    else {
+        return Ok(None);
+    };
+
+    let inner_start = name.len() + 1;
+    let mut depth = 0;
+    let mut arg_start = inner_start;
+    let mut args = Vec::new();
+
+    for (i, c) in value.char_indices().skip(inner_start) {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                args.push(&value[arg_start..i]);
+                return Ok(Some((name, args, &value[i + 1..])));
+            }
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&value[arg_start..i]);
+                arg_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    // The function was never closed
+    Err(ParseError)
+}
@@ -0,0 +1,23 @@
+use crate::{
+    Parse,
+    ParseError,
+};
+
+/// How an SVG with a `viewBox` is scaled into its element, always preserving its aspect ratio and centering it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SvgAspectRatio {
+    /// Scale it to fit inside of the element.
+    Meet,
+    /// Scale it to cover the whole element, cutting out the parts that overflow.
+    Slice,
+}
+
+impl Parse for SvgAspectRatio {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "meet" => Ok(Self::Meet),
+            "slice" => Ok(Self::Slice),
+            _ => Err(ParseError),
+        }
+    }
+}
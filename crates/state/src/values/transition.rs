@@ -0,0 +1,222 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use rustc_hash::FxHashMap;
+use torin::{
+    geometry::Length,
+    size::Size,
+};
+
+use crate::{
+    Parse,
+    ParseError,
+};
+
+/// Properties that can be transitioned with the `transition` attribute.
+///
+/// Only paint properties are supported, layout properties such as `width` or `height`
+/// would need the layout to be recomputed every frame of the transition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    Background,
+    Opacity,
+}
+
+impl Parse for TransitionProperty {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "background" => Ok(Self::Background),
+            "opacity" => Ok(Self::Opacity),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Easing curve applied to a transition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransitionEasing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl TransitionEasing {
+    /// Map a linear progress (`0.0..=1.0`) to the eased progress.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+impl Parse for TransitionEasing {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "linear" => Ok(Self::Linear),
+            "ease-in" => Ok(Self::EaseIn),
+            "ease-out" => Ok(Self::EaseOut),
+            "ease" | "ease-in-out" => Ok(Self::EaseInOut),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Duration and easing of a transitioned property.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransitionTiming {
+    pub duration: Duration,
+    pub easing: TransitionEasing,
+}
+
+impl TransitionTiming {
+    /// Eased progress (`0.0..=1.0`) after the given elapsed time.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.easing
+            .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+}
+
+fn parse_duration(value: &str) -> Result<Duration, ParseError> {
+    if let Some(ms) = value.strip_suffix("ms") {
+        let ms = ms.parse::<f32>().map_err(|_| ParseError)?;
+        if ms < 0.0 {
+            return Err(ParseError);
+        }
+        Ok(Duration::from_secs_f32(ms / 1000.0))
+    } else if let Some(secs) = value.strip_suffix('s') {
+        let secs = secs.parse::<f32>().map_err(|_| ParseError)?;
+        if secs < 0.0 {
+            return Err(ParseError);
+        }
+        Ok(Duration::from_secs_f32(secs))
+    } else {
+        Err(ParseError)
+    }
+}
+
+/// Parsed value of the `transition` attribute, e.g `background 200ms ease-out, opacity 150ms`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Transitions(pub FxHashMap<TransitionProperty, TransitionTiming>);
+
+impl Transitions {
+    pub fn get(&self, property: TransitionProperty) -> Option<&TransitionTiming> {
+        self.0.get(&property)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Parse for Transitions {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut transitions = FxHashMap::default();
+
+        for chunk in value.split(',') {
+            let mut parts = chunk.split_ascii_whitespace();
+
+            let property = TransitionProperty::parse(parts.next().ok_or(ParseError)?)?;
+            let timing = parse_timing(parts)?;
+
+            transitions.insert(property, timing);
+        }
+
+        Ok(Transitions(transitions))
+    }
+}
+
+/// Parsed from a duration and an optional easing, e.g `200ms ease-out`.
+impl Parse for TransitionTiming {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        parse_timing(value.split_ascii_whitespace())
+    }
+}
+
+fn parse_timing<'a>(
+    mut parts: impl Iterator<Item = &'a str>,
+) -> Result<TransitionTiming, ParseError> {
+    let duration = parse_duration(parts.next().ok_or(ParseError)?)?;
+    let easing = parts
+        .next()
+        .map(TransitionEasing::parse)
+        .transpose()?
+        .unwrap_or_default();
+
+    if parts.next().is_some() {
+        return Err(ParseError);
+    }
+
+    Ok(TransitionTiming { duration, easing })
+}
+
+/// A transition in progress, tweening from a previous value towards the current one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActiveTransition<T> {
+    pub from: T,
+    pub started_at: Instant,
+    pub timing: TransitionTiming,
+}
+
+impl<T> ActiveTransition<T> {
+    pub fn new(from: T, started_at: Instant, timing: TransitionTiming) -> Self {
+        Self {
+            from,
+            started_at,
+            timing,
+        }
+    }
+
+    /// Eased progress of the transition at the given instant.
+    pub fn progress(&self, now: Instant) -> f32 {
+        self.timing
+            .progress(now.saturating_duration_since(self.started_at))
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started_at) >= self.timing.duration
+    }
+}
+
+/// Interpolate between two numbers, `t` is clamped between `0.0` and `1.0`.
+pub fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    from + (to - from) * t
+}
+
+/// Interpolate between two sizes of the same unit, `t` is clamped between `0.0` and `1.0`.
+///
+/// Returns `None` if the sizes can't be interpolated, e.g `50%` and `auto`.
+pub fn lerp_size(from: &Size, to: &Size, t: f32) -> Option<Size> {
+    let lerp_length = |from: &Length, to: &Length| Length::new(lerp(from.get(), to.get(), t));
+    match (from, to) {
+        (Size::Pixels(from), Size::Pixels(to)) => Some(Size::Pixels(lerp_length(from, to))),
+        (Size::Percentage(from), Size::Percentage(to)) => {
+            Some(Size::Percentage(lerp_length(from, to)))
+        }
+        (Size::RootPercentage(from), Size::RootPercentage(to)) => {
+            Some(Size::RootPercentage(lerp_length(from, to)))
+        }
+        (Size::InnerPercentage(from), Size::InnerPercentage(to)) => {
+            Some(Size::InnerPercentage(lerp_length(from, to)))
+        }
+        (from, to) if from == to => Some(to.clone()),
+        _ => None,
+    }
+}
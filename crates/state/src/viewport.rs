@@ -12,6 +12,7 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::position::Position;
 
 use crate::{
     CustomAttributeValues,
@@ -26,6 +27,7 @@ pub struct ViewportState {
     pub viewports: Vec<NodeId>,
     pub node_id: NodeId,
     pub overflow: OverflowMode,
+    pub position: Position,
 }
 
 impl ParseAttribute for ViewportState {
@@ -33,13 +35,17 @@ impl ParseAttribute for ViewportState {
         &mut self,
         attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
     ) -> Result<(), crate::ParseError> {
-        #[allow(clippy::single_match)]
         match attr.attribute {
             AttributeName::Overflow => {
                 if let Some(value) = attr.value.as_text() {
                     self.overflow = OverflowMode::parse(value).map_err(|_| ParseError)?;
                 }
             }
+            AttributeName::Position => {
+                if let Some(value) = attr.value.as_text() {
+                    self.position = Position::parse(value).map_err(|_| ParseError)?;
+                }
+            }
             _ => {}
         }
 
@@ -56,7 +62,10 @@ impl State<CustomAttributeValues> for ViewportState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Overflow]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Overflow,
+            AttributeName::Position,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -82,7 +91,8 @@ impl State<CustomAttributeValues> for ViewportState {
             }
         }
 
-        if let Some((parent,)) = parent {
+        // Globally positioned Nodes are not clipped by the viewports of their ancestors
+        if let Some((parent,)) = parent.filter(|_| !viewports_state.position.is_global()) {
             viewports_state.viewports.extend(parent.viewports.clone());
             if parent.overflow == OverflowMode::Clip {
                 viewports_state.viewports.push(parent.node_id);
@@ -0,0 +1,110 @@
+use freya_engine::prelude::*;
+use freya_native_core::{
+    attributes::AttributeName,
+    node::{
+        OwnedAttributeValue,
+        OwnedAttributeView,
+    },
+};
+use freya_node_state::{
+    Border,
+    BorderStyle,
+    CornerRadius,
+    CustomAttributeValues,
+    Fill,
+    FontStyleState,
+    OverflowMode,
+    ParseAttribute,
+    Shadow,
+    StyleState,
+    TextOverflow,
+};
+
+/// Parse the given attributes into a default state, like the DOM does.
+fn parse_attributes<S: ParseAttribute + Default>(attributes: &[(AttributeName, &str)]) -> S {
+    let mut state = S::default();
+    for (attribute, value) in attributes {
+        let value = OwnedAttributeValue::<CustomAttributeValues>::Text(value.to_string());
+        state
+            .parse_attribute(OwnedAttributeView {
+                attribute,
+                value: &value,
+            })
+            .unwrap();
+    }
+    state
+}
+
+#[test]
+fn built_style_matches_parsed_style() {
+    let parsed = parse_attributes::<StyleState>(&[
+        (AttributeName::Background, "red"),
+        (AttributeName::Border, "2 solid blue"),
+        (AttributeName::Shadow, "0 4 10 0 black"),
+        (AttributeName::CornerRadius, "8"),
+        (AttributeName::Overflow, "clip"),
+        (AttributeName::Opacity, "0.5"),
+        (AttributeName::BlendMode, "multiply"),
+    ]);
+
+    let built = StyleState::default()
+        .with_background(Fill::Color(Color::RED))
+        .with_border(Border {
+            fill: Fill::Color(Color::BLUE),
+            style: BorderStyle::Solid,
+            width: 2.0,
+            ..Border::default()
+        })
+        .with_shadows(vec![Shadow {
+            x: 0.0,
+            y: 4.0,
+            blur: 10.0,
+            spread: 0.0,
+            fill: Fill::Color(Color::BLACK),
+            ..Shadow::default()
+        }])
+        .with_corner_radius(CornerRadius {
+            top_left: 8.0,
+            top_right: 8.0,
+            bottom_left: 8.0,
+            bottom_right: 8.0,
+            ..CornerRadius::default()
+        })
+        .with_overflow(OverflowMode::Clip)
+        .with_opacity(0.5)
+        .with_blend_mode(BlendMode::Multiply);
+
+    assert_eq!(built, parsed);
+}
+
+#[test]
+fn built_font_style_matches_parsed_font_style() {
+    let parsed = parse_attributes::<FontStyleState>(&[
+        (AttributeName::Color, "blue"),
+        (AttributeName::FontFamily, "Inter, Noto Sans"),
+        (AttributeName::FontSize, "18"),
+        (AttributeName::FontStyle, "italic"),
+        (AttributeName::FontWeight, "bold"),
+        (AttributeName::LineHeight, "1.5"),
+        (AttributeName::TextAlign, "center"),
+        (AttributeName::MaxLines, "2"),
+        (AttributeName::TextOverflow, "ellipsis"),
+        (AttributeName::LetterSpacing, "1"),
+        (AttributeName::WordSpacing, "4"),
+    ]);
+
+    let built = FontStyleState::default()
+        .with_color(Color::BLUE)
+        .with_font_family(vec!["Inter".to_string(), "Noto Sans".to_string()])
+        .with_font_size(18.0)
+        .with_font_slant(Slant::Italic)
+        .with_font_weight(Weight::BOLD)
+        .with_line_height(1.5)
+        .with_text_align(TextAlign::Center)
+        .with_max_lines(2)
+        .with_text_overflow(TextOverflow::Ellipsis)
+        .with_letter_spacing(1.0)
+        .with_word_spacing(4.0);
+
+    assert_eq!(built, parsed);
+}
@@ -0,0 +1,42 @@
+use freya_node_state::{
+    lerp,
+    lerp_size,
+    Parse,
+};
+use torin::size::Size;
+
+#[test]
+fn lerp_numbers() {
+    assert_eq!(lerp(0.0, 10.0, 0.0), 0.0);
+    assert_eq!(lerp(0.0, 10.0, 0.5), 5.0);
+    assert_eq!(lerp(0.0, 10.0, 1.0), 10.0);
+
+    // Progress is clamped
+    assert_eq!(lerp(0.0, 10.0, 2.0), 10.0);
+    assert_eq!(lerp(0.0, 10.0, -1.0), 0.0);
+    assert_eq!(lerp(0.0, 10.0, f32::NAN), 0.0);
+}
+
+#[test]
+fn lerp_sizes() {
+    assert_eq!(
+        lerp_size(&Size::pixels(100.0), &Size::pixels(200.0), 0.5),
+        Some(Size::pixels(150.0))
+    );
+    assert_eq!(
+        lerp_size(
+            &Size::parse("10%").unwrap(),
+            &Size::parse("50%").unwrap(),
+            0.25
+        ),
+        Some(Size::parse("20%").unwrap())
+    );
+    assert_eq!(lerp_size(&Size::Fill, &Size::Fill, 0.5), Some(Size::Fill));
+
+    // Sizes of different units can't be interpolated
+    assert_eq!(lerp_size(&Size::pixels(100.0), &Size::Inner, 0.5), None);
+    assert_eq!(
+        lerp_size(&Size::pixels(100.0), &Size::parse("50%").unwrap(), 0.5),
+        None
+    );
+}
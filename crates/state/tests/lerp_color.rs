@@ -0,0 +1,68 @@
+use freya_engine::prelude::*;
+use freya_node_state::{
+    lerp_color,
+    lerp_color_with,
+    ColorInterpolation,
+};
+
+#[test]
+fn lerp_color_linear_endpoints() {
+    let from = Color::from_argb(0, 255, 0, 0);
+    let to = Color::from_argb(255, 0, 0, 255);
+
+    assert_eq!(lerp_color(from, to, 0.0), from);
+    assert_eq!(lerp_color(from, to, 1.0), to);
+}
+
+#[test]
+fn lerp_color_linear_midpoint() {
+    let from = Color::from_argb(0, 255, 0, 0);
+    let to = Color::from_argb(255, 0, 100, 255);
+
+    assert_eq!(
+        lerp_color(from, to, 0.5),
+        Color::from_argb(128, 128, 50, 128)
+    );
+}
+
+#[test]
+fn lerp_color_clamps_t() {
+    let from = Color::BLACK;
+    let to = Color::WHITE;
+
+    assert_eq!(lerp_color(from, to, -1.0), from);
+    assert_eq!(lerp_color(from, to, 2.0), to);
+    assert_eq!(lerp_color(from, to, f32::NAN), from);
+}
+
+#[test]
+fn lerp_color_oklab_endpoints() {
+    let from = Color::RED;
+    let to = Color::BLUE;
+
+    assert_eq!(
+        lerp_color_with(from, to, 0.0, ColorInterpolation::OkLab),
+        from
+    );
+    assert_eq!(
+        lerp_color_with(from, to, 1.0, ColorInterpolation::OkLab),
+        to
+    );
+}
+
+#[test]
+fn lerp_color_oklab_midpoint() {
+    let gray = lerp_color_with(Color::BLACK, Color::WHITE, 0.5, ColorInterpolation::OkLab);
+    assert_eq!(gray, Color::from_rgb(99, 99, 99));
+
+    let purple = lerp_color_with(Color::RED, Color::BLUE, 0.5, ColorInterpolation::OkLab);
+    assert_eq!(purple, Color::from_rgb(140, 83, 162));
+
+    let translucent = lerp_color_with(
+        Color::from_argb(0, 0, 0, 0),
+        Color::from_argb(200, 255, 255, 255),
+        0.5,
+        ColorInterpolation::OkLab,
+    );
+    assert_eq!(translucent.a(), 100);
+}
@@ -0,0 +1,15 @@
+use freya_engine::prelude::BlendMode;
+use freya_node_state::Parse;
+
+#[test]
+fn parse_blend_mode() {
+    assert_eq!(BlendMode::parse("normal"), Ok(BlendMode::SrcOver));
+    assert_eq!(BlendMode::parse("multiply"), Ok(BlendMode::Multiply));
+    assert_eq!(BlendMode::parse("color-dodge"), Ok(BlendMode::ColorDodge));
+    assert_eq!(BlendMode::parse("difference"), Ok(BlendMode::Difference));
+}
+
+#[test]
+fn parse_invalid_blend_mode() {
+    assert!(BlendMode::parse("src-over").is_err());
+}
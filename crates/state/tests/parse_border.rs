@@ -2,6 +2,7 @@ use freya_engine::prelude::*;
 use freya_node_state::{
     Border,
     BorderAlignment,
+    BorderSides,
     BorderStyle,
     Fill,
     GradientStop,
@@ -89,3 +90,32 @@ fn parse_border_style() {
     );
     assert!(invalid.is_err());
 }
+
+#[test]
+fn resolve_border_sides() {
+    let border = Border {
+        alignment: BorderAlignment::Outer,
+        ..Border::parse("2 solid blue").unwrap()
+    };
+    let sides = BorderSides {
+        bottom: Border::parse("4 solid red").ok(),
+        left: Border::parse("none").ok(),
+        ..Default::default()
+    };
+
+    let [top, right, bottom, left] = sides.resolve(&border);
+
+    assert_eq!(top, border);
+    assert_eq!(right, border);
+    assert_eq!(
+        bottom,
+        Border {
+            width: 4.0,
+            fill: Fill::Color(Color::RED),
+            style: BorderStyle::Solid,
+            alignment: BorderAlignment::Outer
+        }
+    );
+    assert!(!left.is_visible());
+    assert!(BorderSides::default().is_empty());
+}
@@ -0,0 +1,20 @@
+use freya_node_state::Parse;
+use torin::box_sizing::BoxSizing;
+
+#[test]
+fn parse_border_box_sizing() {
+    let box_sizing = BoxSizing::parse("border-box");
+    assert_eq!(box_sizing, Ok(BoxSizing::BorderBox));
+}
+
+#[test]
+fn parse_content_box_sizing() {
+    let box_sizing = BoxSizing::parse("content-box");
+    assert_eq!(box_sizing, Ok(BoxSizing::ContentBox));
+}
+
+#[test]
+fn parse_invalid_box_sizing() {
+    let box_sizing = BoxSizing::parse("padding-box");
+    assert!(box_sizing.is_err());
+}
@@ -1,5 +1,8 @@
 use freya_engine::prelude::*;
-use freya_node_state::Parse;
+use freya_node_state::{
+    color_to_string,
+    Parse,
+};
 
 #[test]
 fn parse_manual_color() {
@@ -36,6 +39,40 @@ fn parse_hex_color() {
     assert_eq!(color, Ok(Color::from_rgb(255, 165, 0)));
 }
 
+#[test]
+fn parse_hex_color_with_alpha() {
+    let color = Color::parse("#FFA50080");
+    assert_eq!(color, Ok(Color::from_argb(128, 255, 165, 0)));
+}
+
+#[test]
+fn color_to_string_named() {
+    assert_eq!(color_to_string(Color::RED), "#FF0000FF");
+    assert_eq!(color_to_string(Color::TRANSPARENT), "#00000000");
+    assert_eq!(
+        color_to_string(Color::parse("orange").unwrap()),
+        "#FFA500FF"
+    );
+}
+
+#[test]
+fn color_to_string_round_trip() {
+    // Small xorshift generator so the test is deterministic without extra dependencies.
+    let mut seed: u32 = 0x9E37_79B9;
+    for _ in 0..1000 {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+
+        let [a, r, g, b] = seed.to_be_bytes();
+        let color = Color::from_argb(a, r, g, b);
+        let serialized = color_to_string(color);
+
+        assert_eq!(serialized.len(), 9);
+        assert_eq!(Color::parse(&serialized), Ok(color));
+    }
+}
+
 #[test]
 fn invalid_colors() {
     let incorrect_name = Color::parse("wow(0, 0, 0)");
@@ -50,6 +87,8 @@ fn invalid_colors() {
     let bad_unit = Color::parse("hsl(28in, 0.4, 0.25, 50%)");
     let missing_number_sign = Color::parse("FFA500");
     let incorrect_hex_length = Color::parse("#FFA0");
+    let incorrect_hex_alpha_length = Color::parse("#FFA5000");
+    let invalid_hex_alpha = Color::parse("#FFA500GG");
 
     assert!(incorrect_name.is_err());
     assert!(extra_lparen.is_err());
@@ -63,4 +102,6 @@ fn invalid_colors() {
     assert!(bad_unit.is_err());
     assert!(missing_number_sign.is_err());
     assert!(incorrect_hex_length.is_err());
+    assert!(incorrect_hex_alpha_length.is_err());
+    assert!(invalid_hex_alpha.is_err());
 }
@@ -1,5 +1,11 @@
-use freya_node_state::Parse;
-use torin::gaps::Gaps;
+use freya_node_state::{
+    parse_margin,
+    Parse,
+};
+use torin::{
+    gaps::Gaps,
+    margin::AutoMargins,
+};
 
 #[test]
 fn parse_all_gaps() {
@@ -24,3 +30,21 @@ fn parse_horizontal_axis_and_vertical_sides() {
     let gaps = Gaps::parse("5 50 30");
     assert_eq!(gaps, Ok(Gaps::new(5.0, 50.0, 30.0, 50.0)));
 }
+
+#[test]
+fn parse_auto_margin() {
+    let margin = parse_margin("10 auto");
+    assert_eq!(
+        margin,
+        Ok((
+            Gaps::new(10.0, 0.0, 10.0, 0.0),
+            AutoMargins::new(false, true, false, true)
+        ))
+    );
+}
+
+#[test]
+fn parse_invalid_margin() {
+    let margin = parse_margin("10 center");
+    assert!(margin.is_err());
+}
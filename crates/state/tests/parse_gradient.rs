@@ -247,3 +247,51 @@ fn invalid_conic_gradients() {
     assert!(missing_color.is_err());
     assert!(missing_offset.is_err());
 }
+
+#[test]
+fn parse_current_color_gradient_stops() {
+    let current_color = Color::from_rgb(10, 20, 30);
+
+    assert_eq!(
+        LinearGradient::parse_with_current_color(
+            "linear-gradient(currentColor 0%, transparent 100%)",
+            current_color
+        ),
+        Ok(LinearGradient {
+            angle: 0.0,
+            stops: vec![
+                GradientStop {
+                    color: current_color,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: current_color.with_a(0),
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+
+    // Other colors are not affected by it
+    assert_eq!(
+        RadialGradient::parse_with_current_color(
+            "radial-gradient(red 0%, currentColor 100%)",
+            current_color
+        )
+        .map(|gradient| gradient.stops[0].color),
+        Ok(Color::RED)
+    );
+
+    // Gradients without it keep their transparent stops as they were
+    assert_eq!(
+        LinearGradient::parse_with_current_color(
+            "linear-gradient(red 0%, transparent 100%)",
+            current_color
+        )
+        .map(|gradient| gradient.stops[1].color),
+        Ok(Color::TRANSPARENT)
+    );
+
+    // Without a current color the keyword is not valid
+    assert!(ConicGradient::parse("conic-gradient(currentColor 0%, blue 100%)").is_err());
+}
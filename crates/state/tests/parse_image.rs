@@ -0,0 +1,18 @@
+use freya_node_state::{
+    ImageTintMode,
+    Parse,
+};
+
+#[test]
+fn parse_image_tint_mode() {
+    assert_eq!(ImageTintMode::parse("src-in"), Ok(ImageTintMode::SrcIn));
+    assert_eq!(
+        ImageTintMode::parse("multiply"),
+        Ok(ImageTintMode::Multiply)
+    );
+}
+
+#[test]
+fn parse_invalid_image_tint_mode() {
+    assert!(ImageTintMode::parse("tint").is_err());
+}
@@ -83,3 +83,46 @@ fn parse_gradient_shadow() {
         })
     );
 }
+
+#[test]
+fn parse_shadow_without_fill() {
+    let shadow = Shadow::parse("1 2 50 25");
+    assert_eq!(
+        shadow,
+        Ok(Shadow {
+            position: ShadowPosition::Normal,
+            x: 1.0,
+            y: 2.0,
+            blur: 50.0,
+            spread: 25.0,
+            fill: Fill::Color(Color::TRANSPARENT)
+        })
+    );
+
+    let shadow = Shadow::parse("1 2 50");
+    assert!(shadow.is_err());
+}
+
+#[test]
+fn parse_shadow_with_values_after_fill() {
+    let shadow = Shadow::parse("1 2 50 red 25");
+    assert!(shadow.is_err());
+
+    let shadow = Shadow::parse("1 2 50 25 red blue");
+    assert!(shadow.is_err());
+}
+
+#[test]
+fn parse_shadow_with_over_range_alpha() {
+    let shadow = Shadow::parse("1 2 50 rgb(0, 0, 0, 300)");
+    assert_eq!(
+        shadow.map(|shadow| shadow.fill),
+        Ok(Fill::Color(Color::from_argb(255, 0, 0, 0)))
+    );
+
+    let shadow = Shadow::parse("1 2 50 rgb(0, 0, 0, 255)");
+    assert_eq!(
+        shadow.map(|shadow| shadow.fill),
+        Ok(Fill::Color(Color::from_argb(255, 0, 0, 0)))
+    );
+}
@@ -39,3 +39,53 @@ fn parse_calc_size() {
         ])))
     );
 }
+
+#[test]
+fn parse_calc_functions_size() {
+    let size = Size::parse("clamp(200, 50%, 600)");
+    assert_eq!(
+        size,
+        Ok(Size::DynamicCalculations(Box::new(vec![
+            DynamicCalculation::Clamp,
+            DynamicCalculation::Pixels(200.0),
+            DynamicCalculation::FunctionSeparator,
+            DynamicCalculation::Percentage(50.0),
+            DynamicCalculation::FunctionSeparator,
+            DynamicCalculation::Pixels(600.0),
+            DynamicCalculation::FunctionEnd,
+        ])))
+    );
+
+    let size = Size::parse("calc(min(50% - 20, calc(100 * 2)) + max(10, 5%))");
+    assert_eq!(
+        size,
+        Ok(Size::DynamicCalculations(Box::new(vec![
+            DynamicCalculation::Min,
+            DynamicCalculation::Percentage(50.0),
+            DynamicCalculation::Sub,
+            DynamicCalculation::Pixels(20.0),
+            DynamicCalculation::FunctionSeparator,
+            DynamicCalculation::Pixels(100.0),
+            DynamicCalculation::Mul,
+            DynamicCalculation::Pixels(2.0),
+            DynamicCalculation::FunctionEnd,
+            DynamicCalculation::Add,
+            DynamicCalculation::Max,
+            DynamicCalculation::Pixels(10.0),
+            DynamicCalculation::FunctionSeparator,
+            DynamicCalculation::Percentage(5.0),
+            DynamicCalculation::FunctionEnd,
+        ])))
+    );
+
+    assert!(Size::parse("clamp(200, 50%)").is_err());
+    assert!(Size::parse("min(200, 50%").is_err());
+}
+
+#[test]
+fn parse_size_like_constructors() {
+    assert_eq!(Size::parse("123"), Ok(Size::pixels(123.0)));
+    assert_eq!(Size::parse("50%"), Ok(Size::percentage(50.0)));
+    assert_eq!(Size::parse("v50"), Ok(Size::root_percentage(50.0)));
+    assert_eq!(Size::parse("a50"), Ok(Size::inner_percentage(50.0)));
+}
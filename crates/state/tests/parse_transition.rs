@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use freya_node_state::{
+    Parse,
+    TransitionEasing,
+    TransitionProperty,
+    TransitionTiming,
+    Transitions,
+};
+
+#[test]
+fn parse_transitions() {
+    let transitions = Transitions::parse("background 200ms ease-out, opacity 0.15s").unwrap();
+
+    assert_eq!(
+        transitions.get(TransitionProperty::Background),
+        Some(&TransitionTiming {
+            duration: Duration::from_millis(200),
+            easing: TransitionEasing::EaseOut
+        })
+    );
+    assert_eq!(
+        transitions.get(TransitionProperty::Opacity),
+        Some(&TransitionTiming {
+            duration: Duration::from_millis(150),
+            easing: TransitionEasing::Linear
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_transitions() {
+    assert!(Transitions::parse("width 200ms").is_err());
+    assert!(Transitions::parse("background").is_err());
+    assert!(Transitions::parse("background 200").is_err());
+    assert!(Transitions::parse("background -200ms").is_err());
+    assert!(Transitions::parse("background 200ms bouncy").is_err());
+    assert!(Transitions::parse("background 200ms linear extra").is_err());
+}
+
+#[test]
+fn transition_easing_endpoints() {
+    for easing in [
+        TransitionEasing::Linear,
+        TransitionEasing::EaseIn,
+        TransitionEasing::EaseOut,
+        TransitionEasing::EaseInOut,
+    ] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+    assert_eq!(TransitionEasing::EaseInOut.apply(0.5), 0.5);
+}
+
+#[test]
+fn parse_transition_timing() {
+    assert_eq!(
+        TransitionTiming::parse("300ms ease-in"),
+        Ok(TransitionTiming {
+            duration: Duration::from_millis(300),
+            easing: TransitionEasing::EaseIn
+        })
+    );
+    assert!(TransitionTiming::parse("").is_err());
+    assert!(TransitionTiming::parse("300ms ease-in linear").is_err());
+}
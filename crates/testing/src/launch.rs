@@ -8,6 +8,8 @@ use freya_common::EventMessage;
 use freya_components::NativeContainer;
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
+use freya_native_core::prelude::TypeErasedState;
+use freya_node_state::CustomAttributeValues;
 use tokio::sync::{
     broadcast,
     mpsc::unbounded_channel,
@@ -31,8 +33,18 @@ pub fn launch_test(root: AppComponent) -> TestingHandler {
 
 /// Run a Component in a headless testing environment
 pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> TestingHandler {
+    launch_test_with_custom_states(root, config, Vec::new())
+}
+
+/// Run a Component in a headless testing environment, whose nodes also compute the given custom states.
+/// They can be read with [TestNode::get_state](crate::test_node::TestNode::get_state).
+pub fn launch_test_with_custom_states(
+    root: AppComponent,
+    config: TestingConfig,
+    custom_states: Vec<TypeErasedState<CustomAttributeValues>>,
+) -> TestingHandler {
     let vdom = with_accessibility(root);
-    let fdom = FreyaDOM::default();
+    let fdom = FreyaDOM::new(custom_states);
     let sdom = SafeDOM::new(fdom);
 
     let (event_emitter, event_receiver) = unbounded_channel();
@@ -48,6 +60,7 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
     let font_mgr = FontMgr::default();
     font_collection.set_dynamic_font_manager(font_mgr.clone());
     font_collection.set_default_font_manager(font_mgr.clone(), None);
+    font_collection.enable_font_fallback();
 
     let mut handler = TestingHandler {
         vdom,
@@ -55,6 +68,9 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         nodes_state: NodesState::default(),
         font_collection,
         font_mgr,
+        images_cache: ImagesCache::default(),
+        pictures_cache: PicturesCache::default(),
+        paragraph_cache: ParagraphCache::default(),
         event_emitter,
         event_receiver,
         utils: TestUtils { sdom },
@@ -63,7 +79,12 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         platform_event_receiver,
         accessibility_manager: AccessibilityManager::new(ACCESSIBILITY_ROOT_ID).wrap(),
         ticker_sender: broadcast::channel(5).0,
+        close_request_sender: broadcast::channel(1).0,
+        exited: false,
         cursor_icon: CursorIcon::default(),
+        cursor_position: None,
+        touch_gestures: TouchGestures::default(),
+        window_drags: 0,
         platform_sender,
         platform_receiver,
     };
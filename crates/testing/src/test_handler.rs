@@ -18,6 +18,8 @@ use freya_engine::prelude::{
     EncodedImageFormat,
     FontCollection,
     FontMgr,
+    Image,
+    Surface,
 };
 use freya_native_core::dioxus::NodeImmutableDioxusExt;
 use tokio::{
@@ -35,6 +37,7 @@ use tokio::{
 };
 use torin::geometry::{
     Area,
+    CursorPoint,
     Size2D,
 };
 use winit::window::CursorIcon;
@@ -60,10 +63,18 @@ pub struct TestingHandler {
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
+    pub(crate) images_cache: ImagesCache,
+    pub(crate) pictures_cache: PicturesCache,
+    pub(crate) paragraph_cache: ParagraphCache,
     pub(crate) accessibility_manager: SharedAccessibilityManager,
     pub(crate) config: TestingConfig,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) close_request_sender: CloseRequestSender,
+    pub(crate) exited: bool,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) cursor_position: Option<CursorPoint>,
+    pub(crate) touch_gestures: TouchGestures,
+    pub(crate) window_drags: usize,
 }
 
 impl TestingHandler {
@@ -88,6 +99,13 @@ impl TestingHandler {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_sender.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(TextMeasurer::new(
+                self.font_collection.clone(),
+                default_fonts(),
+            )));
     }
 
     /// Wait and apply new changes
@@ -140,6 +158,7 @@ impl TestingHandler {
                             .unwrap()
                             .set_focus_on_next_node(AccessibilityFocusDirection::Forward);
                         self.platform_sender.send_modify(|state| {
+                            state.navigation_mode = NavigationMode::Keyboard;
                             state.focused_id = tree.focus;
                         });
                     }
@@ -150,15 +169,39 @@ impl TestingHandler {
                             .unwrap()
                             .set_focus_on_next_node(AccessibilityFocusDirection::Backward);
                         self.platform_sender.send_modify(|state| {
+                            state.navigation_mode = NavigationMode::Keyboard;
                             state.focused_id = tree.focus;
                         });
                     }
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventMessage::ExitApp => {
+                        self.exited = true;
+                    }
+                    EventMessage::CaptureScreenshot(area, callback) => {
+                        let mut surface = self.render_surface();
+                        callback(encode_surface_png(&mut surface, area));
+                    }
+                    EventMessage::NodeAtPoint(point, callback) => {
+                        callback(measure_node_at_point(
+                            &self.utils.sdom().get(),
+                            point * SCALE_FACTOR,
+                            SCALE_FACTOR,
+                        ));
+                    }
                     EventMessage::RemeasureTextGroup(text_measurement) => {
                         self.measure_text_group(text_measurement);
                     }
+                    EventMessage::ReplayCursorMovement => {
+                        if let Some(cursor) = self.cursor_position {
+                            self.events_queue.push(PlatformEvent::Mouse {
+                                name: EventName::MouseOver,
+                                cursor,
+                                button: None,
+                            });
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -184,11 +227,17 @@ impl TestingHandler {
             .await
             .ok();
 
-        let (must_repaint, must_relayout) = self
-            .utils
-            .sdom()
-            .get_mut()
-            .render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+        let (must_repaint, must_relayout) = {
+            let mut fdom = self.utils.sdom().get_mut();
+            let focused_id = self.platform_sender.borrow().focused_id;
+            self.nodes_state
+                .apply_interactions(fdom.rdom_mut(), focused_id);
+            let changes = fdom.render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+            let invalidated_nodes = fdom.take_invalidated_nodes();
+            self.pictures_cache
+                .invalidate(fdom.rdom(), invalidated_nodes);
+            changes
+        };
 
         self.wait_for_work(self.config.size());
 
@@ -210,25 +259,63 @@ impl TestingHandler {
                 size,
             },
             &mut self.font_collection,
+            &self.font_mgr,
             SCALE_FACTOR,
             &default_fonts(),
+            RenderQuality::default(),
+            &mut self.paragraph_cache,
         );
 
-        let fdom = &self.utils.sdom().get_mut();
+        let mut fdom = self.utils.sdom().get_mut();
 
-        process_accessibility(
+        let restore_focus = process_accessibility(
             &fdom.layout(),
             fdom.rdom(),
             &mut self.accessibility_manager.lock().unwrap(),
         );
 
+        if let Some(node_id) = restore_focus {
+            let tree = self
+                .accessibility_manager
+                .lock()
+                .unwrap()
+                .set_focus_with_update(node_id);
+
+            if let Some(tree) = tree {
+                self.platform_sender.send_modify(|state| {
+                    state.focused_id = tree.focus;
+                });
+            }
+        }
+
+        process_resize_events(
+            &fdom,
+            &self.event_emitter,
+            &mut self.nodes_state,
+            SCALE_FACTOR,
+        );
+
         process_events(
-            fdom,
+            &fdom,
             &mut self.events_queue,
             &self.event_emitter,
             &mut self.nodes_state,
             SCALE_FACTOR,
         );
+
+        // Apply the interaction states caused by the events right away
+        let focused_id = self.platform_sender.borrow().focused_id;
+        self.nodes_state
+            .apply_interactions(fdom.rdom_mut(), focused_id);
+        fdom.update_states();
+        let invalidated_nodes = fdom.take_invalidated_nodes();
+        self.pictures_cache
+            .invalidate(fdom.rdom(), invalidated_nodes);
+        drop(fdom);
+
+        if self.nodes_state.take_window_drag() {
+            self.window_drags += 1;
+        }
     }
 
     fn measure_text_group(&self, text_measurement: TextGroupMeasurement) {
@@ -239,7 +326,34 @@ impl TestingHandler {
 
     /// Push an event to the events queue
     pub fn push_event(&mut self, event: PlatformEvent) {
+        // Like in the renderer, using the mouse leaves the keyboard navigation
+        if let PlatformEvent::Mouse {
+            name:
+                EventName::MouseDown | EventName::Click | EventName::MiddleClick | EventName::RightClick,
+            ..
+        } = event
+        {
+            self.platform_sender.send_modify(|state| {
+                state.navigation_mode = NavigationMode::NotKeyboard;
+            });
+        }
+        if let PlatformEvent::Mouse { cursor, .. } = event {
+            self.cursor_position = Some(cursor);
+        }
+        let gestures = if let PlatformEvent::Touch {
+            location,
+            finger_id,
+            phase,
+            ..
+        } = event
+        {
+            self.touch_gestures
+                .process_touch(finger_id, location, phase)
+        } else {
+            Vec::new()
+        };
         self.events_queue.push(event);
+        self.events_queue.extend(gestures);
     }
 
     /// Get the root node
@@ -270,19 +384,54 @@ impl TestingHandler {
         })
     }
 
+    /// Get the topmost element at the given point of the window, found just like the element that receives the events at that point.
+    pub fn node_at_point(&self, point: impl Into<CursorPoint>) -> Option<TestNode> {
+        let node_id = measure_node_at_point(&self.utils.sdom().get(), point.into(), SCALE_FACTOR)?;
+        Some(self.utils.get_node_by_id(node_id))
+    }
+
     /// Get the current [CursorIcon].
     pub fn cursor_icon(&self) -> CursorIcon {
         self.cursor_icon
     }
 
+    /// Request the window to close, like clicking the close button of its title bar would.
+    /// The app exits right away unless it's listening for close requests, call [Self::wait_for_update] to let it handle them.
+    pub fn request_close(&mut self) {
+        if self.close_request_sender.send(()).is_err() {
+            self.exited = true;
+        }
+    }
+
+    /// Check if the app has asked to exit, either with `UsePlatform::exit` or because the window was requested to close.
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    /// How many times dragging a `window_drag` element has started moving the window.
+    pub fn window_drags(&self) -> usize {
+        self.window_drags
+    }
+
+    /// Get the [PicturesCache] of the subtrees painted with `cache: true`.
+    pub fn pictures_cache(&self) -> &PicturesCache {
+        &self.pictures_cache
+    }
+
+    /// Get the [ParagraphCache] of the measured text.
+    pub fn paragraph_cache(&self) -> &ParagraphCache {
+        &self.paragraph_cache
+    }
+
     /// Get the [SafeDOM]
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
     }
 
-    /// Render the app into a canvas and save it into a file.
-    pub fn save_snapshot(&mut self, snapshot_path: impl Into<PathBuf>) {
+    /// Render the app into a new raster surface.
+    fn render_surface(&mut self) -> Surface {
         let fdom = self.utils.sdom.get();
+        let layout_transitions = fdom.layout_transitions();
         let (width, height) = self.config.size.to_i32().to_tuple();
 
         // Create the canvas
@@ -290,15 +439,27 @@ impl TestingHandler {
             raster_n32_premul((width, height)).expect("Failed to create the surface.");
         surface.canvas().clear(Color::WHITE);
 
+        let platform_state = self.platform_sender.borrow().clone();
+        let focus_visible_id = (platform_state.navigation_mode == NavigationMode::Keyboard)
+            .then_some(platform_state.focused_id);
+
         let mut skia_renderer = SkiaRenderer {
             canvas_area: Area::from_size((width as f32, height as f32).into()),
             canvas: surface.canvas(),
             font_collection: &mut self.font_collection,
             font_manager: &self.font_mgr,
+            images_cache: &mut self.images_cache,
+            pictures_cache: &mut self.pictures_cache,
             matrices: Vec::default(),
             opacities: Vec::default(),
+            layer_root: None,
             default_fonts: &["Fira Sans".to_string()],
             scale_factor: SCALE_FACTOR as f32,
+            render_quality: RenderQuality::default(),
+            focus_visible_id,
+            layout_transitions: &layout_transitions,
+            has_running_transitions: false,
+            caret_area: None,
         };
 
         // Render to the canvas
@@ -308,6 +469,21 @@ impl TestingHandler {
             }
         });
 
+        self.images_cache.evict_unused();
+        self.pictures_cache.evict_unused();
+
+        surface
+    }
+
+    /// Render the app and get the resulting image, useful to assert on the rendered pixels.
+    pub fn create_snapshot(&mut self) -> Image {
+        self.render_surface().image_snapshot()
+    }
+
+    /// Render the app into a canvas and save it into a file.
+    pub fn save_snapshot(&mut self, snapshot_path: impl Into<PathBuf>) {
+        let mut surface = self.render_surface();
+
         // Capture snapshot
         let image = surface.image_snapshot();
         let mut context = surface.direct_context();
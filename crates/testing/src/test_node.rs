@@ -1,5 +1,10 @@
+use freya_common::{
+    MissingFontFamilies,
+    UnresolvedGlyphs,
+};
 use freya_core::node::NodeState;
 use freya_native_core::{
+    exports::shipyard::Component,
     node::NodeType,
     real_dom::NodeImmutable,
     NodeId,
@@ -11,10 +16,16 @@ use freya_node_state::{
 };
 use torin::{
     geometry::Area,
-    prelude::LayoutNode,
+    prelude::{
+        CursorPoint,
+        LayoutNode,
+    },
 };
 
-use crate::test_utils::TestUtils;
+use crate::{
+    test_utils::TestUtils,
+    SCALE_FACTOR,
+};
 
 /// Represents a `Node` in the DOM.
 #[derive(Clone)]
@@ -28,6 +39,11 @@ pub struct TestNode {
 }
 
 impl TestNode {
+    /// Get the id of the Node.
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Quickly get a child of the Node by the given index, if the child is not found it will panic
     #[track_caller]
     pub fn get(&self, child_index: usize) -> Self {
@@ -52,6 +68,15 @@ impl TestNode {
         &self.state
     }
 
+    /// Get a custom state of the Node, registered with [launch_test_with_custom_states](crate::launch::launch_test_with_custom_states).
+    pub fn get_state<S: Component + Send + Sync + Clone>(&self) -> Option<S> {
+        let sdom = self.utils.sdom();
+        let fdom = sdom.get();
+        let node = fdom.rdom().get(self.node_id)?;
+        let state = node.get::<S>().as_deref().cloned();
+        state
+    }
+
     /// Get the Node layout
     pub fn layout(&self) -> Option<LayoutNode> {
         self.utils()
@@ -94,6 +119,30 @@ impl TestNode {
         node.parent_id()
     }
 
+    /// Get the index of the glyph closest to the given point, relative to the top-left corner of this text Node.
+    pub fn glyph_index_at(&self, point: impl Into<CursorPoint>) -> Option<usize> {
+        self.utils()
+            .sdom()
+            .get()
+            .glyph_index_at(self.node_id, point.into(), SCALE_FACTOR)
+    }
+
+    /// Get how many glyphs of this text Node couldn't be shaped with any font, if it's a text Node.
+    pub fn unresolved_glyphs(&self) -> Option<usize> {
+        self.layout()?
+            .data?
+            .get::<UnresolvedGlyphs>()
+            .map(|unresolved_glyphs| unresolved_glyphs.0)
+    }
+
+    /// Get the font families of this text Node that were not found, if it's a text Node.
+    pub fn missing_font_families(&self) -> Option<Vec<String>> {
+        self.layout()?
+            .data?
+            .get::<MissingFontFamilies>()
+            .map(|missing_font_families| missing_font_families.0.clone())
+    }
+
     /// Get the Node height in the DOM
     pub fn dom_height(&self) -> u16 {
         self.height
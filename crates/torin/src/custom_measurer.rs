@@ -7,6 +7,7 @@ use crate::{
     geometry::Size2D,
     node::Node,
     prelude::LayoutNode,
+    torin::Torin,
 };
 
 pub trait LayoutMeasurer<Key: NodeKey> {
@@ -20,4 +21,16 @@ pub trait LayoutMeasurer<Key: NodeKey> {
     fn should_measure_inner_children(&mut self, node_id: Key) -> bool;
 
     fn notify_layout_references(&self, _node_id: Key, _layout_node: &LayoutNode) {}
+
+    /// Notify the Node that has layout references of its new layout,
+    /// where `layout` contains the already measured layout of its inner Nodes.
+    /// Calls [`LayoutMeasurer::notify_layout_references`] by default.
+    fn notify_layout_references_with_layout(
+        &self,
+        node_id: Key,
+        layout_node: &LayoutNode,
+        _layout: &Torin<Key>,
+    ) {
+        self.notify_layout_references(node_id, layout_node)
+    }
 }
@@ -58,8 +58,8 @@ impl AreaModel for Area {
         Area::new(
             Point2D::new(origin.x + margin.left(), origin.y + margin.top()),
             Size2D::new(
-                size.width - margin.horizontal(),
-                size.height - margin.vertical(),
+                (size.width - margin.horizontal()).max(0.0),
+                (size.height - margin.vertical()).max(0.0),
             ),
         )
     }
@@ -17,7 +17,9 @@ use crate::{
     prelude::{
         AlignmentDirection,
         AreaModel,
+        BoxSizing,
         LayoutMetadata,
+        Position,
         Torin,
     },
 };
@@ -60,8 +62,16 @@ pub fn measure_node<Key: NodeKey>(
         // Create the initial Node area size
         let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
 
+        // Padding that goes on top of the declared sizes
+        let box_padding = match node.box_sizing {
+            BoxSizing::BorderBox => Size2D::default(),
+            BoxSizing::ContentBox => {
+                Size2D::new(node.padding.horizontal(), node.padding.vertical())
+            }
+        };
+
         // Compute the width and height given the size, the minimum size, the maximum size and margins
-        area_size.width = node.width.min_max(
+        area_size.width = node.width.min_max_with_box_padding(
             area_size.width,
             parent_area.size.width,
             available_parent_area.size.width,
@@ -70,9 +80,10 @@ pub fn measure_node<Key: NodeKey>(
             &node.minimum_width,
             &node.maximum_width,
             layout_metadata.root_area.width(),
+            box_padding.width,
             phase,
         );
-        area_size.height = node.height.min_max(
+        area_size.height = node.height.min_max_with_box_padding(
             area_size.height,
             parent_area.size.height,
             available_parent_area.size.height,
@@ -81,6 +92,7 @@ pub fn measure_node<Key: NodeKey>(
             &node.minimum_height,
             &node.maximum_height,
             layout_metadata.root_area.height(),
+            box_padding.height,
             phase,
         );
 
@@ -101,7 +113,7 @@ pub fn measure_node<Key: NodeKey>(
             // Compute the width and height again using the new custom area sizes
             if let Some((custom_size, node_data)) = res {
                 if node.width.inner_sized() {
-                    area_size.width = node.width.min_max(
+                    area_size.width = node.width.min_max_with_box_padding(
                         custom_size.width,
                         parent_area.size.width,
                         available_parent_area.size.width,
@@ -110,11 +122,12 @@ pub fn measure_node<Key: NodeKey>(
                         &node.minimum_width,
                         &node.maximum_width,
                         layout_metadata.root_area.width(),
+                        box_padding.width,
                         phase,
                     );
                 }
                 if node.height.inner_sized() {
-                    area_size.height = node.height.min_max(
+                    area_size.height = node.height.min_max_with_box_padding(
                         custom_size.height,
                         parent_area.size.height,
                         available_parent_area.size.height,
@@ -123,6 +136,7 @@ pub fn measure_node<Key: NodeKey>(
                         &node.minimum_height,
                         &node.maximum_height,
                         layout_metadata.root_area.height(),
+                        box_padding.height,
                         phase,
                     );
                 }
@@ -136,6 +150,28 @@ pub fn measure_node<Key: NodeKey>(
             (true, None)
         };
 
+        // Give the space left in the parent to the auto margins, only along the axis with a size not decided by the children
+        let margin = if node.auto_margin.is_horizontal() || node.auto_margin.is_vertical() {
+            let free_space = Size2D::new(
+                if node.width.inner_sized() {
+                    0.0
+                } else {
+                    (available_parent_area.width() - area_size.width).max(0.0)
+                },
+                if node.height.inner_sized() {
+                    0.0
+                } else {
+                    (available_parent_area.height() - area_size.height).max(0.0)
+                },
+            );
+            let margin = node.auto_margin.resolve(&node.margin, &free_space);
+            area_size.width += margin.horizontal() - node.margin.horizontal();
+            area_size.height += margin.vertical() - node.margin.vertical();
+            margin
+        } else {
+            node.margin
+        };
+
         // There is no need to measure inner children in the initial phase if this Node size
         // isn't decided by his children
         let phase_measure_inner_children = if phase == Phase::Initial {
@@ -150,28 +186,30 @@ pub fn measure_node<Key: NodeKey>(
 
             // When having an unsized bound we set it to whatever is still available in the parent's area
             if node.width.inner_sized() {
-                inner_size.width = node.width.min_max(
+                inner_size.width = node.width.min_max_with_box_padding(
                     available_parent_area.width(),
                     parent_area.size.width,
                     available_parent_area.width(),
-                    node.margin.left(),
-                    node.margin.horizontal(),
+                    margin.left(),
+                    margin.horizontal(),
                     &node.minimum_width,
                     &node.maximum_width,
                     layout_metadata.root_area.width(),
+                    box_padding.width,
                     phase,
                 );
             }
             if node.height.inner_sized() {
-                inner_size.height = node.height.min_max(
+                inner_size.height = node.height.min_max_with_box_padding(
                     available_parent_area.height(),
                     parent_area.size.height,
                     available_parent_area.height(),
-                    node.margin.top(),
-                    node.margin.vertical(),
+                    margin.top(),
+                    margin.vertical(),
                     &node.minimum_height,
                     &node.maximum_height,
                     layout_metadata.root_area.height(),
+                    box_padding.height,
                     phase,
                 );
             }
@@ -179,13 +217,16 @@ pub fn measure_node<Key: NodeKey>(
         };
 
         // Create the areas
-        let area_origin = node
-            .position
-            .get_origin(available_parent_area, parent_area, &area_size);
+        let area_origin = node.position.get_origin(
+            available_parent_area,
+            parent_area,
+            &layout_metadata.root_area,
+            &area_size,
+        );
         let mut area = Rect::new(area_origin, area_size);
         let mut inner_area = Rect::new(area_origin, inner_size)
             .after_gaps(&node.padding)
-            .after_gaps(&node.margin);
+            .after_gaps(&margin);
 
         let mut inner_sizes = Size2D::default();
 
@@ -195,6 +236,8 @@ pub fn measure_node<Key: NodeKey>(
 
             available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+            let children_metadata = layout_metadata.for_children(node, &inner_area);
+
             let mut measurement_mode = MeasureMode::ParentIsNotCached {
                 area: &mut area,
                 inner_area: &mut inner_area,
@@ -211,7 +254,7 @@ pub fn measure_node<Key: NodeKey>(
                 must_cache_inner_nodes,
                 &mut measurement_mode,
                 dom_adapter,
-                layout_metadata,
+                children_metadata.as_ref().unwrap_or(layout_metadata),
                 true,
             );
         }
@@ -220,7 +263,7 @@ pub fn measure_node<Key: NodeKey>(
             must_cache_inner_nodes,
             LayoutNode {
                 area,
-                margin: node.margin,
+                margin,
                 inner_area,
                 inner_sizes,
                 data: node_data,
@@ -234,6 +277,8 @@ pub fn measure_node<Key: NodeKey>(
 
         available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+        let children_metadata = layout_metadata.for_children(node, &layout_node.inner_area);
+
         let mut measurement_mode = MeasureMode::ParentIsCached {
             inner_area: &layout_node.inner_area,
         };
@@ -255,7 +300,7 @@ pub fn measure_node<Key: NodeKey>(
                 must_cache_inner_nodes,
                 &mut measurement_mode,
                 dom_adapter,
-                layout_metadata,
+                children_metadata.as_ref().unwrap_or(layout_metadata),
                 false,
             );
         }
@@ -301,16 +346,29 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         let mut initial_phase_mode = initial_phase_mode.to_mut();
         let mut initial_phase_available_area = *available_area;
 
+        let mut after_paragraph = false;
+
         // 1. Measure the children
         for child_id in &children {
-            let Some(child_data) = dom_adapter.get_node(child_id) else {
+            let Some(mut child_data) = dom_adapter.get_node(child_id) else {
                 continue;
             };
+            child_data.auto_margin = child_data.auto_margin.cross_axis(&parent_node.direction);
 
-            if child_data.position.is_absolute() {
+            if child_data.position.is_out_of_flow() {
                 continue;
             }
 
+            if after_paragraph {
+                initial_phase_mode.stack_spacing(
+                    parent_node,
+                    &mut initial_phase_available_area,
+                    &mut initial_phase_inner_sizes,
+                    parent_node.paragraph_spacing.get(),
+                );
+            }
+            after_paragraph = child_data.contains_text;
+
             let inner_area = *initial_phase_mode.inner_area();
 
             let (_, child_areas) = measure_node(
@@ -371,11 +429,29 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
     let initial_available_area = *available_area;
 
+    let mut sticky_children = Vec::new();
+    let mut after_paragraph = false;
+
     // Final phase: measure the children with all the axis and sizes adjusted
     for (child_n, child_id) in children.into_iter().enumerate() {
-        let Some(child_data) = dom_adapter.get_node(&child_id) else {
+        let Some(mut child_data) = dom_adapter.get_node(&child_id) else {
             continue;
         };
+        // Auto margins only take the space left in the cross axis
+        child_data.auto_margin = child_data.auto_margin.cross_axis(&parent_node.direction);
+
+        // Separate the text blocks from whatever comes after them
+        if !child_data.position.is_out_of_flow() {
+            if after_paragraph {
+                mode.stack_spacing(
+                    parent_node,
+                    available_area,
+                    inner_sizes,
+                    parent_node.paragraph_spacing.get(),
+                );
+            }
+            after_paragraph = child_data.contains_text;
+        }
 
         let mut adapted_available_area = *available_area;
 
@@ -440,10 +516,71 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         if child_revalidated && must_cache_inner_nodes {
             if let Some(measurer) = measurer {
                 if child_data.has_layout_references {
-                    measurer.notify_layout_references(child_id, &child_areas);
+                    measurer.notify_layout_references_with_layout(child_id, &child_areas, layout);
                 }
             }
             layout.cache_node(child_id, child_areas);
+
+            if child_data.position.is_sticky() {
+                sticky_children.push(child_id);
+            }
+        }
+    }
+
+    // Pin the sticky children now that the size of their parent is known
+    if layout_metadata.scroll_viewport.is_some() {
+        let containing_area = *mode.inner_area();
+        for child_id in sticky_children {
+            let Some(child_data) = dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            pin_sticky_node(
+                child_id,
+                &child_data,
+                layout,
+                &containing_area,
+                layout_metadata,
+                dom_adapter,
+                measurer,
+            );
+        }
+    }
+}
+
+/// Move a sticky Node, and its inner Nodes, so it stays pinned inside its scroll viewport
+/// for as long as its containing area allows.
+pub fn pin_sticky_node<Key: NodeKey>(
+    node_id: Key,
+    node: &Node,
+    layout: &mut Torin<Key>,
+    containing_area: &Area,
+    layout_metadata: &LayoutMetadata,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+) {
+    let Position::Sticky(sticky_position) = &node.position else {
+        return;
+    };
+    let Some(viewport) = &layout_metadata.scroll_viewport else {
+        return;
+    };
+    let Some(layout_node) = layout.get(node_id) else {
+        return;
+    };
+
+    let (offset_x, offset_y) =
+        sticky_position.get_offset(&layout_node.area, viewport, containing_area);
+
+    if offset_x == 0.0 && offset_y == 0.0 {
+        return;
+    }
+
+    layout.translate_node(node_id, offset_x, offset_y, dom_adapter);
+
+    if node.has_layout_references {
+        if let (Some(measurer), Some(layout_node)) = (measurer, layout.get(node_id)) {
+            measurer.notify_layout_references_with_layout(node_id, layout_node, layout);
         }
     }
 }
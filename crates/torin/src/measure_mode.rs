@@ -142,7 +142,7 @@ impl<'a> MeasureMode<'a> {
         inner_sizes: &mut Size2D,
         node_data: &Node,
     ) {
-        if node_data.position.is_absolute() {
+        if node_data.position.is_out_of_flow() {
             return;
         }
 
@@ -205,6 +205,42 @@ impl<'a> MeasureMode<'a> {
             }
         }
     }
+
+    /// Leave some empty space in the direction of the parent Node, before stacking the next Node
+    pub fn stack_spacing(
+        &mut self,
+        parent_node: &Node,
+        available_area: &mut Area,
+        inner_sizes: &mut Size2D,
+        spacing: f32,
+    ) {
+        match parent_node.direction {
+            DirectionMode::Horizontal => {
+                available_area.origin.x += spacing;
+                available_area.size.width -= spacing;
+
+                if let MeasureMode::ParentIsNotCached { area, .. } = self {
+                    inner_sizes.width += spacing;
+
+                    if parent_node.width.inner_sized() {
+                        area.size.width += spacing;
+                    }
+                }
+            }
+            DirectionMode::Vertical => {
+                available_area.origin.y += spacing;
+                available_area.size.height -= spacing;
+
+                if let MeasureMode::ParentIsNotCached { area, .. } = self {
+                    inner_sizes.height += spacing;
+
+                    if parent_node.height.inner_sized() {
+                        area.size.height += spacing;
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Just an owned version of [MeasureMode]
@@ -6,6 +6,8 @@ use crate::{
     gaps::Gaps,
     geometry::Length,
     prelude::{
+        AutoMargins,
+        BoxSizing,
         Content,
         Position,
     },
@@ -38,6 +40,9 @@ pub struct Node {
     /// Inner margin
     pub margin: Gaps,
 
+    /// Sides of the margin that take the space left in the parent
+    pub auto_margin: AutoMargins,
+
     /// Inner position offsets
     pub offset_x: Length,
     pub offset_y: Length,
@@ -50,6 +55,12 @@ pub struct Node {
 
     pub content: Content,
 
+    /// Whether the declared size includes the padding
+    pub box_sizing: BoxSizing,
+
+    /// Space left after every inner text Node that is followed by another inner Node
+    pub paragraph_spacing: Length,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
@@ -69,15 +80,21 @@ impl Scaled for Node {
         self.offset_x *= scale_factor;
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
+        self.paragraph_spacing *= scale_factor;
     }
 }
 
 impl Node {
-    /// Create a Node with the default values
+    /// Create a Node with the default values, customize it with the `with_*` methods.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Check if the inner Nodes are moved by any offset, like in scroll views.
+    pub fn has_offsets(&self) -> bool {
+        self.offset_x.get() != 0.0 || self.offset_y.get() != 0.0
+    }
+
     /// Construct a new Node given a size and a direction
     pub fn from_size_and_direction(width: Size, height: Size, direction: DirectionMode) -> Self {
         Self {
@@ -198,6 +215,109 @@ impl Node {
         }
     }
 
+    /// Set the width.
+    pub fn with_width(mut self, width: Size) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the height.
+    pub fn with_height(mut self, height: Size) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the minimum width.
+    pub fn with_minimum_width(mut self, minimum_width: Size) -> Self {
+        self.minimum_width = minimum_width;
+        self
+    }
+
+    /// Set the minimum height.
+    pub fn with_minimum_height(mut self, minimum_height: Size) -> Self {
+        self.minimum_height = minimum_height;
+        self
+    }
+
+    /// Set the maximum width.
+    pub fn with_maximum_width(mut self, maximum_width: Size) -> Self {
+        self.maximum_width = maximum_width;
+        self
+    }
+
+    /// Set the maximum height.
+    pub fn with_maximum_height(mut self, maximum_height: Size) -> Self {
+        self.maximum_height = maximum_height;
+        self
+    }
+
+    /// Set the alignment of the inner Nodes in the main axis.
+    pub fn with_main_alignment(mut self, main_alignment: Alignment) -> Self {
+        self.main_alignment = main_alignment;
+        self
+    }
+
+    /// Set the alignment of the inner Nodes in the cross axis.
+    pub fn with_cross_alignment(mut self, cross_alignment: Alignment) -> Self {
+        self.cross_alignment = cross_alignment;
+        self
+    }
+
+    /// Set the padding.
+    pub fn with_padding(mut self, padding: Gaps) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the margin.
+    pub fn with_margin(mut self, margin: Gaps) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the sides of the margin that take the space left in the parent.
+    pub fn with_auto_margin(mut self, auto_margin: AutoMargins) -> Self {
+        self.auto_margin = auto_margin;
+        self
+    }
+
+    /// Set the direction in which the inner Nodes are stacked.
+    pub fn with_direction(mut self, direction: DirectionMode) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the position.
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set how the inner Nodes are sized.
+    pub fn with_content(mut self, content: Content) -> Self {
+        self.content = content;
+        self
+    }
+
+    /// Set whether the declared size includes the padding.
+    pub fn with_box_sizing(mut self, box_sizing: BoxSizing) -> Self {
+        self.box_sizing = box_sizing;
+        self
+    }
+
+    /// Set the offsets of the inner Nodes, like in scroll views.
+    pub fn with_offsets(mut self, offset_x: Length, offset_y: Length) -> Self {
+        self.offset_x = offset_x;
+        self.offset_y = offset_y;
+        self
+    }
+
+    /// Set the space left after every inner text Node that is followed by another inner Node.
+    pub fn with_paragraph_spacing(mut self, paragraph_spacing: Length) -> Self {
+        self.paragraph_spacing = paragraph_spacing;
+        self
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -23,8 +23,10 @@ use crate::{
     },
     measure::{
         measure_node,
+        pin_sticky_node,
         Phase,
     },
+    node::Node,
     prelude::{
         AreaModel,
         Gaps,
@@ -33,6 +35,18 @@ use crate::{
 
 pub struct LayoutMetadata {
     pub root_area: Area,
+    /// Inner area of the closest ancestor that moves its children with offsets, used by sticky Nodes.
+    pub scroll_viewport: Option<Area>,
+}
+
+impl LayoutMetadata {
+    /// Get the metadata for the children of the given Node.
+    pub fn for_children(&self, node: &Node, inner_area: &Area) -> Option<Self> {
+        node.has_offsets().then(|| Self {
+            root_area: self.root_area,
+            scroll_viewport: Some(*inner_area),
+        })
+    }
 }
 
 /// Contains the best Root node candidate from where to start measuring
@@ -265,7 +279,11 @@ impl<Key: NodeKey> Torin<Key> {
             root_height
         );
 
-        let metadata = LayoutMetadata { root_area };
+        let metadata = LayoutMetadata {
+            root_area,
+            scroll_viewport: root_parent_id
+                .and_then(|root_parent_id| self.find_scroll_viewport(root_parent_id, dom_adapter)),
+        };
 
         let mut available_area = layout_node.inner_area;
         if let Some(root_parent_id) = root_parent_id {
@@ -294,16 +312,61 @@ impl<Key: NodeKey> Torin<Key> {
         if root_revalidated {
             if let Some(measurer) = measurer {
                 if root.has_layout_references {
-                    measurer.notify_layout_references(root_id, &root_layout_node);
+                    measurer.notify_layout_references_with_layout(root_id, &root_layout_node, self);
                 }
             }
             self.cache_node(root_id, root_layout_node);
+
+            pin_sticky_node(
+                root_id,
+                &root,
+                self,
+                &layout_node.inner_area,
+                &metadata,
+                dom_adapter,
+                measurer,
+            );
         }
 
         self.dirty.clear();
         self.root_node_candidate = RootNodeCandidate::None;
     }
 
+    /// Find the inner area of the closest ancestor, the given Node included, that moves its children with offsets.
+    fn find_scroll_viewport(
+        &self,
+        node_id: Key,
+        dom_adapter: &mut impl DOMAdapter<Key>,
+    ) -> Option<Area> {
+        let mut node_id = Some(node_id);
+        while let Some(id) = node_id {
+            if dom_adapter.get_node(&id)?.has_offsets() {
+                return self.get(id).map(|layout_node| layout_node.inner_area);
+            }
+            node_id = dom_adapter.parent_of(&id);
+        }
+        None
+    }
+
+    /// Move a Node and all its inner Nodes by the given offsets.
+    pub fn translate_node(
+        &mut self,
+        node_id: Key,
+        offset_x: f32,
+        offset_y: f32,
+        dom_adapter: &mut impl DOMAdapter<Key>,
+    ) {
+        if let Some(layout_node) = self.results.get_mut(&node_id) {
+            layout_node.area.origin.x += offset_x;
+            layout_node.area.origin.y += offset_y;
+            layout_node.inner_area.origin.x += offset_x;
+            layout_node.inner_area.origin.y += offset_y;
+        }
+        for child_id in dom_adapter.children_of(&node_id) {
+            self.translate_node(child_id, offset_x, offset_y, dom_adapter);
+        }
+    }
+
     /// Get the layout_node of a Node
     pub fn get(&self, node_id: Key) -> Option<&LayoutNode> {
         self.results.get(&node_id)
@@ -0,0 +1,20 @@
+/// What the declared size of a Node includes.
+///
+/// Percentages of the inner Nodes are always resolved against the area inside the padding (the content box) of their parent.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum BoxSizing {
+    /// The declared size doesn't include the padding, which is added on top of it.
+    #[default]
+    ContentBox,
+    /// The declared size includes the padding.
+    BorderBox,
+}
+
+impl BoxSizing {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::ContentBox => "content-box".to_owned(),
+            Self::BorderBox => "border-box".to_owned(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    direction::DirectionMode,
+    gaps::Gaps,
+    geometry::Size2D,
+};
+
+/// Sides of the margin of a Node that are `auto`.
+///
+/// The space left in the parent along an axis is split equally between the `auto` sides of that axis,
+/// so a Node with both sides `auto` is centered and a Node with only one of them is pushed to the opposite side.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct AutoMargins {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl AutoMargins {
+    pub fn new(top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.left || self.right
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.top || self.bottom
+    }
+
+    /// Keep only the `auto` sides along the cross axis of a parent with the given direction.
+    /// The space left along its main axis is shared with the next siblings, so it's not given to any of them.
+    pub fn cross_axis(&self, direction: &DirectionMode) -> Self {
+        match direction {
+            DirectionMode::Vertical => Self {
+                top: false,
+                bottom: false,
+                ..*self
+            },
+            DirectionMode::Horizontal => Self {
+                left: false,
+                right: false,
+                ..*self
+            },
+        }
+    }
+
+    /// Give the `free_space` of every axis to its `auto` sides of the `margin`.
+    pub fn resolve(&self, margin: &Gaps, free_space: &Size2D) -> Gaps {
+        let split = |start: bool, end: bool, free_space: f32| match (start, end) {
+            (true, true) => (free_space / 2.0, free_space / 2.0),
+            (true, false) => (free_space, 0.0),
+            (false, true) => (0.0, free_space),
+            (false, false) => (0.0, 0.0),
+        };
+        let (left, right) = split(self.left, self.right, free_space.width);
+        let (top, bottom) = split(self.top, self.bottom, free_space.height);
+
+        Gaps::new(
+            margin.top() + top,
+            margin.right() + right,
+            margin.bottom() + bottom,
+            margin.left() + left,
+        )
+    }
+
+    pub fn pretty(&self) -> String {
+        format!(
+            "({}, {}, {}, {})",
+            self.top, self.right, self.bottom, self.left
+        )
+    }
+}
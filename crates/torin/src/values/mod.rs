@@ -1,16 +1,20 @@
 pub mod alignment;
+pub mod box_sizing;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod margin;
 pub mod position;
 pub mod size;
 
 pub mod prelude {
     pub use crate::{
         alignment::*,
+        box_sizing::*,
         content::*,
         direction::*,
         gaps::*,
+        margin::*,
         position::*,
         size::*,
     };
@@ -17,18 +17,54 @@ pub struct AbsolutePosition {
     pub left: Option<f32>,
 }
 
+/// Edges a sticky Node is pinned to, relative to its scroll viewport.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct StickyPosition {
+    pub top: Option<f32>,
+    pub left: Option<f32>,
+}
+
+impl StickyPosition {
+    /// Get how much a Node in the given `area` must be moved to stay pinned inside the `viewport`,
+    /// without leaving its `containing_area`.
+    pub fn get_offset(&self, area: &Area, viewport: &Area, containing_area: &Area) -> (f32, f32) {
+        let offset_y = self
+            .top
+            .map(|top| {
+                let pinned_y =
+                    (viewport.min_y() + top).min(containing_area.max_y() - area.height());
+                (pinned_y - area.min_y()).max(0.0)
+            })
+            .unwrap_or_default();
+        let offset_x = self
+            .left
+            .map(|left| {
+                let pinned_x =
+                    (viewport.min_x() + left).min(containing_area.max_x() - area.width());
+                (pinned_x - area.min_x()).max(0.0)
+            })
+            .unwrap_or_default();
+        (offset_x, offset_y)
+    }
+}
+
 #[derive(Default, PartialEq, Clone, Debug)]
 pub enum Position {
     #[default]
     Stacked,
 
     Absolute(Box<AbsolutePosition>),
+
+    Sticky(Box<StickyPosition>),
+
+    /// Like `Absolute`, but relative to the root area (e.g the window) instead of the parent.
+    Global(Box<AbsolutePosition>),
 }
 
 impl Position {
     pub fn is_empty(&self) -> bool {
         match self {
-            Self::Absolute(absolute_position) => {
+            Self::Absolute(absolute_position) | Self::Global(absolute_position) => {
                 let AbsolutePosition {
                     top,
                     right,
@@ -38,6 +74,7 @@ impl Position {
                 top.is_some() && right.is_some() && bottom.is_some() && left.is_some()
             }
             Self::Stacked => true,
+            Self::Sticky(_) => false,
         }
     }
 
@@ -54,38 +91,87 @@ impl Position {
         matches!(self, Self::Absolute { .. })
     }
 
+    pub fn new_global() -> Self {
+        Self::Global(Box::default())
+    }
+
+    pub fn is_global(&self) -> bool {
+        matches!(self, Self::Global { .. })
+    }
+
+    /// Check if the Node is laid out outside the flow of its siblings, so it doesn't take space in its parent.
+    pub fn is_out_of_flow(&self) -> bool {
+        self.is_absolute() || self.is_global()
+    }
+
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::default())
+    }
+
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
+    }
+
+    /// Turn into a sticky position, keeping the top and left edges if there were any.
+    pub fn make_sticky(&mut self) {
+        match self {
+            Self::Sticky(_) => {}
+            Self::Absolute(absolute_position) => {
+                *self = Self::Sticky(Box::new(StickyPosition {
+                    top: absolute_position.top,
+                    left: absolute_position.left,
+                }))
+            }
+            Self::Stacked | Self::Global(_) => *self = Self::new_sticky(),
+        }
+    }
+
     pub fn set_top(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Sticky(sticky_position) = self {
+            sticky_position.top = Some(value);
+            return;
+        }
+        if !self.is_out_of_flow() {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position) | Self::Global(absolute_position) = self {
             absolute_position.top = Some(value)
         }
     }
 
     pub fn set_right(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if self.is_sticky() {
+            return;
+        }
+        if !self.is_out_of_flow() {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position) | Self::Global(absolute_position) = self {
             absolute_position.right = Some(value)
         }
     }
 
     pub fn set_bottom(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if self.is_sticky() {
+            return;
+        }
+        if !self.is_out_of_flow() {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position) | Self::Global(absolute_position) = self {
             absolute_position.bottom = Some(value)
         }
     }
 
     pub fn set_left(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Sticky(sticky_position) = self {
+            sticky_position.left = Some(value);
+            return;
+        }
+        if !self.is_out_of_flow() {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position) | Self::Global(absolute_position) = self {
             absolute_position.left = Some(value)
         }
     }
@@ -94,11 +180,18 @@ impl Position {
         &self,
         available_parent_area: &Area,
         parent_area: &Area,
+        root_area: &Area,
         area_size: &Size2D,
     ) -> Point2D {
         match self {
-            Position::Stacked => available_parent_area.origin,
-            Position::Absolute(absolute_position) => {
+            // Sticky Nodes are laid out like stacked ones and then moved once their parent is measured
+            Position::Stacked | Position::Sticky(_) => available_parent_area.origin,
+            Position::Absolute(absolute_position) | Position::Global(absolute_position) => {
+                let parent_area = if self.is_global() {
+                    root_area
+                } else {
+                    parent_area
+                };
                 let AbsolutePosition {
                     top,
                     right,
@@ -131,7 +224,15 @@ impl Position {
 
 impl Scaled for Position {
     fn scale(&mut self, scale_factor: f32) {
-        if let Self::Absolute(absolute_postion) = self {
+        if let Self::Sticky(sticky_position) = self {
+            if let Some(top) = &mut sticky_position.top {
+                *top *= scale_factor;
+            }
+            if let Some(left) = &mut sticky_position.left {
+                *left *= scale_factor;
+            }
+        }
+        if let Self::Absolute(absolute_postion) | Self::Global(absolute_postion) = self {
             if let Some(top) = &mut absolute_postion.top {
                 *top *= scale_factor;
             }
@@ -159,6 +260,18 @@ impl Position {
                 positions.bottom.unwrap_or_default(),
                 positions.left.unwrap_or_default()
             ),
+            Self::Sticky(positions) => format!(
+                "sticky, {}, {}",
+                positions.top.unwrap_or_default(),
+                positions.left.unwrap_or_default()
+            ),
+            Self::Global(positions) => format!(
+                "global, {}, {}, {}, {}",
+                positions.top.unwrap_or_default(),
+                positions.right.unwrap_or_default(),
+                positions.bottom.unwrap_or_default(),
+                positions.left.unwrap_or_default()
+            ),
         }
     }
 }
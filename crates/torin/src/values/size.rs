@@ -27,6 +27,26 @@ impl Default for Size {
 }
 
 impl Size {
+    /// Size of `value` pixels, like `"50"`.
+    pub fn pixels(value: f32) -> Self {
+        Self::Pixels(Length::new(value))
+    }
+
+    /// Size of `value` percent of the parent, like `"50%"`.
+    pub fn percentage(value: f32) -> Self {
+        Self::Percentage(Length::new(value))
+    }
+
+    /// Size of `value` percent of the root, like `"v50"`.
+    pub fn root_percentage(value: f32) -> Self {
+        Self::RootPercentage(Length::new(value))
+    }
+
+    /// Size of `value` percent of the inner Nodes, like `"a50"`.
+    pub fn inner_percentage(value: f32) -> Self {
+        Self::InnerPercentage(Length::new(value))
+    }
+
     pub fn inner_sized(&self) -> bool {
         matches!(
             self,
@@ -49,6 +69,10 @@ impl Size {
                     .map(|c| c.to_string())
                     .collect::<Vec<String>>()
                     .join(" ")
+                    // Functions are written like `min(50%, 200)`
+                    .replace("( ", "(")
+                    .replace(" ,", ",")
+                    .replace(" )", ")")
             ),
             Size::Percentage(p) => format!("{}%", p.get()),
             Size::Fill => "fill".to_string(),
@@ -65,12 +89,32 @@ impl Size {
         parent_margin: f32,
         root_value: f32,
         phase: Phase,
+    ) -> Option<f32> {
+        self.eval_with_box_padding(
+            parent_value,
+            available_parent_value,
+            parent_margin,
+            root_value,
+            0.0,
+            phase,
+        )
+    }
+
+    /// Same as [Size::eval] but adding the given padding on top of the declared sizes, see [BoxSizing](crate::prelude::BoxSizing).
+    pub fn eval_with_box_padding(
+        &self,
+        parent_value: f32,
+        available_parent_value: f32,
+        parent_margin: f32,
+        root_value: f32,
+        box_padding: f32,
+        phase: Phase,
     ) -> Option<f32> {
         match self {
-            Size::Pixels(px) => Some(px.get() + parent_margin),
-            Size::Percentage(per) => Some(parent_value / 100.0 * per.get()),
+            Size::Pixels(px) => Some(px.get() + parent_margin + box_padding),
+            Size::Percentage(per) => Some(parent_value / 100.0 * per.get() + box_padding),
             Size::DynamicCalculations(calculations) => {
-                Some(run_calculations(calculations.deref(), parent_value))
+                Some(run_calculations(calculations.deref(), parent_value) + box_padding)
             }
             Size::Fill => Some(available_parent_value),
             Size::FillMinimum => {
@@ -80,7 +124,7 @@ impl Size {
                     Some(available_parent_value)
                 }
             }
-            Size::RootPercentage(per) => Some(root_value / 100.0 * per.get()),
+            Size::RootPercentage(per) => Some(root_value / 100.0 * per.get() + box_padding),
             _ => None,
         }
     }
@@ -97,31 +141,63 @@ impl Size {
         maximum: &Self,
         root_value: f32,
         phase: Phase,
+    ) -> f32 {
+        self.min_max_with_box_padding(
+            value,
+            parent_value,
+            available_parent_value,
+            single_margin,
+            margin,
+            minimum,
+            maximum,
+            root_value,
+            0.0,
+            phase,
+        )
+    }
+
+    /// Same as [Size::min_max] but adding the given padding on top of the declared sizes, see [BoxSizing](crate::prelude::BoxSizing).
+    #[allow(clippy::too_many_arguments)]
+    pub fn min_max_with_box_padding(
+        &self,
+        value: f32,
+        parent_value: f32,
+        available_parent_value: f32,
+        single_margin: f32,
+        margin: f32,
+        minimum: &Self,
+        maximum: &Self,
+        root_value: f32,
+        box_padding: f32,
+        phase: Phase,
     ) -> f32 {
         let value = self
-            .eval(
+            .eval_with_box_padding(
                 parent_value,
                 available_parent_value,
                 margin,
                 root_value,
+                box_padding,
                 phase,
             )
             .unwrap_or(value + margin);
 
         let minimum_value = minimum
-            .eval(
+            .eval_with_box_padding(
                 parent_value,
                 available_parent_value,
                 margin,
                 root_value,
+                box_padding,
                 phase,
             )
             .map(|v| v + single_margin);
-        let maximum_value = maximum.eval(
+        let maximum_value = maximum.eval_with_box_padding(
             parent_value,
             available_parent_value,
             margin,
             root_value,
+            box_padding,
             phase,
         );
 
@@ -139,7 +215,12 @@ impl Size {
             }
         }
 
-        final_value
+        // Sizes that resolve to negative or invalid values, e.g `calc(50% - 200)` in a small parent, are empty
+        if final_value.is_nan() {
+            0.0
+        } else {
+            final_value.max(0.0)
+        }
     }
 
     pub fn most_fitting_size<'a>(&self, size: &'a f32, available_size: &'a f32) -> &'a f32 {
@@ -170,6 +251,18 @@ pub enum DynamicCalculation {
     Add,
     Percentage(f32),
     Pixels(f32),
+    /// Start of a function with the smallest value of its arguments.
+    /// They are separated by [DynamicCalculation::FunctionSeparator] and closed by [DynamicCalculation::FunctionEnd].
+    Min,
+    /// Start of a function with the biggest value of its arguments, like [DynamicCalculation::Min].
+    Max,
+    /// Start of a function with the preferred value (second argument) kept between the minimum (first)
+    /// and maximum (third) values, which is `max(minimum, min(preferred, maximum))`.
+    Clamp,
+    /// Separator of the arguments of a function.
+    FunctionSeparator,
+    /// End of a function.
+    FunctionEnd,
 }
 
 impl Scaled for DynamicCalculation {
@@ -189,6 +282,11 @@ impl std::fmt::Display for DynamicCalculation {
             DynamicCalculation::Add => f.write_str("+"),
             DynamicCalculation::Percentage(p) => f.write_fmt(format_args!("{p}%")),
             DynamicCalculation::Pixels(s) => f.write_fmt(format_args!("{s}")),
+            DynamicCalculation::Min => f.write_str("min("),
+            DynamicCalculation::Max => f.write_str("max("),
+            DynamicCalculation::Clamp => f.write_str("clamp("),
+            DynamicCalculation::FunctionSeparator => f.write_str(","),
+            DynamicCalculation::FunctionEnd => f.write_str(")"),
         }
     }
 }
@@ -196,47 +294,71 @@ impl std::fmt::Display for DynamicCalculation {
 /// Calculate some chained operations with a given value.
 /// This value could be for example the width of a node's parent area.
 pub fn run_calculations(calcs: &[DynamicCalculation], value: f32) -> f32 {
+    run_operations(&mut calcs.iter(), value).0
+}
+
+/// Run the chained operations until the end of the calculations or of the current function argument.
+/// Returns the result and the separator or end of the function where it stopped, if any.
+fn run_operations<'a>(
+    calcs: &mut impl Iterator<Item = &'a DynamicCalculation>,
+    value: f32,
+) -> (f32, Option<DynamicCalculation>) {
     let mut prev_number: Option<f32> = None;
     let mut prev_op: Option<DynamicCalculation> = None;
 
-    let mut calc_with_op = |val: f32, prev_op: Option<DynamicCalculation>| {
-        if let Some(op) = prev_op {
-            match op {
-                DynamicCalculation::Sub => {
-                    prev_number = Some(prev_number.unwrap() - val);
-                }
-                DynamicCalculation::Add => {
-                    prev_number = Some(prev_number.unwrap() + val);
-                }
-                DynamicCalculation::Mul => {
-                    prev_number = Some(prev_number.unwrap() * val);
-                }
-                DynamicCalculation::Div => {
-                    prev_number = Some(prev_number.unwrap() / val);
-                }
-                _ => {}
-            }
-        } else {
-            prev_number = Some(val);
+    let calc_with_op = |prev_number: Option<f32>, val: f32, prev_op: Option<DynamicCalculation>| {
+        let Some(op) = prev_op else {
+            return val;
+        };
+        let prev_number = prev_number.unwrap_or_default();
+        match op {
+            DynamicCalculation::Sub => prev_number - val,
+            DynamicCalculation::Add => prev_number + val,
+            DynamicCalculation::Mul => prev_number * val,
+            // Dividing by zero would result in an infinite or invalid size
+            DynamicCalculation::Div if val == 0.0 => 0.0,
+            DynamicCalculation::Div => prev_number / val,
+            _ => prev_number,
         }
     };
 
-    for calc in calcs {
-        match calc {
-            DynamicCalculation::Percentage(per) => {
-                let val = (value / 100.0 * per).round();
-
-                calc_with_op(val, prev_op);
+    while let Some(calc) = calcs.next() {
+        let val = match calc {
+            DynamicCalculation::Percentage(per) => (value / 100.0 * per).round(),
+            DynamicCalculation::Pixels(val) => *val,
+            DynamicCalculation::Min | DynamicCalculation::Max | DynamicCalculation::Clamp => {
+                let mut args = Vec::new();
+                loop {
+                    let (arg, end) = run_operations(calcs, value);
+                    args.push(arg);
+                    if end != Some(DynamicCalculation::FunctionSeparator) {
+                        break;
+                    }
+                }
 
-                prev_op = None;
+                match (calc, args.as_slice()) {
+                    (DynamicCalculation::Min, _) => {
+                        args.iter().copied().fold(f32::INFINITY, f32::min)
+                    }
+                    (DynamicCalculation::Max, _) => {
+                        args.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+                    }
+                    (_, [minimum, preferred, maximum]) => minimum.max(preferred.min(*maximum)),
+                    _ => 0.0,
+                }
             }
-            DynamicCalculation::Pixels(val) => {
-                calc_with_op(*val, prev_op);
-                prev_op = None;
+            DynamicCalculation::FunctionSeparator | DynamicCalculation::FunctionEnd => {
+                return (prev_number.unwrap_or_default(), Some(*calc));
             }
-            _ => prev_op = Some(*calc),
-        }
+            _ => {
+                prev_op = Some(*calc);
+                continue;
+            }
+        };
+
+        prev_number = Some(calc_with_op(prev_number, val, prev_op));
+        prev_op = None;
     }
 
-    prev_number.unwrap()
+    (prev_number.unwrap_or_default(), None)
 }
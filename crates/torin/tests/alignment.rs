@@ -103,12 +103,12 @@ pub fn display_vertical_with_inner_children() {
 
     assert_eq!(
         layout.get(1).unwrap().area,
-        Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
+        Rect::new(Point2D::new(45.0, 45.0), Size2D::new(110.0, 110.0)),
     );
 
     assert_eq!(
         layout.get(2).unwrap().area,
-        Rect::new(Point2D::new(55.0, 55.0), Size2D::new(90.0, 90.0)),
+        Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
     );
 }
 
@@ -59,3 +59,119 @@ pub fn margin() {
         Rect::new(Point2D::new(5.0, 5.0), Size2D::new(200.0, 200.0)),
     );
 }
+
+#[test]
+pub fn auto_margin() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(100.0)),
+            auto_margin: AutoMargins::new(false, true, false, true),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(100.0)),
+            margin: Gaps::new(0.0, 50.0, 0.0, 0.0),
+            auto_margin: AutoMargins::new(false, false, false, true),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Centered horizontally in the parent
+    let layout_node = layout.get(1).unwrap();
+    assert_eq!(
+        layout_node.visible_area(),
+        Rect::new(Point2D::new(400.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(layout_node.margin, Gaps::new(0.0, 400.0, 0.0, 400.0));
+
+    // Pushed to the right, next to its fixed margin
+    assert_eq!(
+        layout.get(2).unwrap().visible_area(),
+        Rect::new(Point2D::new(750.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn auto_margin_main_axis() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(100.0)),
+            auto_margin: AutoMargins::new(true, true, true, true),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Only centered in the cross axis, the main axis is left for the next siblings
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(400.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+}
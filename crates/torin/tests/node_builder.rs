@@ -0,0 +1,51 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn node_builder() {
+    let node = Node::new()
+        .with_width(Size::pixels(200.0))
+        .with_height(Size::pixels(200.0))
+        .with_padding(Gaps::new(5.0, 10.0, 15.0, 20.0))
+        .with_main_alignment(Alignment::Center)
+        .with_direction(DirectionMode::Horizontal);
+
+    assert_eq!(
+        node,
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(200.0)),
+            padding: Gaps::new(5.0, 10.0, 15.0, 20.0),
+            main_alignment: Alignment::Center,
+            direction: DirectionMode::Horizontal,
+            ..Default::default()
+        }
+    );
+
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1], node);
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::new()
+            .with_width(Size::percentage(50.0))
+            .with_height(Size::percentage(50.0)),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(62.5, 5.0), Size2D::new(85.0, 90.0)),
+    );
+}
@@ -40,7 +40,7 @@ pub fn caching() {
 
     assert_eq!(
         layout.get(1).unwrap().area,
-        Rect::new(Point2D::new(0.0, 5.0), Size2D::new(200.0, 195.0)),
+        Rect::new(Point2D::new(0.0, 5.0), Size2D::new(200.0, 200.0)),
     );
 
     mocked_dom.set_node(
@@ -62,7 +62,7 @@ pub fn caching() {
 
     assert_eq!(
         layout.get(1).unwrap().area,
-        Rect::new(Point2D::new(0.0, 5.0), Size2D::new(100.0, 195.0)),
+        Rect::new(Point2D::new(0.0, 5.0), Size2D::new(100.0, 200.0)),
     );
 }
 
@@ -38,6 +38,6 @@ pub fn padding() {
 
     assert_eq!(
         layout.get(1).unwrap().area,
-        Rect::new(Point2D::new(20.0, 5.0), Size2D::new(170.0, 180.0)),
+        Rect::new(Point2D::new(20.0, 5.0), Size2D::new(200.0, 200.0)),
     );
 }
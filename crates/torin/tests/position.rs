@@ -103,14 +103,219 @@ pub fn position() {
     );
     assert_eq!(
         layout.get(3).unwrap().area.round(),
-        Rect::new(Point2D::new(700.0, 150.0), Size2D::new(200.0, 200.0)),
+        Rect::new(Point2D::new(800.0, 150.0), Size2D::new(200.0, 200.0)),
     );
     assert_eq!(
         layout.get(4).unwrap().area.round(),
-        Rect::new(Point2D::new(700.0, 650.0), Size2D::new(200.0, 200.0)),
+        Rect::new(Point2D::new(800.0, 750.0), Size2D::new(200.0, 200.0)),
     );
     assert_eq!(
         layout.get(5).unwrap().area.round(),
-        Rect::new(Point2D::new(100.0, 650.0), Size2D::new(200.0, 200.0)),
+        Rect::new(Point2D::new(100.0, 750.0), Size2D::new(200.0, 200.0)),
     );
 }
+
+#[test]
+pub fn global_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Gaps::new(20.0, 20.0, 20.0, 20.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_padding(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Gaps::new(30.0, 30.0, 30.0, 30.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Position::Global(Box::new(AbsolutePosition {
+                top: Some(100.0),
+                right: None,
+                bottom: None,
+                left: Some(50.0),
+            })),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Position::Global(Box::new(AbsolutePosition {
+                top: None,
+                right: Some(50.0),
+                bottom: Some(100.0),
+                left: None,
+            })),
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Globally positioned Nodes ignore the paddings of their ancestors
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(50.0, 100.0), Size2D::new(200.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area.round(),
+        Rect::new(Point2D::new(750.0, 700.0), Size2D::new(200.0, 200.0)),
+    );
+    // And don't take space from their siblings
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn sticky_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let sticky_header = || {
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Position::Sticky(Box::new(StickyPosition {
+                top: Some(0.0),
+                left: None,
+            })),
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(500.0)),
+            Length::new(0.0),
+            Length::new(-300.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![4, 5],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![6, 7],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(4, Some(2), vec![], sticky_header());
+    mocked_dom.add(
+        5,
+        Some(2),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(350.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(6, Some(3), vec![], sticky_header());
+    mocked_dom.add(
+        7,
+        Some(3),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(350.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first header is pinned to the top of the viewport
+    assert_eq!(layout.get(2).unwrap().area.min_y(), -300.0);
+    assert_eq!(layout.get(4).unwrap().area.min_y(), 0.0);
+    assert_eq!(layout.get(5).unwrap().area.min_y(), -250.0);
+    assert_eq!(layout.get(6).unwrap().area.min_y(), 100.0);
+
+    mocked_dom.set_node(
+        1,
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(500.0)),
+            Length::new(0.0),
+            Length::new(-380.0),
+        ),
+    );
+    layout.invalidate(1);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first header is pushed away by the end of its section
+    assert_eq!(layout.get(4).unwrap().area.min_y(), -30.0);
+    assert_eq!(layout.get(6).unwrap().area.min_y(), 20.0);
+}
@@ -777,3 +777,258 @@ pub fn inner_percentage() {
         Rect::new(Point2D::new(0.0, 300.0), Size2D::new(100.0, 100.0)),
     );
 }
+
+#[test]
+pub fn negative_and_invalid_sizes() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::DynamicCalculations(Box::new(vec![
+                DynamicCalculation::Percentage(50.0),
+                DynamicCalculation::Sub,
+                DynamicCalculation::Pixels(200.0),
+            ])),
+            Size::DynamicCalculations(Box::new(vec![
+                DynamicCalculation::Percentage(50.0),
+                DynamicCalculation::Sub,
+                DynamicCalculation::Pixels(200.0),
+            ])),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::DynamicCalculations(Box::new(vec![
+                DynamicCalculation::Pixels(100.0),
+                DynamicCalculation::Div,
+                DynamicCalculation::Pixels(0.0),
+            ])),
+            Size::Pixels(Length::new(20.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node {
+            box_sizing: BoxSizing::BorderBox,
+            ..Node::from_size_and_padding(
+                Size::Pixels(Length::new(20.0)),
+                Size::Pixels(Length::new(20.0)),
+                Gaps::new(30.0, 30.0, 30.0, 30.0),
+            )
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Negative sizes are clamped to empty boxes
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(0.0, 0.0)),
+    );
+    // Dividing by zero doesn't result in an infinite or invalid size
+    assert_eq!(
+        layout.get(2).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(0.0, 20.0)),
+    );
+    // Paddings bigger than a `border-box` Node leave an empty inner area, but the Node still takes its place
+    let node = layout.get(3).unwrap();
+    assert_eq!(
+        node.visible_area(),
+        Rect::new(Point2D::new(0.0, 20.0), Size2D::new(20.0, 20.0)),
+    );
+    assert_eq!(node.inner_area.size, Size2D::new(0.0, 0.0));
+}
+
+#[test]
+pub fn calc_functions_sizes() {
+    // clamp(200, 50%, 600)
+    let width = Size::DynamicCalculations(Box::new(vec![
+        DynamicCalculation::Clamp,
+        DynamicCalculation::Pixels(200.0),
+        DynamicCalculation::FunctionSeparator,
+        DynamicCalculation::Percentage(50.0),
+        DynamicCalculation::FunctionSeparator,
+        DynamicCalculation::Pixels(600.0),
+        DynamicCalculation::FunctionEnd,
+    ]));
+    // calc(min(50%, 300) + max(10, 10%))
+    let height = Size::DynamicCalculations(Box::new(vec![
+        DynamicCalculation::Min,
+        DynamicCalculation::Percentage(50.0),
+        DynamicCalculation::FunctionSeparator,
+        DynamicCalculation::Pixels(300.0),
+        DynamicCalculation::FunctionEnd,
+        DynamicCalculation::Add,
+        DynamicCalculation::Max,
+        DynamicCalculation::Pixels(10.0),
+        DynamicCalculation::FunctionSeparator,
+        DynamicCalculation::Percentage(10.0),
+        DynamicCalculation::FunctionEnd,
+    ]));
+
+    for (parent_size, expected_size) in [
+        (200.0, Size2D::new(200.0, 120.0)),
+        (800.0, Size2D::new(400.0, 380.0)),
+        (2000.0, Size2D::new(600.0, 500.0)),
+    ] {
+        let (mut layout, mut measurer) = test_utils();
+
+        let mut mocked_dom = TestingDOM::default();
+        mocked_dom.add(
+            0,
+            None,
+            vec![1],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(parent_size)),
+                Size::Pixels(Length::new(parent_size)),
+                DirectionMode::Vertical,
+            ),
+        );
+        mocked_dom.add(
+            1,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(width.clone(), height.clone(), DirectionMode::Vertical),
+        );
+
+        layout.measure(
+            0,
+            Rect::new(Point2D::new(0.0, 0.0), Size2D::new(2000.0, 2000.0)),
+            &mut measurer,
+            &mut mocked_dom,
+        );
+
+        assert_eq!(layout.get(1).unwrap().visible_area().size, expected_size);
+    }
+}
+
+#[test]
+pub fn percentage_of_parent_with_padding() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            Gaps::new(50.0, 50.0, 50.0, 50.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Percentages are resolved against the content box of the parent, so they don't overflow its padding
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 400.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(50.0, 50.0), Size2D::new(300.0, 150.0)),
+    );
+}
+
+#[test]
+pub fn box_sizing() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_padding(
+            Size::Pixels(Length::new(100.0)),
+            Size::Percentage(Length::new(25.0)),
+            Gaps::new(10.0, 10.0, 10.0, 10.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            box_sizing: BoxSizing::BorderBox,
+            ..Node::from_size_and_padding(
+                Size::Pixels(Length::new(100.0)),
+                Size::Percentage(Length::new(25.0)),
+                Gaps::new(10.0, 10.0, 10.0, 10.0),
+            )
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The padding goes on top of the declared size by default
+    assert_eq!(layout.get(1).unwrap().area.size, Size2D::new(120.0, 120.0));
+    assert_eq!(
+        layout.get(1).unwrap().inner_area.size,
+        Size2D::new(100.0, 100.0)
+    );
+
+    // And it's part of it with `border-box`
+    assert_eq!(layout.get(2).unwrap().area.size, Size2D::new(100.0, 100.0));
+    assert_eq!(
+        layout.get(2).unwrap().inner_area.size,
+        Size2D::new(80.0, 80.0)
+    );
+}
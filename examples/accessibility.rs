@@ -25,6 +25,7 @@ fn app() -> Element {
         rect {
             focus_id: focus_a.attribute(),
             background: "rgb(233, 196, 106)",
+            box_sizing: "border-box",
             padding: "25",
             width: "50%",
             height: "50%",
@@ -51,6 +52,7 @@ fn app() -> Element {
         rect {
             focus_id: focus_b.attribute(),
             background: "rgb(150, 100, 231)",
+            box_sizing: "border-box",
             padding: "25",
             width: "100%",
             height: "50%",
@@ -40,6 +40,7 @@ fn app() -> Element {
                         main_align: "space-evenly",
                         cross_align: "center",
                         background: "orange",
+                        box_sizing: "border-box",
                         padding: "15",
                         height: "70%",
                         rect {
@@ -18,6 +18,7 @@ fn app() -> Element {
             background: "rgb(30, 30, 30)",
             color: "rgb(240, 240, 240)",
             direction: "horizontal",
+            box_sizing: "border-box",
             padding: "14",
             border: "3 solid rgb(242, 76, 61)",
             rect {
@@ -23,6 +23,7 @@ fn app() -> Element {
         rect {
             width: "100%",
             height: "100%",
+            box_sizing: "border-box",
             padding: "50",
             if let Some(err) = &*camera_error.read() {
                 label {
@@ -49,6 +49,7 @@ fn Body() -> Element {
         rect {
             width: "100%",
             height: "100%",
+            box_sizing: "border-box",
             padding: "10",
             onkeydown,
             onkeyup,
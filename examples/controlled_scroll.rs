@@ -70,6 +70,7 @@ fn Card() -> Element {
             height: "220",
             width: "420",
             background: "white",
+            box_sizing: "border-box",
             padding: "25",
             label {  "Scroll..." }
         }
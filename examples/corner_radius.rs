@@ -22,6 +22,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "100%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "60",
             onwheel: onwheel,
             rect {
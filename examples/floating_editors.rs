@@ -98,6 +98,7 @@ fn app() -> Element {
                                         width: "600",
                                         height: "400",
                                         corner_radius: "15",
+                                        box_sizing: "border-box",
                                         padding: "10",
                                         shadow: "0 0 30 0 rgb(0, 0, 0, 150)",
                                         onmousedown:  move |e: MouseEvent| {
@@ -125,6 +126,7 @@ fn app() -> Element {
                 width: "100%",
                 main_align: "center",
                 cross_align: "center",
+                box_sizing: "border-box",
                 padding: "15",
                 layer: "-100",
                 shadow: "0 -2 5 0 rgb(0, 0, 0, 0.1)",
@@ -208,6 +210,7 @@ fn Editor() -> Element {
             rect {
                 width: "100%",
                 height: "70",
+                box_sizing: "border-box",
                 padding: "5",
                 direction: "horizontal",
                 cross_align: "center",
@@ -269,6 +272,7 @@ fn Editor() -> Element {
             rect {
                 width: "100%",
                 height: "calc(100% - 80)",
+                box_sizing: "border-box",
                 padding: "5",
                 onkeydown,
                 onkeyup,
@@ -277,6 +281,7 @@ fn Editor() -> Element {
                 rect {
                     width: "100%",
                     height: "100%",
+                    box_sizing: "border-box",
                     padding: "5",
                     ScrollView {
                         scroll_with_arrows: false,
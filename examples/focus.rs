@@ -19,6 +19,7 @@ fn Child() -> Element {
             height: "60",
             focus_id: focus_manager.attribute(),
             background: "rgb(45, 45, 45)",
+            box_sizing: "border-box",
             padding: "10",
             color: "white",
             onclick: move |_| {
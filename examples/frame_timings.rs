@@ -0,0 +1,42 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+// Run with `cargo run --example frame_timings --features frame-timings`
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Frame Timings", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let timings = use_frame_timings();
+    let timings = timings.read();
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            box_sizing: "border-box",
+            padding: "10",
+            label {
+                font_size: "24",
+                "{timings.fps} FPS"
+            }
+            label {
+                "Frame: {timings.frame:?}"
+            }
+            label {
+                "DOM Updates: {timings.dom_updates:?}"
+            }
+            label {
+                "Layout: {timings.layout:?}"
+            }
+            label {
+                "Render: {timings.render:?}"
+            }
+        }
+    )
+}
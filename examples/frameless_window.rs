@@ -20,6 +20,7 @@ fn app() -> Element {
     rsx!(
         rect {
             background: "white",
+            box_sizing: "border-box",
             padding: "10",
             main_align: "center",
             cross_align: "center",
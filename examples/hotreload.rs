@@ -20,6 +20,7 @@ fn app() -> Element {
         rect {
             overflow: "clip",
             background: "rgb(15, 15, 15)",
+            box_sizing: "border-box",
             padding: "50",
             direction: "horizontal",
             width: "auto",
@@ -29,6 +30,7 @@ fn app() -> Element {
             },
             rect {
                 overflow: "clip",
+                box_sizing: "border-box",
                 padding: "50",
                 height: "100%",
                 width: "50%",
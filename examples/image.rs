@@ -28,6 +28,7 @@ fn app() -> Element {
         rect {
             width: "100%",
             height: "100%",
+            box_sizing: "border-box",
             padding: "50",
             main_align: "center",
             cross_align: "center",
@@ -15,6 +15,7 @@ fn app() -> Element {
     rsx!(
         rect {
             overflow: "clip",
+            box_sizing: "border-box",
             padding: "7",
             width: "100%",
             height: "100%",
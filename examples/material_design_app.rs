@@ -57,6 +57,7 @@ fn FloatingButton(FloatingButtonProps { children }: FloatingButtonProps) -> Elem
             width: "50",
             background: "rgb(104, 24, 245)",
             shadow: "0 0 15 3 rgb(0, 0, 0, 60)",
+            box_sizing: "border-box",
             padding: "15",
             color: "white",
             font_size: "22",
@@ -132,6 +133,7 @@ fn Navbar(NavbarProps { title }: NavbarProps) -> Element {
             rect {
                 width: "100%",
                 direction: "horizontal",
+                box_sizing: "border-box",
                 padding: "0 20",
                 font_size: "20",
                 {title}
@@ -155,6 +157,7 @@ fn Card(CardProps { title, content }: CardProps) -> Element {
             height: "200",
             background: "rgb(240, 240, 240)",
             shadow: "0 0 15 3 rgb(0, 0, 0, 60)",
+            box_sizing: "border-box",
             padding: "15",
             corner_radius: "8",
             label {
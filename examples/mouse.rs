@@ -51,6 +51,7 @@ fn Area() -> Element {
             height: "50%",
             width: "100%",
             background: "blue",
+            box_sizing: "border-box",
             padding: "5",
             onmouseover: cursor_moved,
             onclick: cursor_clicked,
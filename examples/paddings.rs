@@ -15,6 +15,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "25%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "15",
             background: "black",
             rect {
@@ -27,6 +28,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "25%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "10 30 50 70",
             background: "gray",
             rect {
@@ -39,6 +41,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "25%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "25 125",
             background: "black",
             rect {
@@ -51,6 +54,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "25%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "30 50 10",
             background: "gray",
             rect {
@@ -40,6 +40,7 @@ fn app() -> Element {
             height: "100%",
             width: "100%",
             background: "rgb(100, 100, 100)",
+            box_sizing: "border-box",
             padding: "12",
             rect {
                 overflow: "clip",
@@ -47,6 +48,7 @@ fn app() -> Element {
                 width: "100%",
                 background: "rgb(168, 218, 220)",
                 color: "black",
+                box_sizing: "border-box",
                 padding: "12",
                 onpointerdown,
                 onpointerup,
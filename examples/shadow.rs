@@ -15,6 +15,7 @@ fn app() -> Element {
             overflow: "clip",
             height: "100%",
             width: "100%",
+            box_sizing: "border-box",
             padding: "60",
             background: "rgb(224, 224, 224)",
             rect {
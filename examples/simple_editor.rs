@@ -65,6 +65,7 @@ fn app() -> Element {
                     main_align: "center",
                     cursor_id: "0",
                     cursor_index: "{cursor_char}",
+                    cursor_affinity: "{editable.cursor_affinity()}",
                     cursor_mode: "editable",
                     cursor_color: "black",
                     highlights,
@@ -0,0 +1,48 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(400.0, 300.0)
+            .with_title("Software rendering")
+            .with_software_rendering(true),
+    );
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let mut count = use_signal(|| 0);
+    let mut renderer = use_signal(|| "...");
+
+    use_hook(move || {
+        spawn(async move {
+            // The GPU resources cache is only available when rendered with the GPU
+            match platform.gpu_resource_cache_usage().await {
+                Some(_) => renderer.set("GPU"),
+                None => renderer.set("CPU"),
+            }
+        });
+    });
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label {
+                "Rendered with the {renderer}"
+            }
+            Button {
+                onpress: move |_| count += 1,
+                label { "Clicked {count} times" }
+            }
+        }
+    )
+}
@@ -83,6 +83,7 @@ fn Navbar(NavbarProps { title }: NavbarProps) -> Element {
             height: "75",
             width: "100%",
             background: "rgb(20, 20, 20)",
+            box_sizing: "border-box",
             padding: "15",
             main_align: "center",
             cross_align: "center",
@@ -129,11 +130,13 @@ fn Card(props: CardProps) -> Element {
         rect {
             width: "100%",
             height: "200",
+            box_sizing: "border-box",
             padding: "10",
             background: "rgb(35, 35, 35)",
             rect {
                 width: "100%",
                 height: "50%",
+                box_sizing: "border-box",
                 padding: "5",
                 corner_radius: "10",
                 main_align: "center",
@@ -179,6 +182,7 @@ fn Area() -> Element {
             height: "50%",
             width: "100%",
             background: "rgb(228, 143, 69)",
+            box_sizing: "border-box",
             padding: "10",
             corner_radius: "12",
             main_align: "center",
@@ -0,0 +1,33 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(400.0, 300.0)
+            .with_blur(true)
+            .with_background("rgb(255, 255, 255, 0.4)")
+            .with_title("Blurred window"),
+    );
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            width: "100%",
+            height: "100%",
+            label {
+                color: "black",
+                font_size: "20",
+                "The desktop is blurred behind this window"
+            }
+        }
+    )
+}